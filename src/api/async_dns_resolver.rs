@@ -0,0 +1,175 @@
+use crate::{SocketAddressRef, ffi};
+use std::net::IpAddr;
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+
+/// 名前解決で要求されたアドレスファミリー。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressFamily {
+    /// ファミリーを問わない。
+    Unspecified,
+    Inet,
+    Inet6,
+    Unknown(i32),
+}
+
+impl AddressFamily {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == 0 {
+                AddressFamily::Unspecified
+            } else if value == ffi::webrtc_AF_INET {
+                AddressFamily::Inet
+            } else if value == ffi::webrtc_AF_INET6 {
+                AddressFamily::Inet6
+            } else {
+                AddressFamily::Unknown(value)
+            }
+        }
+    }
+}
+
+/// 1 回の名前解決要求。
+///
+/// resolve / fail のどちらも呼ばずに破棄した場合は解決失敗として扱う。
+/// 任意のスレッドへ移して完了させてよい。resolver が破棄された後に
+/// 完了した場合、結果は捨てられる。
+pub struct AsyncDnsResolveRequest {
+    raw_unique: NonNull<ffi::webrtc_AsyncDnsResolveRequest_unique>,
+}
+
+unsafe impl Send for AsyncDnsResolveRequest {}
+
+impl AsyncDnsResolveRequest {
+    /// 解決した IP アドレスの一覧で完了する。一覧が空の場合は失敗になる。
+    pub fn resolve(self, addresses: &[IpAddr]) {
+        if addresses.is_empty() {
+            self.complete(-1);
+            return;
+        }
+        let raw =
+            unsafe { ffi::webrtc_AsyncDnsResolveRequest_unique_get(self.raw_unique.as_ptr()) };
+        for address in addresses {
+            match address {
+                IpAddr::V4(v4) => unsafe {
+                    ffi::webrtc_AsyncDnsResolveRequest_AddIPv4Address(raw, v4.octets().as_ptr())
+                },
+                IpAddr::V6(v6) => unsafe {
+                    ffi::webrtc_AsyncDnsResolveRequest_AddIPv6Address(raw, v6.octets().as_ptr())
+                },
+            }
+        }
+        self.complete(0);
+    }
+
+    /// error (0 以外) で失敗として完了する。
+    pub fn fail(self, error: i32) {
+        assert!(error != 0, "error に 0 は指定できません");
+        self.complete(error);
+    }
+
+    fn complete(self, error: i32) {
+        let raw = std::mem::ManuallyDrop::new(self).raw_unique.as_ptr();
+        unsafe { ffi::webrtc_AsyncDnsResolveRequest_Complete(raw, error) };
+    }
+}
+
+impl Drop for AsyncDnsResolveRequest {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_AsyncDnsResolveRequest_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
+
+/// 名前解決の実装。
+///
+/// resolve は network thread から呼ばれる。結果は request に通知する。
+pub trait AsyncDnsResolverHandler: Send {
+    fn resolve(
+        &mut self,
+        address: SocketAddressRef<'_>,
+        family: AddressFamily,
+        request: AsyncDnsResolveRequest,
+    );
+}
+
+struct AsyncDnsResolverHandlerState {
+    handler: Box<dyn AsyncDnsResolverHandler>,
+}
+
+unsafe impl Send for AsyncDnsResolverHandlerState {}
+
+unsafe extern "C" fn async_dns_resolver_resolve(
+    address: *const ffi::webrtc_SocketAddress,
+    family: i32,
+    request: *mut ffi::webrtc_AsyncDnsResolveRequest_unique,
+    user_data: *mut c_void,
+) {
+    assert!(
+        !user_data.is_null(),
+        "async_dns_resolver_resolve: user_data is null"
+    );
+    let state = unsafe { &mut *(user_data as *mut AsyncDnsResolverHandlerState) };
+    let address =
+        NonNull::new(address as *mut ffi::webrtc_SocketAddress).expect("BUG: address が null です");
+    let request = AsyncDnsResolveRequest {
+        raw_unique: NonNull::new(request).expect("BUG: request が null です"),
+    };
+    state.handler.resolve(
+        SocketAddressRef::from_raw(address),
+        AddressFamily::from_int(family),
+        request,
+    );
+}
+
+unsafe extern "C" fn async_dns_resolver_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "async_dns_resolver_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut AsyncDnsResolverHandlerState) };
+}
+
+/// Rust で実装する webrtc::AsyncDnsResolverFactoryInterface。
+///
+/// PeerConnectionDependencies::set_async_dns_resolver_factory でリモートの
+/// mDNS candidate の解決に、PacketSocketFactory::set_async_dns_resolver_factory で
+/// STUN / TURN サーバーのホスト名解決に使う。
+pub struct AsyncDnsResolverFactory {
+    raw_unique: NonNull<ffi::webrtc_AsyncDnsResolverFactory_unique>,
+}
+
+unsafe impl Send for AsyncDnsResolverFactory {}
+
+impl AsyncDnsResolverFactory {
+    pub fn new_with_handler(handler: Box<dyn AsyncDnsResolverHandler>) -> Self {
+        let state = Box::new(AsyncDnsResolverHandlerState { handler });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_AsyncDnsResolverFactory_cbs {
+            Resolve: Some(async_dns_resolver_resolve),
+            OnDestroy: Some(async_dns_resolver_on_destroy),
+        };
+        let raw = unsafe { ffi::webrtc_AsyncDnsResolverFactory_new(&cbs, user_data) };
+        let raw_unique = match NonNull::new(raw) {
+            Some(raw_unique) => raw_unique,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut AsyncDnsResolverHandlerState) };
+                panic!("BUG: webrtc_AsyncDnsResolverFactory_new が null を返しました");
+            }
+        };
+        Self { raw_unique }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_AsyncDnsResolverFactory {
+        unsafe { ffi::webrtc_AsyncDnsResolverFactory_unique_get(self.raw_unique.as_ptr()) }
+    }
+
+    pub fn into_raw(self) -> *mut ffi::webrtc_AsyncDnsResolverFactory_unique {
+        std::mem::ManuallyDrop::new(self).raw_unique.as_ptr()
+    }
+}
+
+impl Drop for AsyncDnsResolverFactory {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_AsyncDnsResolverFactory_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
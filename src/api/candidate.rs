@@ -0,0 +1,385 @@
+use crate::{CxxString, Result, SocketAddress, SocketAddressRef, ffi};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/// ICE candidate の種別。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IceCandidateType {
+    Host,
+    Srflx,
+    Prflx,
+    Relay,
+    Unknown(i32),
+}
+
+impl IceCandidateType {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_IceCandidateType_kHost {
+                IceCandidateType::Host
+            } else if value == ffi::webrtc_IceCandidateType_kSrflx {
+                IceCandidateType::Srflx
+            } else if value == ffi::webrtc_IceCandidateType_kPrflx {
+                IceCandidateType::Prflx
+            } else if value == ffi::webrtc_IceCandidateType_kRelay {
+                IceCandidateType::Relay
+            } else {
+                IceCandidateType::Unknown(value)
+            }
+        }
+    }
+
+    pub fn to_int(self) -> i32 {
+        unsafe {
+            match self {
+                IceCandidateType::Host => ffi::webrtc_IceCandidateType_kHost,
+                IceCandidateType::Srflx => ffi::webrtc_IceCandidateType_kSrflx,
+                IceCandidateType::Prflx => ffi::webrtc_IceCandidateType_kPrflx,
+                IceCandidateType::Relay => ffi::webrtc_IceCandidateType_kRelay,
+                IceCandidateType::Unknown(value) => value,
+            }
+        }
+    }
+}
+
+fn borrowed_string(ptr: *const std::os::raw::c_char, len: usize) -> Result<String> {
+    assert!(!ptr.is_null());
+    let bytes = unsafe { std::slice::from_raw_parts(ptr.cast::<u8>(), len) };
+    Ok(std::str::from_utf8(bytes)?.to_owned())
+}
+
+/// webrtc::Candidate の借用ラッパー。
+pub struct CandidateRef<'a> {
+    raw: NonNull<ffi::webrtc_Candidate>,
+    _marker: PhantomData<&'a ffi::webrtc_Candidate>,
+}
+
+unsafe impl<'a> Send for CandidateRef<'a> {}
+
+impl<'a> CandidateRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_Candidate>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_Candidate {
+        self.raw.as_ptr()
+    }
+
+    pub fn foundation(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_Candidate_foundation(self.raw.as_ptr(), &mut ptr, &mut len) };
+        borrowed_string(ptr, len)
+    }
+
+    /// RTP は 1、RTCP は 2。
+    pub fn component(&self) -> i32 {
+        unsafe { ffi::webrtc_Candidate_component(self.raw.as_ptr()) }
+    }
+
+    /// "udp" / "tcp" など。
+    pub fn protocol(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_Candidate_protocol(self.raw.as_ptr(), &mut ptr, &mut len) };
+        borrowed_string(ptr, len)
+    }
+
+    pub fn priority(&self) -> u32 {
+        unsafe { ffi::webrtc_Candidate_priority(self.raw.as_ptr()) }
+    }
+
+    pub fn address(&self) -> SocketAddressRef<'a> {
+        let raw = unsafe { ffi::webrtc_Candidate_address(self.raw.as_ptr()) };
+        SocketAddressRef::from_raw(
+            NonNull::new(raw as *mut ffi::webrtc_SocketAddress)
+                .expect("BUG: webrtc_Candidate_address が null を返しました"),
+        )
+    }
+
+    pub fn candidate_type(&self) -> IceCandidateType {
+        IceCandidateType::from_int(unsafe { ffi::webrtc_Candidate_type(self.raw.as_ptr()) })
+    }
+
+    /// srflx / prflx / relay の場合の関連アドレス (raddr / rport)。
+    pub fn related_address(&self) -> SocketAddressRef<'a> {
+        let raw = unsafe { ffi::webrtc_Candidate_related_address(self.raw.as_ptr()) };
+        SocketAddressRef::from_raw(
+            NonNull::new(raw as *mut ffi::webrtc_SocketAddress)
+                .expect("BUG: webrtc_Candidate_related_address が null を返しました"),
+        )
+    }
+
+    /// TCP candidate の種別 ("active" / "passive" / "so")。UDP の場合は空文字列。
+    pub fn tcptype(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_Candidate_tcptype(self.raw.as_ptr(), &mut ptr, &mut len) };
+        borrowed_string(ptr, len)
+    }
+
+    pub fn network_id(&self) -> u16 {
+        unsafe { ffi::webrtc_Candidate_network_id(self.raw.as_ptr()) }
+    }
+
+    pub fn network_cost(&self) -> u16 {
+        unsafe { ffi::webrtc_Candidate_network_cost(self.raw.as_ptr()) }
+    }
+
+    /// ICE ufrag。
+    pub fn username(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_Candidate_username(self.raw.as_ptr(), &mut ptr, &mut len) };
+        borrowed_string(ptr, len)
+    }
+
+    /// candidate が属する m= セクションの mid。
+    pub fn transport_name(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_Candidate_transport_name(self.raw.as_ptr(), &mut ptr, &mut len) };
+        borrowed_string(ptr, len)
+    }
+
+    /// candidate を収集した STUN / TURN サーバーの URL。
+    pub fn url(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_Candidate_url(self.raw.as_ptr(), &mut ptr, &mut len) };
+        borrowed_string(ptr, len)
+    }
+
+    pub fn to_string(&self) -> Result<String> {
+        let raw = unsafe { ffi::webrtc_Candidate_ToString(self.raw.as_ptr()) };
+        CxxString::from_unique(
+            NonNull::new(raw).expect("BUG: webrtc_Candidate_ToString が null を返しました"),
+        )
+        .to_string()
+    }
+}
+
+/// webrtc::Candidate の所有ラッパー。
+pub struct Candidate {
+    raw: NonNull<ffi::webrtc_Candidate>,
+}
+
+unsafe impl Send for Candidate {}
+
+impl Candidate {
+    /// 空の Candidate を生成する。各フィールドは setter で設定する。
+    pub fn new() -> Self {
+        let raw = unsafe { ffi::webrtc_Candidate_new() };
+        Self {
+            raw: NonNull::new(raw).expect("BUG: webrtc_Candidate_new が null を返しました"),
+        }
+    }
+
+    pub fn as_ref(&self) -> CandidateRef<'_> {
+        CandidateRef::from_raw(self.raw)
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_Candidate {
+        self.raw.as_ptr()
+    }
+
+    pub fn set_foundation(&mut self, foundation: &str) {
+        unsafe {
+            ffi::webrtc_Candidate_set_foundation(
+                self.raw.as_ptr(),
+                foundation.as_ptr() as *const _,
+                foundation.len(),
+            )
+        };
+    }
+
+    pub fn set_component(&mut self, component: i32) {
+        unsafe { ffi::webrtc_Candidate_set_component(self.raw.as_ptr(), component) };
+    }
+
+    pub fn set_protocol(&mut self, protocol: &str) {
+        unsafe {
+            ffi::webrtc_Candidate_set_protocol(
+                self.raw.as_ptr(),
+                protocol.as_ptr() as *const _,
+                protocol.len(),
+            )
+        };
+    }
+
+    pub fn set_priority(&mut self, priority: u32) {
+        unsafe { ffi::webrtc_Candidate_set_priority(self.raw.as_ptr(), priority) };
+    }
+
+    pub fn set_address(&mut self, address: &SocketAddress) {
+        unsafe { ffi::webrtc_Candidate_set_address(self.raw.as_ptr(), address.as_ptr()) };
+    }
+
+    pub fn set_candidate_type(&mut self, candidate_type: IceCandidateType) {
+        unsafe { ffi::webrtc_Candidate_set_type(self.raw.as_ptr(), candidate_type.to_int()) };
+    }
+
+    pub fn set_related_address(&mut self, related_address: &SocketAddress) {
+        unsafe {
+            ffi::webrtc_Candidate_set_related_address(self.raw.as_ptr(), related_address.as_ptr())
+        };
+    }
+
+    pub fn set_tcptype(&mut self, tcptype: &str) {
+        unsafe {
+            ffi::webrtc_Candidate_set_tcptype(
+                self.raw.as_ptr(),
+                tcptype.as_ptr() as *const _,
+                tcptype.len(),
+            )
+        };
+    }
+
+    pub fn set_network_id(&mut self, network_id: u16) {
+        unsafe { ffi::webrtc_Candidate_set_network_id(self.raw.as_ptr(), network_id) };
+    }
+
+    pub fn set_network_cost(&mut self, network_cost: u16) {
+        unsafe { ffi::webrtc_Candidate_set_network_cost(self.raw.as_ptr(), network_cost) };
+    }
+
+    pub fn set_username(&mut self, username: &str) {
+        unsafe {
+            ffi::webrtc_Candidate_set_username(
+                self.raw.as_ptr(),
+                username.as_ptr() as *const _,
+                username.len(),
+            )
+        };
+    }
+
+    /// PeerConnection::remove_ice_candidates はこの値で m= セクションを特定する。
+    pub fn set_transport_name(&mut self, transport_name: &str) {
+        unsafe {
+            ffi::webrtc_Candidate_set_transport_name(
+                self.raw.as_ptr(),
+                transport_name.as_ptr() as *const _,
+                transport_name.len(),
+            )
+        };
+    }
+
+    pub fn set_url(&mut self, url: &str) {
+        unsafe {
+            ffi::webrtc_Candidate_set_url(self.raw.as_ptr(), url.as_ptr() as *const _, url.len())
+        };
+    }
+
+    pub fn to_string(&self) -> Result<String> {
+        self.as_ref().to_string()
+    }
+}
+
+impl Default for Candidate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Candidate {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_Candidate_delete(self.raw.as_ptr()) };
+    }
+}
+
+/// webrtc::CandidatePair の借用ラッパー。
+pub struct CandidatePairRef<'a> {
+    raw: NonNull<ffi::webrtc_CandidatePair>,
+    _marker: PhantomData<&'a ffi::webrtc_CandidatePair>,
+}
+
+unsafe impl<'a> Send for CandidatePairRef<'a> {}
+
+impl<'a> CandidatePairRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_CandidatePair>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_CandidatePair {
+        self.raw.as_ptr()
+    }
+
+    pub fn local_candidate(&self) -> CandidateRef<'a> {
+        let raw = unsafe { ffi::webrtc_CandidatePair_local_candidate(self.raw.as_ptr()) };
+        CandidateRef::from_raw(
+            NonNull::new(raw as *mut ffi::webrtc_Candidate)
+                .expect("BUG: webrtc_CandidatePair_local_candidate が null を返しました"),
+        )
+    }
+
+    pub fn remote_candidate(&self) -> CandidateRef<'a> {
+        let raw = unsafe { ffi::webrtc_CandidatePair_remote_candidate(self.raw.as_ptr()) };
+        CandidateRef::from_raw(
+            NonNull::new(raw as *mut ffi::webrtc_Candidate)
+                .expect("BUG: webrtc_CandidatePair_remote_candidate が null を返しました"),
+        )
+    }
+}
+
+/// webrtc::CandidatePairChangeEvent の借用ラッパー。
+pub struct CandidatePairChangeEventRef<'a> {
+    raw: NonNull<ffi::webrtc_CandidatePairChangeEvent>,
+    _marker: PhantomData<&'a ffi::webrtc_CandidatePairChangeEvent>,
+}
+
+unsafe impl<'a> Send for CandidatePairChangeEventRef<'a> {}
+
+impl<'a> CandidatePairChangeEventRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_CandidatePairChangeEvent>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_CandidatePairChangeEvent {
+        self.raw.as_ptr()
+    }
+
+    pub fn selected_candidate_pair(&self) -> CandidatePairRef<'a> {
+        let raw = unsafe {
+            ffi::webrtc_CandidatePairChangeEvent_get_selected_candidate_pair(self.raw.as_ptr())
+        };
+        CandidatePairRef::from_raw(NonNull::new(raw as *mut ffi::webrtc_CandidatePair).expect(
+            "BUG: webrtc_CandidatePairChangeEvent_get_selected_candidate_pair が null を返しました",
+        ))
+    }
+
+    pub fn last_data_received_ms(&self) -> i64 {
+        unsafe { ffi::webrtc_CandidatePairChangeEvent_get_last_data_received_ms(self.raw.as_ptr()) }
+    }
+
+    pub fn reason(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe {
+            ffi::webrtc_CandidatePairChangeEvent_get_reason(self.raw.as_ptr(), &mut ptr, &mut len)
+        };
+        assert!(!ptr.is_null());
+        let bytes = unsafe { std::slice::from_raw_parts(ptr.cast::<u8>(), len) };
+        let reason = std::str::from_utf8(bytes)?;
+        Ok(reason.to_owned())
+    }
+
+    pub fn estimated_disconnected_time_ms(&self) -> i64 {
+        unsafe {
+            ffi::webrtc_CandidatePairChangeEvent_get_estimated_disconnected_time_ms(
+                self.raw.as_ptr(),
+            )
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::ffi;
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+use std::time::Duration;
+
+/// libwebrtc が参照する現在時刻を提供する。
+///
+/// current_time は任意のスレッドから同時に呼ばれうる。
+pub trait ClockHandler: Send + Sync {
+    /// 現在時刻を返す。起点は任意だが、時刻を巻き戻してはならない。
+    fn current_time(&self) -> Duration;
+}
+
+struct ClockHandlerState {
+    handler: Box<dyn ClockHandler>,
+}
+
+unsafe extern "C" fn clock_current_time_us(user_data: *mut c_void) -> i64 {
+    assert!(
+        !user_data.is_null(),
+        "clock_current_time_us: user_data is null"
+    );
+    let state = unsafe { &*(user_data as *const ClockHandlerState) };
+    state.handler.current_time().as_micros() as i64
+}
+
+unsafe extern "C" fn clock_on_destroy(user_data: *mut c_void) {
+    assert!(!user_data.is_null(), "clock_on_destroy: user_data is null");
+    let _ = unsafe { Box::from_raw(user_data as *mut ClockHandlerState) };
+}
+
+/// Rust で実装する webrtc::Clock。
+///
+/// EnvironmentBuilder::set_clock で設定する。
+pub struct Clock {
+    raw_unique: NonNull<ffi::webrtc_Clock_unique>,
+}
+
+unsafe impl Send for Clock {}
+
+impl Clock {
+    pub fn new_with_handler(handler: Box<dyn ClockHandler>) -> Self {
+        let state = Box::new(ClockHandlerState { handler });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_Clock_cbs {
+            CurrentTimeUs: Some(clock_current_time_us),
+            OnDestroy: Some(clock_on_destroy),
+        };
+        let raw = unsafe { ffi::webrtc_Clock_new(&cbs, user_data) };
+        let raw_unique = match NonNull::new(raw) {
+            Some(raw_unique) => raw_unique,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut ClockHandlerState) };
+                panic!("BUG: webrtc_Clock_new が null を返しました");
+            }
+        };
+        Self { raw_unique }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_Clock {
+        unsafe { ffi::webrtc_Clock_unique_get(self.raw_unique.as_ptr()) }
+    }
+
+    pub fn into_raw(self) -> *mut ffi::webrtc_Clock_unique {
+        std::mem::ManuallyDrop::new(self).raw_unique.as_ptr()
+    }
+
+    pub fn current_time(&self) -> Duration {
+        let us = unsafe { ffi::webrtc_Clock_CurrentTimeUs(self.as_ptr()) };
+        Duration::from_micros(us.max(0) as u64)
+    }
+}
+
+impl Drop for Clock {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_Clock_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
@@ -0,0 +1,144 @@
+use crate::ffi;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/// webrtc::CryptoOptions の借用ラッパー。
+pub struct CryptoOptionsRef<'a> {
+    raw: NonNull<ffi::webrtc_CryptoOptions>,
+    _marker: PhantomData<&'a mut ffi::webrtc_CryptoOptions>,
+}
+
+unsafe impl<'a> Send for CryptoOptionsRef<'a> {}
+
+impl<'a> CryptoOptionsRef<'a> {
+    pub fn from_raw(raw: NonNull<ffi::webrtc_CryptoOptions>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_CryptoOptions {
+        self.raw.as_ptr()
+    }
+
+    pub fn srtp(&mut self) -> CryptoOptionsSrtpRef<'_> {
+        let raw = unsafe { ffi::webrtc_CryptoOptions_get_srtp(self.raw.as_ptr()) };
+        CryptoOptionsSrtpRef::from_raw(
+            NonNull::new(raw).expect("BUG: webrtc_CryptoOptions_get_srtp が null を返しました"),
+        )
+    }
+
+    pub fn sframe(&mut self) -> CryptoOptionsSFrameRef<'_> {
+        let raw = unsafe { ffi::webrtc_CryptoOptions_get_sframe(self.raw.as_ptr()) };
+        CryptoOptionsSFrameRef::from_raw(
+            NonNull::new(raw).expect("BUG: webrtc_CryptoOptions_get_sframe が null を返しました"),
+        )
+    }
+}
+
+/// webrtc::CryptoOptions::Srtp の借用ラッパー。
+pub struct CryptoOptionsSrtpRef<'a> {
+    raw: NonNull<ffi::webrtc_CryptoOptions_Srtp>,
+    _marker: PhantomData<&'a mut ffi::webrtc_CryptoOptions_Srtp>,
+}
+
+unsafe impl<'a> Send for CryptoOptionsSrtpRef<'a> {}
+
+impl<'a> CryptoOptionsSrtpRef<'a> {
+    pub fn from_raw(raw: NonNull<ffi::webrtc_CryptoOptions_Srtp>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_CryptoOptions_Srtp {
+        self.raw.as_ptr()
+    }
+
+    pub fn enable_gcm_crypto_suites(&self) -> bool {
+        unsafe {
+            ffi::webrtc_CryptoOptions_Srtp_get_enable_gcm_crypto_suites(self.raw.as_ptr()) != 0
+        }
+    }
+
+    pub fn set_enable_gcm_crypto_suites(&mut self, value: bool) {
+        unsafe {
+            ffi::webrtc_CryptoOptions_Srtp_set_enable_gcm_crypto_suites(
+                self.raw.as_ptr(),
+                value as i32,
+            );
+        }
+    }
+
+    pub fn enable_aes128_sha1_32_crypto_cipher(&self) -> bool {
+        unsafe {
+            ffi::webrtc_CryptoOptions_Srtp_get_enable_aes128_sha1_32_crypto_cipher(
+                self.raw.as_ptr(),
+            ) != 0
+        }
+    }
+
+    pub fn set_enable_aes128_sha1_32_crypto_cipher(&mut self, value: bool) {
+        unsafe {
+            ffi::webrtc_CryptoOptions_Srtp_set_enable_aes128_sha1_32_crypto_cipher(
+                self.raw.as_ptr(),
+                value as i32,
+            );
+        }
+    }
+
+    pub fn enable_encrypted_rtp_header_extensions(&self) -> bool {
+        unsafe {
+            ffi::webrtc_CryptoOptions_Srtp_get_enable_encrypted_rtp_header_extensions(
+                self.raw.as_ptr(),
+            ) != 0
+        }
+    }
+
+    pub fn set_enable_encrypted_rtp_header_extensions(&mut self, value: bool) {
+        unsafe {
+            ffi::webrtc_CryptoOptions_Srtp_set_enable_encrypted_rtp_header_extensions(
+                self.raw.as_ptr(),
+                value as i32,
+            );
+        }
+    }
+}
+
+/// webrtc::CryptoOptions::SFrame の借用ラッパー。
+pub struct CryptoOptionsSFrameRef<'a> {
+    raw: NonNull<ffi::webrtc_CryptoOptions_SFrame>,
+    _marker: PhantomData<&'a mut ffi::webrtc_CryptoOptions_SFrame>,
+}
+
+unsafe impl<'a> Send for CryptoOptionsSFrameRef<'a> {}
+
+impl<'a> CryptoOptionsSFrameRef<'a> {
+    pub fn from_raw(raw: NonNull<ffi::webrtc_CryptoOptions_SFrame>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_CryptoOptions_SFrame {
+        self.raw.as_ptr()
+    }
+
+    pub fn require_frame_encryption(&self) -> bool {
+        unsafe {
+            ffi::webrtc_CryptoOptions_SFrame_get_require_frame_encryption(self.raw.as_ptr()) != 0
+        }
+    }
+
+    pub fn set_require_frame_encryption(&mut self, value: bool) {
+        unsafe {
+            ffi::webrtc_CryptoOptions_SFrame_set_require_frame_encryption(
+                self.raw.as_ptr(),
+                value as i32,
+            );
+        }
+    }
+}
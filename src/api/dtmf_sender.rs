@@ -0,0 +1,182 @@
+use crate::ref_count::DtmfSenderHandle;
+use crate::{CxxString, Result, ScopedRef, ffi};
+use std::os::raw::{c_char, c_void};
+use std::ptr::NonNull;
+
+// -------------------------
+// DtmfSenderObserver
+// -------------------------
+
+pub trait DtmfSenderObserverHandler: Send {
+    /// トーンの送信を開始したときに呼ばれる。
+    ///
+    /// tone_buffer はまだ送信していない残りのトーン。
+    /// 全トーンの送信が終わると tone は空文字列で呼ばれる。
+    #[expect(unused_variables)]
+    fn on_tone_change(&mut self, tone: &str, tone_buffer: &str) {}
+}
+
+struct DtmfSenderObserverHandlerState {
+    handler: Box<dyn DtmfSenderObserverHandler>,
+}
+
+unsafe impl Send for DtmfSenderObserverHandlerState {}
+
+unsafe extern "C" fn dtmf_observer_on_tone_change(
+    tone: *const c_char,
+    tone_len: usize,
+    tone_buffer: *const c_char,
+    tone_buffer_len: usize,
+    user_data: *mut c_void,
+) {
+    assert!(
+        !user_data.is_null(),
+        "dtmf_observer_on_tone_change: user_data is null"
+    );
+    let state = unsafe { &mut *(user_data as *mut DtmfSenderObserverHandlerState) };
+    let tone = unsafe { std::slice::from_raw_parts(tone as *const u8, tone_len) };
+    let tone = std::str::from_utf8(tone).expect("BUG: tone が UTF-8 ではありません");
+    let tone_buffer =
+        unsafe { std::slice::from_raw_parts(tone_buffer as *const u8, tone_buffer_len) };
+    let tone_buffer =
+        std::str::from_utf8(tone_buffer).expect("BUG: tone_buffer が UTF-8 ではありません");
+    state.handler.on_tone_change(tone, tone_buffer);
+}
+
+unsafe extern "C" fn dtmf_observer_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "dtmf_observer_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut DtmfSenderObserverHandlerState) };
+}
+
+/// webrtc::DtmfSenderObserverInterface のラッパー。
+pub struct DtmfSenderObserver {
+    raw: NonNull<ffi::webrtc_DtmfSenderObserverInterface>,
+}
+
+unsafe impl Send for DtmfSenderObserver {}
+
+impl DtmfSenderObserver {
+    pub fn new_with_handler(handler: Box<dyn DtmfSenderObserverHandler>) -> Self {
+        let state = Box::new(DtmfSenderObserverHandlerState { handler });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_DtmfSenderObserverInterface_cbs {
+            OnToneChange: Some(dtmf_observer_on_tone_change),
+            OnDestroy: Some(dtmf_observer_on_destroy),
+        };
+        let raw = match NonNull::new(unsafe {
+            ffi::webrtc_DtmfSenderObserverInterface_new(&cbs, user_data)
+        }) {
+            Some(raw) => raw,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut DtmfSenderObserverHandlerState) };
+                panic!("BUG: webrtc_DtmfSenderObserverInterface_new が null を返しました");
+            }
+        };
+        Self { raw }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_DtmfSenderObserverInterface {
+        self.raw.as_ptr()
+    }
+}
+
+impl Drop for DtmfSenderObserver {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_DtmfSenderObserverInterface_delete(self.raw.as_ptr()) };
+    }
+}
+
+// -------------------------
+// DtmfSender
+// -------------------------
+
+/// webrtc::DtmfSenderInterface のラッパー。RFC 4733 の DTMF を送信する。
+pub struct DtmfSender {
+    raw_ref: ScopedRef<DtmfSenderHandle>,
+    observer: Option<DtmfSenderObserver>,
+}
+
+unsafe impl Send for DtmfSender {}
+
+impl DtmfSender {
+    pub(crate) fn from_scoped_ref(raw_ref: ScopedRef<DtmfSenderHandle>) -> Self {
+        Self {
+            raw_ref,
+            observer: None,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_DtmfSenderInterface {
+        self.raw_ref.as_ptr()
+    }
+
+    pub fn as_refcounted_ptr(&self) -> *mut ffi::webrtc_DtmfSenderInterface_refcounted {
+        self.raw_ref.as_refcounted_ptr()
+    }
+
+    /// Observer を登録する。
+    ///
+    /// observer は置き換えられるか、解除されるか、このラッパーが破棄されるまで保持する。
+    pub fn register_observer(&mut self, observer: DtmfSenderObserver) {
+        unsafe {
+            ffi::webrtc_DtmfSenderInterface_RegisterObserver(self.as_ptr(), observer.as_ptr())
+        };
+        // 差し替えが終わってから古い observer を破棄する。
+        self.observer = Some(observer);
+    }
+
+    /// Observer を解除して破棄する。
+    pub fn unregister_observer(&mut self) {
+        unsafe { ffi::webrtc_DtmfSenderInterface_UnregisterObserver(self.as_ptr()) };
+        self.observer = None;
+    }
+
+    /// 送信可能な状態かどうか。
+    pub fn can_insert_dtmf(&self) -> bool {
+        unsafe { ffi::webrtc_DtmfSenderInterface_CanInsertDtmf(self.as_ptr()) != 0 }
+    }
+
+    /// トーンを送信キューに積む。送信待ちのトーンは置き換えられる。
+    ///
+    /// tones は "0-9", "A-D", "#", "*" と待機を表す "," からなる文字列。
+    /// duration_ms は 40-6000、inter_tone_gap_ms は 30 以上でなければならない。
+    /// 空文字列を渡すと送信待ちのトーンを取り消す。
+    pub fn insert_dtmf(&mut self, tones: &str, duration_ms: i32, inter_tone_gap_ms: i32) -> bool {
+        unsafe {
+            ffi::webrtc_DtmfSenderInterface_InsertDtmf(
+                self.as_ptr(),
+                tones.as_ptr() as *const _,
+                tones.len(),
+                duration_ms,
+                inter_tone_gap_ms,
+            ) != 0
+        }
+    }
+
+    /// まだ送信していないトーンを返す。
+    pub fn tones(&self) -> Result<String> {
+        let raw = NonNull::new(unsafe { ffi::webrtc_DtmfSenderInterface_tones(self.as_ptr()) })
+            .expect("BUG: webrtc_DtmfSenderInterface_tones が null を返しました");
+        CxxString::from_unique(raw).to_string()
+    }
+
+    pub fn duration(&self) -> i32 {
+        unsafe { ffi::webrtc_DtmfSenderInterface_duration(self.as_ptr()) }
+    }
+
+    pub fn inter_tone_gap(&self) -> i32 {
+        unsafe { ffi::webrtc_DtmfSenderInterface_inter_tone_gap(self.as_ptr()) }
+    }
+}
+
+impl Drop for DtmfSender {
+    fn drop(&mut self) {
+        // 保持している observer を破棄する前に libwebrtc 側の参照を外す。
+        if self.observer.is_some() {
+            unsafe { ffi::webrtc_DtmfSenderInterface_UnregisterObserver(self.raw_ref.as_ptr()) };
+        }
+    }
+}
@@ -1,5 +1,6 @@
-use crate::ffi;
+use crate::{Clock, CxxString, Error, Result, TaskQueueFactory, TaskQueueFactoryRef, ffi};
 use std::marker::PhantomData;
+use std::os::raw::{c_char, c_void};
 use std::ptr::NonNull;
 
 /// webrtc_c の webrtc_Environment を安全に扱うラッパー。
@@ -28,6 +29,15 @@ impl Environment {
         // Safety: self.raw は Environment の生存中は常に有効です。
         unsafe { EnvironmentRef::from_raw(self.raw) }
     }
+
+    /// この Environment のタスクキューの生成元を返す。
+    pub fn task_queue_factory(&self) -> TaskQueueFactoryRef<'_> {
+        let raw = unsafe { ffi::webrtc_Environment_task_queue_factory(self.raw.as_ptr()) };
+        let raw = NonNull::new(raw)
+            .expect("BUG: webrtc_Environment_task_queue_factory が null を返しました");
+        // Safety: TaskQueueFactory は Environment の生存中は有効です。
+        unsafe { TaskQueueFactoryRef::from_raw(raw) }
+    }
 }
 
 impl Default for Environment {
@@ -42,6 +52,140 @@ impl Drop for Environment {
     }
 }
 
+/// libwebrtc の field trial の値を key から引く。
+///
+/// lookup は任意のスレッドから同時に呼ばれうる。
+pub trait FieldTrialsHandler: Send + Sync {
+    /// key に対応する値を返す。未設定の場合は None を返す。
+    fn lookup(&self, key: &str) -> Option<String>;
+}
+
+struct FieldTrialsHandlerState {
+    handler: Box<dyn FieldTrialsHandler>,
+}
+
+unsafe extern "C" fn field_trials_lookup(
+    key: *const c_char,
+    key_len: usize,
+    user_data: *mut c_void,
+) -> *mut ffi::std_string_unique {
+    assert!(
+        !user_data.is_null(),
+        "field_trials_lookup: user_data is null"
+    );
+    assert!(!key.is_null());
+    let state = unsafe { &*(user_data as *const FieldTrialsHandlerState) };
+    let bytes = unsafe { std::slice::from_raw_parts(key.cast::<u8>(), key_len) };
+    let key = std::str::from_utf8(bytes).expect("BUG: key が UTF-8 ではありません");
+    match state.handler.lookup(key) {
+        Some(value) => CxxString::from_str(&value).into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn field_trials_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "field_trials_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut FieldTrialsHandlerState) };
+}
+
+/// webrtc::EnvironmentFactory のラッパー。
+///
+/// 設定した field trial は build した Environment を渡した factory、ADM、
+/// PeerConnection で利用される。
+pub struct EnvironmentBuilder {
+    raw: NonNull<ffi::webrtc_EnvironmentFactory>,
+}
+
+unsafe impl Send for EnvironmentBuilder {}
+
+impl EnvironmentBuilder {
+    pub fn new() -> Self {
+        let raw = unsafe { ffi::webrtc_EnvironmentFactory_new() };
+        Self {
+            raw: NonNull::new(raw)
+                .expect("BUG: webrtc_EnvironmentFactory_new が null を返しました"),
+        }
+    }
+
+    /// "WebRTC-Foo/Enabled/WebRTC-Bar/Disabled/" 形式の文字列で field trial を設定する。
+    ///
+    /// libwebrtc と同じ規則で検証し、不正な文字列の場合はエラーを返す。
+    pub fn set_field_trials(&mut self, field_trials: &str) -> Result<&mut Self> {
+        let raw = unsafe {
+            ffi::webrtc_FieldTrials_Create(
+                field_trials.as_ptr() as *const c_char,
+                field_trials.len(),
+            )
+        };
+        if raw.is_null() {
+            return Err(Error::Message(format!(
+                "不正な field trial 文字列です: {field_trials}"
+            )));
+        }
+        unsafe { ffi::webrtc_EnvironmentFactory_set_field_trials(self.raw.as_ptr(), raw) };
+        Ok(self)
+    }
+
+    /// field trial の値を handler から引くようにする。
+    pub fn set_field_trials_handler(&mut self, handler: Box<dyn FieldTrialsHandler>) -> &mut Self {
+        let state = Box::new(FieldTrialsHandlerState { handler });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_FieldTrialsView_cbs {
+            Lookup: Some(field_trials_lookup),
+            OnDestroy: Some(field_trials_on_destroy),
+        };
+        let raw = unsafe { ffi::webrtc_FieldTrialsView_new(&cbs, user_data) };
+        if raw.is_null() {
+            let _ = unsafe { Box::from_raw(user_data as *mut FieldTrialsHandlerState) };
+            panic!("BUG: webrtc_FieldTrialsView_new が null を返しました");
+        }
+        unsafe { ffi::webrtc_EnvironmentFactory_set_field_trials(self.raw.as_ptr(), raw) };
+        self
+    }
+
+    /// libwebrtc が参照する現在時刻を clock から取得するようにする。
+    ///
+    /// 統計情報のタイムスタンプや帯域推定などが clock の時刻で動作する。
+    pub fn set_clock(&mut self, clock: Clock) -> &mut Self {
+        unsafe { ffi::webrtc_EnvironmentFactory_set_clock(self.raw.as_ptr(), clock.into_raw()) };
+        self
+    }
+
+    /// libwebrtc がエンコーダーやペーシングに使うタスクキューの生成元を設定する。
+    pub fn set_task_queue_factory(&mut self, task_queue_factory: TaskQueueFactory) -> &mut Self {
+        unsafe {
+            ffi::webrtc_EnvironmentFactory_set_task_queue_factory(
+                self.raw.as_ptr(),
+                task_queue_factory.into_raw(),
+            )
+        };
+        self
+    }
+
+    pub fn build(&self) -> Environment {
+        let raw = unsafe { ffi::webrtc_EnvironmentFactory_Create(self.raw.as_ptr()) };
+        Environment {
+            raw: NonNull::new(raw)
+                .expect("BUG: webrtc_EnvironmentFactory_Create が null を返しました"),
+        }
+    }
+}
+
+impl Default for EnvironmentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for EnvironmentBuilder {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_EnvironmentFactory_delete(self.raw.as_ptr()) };
+    }
+}
+
 #[allow(dead_code)]
 pub struct EnvironmentRef<'a> {
     raw: NonNull<ffi::webrtc_Environment>,
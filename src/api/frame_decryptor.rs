@@ -0,0 +1,213 @@
+use crate::ref_count::FrameDecryptorHandle;
+use crate::{MediaType, ScopedRef, ffi};
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+use std::sync::Mutex;
+
+/// FrameDecryptorInterface::Status のラッパー。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameDecryptorStatus {
+    Ok,
+    Recoverable,
+    FailedToDecrypt,
+    Unknown(i32),
+}
+
+impl FrameDecryptorStatus {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_FrameDecryptorInterface_Status_kOk {
+                FrameDecryptorStatus::Ok
+            } else if value == ffi::webrtc_FrameDecryptorInterface_Status_kRecoverable {
+                FrameDecryptorStatus::Recoverable
+            } else if value == ffi::webrtc_FrameDecryptorInterface_Status_kFailedToDecrypt {
+                FrameDecryptorStatus::FailedToDecrypt
+            } else {
+                FrameDecryptorStatus::Unknown(value)
+            }
+        }
+    }
+
+    pub fn to_int(self) -> i32 {
+        match self {
+            FrameDecryptorStatus::Ok => unsafe { ffi::webrtc_FrameDecryptorInterface_Status_kOk },
+            FrameDecryptorStatus::Recoverable => unsafe {
+                ffi::webrtc_FrameDecryptorInterface_Status_kRecoverable
+            },
+            FrameDecryptorStatus::FailedToDecrypt => unsafe {
+                ffi::webrtc_FrameDecryptorInterface_Status_kFailedToDecrypt
+            },
+            FrameDecryptorStatus::Unknown(value) => value,
+        }
+    }
+}
+
+/// FrameDecryptorInterface::Result のラッパー。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameDecryptorResult {
+    pub status: FrameDecryptorStatus,
+    pub bytes_written: usize,
+}
+
+impl FrameDecryptorResult {
+    pub fn ok(bytes_written: usize) -> Self {
+        Self {
+            status: FrameDecryptorStatus::Ok,
+            bytes_written,
+        }
+    }
+
+    pub fn failed_to_decrypt() -> Self {
+        Self {
+            status: FrameDecryptorStatus::FailedToDecrypt,
+            bytes_written: 0,
+        }
+    }
+}
+
+/// フレーム単位の復号処理。
+///
+/// decrypt はデコーダーの前段のスレッドから呼ばれる。
+/// 同じインスタンスを複数の RtpReceiver に設定した場合など複数のスレッドから呼ばれうるため、
+/// handler の呼び出しは Mutex で直列化する。
+pub trait FrameDecryptorHandler: Send {
+    /// encrypted_frame を復号して frame に書き込む。
+    fn decrypt(
+        &mut self,
+        media_type: MediaType,
+        csrcs: &[u32],
+        additional_data: &[u8],
+        encrypted_frame: &[u8],
+        frame: &mut [u8],
+    ) -> FrameDecryptorResult;
+
+    /// encrypted_frame_size バイトの暗号化フレームを復号した際の最大バイト数を返す。
+    /// 映像の場合は encrypted_frame_size 以下でなければならない。
+    fn get_max_plaintext_byte_size(
+        &mut self,
+        media_type: MediaType,
+        encrypted_frame_size: usize,
+    ) -> usize;
+}
+
+struct FrameDecryptorHandlerState {
+    handler: Mutex<Box<dyn FrameDecryptorHandler>>,
+}
+
+unsafe impl Send for FrameDecryptorHandlerState {}
+
+unsafe extern "C" fn frame_decryptor_decrypt(
+    media_type: i32,
+    csrcs: *const u32,
+    csrcs_size: usize,
+    additional_data: *const u8,
+    additional_data_size: usize,
+    encrypted_frame: *const u8,
+    encrypted_frame_size: usize,
+    frame: *mut u8,
+    frame_size: usize,
+    bytes_written: *mut usize,
+    user_data: *mut c_void,
+) -> i32 {
+    let state = unsafe { &*(user_data as *const FrameDecryptorHandlerState) };
+    // 長さ 0 の場合は null が渡されることがあるため空スライスとして扱う。
+    let csrcs = if csrcs_size == 0 {
+        &[]
+    } else {
+        assert!(!csrcs.is_null());
+        unsafe { std::slice::from_raw_parts(csrcs, csrcs_size) }
+    };
+    let additional_data = if additional_data_size == 0 {
+        &[]
+    } else {
+        assert!(!additional_data.is_null());
+        unsafe { std::slice::from_raw_parts(additional_data, additional_data_size) }
+    };
+    // 映像は同じバッファ上でインプレース復号され frame と領域が重なるため、複製してから渡す。
+    let encrypted_frame = if encrypted_frame_size == 0 {
+        Vec::new()
+    } else {
+        assert!(!encrypted_frame.is_null());
+        unsafe { std::slice::from_raw_parts(encrypted_frame, encrypted_frame_size) }.to_vec()
+    };
+    let frame = if frame_size == 0 {
+        &mut []
+    } else {
+        assert!(!frame.is_null());
+        unsafe { std::slice::from_raw_parts_mut(frame, frame_size) }
+    };
+    assert!(!bytes_written.is_null());
+    let result = state.handler.lock().unwrap().decrypt(
+        MediaType::from_int(media_type),
+        csrcs,
+        additional_data,
+        &encrypted_frame,
+        frame,
+    );
+    assert!(result.bytes_written <= frame_size);
+    unsafe { *bytes_written = result.bytes_written };
+    result.status.to_int()
+}
+
+unsafe extern "C" fn frame_decryptor_get_max_plaintext_byte_size(
+    media_type: i32,
+    encrypted_frame_size: usize,
+    user_data: *mut c_void,
+) -> usize {
+    let state = unsafe { &*(user_data as *const FrameDecryptorHandlerState) };
+    state
+        .handler
+        .lock()
+        .unwrap()
+        .get_max_plaintext_byte_size(MediaType::from_int(media_type), encrypted_frame_size)
+}
+
+unsafe extern "C" fn frame_decryptor_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "frame_decryptor_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut FrameDecryptorHandlerState) };
+}
+
+/// webrtc::FrameDecryptorInterface のラッパー。
+///
+/// RtpReceiver::set_frame_decryptor で設定する。
+pub struct FrameDecryptor {
+    raw_ref: ScopedRef<FrameDecryptorHandle>,
+}
+
+unsafe impl Send for FrameDecryptor {}
+
+impl FrameDecryptor {
+    pub fn new_with_handler(handler: Box<dyn FrameDecryptorHandler>) -> Self {
+        let state = Box::new(FrameDecryptorHandlerState {
+            handler: Mutex::new(handler),
+        });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_FrameDecryptorInterface_cbs {
+            Decrypt: Some(frame_decryptor_decrypt),
+            GetMaxPlaintextByteSize: Some(frame_decryptor_get_max_plaintext_byte_size),
+            OnDestroy: Some(frame_decryptor_on_destroy),
+        };
+        let raw = match NonNull::new(unsafe {
+            ffi::webrtc_FrameDecryptorInterface_make_ref_counted(&cbs, user_data)
+        }) {
+            Some(raw) => raw,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut FrameDecryptorHandlerState) };
+                panic!("BUG: webrtc_FrameDecryptorInterface_make_ref_counted が null を返しました");
+            }
+        };
+        let raw_ref = ScopedRef::<FrameDecryptorHandle>::from_raw(raw);
+        Self { raw_ref }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_FrameDecryptorInterface {
+        self.raw_ref.as_ptr()
+    }
+
+    pub fn as_refcounted_ptr(&self) -> *mut ffi::webrtc_FrameDecryptorInterface_refcounted {
+        self.raw_ref.as_refcounted_ptr()
+    }
+}
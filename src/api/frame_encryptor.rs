@@ -0,0 +1,147 @@
+use crate::ref_count::FrameEncryptorHandle;
+use crate::{MediaType, ScopedRef, ffi};
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+use std::sync::Mutex;
+
+/// フレーム単位の暗号化処理。
+///
+/// encrypt はエンコーダーのスレッドから呼ばれる。
+/// 同じインスタンスを複数の RtpSender に設定した場合など複数のスレッドから呼ばれうるため、
+/// handler の呼び出しは Mutex で直列化する。
+pub trait FrameEncryptorHandler: Send {
+    /// frame を暗号化して encrypted_frame に書き込み、書き込んだバイト数を返す。
+    /// None を返した場合はフレームを送信しない。
+    fn encrypt(
+        &mut self,
+        media_type: MediaType,
+        ssrc: u32,
+        additional_data: &[u8],
+        frame: &[u8],
+        encrypted_frame: &mut [u8],
+    ) -> Option<usize>;
+
+    /// frame_size バイトのフレームを暗号化した際の最大バイト数を返す。
+    fn get_max_ciphertext_byte_size(&mut self, media_type: MediaType, frame_size: usize) -> usize;
+}
+
+struct FrameEncryptorHandlerState {
+    handler: Mutex<Box<dyn FrameEncryptorHandler>>,
+}
+
+unsafe impl Send for FrameEncryptorHandlerState {}
+
+unsafe extern "C" fn frame_encryptor_encrypt(
+    media_type: i32,
+    ssrc: u32,
+    additional_data: *const u8,
+    additional_data_size: usize,
+    frame: *const u8,
+    frame_size: usize,
+    encrypted_frame: *mut u8,
+    encrypted_frame_size: usize,
+    bytes_written: *mut usize,
+    user_data: *mut c_void,
+) -> i32 {
+    let state = unsafe { &*(user_data as *const FrameEncryptorHandlerState) };
+    // 長さ 0 の場合は null が渡されることがあるため空スライスとして扱う。
+    let additional_data = if additional_data_size == 0 {
+        &[]
+    } else {
+        assert!(!additional_data.is_null());
+        unsafe { std::slice::from_raw_parts(additional_data, additional_data_size) }
+    };
+    let frame = if frame_size == 0 {
+        &[]
+    } else {
+        assert!(!frame.is_null());
+        unsafe { std::slice::from_raw_parts(frame, frame_size) }
+    };
+    let encrypted_frame = if encrypted_frame_size == 0 {
+        &mut []
+    } else {
+        assert!(!encrypted_frame.is_null());
+        unsafe { std::slice::from_raw_parts_mut(encrypted_frame, encrypted_frame_size) }
+    };
+    assert!(!bytes_written.is_null());
+    match state.handler.lock().unwrap().encrypt(
+        MediaType::from_int(media_type),
+        ssrc,
+        additional_data,
+        frame,
+        encrypted_frame,
+    ) {
+        Some(written) => {
+            assert!(written <= encrypted_frame_size);
+            unsafe { *bytes_written = written };
+            0
+        }
+        None => {
+            unsafe { *bytes_written = 0 };
+            -1
+        }
+    }
+}
+
+unsafe extern "C" fn frame_encryptor_get_max_ciphertext_byte_size(
+    media_type: i32,
+    frame_size: usize,
+    user_data: *mut c_void,
+) -> usize {
+    let state = unsafe { &*(user_data as *const FrameEncryptorHandlerState) };
+    state
+        .handler
+        .lock()
+        .unwrap()
+        .get_max_ciphertext_byte_size(MediaType::from_int(media_type), frame_size)
+}
+
+unsafe extern "C" fn frame_encryptor_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "frame_encryptor_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut FrameEncryptorHandlerState) };
+}
+
+/// webrtc::FrameEncryptorInterface のラッパー。
+///
+/// RtpSender::set_frame_encryptor で設定する。
+pub struct FrameEncryptor {
+    raw_ref: ScopedRef<FrameEncryptorHandle>,
+}
+
+unsafe impl Send for FrameEncryptor {}
+
+impl FrameEncryptor {
+    pub fn new_with_handler(handler: Box<dyn FrameEncryptorHandler>) -> Self {
+        let state = Box::new(FrameEncryptorHandlerState {
+            handler: Mutex::new(handler),
+        });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_FrameEncryptorInterface_cbs {
+            Encrypt: Some(frame_encryptor_encrypt),
+            GetMaxCiphertextByteSize: Some(frame_encryptor_get_max_ciphertext_byte_size),
+            OnDestroy: Some(frame_encryptor_on_destroy),
+        };
+        let raw = match NonNull::new(unsafe {
+            ffi::webrtc_FrameEncryptorInterface_make_ref_counted(&cbs, user_data)
+        }) {
+            Some(raw) => raw,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut FrameEncryptorHandlerState) };
+                panic!("BUG: webrtc_FrameEncryptorInterface_make_ref_counted が null を返しました");
+            }
+        };
+        let raw_ref = ScopedRef::<FrameEncryptorHandle>::from_raw(raw);
+        Self { raw_ref }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_FrameEncryptorInterface {
+        self.raw_ref.as_ptr()
+    }
+
+    pub fn as_refcounted_ptr(&self) -> *mut ffi::webrtc_FrameEncryptorInterface_refcounted {
+        self.raw_ref.as_refcounted_ptr()
+    }
+}
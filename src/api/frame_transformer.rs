@@ -0,0 +1,362 @@
+use crate::ref_count::{FrameTransformerHandle, TransformedFrameCallbackHandle};
+use crate::{CxxString, Result, ScopedRef, ffi};
+use std::collections::HashMap;
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+use std::sync::Mutex;
+
+/// TransformableFrameInterface::Direction のラッパー。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformableFrameDirection {
+    Receiver,
+    Sender,
+    Unknown(i32),
+}
+
+impl TransformableFrameDirection {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_TransformableFrameInterface_Direction_kReceiver {
+                TransformableFrameDirection::Receiver
+            } else if value == ffi::webrtc_TransformableFrameInterface_Direction_kSender {
+                TransformableFrameDirection::Sender
+            } else {
+                TransformableFrameDirection::Unknown(value)
+            }
+        }
+    }
+}
+
+/// 映像フレームのメタデータ。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransformableVideoFrameMetadata {
+    pub width: u16,
+    pub height: u16,
+    pub frame_id: Option<i64>,
+    pub spatial_index: i32,
+    pub temporal_index: i32,
+}
+
+/// webrtc::TransformableFrameInterface の所有ラッパー。
+pub struct TransformableFrame {
+    raw_unique: NonNull<ffi::webrtc_TransformableFrameInterface_unique>,
+}
+
+unsafe impl Send for TransformableFrame {}
+
+impl TransformableFrame {
+    fn from_unique_ptr(
+        raw_unique: NonNull<ffi::webrtc_TransformableFrameInterface_unique>,
+    ) -> Self {
+        Self { raw_unique }
+    }
+
+    fn into_raw(self) -> NonNull<ffi::webrtc_TransformableFrameInterface_unique> {
+        let raw_unique = self.raw_unique;
+        std::mem::forget(self);
+        raw_unique
+    }
+
+    fn raw(&self) -> NonNull<ffi::webrtc_TransformableFrameInterface> {
+        let raw =
+            unsafe { ffi::webrtc_TransformableFrameInterface_unique_get(self.raw_unique.as_ptr()) };
+        NonNull::new(raw)
+            .expect("BUG: webrtc_TransformableFrameInterface_unique_get が null を返しました")
+    }
+
+    /// エンコード済みのペイロード。
+    pub fn data(&self) -> &[u8] {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe {
+            ffi::webrtc_TransformableFrameInterface_GetData(self.raw().as_ptr(), &mut ptr, &mut len)
+        };
+        if len == 0 {
+            return &[];
+        }
+        assert!(!ptr.is_null());
+        unsafe { std::slice::from_raw_parts(ptr, len) }
+    }
+
+    pub fn set_data(&mut self, data: &[u8]) {
+        unsafe {
+            ffi::webrtc_TransformableFrameInterface_SetData(
+                self.raw().as_ptr(),
+                data.as_ptr(),
+                data.len(),
+            )
+        };
+    }
+
+    pub fn payload_type(&self) -> u8 {
+        unsafe { ffi::webrtc_TransformableFrameInterface_GetPayloadType(self.raw().as_ptr()) as u8 }
+    }
+
+    pub fn ssrc(&self) -> u32 {
+        unsafe { ffi::webrtc_TransformableFrameInterface_GetSsrc(self.raw().as_ptr()) }
+    }
+
+    pub fn rtp_timestamp(&self) -> u32 {
+        unsafe { ffi::webrtc_TransformableFrameInterface_GetTimestamp(self.raw().as_ptr()) }
+    }
+
+    pub fn set_rtp_timestamp(&mut self, timestamp: u32) {
+        unsafe {
+            ffi::webrtc_TransformableFrameInterface_SetRTPTimestamp(self.raw().as_ptr(), timestamp)
+        };
+    }
+
+    pub fn direction(&self) -> TransformableFrameDirection {
+        let value =
+            unsafe { ffi::webrtc_TransformableFrameInterface_GetDirection(self.raw().as_ptr()) };
+        TransformableFrameDirection::from_int(value)
+    }
+
+    /// "video/VP8" や "audio/opus" などの MIME タイプ。
+    pub fn mime_type(&self) -> Result<String> {
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_TransformableFrameInterface_GetMimeType(self.raw().as_ptr())
+        })
+        .expect("BUG: webrtc_TransformableFrameInterface_GetMimeType が null を返しました");
+        CxxString::from_unique(raw).to_string()
+    }
+
+    pub fn is_video(&self) -> bool {
+        self.mime_type()
+            .map(|mime_type| mime_type.starts_with("video/"))
+            .unwrap_or(false)
+    }
+
+    /// 映像フレームの場合のみ Some を返す。
+    pub fn is_key_frame(&self) -> Option<bool> {
+        let video = self.video_raw()?;
+        Some(unsafe {
+            ffi::webrtc_TransformableVideoFrameInterface_IsKeyFrame(video.as_ptr()) != 0
+        })
+    }
+
+    /// 映像フレームの場合のみ Some を返す。
+    pub fn video_metadata(&self) -> Option<TransformableVideoFrameMetadata> {
+        let video = self.video_raw()?;
+        let mut has_frame_id = 0;
+        let mut frame_id = 0i64;
+        unsafe {
+            ffi::webrtc_TransformableVideoFrameInterface_GetFrameId(
+                video.as_ptr(),
+                &mut has_frame_id,
+                &mut frame_id,
+            )
+        };
+        Some(TransformableVideoFrameMetadata {
+            width: unsafe { ffi::webrtc_TransformableVideoFrameInterface_GetWidth(video.as_ptr()) }
+                as u16,
+            height: unsafe {
+                ffi::webrtc_TransformableVideoFrameInterface_GetHeight(video.as_ptr())
+            } as u16,
+            frame_id: (has_frame_id != 0).then_some(frame_id),
+            spatial_index: unsafe {
+                ffi::webrtc_TransformableVideoFrameInterface_GetSpatialIndex(video.as_ptr())
+            },
+            temporal_index: unsafe {
+                ffi::webrtc_TransformableVideoFrameInterface_GetTemporalIndex(video.as_ptr())
+            },
+        })
+    }
+
+    // libwebrtc は RTTI 無しでビルドされるため、MIME タイプで映像フレームかを判定してからキャストする。
+    fn video_raw(&self) -> Option<NonNull<ffi::webrtc_TransformableVideoFrameInterface>> {
+        if !self.is_video() {
+            return None;
+        }
+        let raw = unsafe {
+            ffi::webrtc_TransformableFrameInterface_cast_to_webrtc_TransformableVideoFrameInterface(
+                self.raw().as_ptr(),
+            )
+        };
+        Some(NonNull::new(raw).expect(
+            "BUG: webrtc_TransformableFrameInterface_cast_to_webrtc_TransformableVideoFrameInterface が null を返しました",
+        ))
+    }
+}
+
+impl Drop for TransformableFrame {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_TransformableFrameInterface_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
+
+/// フレーム変換処理。
+///
+/// transform はエンコーダー / デコーダーのスレッドから呼ばれる。
+pub trait FrameTransformerHandler: Send {
+    /// 変換後のフレームを返す。None を返した場合はフレームを破棄する。
+    fn transform(&mut self, frame: TransformableFrame) -> Option<TransformableFrame> {
+        Some(frame)
+    }
+}
+
+#[derive(Clone)]
+struct TransformedFrameCallback {
+    raw_ref: ScopedRef<TransformedFrameCallbackHandle>,
+}
+
+unsafe impl Send for TransformedFrameCallback {}
+
+impl TransformedFrameCallback {
+    fn on_transformed_frame(&self, frame: TransformableFrame) {
+        unsafe {
+            ffi::webrtc_TransformedFrameCallback_OnTransformedFrame(
+                self.raw_ref.as_ptr(),
+                frame.into_raw().as_ptr(),
+            )
+        };
+    }
+}
+
+#[derive(Default)]
+struct TransformedFrameCallbacks {
+    callback: Option<TransformedFrameCallback>,
+    sink_callbacks: HashMap<u32, TransformedFrameCallback>,
+}
+
+struct FrameTransformerHandlerState {
+    handler: Mutex<Box<dyn FrameTransformerHandler>>,
+    callbacks: Mutex<TransformedFrameCallbacks>,
+}
+
+unsafe impl Send for FrameTransformerHandlerState {}
+
+fn new_transformed_frame_callback(
+    callback: *mut ffi::webrtc_TransformedFrameCallback_refcounted,
+) -> TransformedFrameCallback {
+    let raw = NonNull::new(callback).expect("BUG: callback が null です");
+    TransformedFrameCallback {
+        raw_ref: ScopedRef::<TransformedFrameCallbackHandle>::from_raw(raw),
+    }
+}
+
+unsafe extern "C" fn frame_transformer_transform(
+    frame: *mut ffi::webrtc_TransformableFrameInterface_unique,
+    user_data: *mut c_void,
+) {
+    let state = unsafe { &*(user_data as *const FrameTransformerHandlerState) };
+    let frame =
+        TransformableFrame::from_unique_ptr(NonNull::new(frame).expect("BUG: frame が null です"));
+    let ssrc = frame.ssrc();
+    let Some(frame) = state.handler.lock().unwrap().transform(frame) else {
+        return;
+    };
+    // ssrc 別のコールバックが登録されている場合はそちらを優先する。
+    let callback = {
+        let callbacks = state.callbacks.lock().unwrap();
+        callbacks
+            .sink_callbacks
+            .get(&ssrc)
+            .or(callbacks.callback.as_ref())
+            .cloned()
+    };
+    if let Some(callback) = callback {
+        callback.on_transformed_frame(frame);
+    }
+}
+
+unsafe extern "C" fn frame_transformer_register_transformed_frame_callback(
+    callback: *mut ffi::webrtc_TransformedFrameCallback_refcounted,
+    user_data: *mut c_void,
+) {
+    let state = unsafe { &*(user_data as *const FrameTransformerHandlerState) };
+    state.callbacks.lock().unwrap().callback = Some(new_transformed_frame_callback(callback));
+}
+
+unsafe extern "C" fn frame_transformer_register_transformed_frame_sink_callback(
+    callback: *mut ffi::webrtc_TransformedFrameCallback_refcounted,
+    ssrc: u32,
+    user_data: *mut c_void,
+) {
+    let state = unsafe { &*(user_data as *const FrameTransformerHandlerState) };
+    state
+        .callbacks
+        .lock()
+        .unwrap()
+        .sink_callbacks
+        .insert(ssrc, new_transformed_frame_callback(callback));
+}
+
+unsafe extern "C" fn frame_transformer_unregister_transformed_frame_callback(
+    user_data: *mut c_void,
+) {
+    let state = unsafe { &*(user_data as *const FrameTransformerHandlerState) };
+    state.callbacks.lock().unwrap().callback = None;
+}
+
+unsafe extern "C" fn frame_transformer_unregister_transformed_frame_sink_callback(
+    ssrc: u32,
+    user_data: *mut c_void,
+) {
+    let state = unsafe { &*(user_data as *const FrameTransformerHandlerState) };
+    state.callbacks.lock().unwrap().sink_callbacks.remove(&ssrc);
+}
+
+unsafe extern "C" fn frame_transformer_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "frame_transformer_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut FrameTransformerHandlerState) };
+}
+
+/// webrtc::FrameTransformerInterface のラッパー。
+///
+/// RtpSender / RtpReceiver に設定してエンコード済みフレームを変換する。
+pub struct FrameTransformer {
+    raw_ref: ScopedRef<FrameTransformerHandle>,
+}
+
+unsafe impl Send for FrameTransformer {}
+
+impl FrameTransformer {
+    pub fn new_with_handler(handler: Box<dyn FrameTransformerHandler>) -> Self {
+        let state = Box::new(FrameTransformerHandlerState {
+            handler: Mutex::new(handler),
+            callbacks: Mutex::new(TransformedFrameCallbacks::default()),
+        });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_FrameTransformerInterface_cbs {
+            Transform: Some(frame_transformer_transform),
+            RegisterTransformedFrameCallback: Some(
+                frame_transformer_register_transformed_frame_callback,
+            ),
+            RegisterTransformedFrameSinkCallback: Some(
+                frame_transformer_register_transformed_frame_sink_callback,
+            ),
+            UnregisterTransformedFrameCallback: Some(
+                frame_transformer_unregister_transformed_frame_callback,
+            ),
+            UnregisterTransformedFrameSinkCallback: Some(
+                frame_transformer_unregister_transformed_frame_sink_callback,
+            ),
+            OnDestroy: Some(frame_transformer_on_destroy),
+        };
+        let raw = match NonNull::new(unsafe {
+            ffi::webrtc_FrameTransformerInterface_make_ref_counted(&cbs, user_data)
+        }) {
+            Some(raw) => raw,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut FrameTransformerHandlerState) };
+                panic!(
+                    "BUG: webrtc_FrameTransformerInterface_make_ref_counted が null を返しました"
+                );
+            }
+        };
+        let raw_ref = ScopedRef::<FrameTransformerHandle>::from_raw(raw);
+        Self { raw_ref }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_FrameTransformerInterface {
+        self.raw_ref.as_ptr()
+    }
+
+    pub fn as_refcounted_ptr(&self) -> *mut ffi::webrtc_FrameTransformerInterface_refcounted {
+        self.raw_ref.as_refcounted_ptr()
+    }
+}
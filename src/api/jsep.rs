@@ -1,4 +1,4 @@
-use crate::{CxxString, Error, Result, ffi};
+use crate::{Candidate, CandidateRef, CxxString, Error, ParsedSessionDescriptionRef, Result, ffi};
 use std::marker::PhantomData;
 use std::mem::ManuallyDrop;
 use std::ptr::NonNull;
@@ -97,6 +97,9 @@ pub struct SessionDescription {
 unsafe impl Send for SessionDescription {}
 
 impl SessionDescription {
+    /// SDP をパースして SessionDescription を生成する。
+    ///
+    /// sdp_type が SdpType::Rollback の場合、sdp は無視される。
     pub fn new(sdp_type: SdpType, sdp: &str) -> Result<Self> {
         let raw = unsafe {
             ffi::webrtc_CreateSessionDescription(
@@ -125,18 +128,31 @@ impl SessionDescription {
         SdpType::from_int(ty)
     }
 
+    /// SDP 文字列を返す。rollback の場合は空文字列を返す。
     pub fn to_string(&self) -> Result<String> {
         let raw = self.raw();
         let mut out = std::ptr::null_mut();
         let ok =
             unsafe { ffi::webrtc_SessionDescriptionInterface_ToString(raw.as_ptr(), &mut out) };
         if ok == 0 {
+            // rollback は SDP を持たないため ToString が失敗する。
+            if self.sdp_type() == SdpType::Rollback {
+                return Ok(String::new());
+            }
             return Err(Error::InvalidSdp);
         }
         CxxString::from_unique(NonNull::new(out).expect("BUG: ok != 0 なのに out が null"))
             .to_string()
     }
 
+    /// パース済みの内容を返す。rollback の場合は None を返す。
+    pub fn description(&self) -> Option<ParsedSessionDescriptionRef<'_>> {
+        let raw =
+            unsafe { ffi::webrtc_SessionDescriptionInterface_description(self.raw().as_ptr()) };
+        NonNull::new(raw as *mut ffi::webrtc_SessionDescription)
+            .map(ParsedSessionDescriptionRef::from_raw)
+    }
+
     fn raw(&self) -> NonNull<ffi::webrtc_SessionDescriptionInterface> {
         let raw =
             unsafe { ffi::webrtc_SessionDescriptionInterface_unique_get(self.raw_unique.as_ptr()) };
@@ -184,6 +200,15 @@ impl<'a> IceCandidateRef<'a> {
         unsafe { ffi::webrtc_IceCandidate_sdp_mline_index(self.raw.as_ptr()) }
     }
 
+    /// 構造化された candidate を返す。
+    pub fn candidate(&self) -> CandidateRef<'a> {
+        let raw = unsafe { ffi::webrtc_IceCandidate_candidate(self.raw.as_ptr()) };
+        CandidateRef::from_raw(
+            NonNull::new(raw as *mut ffi::webrtc_Candidate)
+                .expect("BUG: webrtc_IceCandidate_candidate が null を返しました"),
+        )
+    }
+
     pub fn to_string(&self) -> Result<String> {
         let mut out: *mut ffi::std_string_unique = std::ptr::null_mut();
         let ok = unsafe { ffi::webrtc_IceCandidate_ToString(self.raw.as_ptr(), &mut out) };
@@ -229,10 +254,29 @@ impl IceCandidate {
         })
     }
 
+    /// 各フィールドを設定した Candidate から IceCandidate を生成する。
+    pub fn from_candidate(sdp_mid: &str, sdp_mline_index: i32, candidate: &Candidate) -> Self {
+        let raw = unsafe {
+            ffi::webrtc_IceCandidate_new(
+                sdp_mid.as_ptr() as *const _,
+                sdp_mid.len(),
+                sdp_mline_index,
+                candidate.as_ptr(),
+            )
+        };
+        Self {
+            raw: NonNull::new(raw).expect("BUG: webrtc_IceCandidate_new が null を返しました"),
+        }
+    }
+
     pub fn as_ref(&self) -> IceCandidateRef<'_> {
         IceCandidateRef::from_raw(self.raw)
     }
 
+    pub fn candidate(&self) -> CandidateRef<'_> {
+        self.as_ref().candidate()
+    }
+
     pub fn as_ptr(&self) -> *mut ffi::webrtc_IceCandidate {
         self.raw.as_ptr()
     }
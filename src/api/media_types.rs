@@ -5,6 +5,7 @@ use crate::ffi;
 pub enum MediaType {
     Audio,
     Video,
+    Data,
     Unknown(i32),
 }
 
@@ -15,6 +16,8 @@ impl MediaType {
                 MediaType::Audio
             } else if v == ffi::webrtc_MediaType_VIDEO {
                 MediaType::Video
+            } else if v == ffi::webrtc_MediaType_DATA {
+                MediaType::Data
             } else {
                 MediaType::Unknown(v)
             }
@@ -25,6 +28,7 @@ impl MediaType {
         match self {
             MediaType::Audio => unsafe { ffi::webrtc_MediaType_AUDIO },
             MediaType::Video => unsafe { ffi::webrtc_MediaType_VIDEO },
+            MediaType::Data => unsafe { ffi::webrtc_MediaType_DATA },
             MediaType::Unknown(v) => *v,
         }
     }
@@ -1,34 +1,60 @@
+mod async_dns_resolver;
 mod audio;
 mod audio_device_module;
+mod candidate;
+mod clock;
+mod crypto_options;
 mod data_channel;
 mod dtls_transport;
+mod dtmf_sender;
 mod environment;
+mod frame_decryptor;
+mod frame_encryptor;
+mod frame_transformer;
 mod jsep;
 mod media_stream;
 mod media_types;
+mod network_emulation;
+mod packet_socket_factory;
 mod peer_connection;
 mod rtc_error;
 mod rtc_event_log;
 mod rtp;
+mod session_description;
 mod stats;
+mod task_queue;
+mod time_controller;
 mod video;
 mod video_codec_common;
 mod video_decoder;
 mod video_encoder;
 
+pub use async_dns_resolver::*;
 pub use audio::*;
 pub use audio_device_module::*;
+pub use candidate::*;
+pub use clock::*;
+pub use crypto_options::*;
 pub use data_channel::*;
 pub use dtls_transport::*;
+pub use dtmf_sender::*;
 pub use environment::*;
+pub use frame_decryptor::*;
+pub use frame_encryptor::*;
+pub use frame_transformer::*;
 pub use jsep::*;
 pub use media_stream::*;
 pub use media_types::*;
+pub use network_emulation::*;
+pub use packet_socket_factory::*;
 pub use peer_connection::*;
 pub use rtc_error::*;
 pub use rtc_event_log::*;
 pub use rtp::*;
+pub use session_description::*;
 pub use stats::*;
+pub use task_queue::*;
+pub use time_controller::*;
 pub use video::*;
 pub use video_codec_common::*;
 pub use video_decoder::*;
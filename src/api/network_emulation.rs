@@ -0,0 +1,352 @@
+use crate::ffi;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/// webrtc::BuiltInNetworkBehaviorConfig のラッパー。
+///
+/// エミュレートするリンクの遅延・損失・帯域などを設定する。
+pub struct BuiltInNetworkBehaviorConfig {
+    raw: NonNull<ffi::webrtc_BuiltInNetworkBehaviorConfig>,
+}
+
+unsafe impl Send for BuiltInNetworkBehaviorConfig {}
+
+impl BuiltInNetworkBehaviorConfig {
+    pub fn new() -> Self {
+        let raw = NonNull::new(unsafe { ffi::webrtc_BuiltInNetworkBehaviorConfig_new() })
+            .expect("BUG: webrtc_BuiltInNetworkBehaviorConfig_new が null を返しました");
+        Self { raw }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_BuiltInNetworkBehaviorConfig {
+        self.raw.as_ptr()
+    }
+
+    /// キューに保持できる最大パケット数。0 の場合は無制限。
+    pub fn queue_length_packets(&self) -> usize {
+        unsafe { ffi::webrtc_BuiltInNetworkBehaviorConfig_get_queue_length_packets(self.as_ptr()) }
+    }
+
+    pub fn set_queue_length_packets(&mut self, value: usize) {
+        unsafe {
+            ffi::webrtc_BuiltInNetworkBehaviorConfig_set_queue_length_packets(self.as_ptr(), value)
+        };
+    }
+
+    /// 片方向の遅延 (ミリ秒)。
+    pub fn queue_delay_ms(&self) -> i32 {
+        unsafe { ffi::webrtc_BuiltInNetworkBehaviorConfig_get_queue_delay_ms(self.as_ptr()) }
+    }
+
+    pub fn set_queue_delay_ms(&mut self, value: i32) {
+        unsafe {
+            ffi::webrtc_BuiltInNetworkBehaviorConfig_set_queue_delay_ms(self.as_ptr(), value)
+        };
+    }
+
+    /// 遅延のジッター (標準偏差、ミリ秒)。
+    pub fn delay_standard_deviation_ms(&self) -> i32 {
+        unsafe {
+            ffi::webrtc_BuiltInNetworkBehaviorConfig_get_delay_standard_deviation_ms(self.as_ptr())
+        }
+    }
+
+    pub fn set_delay_standard_deviation_ms(&mut self, value: i32) {
+        unsafe {
+            ffi::webrtc_BuiltInNetworkBehaviorConfig_set_delay_standard_deviation_ms(
+                self.as_ptr(),
+                value,
+            )
+        };
+    }
+
+    /// リンクの帯域 (kbps)。None の場合は無制限。
+    pub fn link_capacity_kbps(&self) -> Option<i64> {
+        let mut has = 0;
+        let mut value = 0;
+        unsafe {
+            ffi::webrtc_BuiltInNetworkBehaviorConfig_get_link_capacity_kbps(
+                self.as_ptr(),
+                &mut has,
+                &mut value,
+            );
+        }
+        if has == 0 { None } else { Some(value) }
+    }
+
+    pub fn set_link_capacity_kbps(&mut self, value: Option<i64>) {
+        match value {
+            Some(v) => unsafe {
+                ffi::webrtc_BuiltInNetworkBehaviorConfig_set_link_capacity_kbps(
+                    self.as_ptr(),
+                    1,
+                    &v,
+                );
+            },
+            None => unsafe {
+                ffi::webrtc_BuiltInNetworkBehaviorConfig_set_link_capacity_kbps(
+                    self.as_ptr(),
+                    0,
+                    std::ptr::null(),
+                );
+            },
+        }
+    }
+
+    /// パケット損失率 (%)。
+    pub fn loss_percent(&self) -> i32 {
+        unsafe { ffi::webrtc_BuiltInNetworkBehaviorConfig_get_loss_percent(self.as_ptr()) }
+    }
+
+    pub fn set_loss_percent(&mut self, value: i32) {
+        unsafe { ffi::webrtc_BuiltInNetworkBehaviorConfig_set_loss_percent(self.as_ptr(), value) };
+    }
+
+    /// ジッターによるパケットの順序入れ替えを許可するかどうか。
+    pub fn allow_reordering(&self) -> bool {
+        unsafe { ffi::webrtc_BuiltInNetworkBehaviorConfig_get_allow_reordering(self.as_ptr()) != 0 }
+    }
+
+    pub fn set_allow_reordering(&mut self, value: bool) {
+        unsafe {
+            ffi::webrtc_BuiltInNetworkBehaviorConfig_set_allow_reordering(
+                self.as_ptr(),
+                value as i32,
+            )
+        };
+    }
+
+    /// バースト損失の平均長 (パケット数)。-1 の場合はランダム損失。
+    pub fn avg_burst_loss_length(&self) -> i32 {
+        unsafe { ffi::webrtc_BuiltInNetworkBehaviorConfig_get_avg_burst_loss_length(self.as_ptr()) }
+    }
+
+    pub fn set_avg_burst_loss_length(&mut self, value: i32) {
+        unsafe {
+            ffi::webrtc_BuiltInNetworkBehaviorConfig_set_avg_burst_loss_length(self.as_ptr(), value)
+        };
+    }
+
+    /// パケットごとに加算するオーバーヘッド (バイト)。
+    pub fn packet_overhead(&self) -> i32 {
+        unsafe { ffi::webrtc_BuiltInNetworkBehaviorConfig_get_packet_overhead(self.as_ptr()) }
+    }
+
+    pub fn set_packet_overhead(&mut self, value: i32) {
+        unsafe {
+            ffi::webrtc_BuiltInNetworkBehaviorConfig_set_packet_overhead(self.as_ptr(), value)
+        };
+    }
+}
+
+impl Default for BuiltInNetworkBehaviorConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for BuiltInNetworkBehaviorConfig {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_BuiltInNetworkBehaviorConfig_delete(self.raw.as_ptr()) };
+    }
+}
+
+/// webrtc::EmulatedNetworkNode の借用ラッパー。
+#[derive(Clone, Copy)]
+pub struct EmulatedNetworkNodeRef<'a> {
+    raw: NonNull<ffi::webrtc_EmulatedNetworkNode>,
+    _marker: PhantomData<&'a NetworkEmulationManager>,
+}
+
+unsafe impl<'a> Send for EmulatedNetworkNodeRef<'a> {}
+
+impl<'a> EmulatedNetworkNodeRef<'a> {
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_EmulatedNetworkNode {
+        self.raw.as_ptr()
+    }
+}
+
+/// webrtc::EmulatedEndpoint の借用ラッパー。
+#[derive(Clone, Copy)]
+pub struct EmulatedEndpointRef<'a> {
+    raw: NonNull<ffi::webrtc_EmulatedEndpoint>,
+    _marker: PhantomData<&'a NetworkEmulationManager>,
+}
+
+unsafe impl<'a> Send for EmulatedEndpointRef<'a> {}
+
+impl<'a> EmulatedEndpointRef<'a> {
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_EmulatedEndpoint {
+        self.raw.as_ptr()
+    }
+}
+
+/// webrtc::EmulatedRoute の借用ラッパー。
+#[derive(Clone, Copy)]
+pub struct EmulatedRouteRef<'a> {
+    raw: NonNull<ffi::webrtc_EmulatedRoute>,
+    _marker: PhantomData<&'a NetworkEmulationManager>,
+}
+
+unsafe impl<'a> Send for EmulatedRouteRef<'a> {}
+
+impl<'a> EmulatedRouteRef<'a> {
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_EmulatedRoute {
+        self.raw.as_ptr()
+    }
+}
+
+/// webrtc::EmulatedNetworkManagerInterface の借用ラッパー。
+///
+/// PeerConnectionFactoryDependencies::set_emulated_network で PeerConnectionFactory に渡す。
+/// NetworkManager の所有権を 1 度だけ渡せるよう、複製できない。
+pub struct EmulatedNetworkManagerInterfaceRef<'a> {
+    raw: NonNull<ffi::webrtc_EmulatedNetworkManagerInterface>,
+    _marker: PhantomData<&'a NetworkEmulationManager>,
+}
+
+unsafe impl<'a> Send for EmulatedNetworkManagerInterfaceRef<'a> {}
+
+impl<'a> EmulatedNetworkManagerInterfaceRef<'a> {
+    fn from_raw(raw: *mut ffi::webrtc_EmulatedNetworkManagerInterface) -> Self {
+        Self {
+            raw: NonNull::new(raw)
+                .expect("BUG: EmulatedNetworkManagerInterface が null を返しました"),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_EmulatedNetworkManagerInterface {
+        self.raw.as_ptr()
+    }
+}
+
+/// webrtc::NetworkEmulationManager のラッパー。
+///
+/// 生成したノードやエンドポイント、ネットワークスレッドはこのオブジェクトが所有するため、
+/// これらを使う PeerConnectionFactory より後に破棄すること。
+pub struct NetworkEmulationManager {
+    raw_unique: NonNull<ffi::webrtc_NetworkEmulationManager_unique>,
+}
+
+unsafe impl Send for NetworkEmulationManager {}
+
+impl NetworkEmulationManager {
+    /// 実時間で動作する NetworkEmulationManager を生成する。
+    pub fn new() -> Self {
+        let raw_unique = NonNull::new(unsafe { ffi::webrtc_CreateNetworkEmulationManager() })
+            .expect("BUG: webrtc_CreateNetworkEmulationManager が null を返しました");
+        Self { raw_unique }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_NetworkEmulationManager {
+        unsafe { ffi::webrtc_NetworkEmulationManager_unique_get(self.raw_unique.as_ptr()) }
+    }
+
+    /// config の特性を持つノードを生成する。random_seed は損失やジッターの乱数に使う。
+    pub fn create_emulated_node(
+        &self,
+        config: &BuiltInNetworkBehaviorConfig,
+        random_seed: u64,
+    ) -> EmulatedNetworkNodeRef<'_> {
+        let raw = unsafe {
+            ffi::webrtc_NetworkEmulationManager_CreateEmulatedNode(
+                self.as_ptr(),
+                config.as_ptr(),
+                random_seed,
+            )
+        };
+        EmulatedNetworkNodeRef {
+            raw: NonNull::new(raw).expect(
+                "BUG: webrtc_NetworkEmulationManager_CreateEmulatedNode が null を返しました",
+            ),
+            _marker: PhantomData,
+        }
+    }
+
+    /// IPv4 のエンドポイントを生成する。
+    pub fn create_endpoint(&self) -> EmulatedEndpointRef<'_> {
+        let raw = unsafe { ffi::webrtc_NetworkEmulationManager_CreateEndpoint(self.as_ptr()) };
+        EmulatedEndpointRef {
+            raw: NonNull::new(raw)
+                .expect("BUG: webrtc_NetworkEmulationManager_CreateEndpoint が null を返しました"),
+            _marker: PhantomData,
+        }
+    }
+
+    /// from から via_nodes を順に経由して to へ届く片方向の経路を生成する。
+    pub fn create_route(
+        &self,
+        from: EmulatedEndpointRef<'_>,
+        via_nodes: &[EmulatedNetworkNodeRef<'_>],
+        to: EmulatedEndpointRef<'_>,
+    ) -> EmulatedRouteRef<'_> {
+        let mut nodes: Vec<_> = via_nodes.iter().map(|n| n.as_ptr()).collect();
+        let raw = unsafe {
+            ffi::webrtc_NetworkEmulationManager_CreateRoute(
+                self.as_ptr(),
+                from.as_ptr(),
+                nodes.as_mut_ptr(),
+                nodes.len(),
+                to.as_ptr(),
+            )
+        };
+        EmulatedRouteRef {
+            raw: NonNull::new(raw)
+                .expect("BUG: webrtc_NetworkEmulationManager_CreateRoute が null を返しました"),
+            _marker: PhantomData,
+        }
+    }
+
+    /// endpoints をネットワークインターフェースとして公開する NetworkManager を生成する。
+    pub fn create_emulated_network_manager_interface(
+        &self,
+        endpoints: &[EmulatedEndpointRef<'_>],
+    ) -> EmulatedNetworkManagerInterfaceRef<'_> {
+        let mut endpoints: Vec<_> = endpoints.iter().map(|e| e.as_ptr()).collect();
+        let raw = unsafe {
+            ffi::webrtc_NetworkEmulationManager_CreateEmulatedNetworkManagerInterface(
+                self.as_ptr(),
+                endpoints.as_mut_ptr(),
+                endpoints.len(),
+            )
+        };
+        EmulatedNetworkManagerInterfaceRef::from_raw(raw)
+    }
+
+    /// config の特性を持つ双方向の経路で結ばれたエンドポイントの組を生成する。
+    pub fn create_endpoint_pair_with_two_way_routes(
+        &self,
+        config: &BuiltInNetworkBehaviorConfig,
+    ) -> (
+        EmulatedNetworkManagerInterfaceRef<'_>,
+        EmulatedNetworkManagerInterfaceRef<'_>,
+    ) {
+        let mut first = std::ptr::null_mut();
+        let mut second = std::ptr::null_mut();
+        unsafe {
+            ffi::webrtc_NetworkEmulationManager_CreateEndpointPairWithTwoWayRoutes(
+                self.as_ptr(),
+                config.as_ptr(),
+                &mut first,
+                &mut second,
+            );
+        }
+        (
+            EmulatedNetworkManagerInterfaceRef::from_raw(first),
+            EmulatedNetworkManagerInterfaceRef::from_raw(second),
+        )
+    }
+}
+
+impl Default for NetworkEmulationManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for NetworkEmulationManager {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_NetworkEmulationManager_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{AsyncDnsResolverFactory, AsyncPacketSocket, SocketAddressRef, ffi};
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+
+/// パケットソケットを生成する。
+///
+/// コールバックは network thread から呼ばれる。
+/// None を返した場合はソケットの生成に失敗したものとして扱われる。
+pub trait PacketSocketFactoryHandler: Send {
+    /// address に、min_port から max_port の範囲でバインドした UDP ソケットを返す。
+    /// min_port と max_port が 0 の場合は任意のポートを使ってよい。
+    fn create_udp_socket(
+        &mut self,
+        address: SocketAddressRef<'_>,
+        min_port: u16,
+        max_port: u16,
+    ) -> Option<AsyncPacketSocket>;
+
+    #[expect(unused_variables)]
+    fn create_client_tcp_socket(
+        &mut self,
+        local_address: SocketAddressRef<'_>,
+        remote_address: SocketAddressRef<'_>,
+    ) -> Option<AsyncPacketSocket> {
+        None
+    }
+}
+
+struct PacketSocketFactoryHandlerState {
+    handler: Box<dyn PacketSocketFactoryHandler>,
+}
+
+unsafe impl Send for PacketSocketFactoryHandlerState {}
+
+unsafe extern "C" fn packet_socket_factory_create_udp_socket(
+    address: *const ffi::webrtc_SocketAddress,
+    min_port: u16,
+    max_port: u16,
+    user_data: *mut c_void,
+) -> *mut ffi::webrtc_AsyncPacketSocket_unique {
+    assert!(
+        !user_data.is_null(),
+        "packet_socket_factory_create_udp_socket: user_data is null"
+    );
+    let state = unsafe { &mut *(user_data as *mut PacketSocketFactoryHandlerState) };
+    let address =
+        NonNull::new(address as *mut ffi::webrtc_SocketAddress).expect("BUG: address が null です");
+    match state
+        .handler
+        .create_udp_socket(SocketAddressRef::from_raw(address), min_port, max_port)
+    {
+        Some(socket) => socket.into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn packet_socket_factory_create_client_tcp_socket(
+    local_address: *const ffi::webrtc_SocketAddress,
+    remote_address: *const ffi::webrtc_SocketAddress,
+    user_data: *mut c_void,
+) -> *mut ffi::webrtc_AsyncPacketSocket_unique {
+    assert!(
+        !user_data.is_null(),
+        "packet_socket_factory_create_client_tcp_socket: user_data is null"
+    );
+    let state = unsafe { &mut *(user_data as *mut PacketSocketFactoryHandlerState) };
+    let local_address = NonNull::new(local_address as *mut ffi::webrtc_SocketAddress)
+        .expect("BUG: local_address が null です");
+    let remote_address = NonNull::new(remote_address as *mut ffi::webrtc_SocketAddress)
+        .expect("BUG: remote_address が null です");
+    match state.handler.create_client_tcp_socket(
+        SocketAddressRef::from_raw(local_address),
+        SocketAddressRef::from_raw(remote_address),
+    ) {
+        Some(socket) => socket.into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn packet_socket_factory_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "packet_socket_factory_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut PacketSocketFactoryHandlerState) };
+}
+
+/// Rust で実装する webrtc::PacketSocketFactory。
+///
+/// PeerConnectionDependencies::set_port_allocator などで設定する。
+pub struct PacketSocketFactory {
+    raw_unique: NonNull<ffi::webrtc_PacketSocketFactory_unique>,
+}
+
+unsafe impl Send for PacketSocketFactory {}
+
+impl PacketSocketFactory {
+    pub fn new_with_handler(handler: Box<dyn PacketSocketFactoryHandler>) -> Self {
+        let state = Box::new(PacketSocketFactoryHandlerState { handler });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_PacketSocketFactory_cbs {
+            CreateUdpSocket: Some(packet_socket_factory_create_udp_socket),
+            CreateClientTcpSocket: Some(packet_socket_factory_create_client_tcp_socket),
+            OnDestroy: Some(packet_socket_factory_on_destroy),
+        };
+        let raw = unsafe { ffi::webrtc_PacketSocketFactory_new(&cbs, user_data) };
+        let raw_unique = match NonNull::new(raw) {
+            Some(raw_unique) => raw_unique,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut PacketSocketFactoryHandlerState) };
+                panic!("BUG: webrtc_PacketSocketFactory_new が null を返しました");
+            }
+        };
+        Self { raw_unique }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_PacketSocketFactory {
+        unsafe { ffi::webrtc_PacketSocketFactory_unique_get(self.raw_unique.as_ptr()) }
+    }
+
+    pub fn into_raw(self) -> *mut ffi::webrtc_PacketSocketFactory_unique {
+        std::mem::ManuallyDrop::new(self).raw_unique.as_ptr()
+    }
+
+    /// STUN / TURN サーバーのホスト名解決に使う。
+    /// 設定しない場合は組み込みの resolver を使う。
+    pub fn set_async_dns_resolver_factory(&mut self, resolver_factory: AsyncDnsResolverFactory) {
+        unsafe {
+            ffi::webrtc_PacketSocketFactory_set_async_dns_resolver_factory(
+                self.as_ptr(),
+                resolver_factory.into_raw(),
+            )
+        };
+    }
+}
+
+impl Drop for PacketSocketFactory {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_PacketSocketFactory_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
@@ -1,21 +1,31 @@
 use crate::ref_count::{
     AudioTrackHandle, AudioTrackSourceHandle, ConnectionContextHandle, DataChannelHandle,
     DtlsTransportHandle, MediaStreamHandle, PeerConnectionFactoryHandle, PeerConnectionHandle,
-    RtpReceiverHandle, RtpSenderHandle, RtpTransceiverHandle, SetLocalDescriptionObserverHandle,
-    SetRemoteDescriptionObserverHandle, VideoTrackHandle,
+    RTCCertificateHandle, RtpReceiverHandle, RtpSenderHandle, RtpTransceiverHandle,
+    SetLocalDescriptionObserverHandle, SetRemoteDescriptionObserverHandle, VideoTrackHandle,
 };
 use crate::{
-    AudioDecoderFactory, AudioDeviceModule, AudioEncoderFactory, AudioProcessingBuilder,
-    AudioTrack, AudioTrackSource, CxxString, DataChannel, DataChannelInit, DtlsTransport, Error,
-    IceCandidate, IceCandidateRef, MediaStream, MediaStreamTrack, MediaType, RTCStatsReport,
-    Result, RtcError, RtcEventLogFactory, RtpCapabilities, RtpReceiver, RtpSender, RtpTransceiver,
-    RtpTransceiverInit, SSLCertificateVerifier, SSLIdentity, ScopedRef, SessionDescription,
-    StringVector, Thread, VideoDecoderFactory, VideoEncoderFactory, VideoTrack, VideoTrackSource,
-    ffi,
+    AsyncDnsResolverFactory, AudioDecoderFactory, AudioDeviceModule, AudioEncoderFactory,
+    AudioProcessingBuilder, AudioTrack, AudioTrackSource, CandidatePairChangeEventRef,
+    CandidateRef, CryptoOptionsRef, CxxString, DataChannel, DataChannelInit, DtlsTransport,
+    EmulatedNetworkManagerInterfaceRef, Environment, Error, IceCandidate, IceCandidateRef,
+    MediaStream, MediaStreamTrack, MediaType, NetworkManager, PacketSocketFactory, RTCCertificate,
+    RTCStatsReport, Result, RtcError, RtcEventLogFactory, RtcEventLogOutput, RtpCapabilities,
+    RtpReceiver, RtpSender, RtpTransceiver, RtpTransceiverInit, SSLCertificateVerifier,
+    SSLIdentity, ScopedRef, SessionDescription, StringVector, Thread, VideoDecoderFactory,
+    VideoEncoderFactory, VideoTrack, VideoTrackSource, ffi,
 };
+use std::collections::VecDeque;
+use std::future::Future;
 use std::marker::PhantomData;
 use std::os::raw::{c_char, c_void};
+use std::pin::Pin;
 use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{RecvError, RecvTimeoutError, TryRecvError};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
 
 /// PeerConnectionFactoryDependencies のラッパー。
 /// スレッドやファクトリーのライフサイクルは呼び出し側で管理する。
@@ -65,6 +75,68 @@ impl PeerConnectionFactoryDependencies {
         }
     }
 
+    /// エミュレートされたネットワークのスレッド・ソケット・NetworkManager を設定する。
+    ///
+    /// network thread も置き換えるため set_network_thread とは併用しないこと。
+    /// NetworkManager の所有権を移すため network は消費される。
+    ///
+    /// # Safety
+    /// network のスレッドとソケットは NetworkEmulationManager が所有する。
+    /// このオブジェクトから生成した PeerConnectionFactory と PeerConnection を
+    /// すべて破棄するまで NetworkEmulationManager を破棄してはならない。
+    pub unsafe fn set_emulated_network(&mut self, network: EmulatedNetworkManagerInterfaceRef<'_>) {
+        unsafe {
+            ffi::webrtc_PeerConnectionFactoryDependencies_set_network_thread(
+                self.raw.as_ptr(),
+                ffi::webrtc_EmulatedNetworkManagerInterface_network_thread(network.as_ptr()),
+            );
+            ffi::webrtc_PeerConnectionFactoryDependencies_set_socket_factory(
+                self.raw.as_ptr(),
+                ffi::webrtc_EmulatedNetworkManagerInterface_socket_factory(network.as_ptr()),
+            );
+            let network_manager =
+                ffi::webrtc_EmulatedNetworkManagerInterface_ReleaseNetworkManager(network.as_ptr());
+            assert!(
+                !network_manager.is_null(),
+                "BUG: webrtc_EmulatedNetworkManagerInterface_ReleaseNetworkManager が null を返しました"
+            );
+            ffi::webrtc_PeerConnectionFactoryDependencies_set_network_manager(
+                self.raw.as_ptr(),
+                network_manager,
+            );
+        }
+    }
+
+    /// ConnectionContext の既定の NetworkManager を置き換える。
+    pub fn set_network_manager(&mut self, network_manager: NetworkManager) {
+        unsafe {
+            ffi::webrtc_PeerConnectionFactoryDependencies_set_network_manager(
+                self.raw.as_ptr(),
+                network_manager.into_raw(),
+            );
+        }
+    }
+
+    /// ConnectionContext の既定の PacketSocketFactory を置き換える。
+    pub fn set_packet_socket_factory(&mut self, packet_socket_factory: PacketSocketFactory) {
+        unsafe {
+            ffi::webrtc_PeerConnectionFactoryDependencies_set_packet_socket_factory(
+                self.raw.as_ptr(),
+                packet_socket_factory.into_raw(),
+            );
+        }
+    }
+
+    /// 設定しない場合は既定の Environment が使われる。
+    ///
+    /// EnvironmentBuilder で設定した field trial は factory と、
+    /// そこから生成した PeerConnection で利用される。
+    pub fn set_env(&mut self, env: &Environment) {
+        unsafe {
+            ffi::webrtc_PeerConnectionFactoryDependencies_set_env(self.raw.as_ptr(), env.as_ptr());
+        }
+    }
+
     pub fn set_audio_encoder_factory(&mut self, factory: &AudioEncoderFactory) {
         let raw_ref = factory.as_refcounted_ptr();
         unsafe {
@@ -464,6 +536,10 @@ impl PeerConnectionRtcConfiguration {
         Self { raw }
     }
 
+    fn from_raw(raw: NonNull<ffi::webrtc_PeerConnectionInterface_RTCConfiguration>) -> Self {
+        Self { raw }
+    }
+
     pub fn set_type(&mut self, ice_type: IceTransportsType) {
         unsafe {
             ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_type(
@@ -473,58 +549,622 @@ impl PeerConnectionRtcConfiguration {
         }
     }
 
-    /// DataChannel 未生成でも SDP に DataChannel 用の m=application セクションを常にネゴシエートする。
-    pub fn set_always_negotiate_data_channels(&mut self, enable: bool) {
+    /// DataChannel 未生成でも SDP に DataChannel 用の m=application セクションを常にネゴシエートする。
+    pub fn set_always_negotiate_data_channels(&mut self, enable: bool) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_always_negotiate_data_channels(
+                self.raw.as_ptr(),
+                enable as i32,
+            );
+        }
+    }
+
+    /// have-local-offer 状態で offer を set_remote_description したときに、
+    /// 自身の offer を自動で rollback するかどうか。
+    pub fn enable_implicit_rollback(&self) -> bool {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_enable_implicit_rollback(
+                self.raw.as_ptr(),
+            ) != 0
+        }
+    }
+
+    pub fn set_enable_implicit_rollback(&mut self, enable: bool) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_enable_implicit_rollback(
+                self.raw.as_ptr(),
+                enable as i32,
+            );
+        }
+    }
+
+    /// 統計情報のタイムスタンプを Environment の Clock から取得するかどうか。
+    /// 無効の場合は UTC の現在時刻を使う。
+    pub fn stats_timestamp_with_environment_clock(&self) -> bool {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_stats_timestamp_with_environment_clock(
+                self.raw.as_ptr(),
+            ) != 0
+        }
+    }
+
+    pub fn set_stats_timestamp_with_environment_clock(&mut self, enable: bool) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_stats_timestamp_with_environment_clock(
+                self.raw.as_ptr(),
+                enable as i32,
+            );
+        }
+    }
+
+    pub fn ice_type(&self) -> IceTransportsType {
+        IceTransportsType::from_int(unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_type(self.raw.as_ptr())
+        })
+    }
+
+    pub fn bundle_policy(&self) -> BundlePolicy {
+        BundlePolicy::from_int(unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_bundle_policy(
+                self.raw.as_ptr(),
+            )
+        })
+    }
+
+    pub fn set_bundle_policy(&mut self, bundle_policy: BundlePolicy) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_bundle_policy(
+                self.raw.as_ptr(),
+                bundle_policy.to_int(),
+            );
+        }
+    }
+
+    pub fn rtcp_mux_policy(&self) -> RtcpMuxPolicy {
+        RtcpMuxPolicy::from_int(unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_rtcp_mux_policy(
+                self.raw.as_ptr(),
+            )
+        })
+    }
+
+    pub fn set_rtcp_mux_policy(&mut self, rtcp_mux_policy: RtcpMuxPolicy) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_rtcp_mux_policy(
+                self.raw.as_ptr(),
+                rtcp_mux_policy.to_int(),
+            );
+        }
+    }
+
+    pub fn continual_gathering_policy(&self) -> ContinualGatheringPolicy {
+        ContinualGatheringPolicy::from_int(unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_continual_gathering_policy(
+                self.raw.as_ptr(),
+            )
+        })
+    }
+
+    pub fn set_continual_gathering_policy(
+        &mut self,
+        continual_gathering_policy: ContinualGatheringPolicy,
+    ) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_continual_gathering_policy(
+                self.raw.as_ptr(),
+                continual_gathering_policy.to_int(),
+            );
+        }
+    }
+
+    pub fn tcp_candidate_policy(&self) -> TcpCandidatePolicy {
+        TcpCandidatePolicy::from_int(unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_tcp_candidate_policy(
+                self.raw.as_ptr(),
+            )
+        })
+    }
+
+    pub fn set_tcp_candidate_policy(&mut self, tcp_candidate_policy: TcpCandidatePolicy) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_tcp_candidate_policy(
+                self.raw.as_ptr(),
+                tcp_candidate_policy.to_int(),
+            );
+        }
+    }
+
+    pub fn candidate_network_policy(&self) -> CandidateNetworkPolicy {
+        CandidateNetworkPolicy::from_int(unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_candidate_network_policy(
+                self.raw.as_ptr(),
+            )
+        })
+    }
+
+    pub fn set_candidate_network_policy(
+        &mut self,
+        candidate_network_policy: CandidateNetworkPolicy,
+    ) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_candidate_network_policy(
+                self.raw.as_ptr(),
+                candidate_network_policy.to_int(),
+            );
+        }
+    }
+
+    /// 事前に収集しておく ICE 候補のプールサイズ。
+    pub fn ice_candidate_pool_size(&self) -> i32 {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_ice_candidate_pool_size(
+                self.raw.as_ptr(),
+            )
+        }
+    }
+
+    pub fn set_ice_candidate_pool_size(&mut self, size: i32) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_ice_candidate_pool_size(
+                self.raw.as_ptr(),
+                size,
+            );
+        }
+    }
+
+    /// ICE で使用するポート範囲の下限。0 の場合は制限しない。
+    pub fn min_port(&self) -> i32 {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_PortAllocatorConfig_get_min_port(
+                self.port_allocator_config(),
+            )
+        }
+    }
+
+    pub fn set_min_port(&mut self, port: i32) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_PortAllocatorConfig_set_min_port(
+                self.port_allocator_config(),
+                port,
+            );
+        }
+    }
+
+    /// ICE で使用するポート範囲の上限。0 の場合は制限しない。
+    pub fn max_port(&self) -> i32 {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_PortAllocatorConfig_get_max_port(
+                self.port_allocator_config(),
+            )
+        }
+    }
+
+    pub fn set_max_port(&mut self, port: i32) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_PortAllocatorConfig_set_max_port(
+                self.port_allocator_config(),
+                port,
+            );
+        }
+    }
+
+    fn port_allocator_config(
+        &self,
+    ) -> *mut ffi::webrtc_PeerConnectionInterface_PortAllocatorConfig {
+        let raw = unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_port_allocator_config(
+                self.raw.as_ptr(),
+            )
+        };
+        assert!(!raw.is_null());
+        raw
+    }
+
+    /// DTLS に使用する証明書の一覧を取得する。
+    pub fn certificates(&self) -> Vec<RTCCertificate> {
+        let raw = self.certificates_ptr();
+        let len = unsafe { ffi::webrtc_RTCCertificate_refcounted_vector_size(raw) }.max(0) as usize;
+        let mut out = Vec::with_capacity(len);
+        for i in 0..len {
+            let certificate =
+                unsafe { ffi::webrtc_RTCCertificate_refcounted_vector_get(raw, i as i32) };
+            if let Some(certificate) = NonNull::new(certificate) {
+                let raw_ref = ScopedRef::<RTCCertificateHandle>::from_raw(certificate);
+                out.push(RTCCertificate::from_scoped_ref(raw_ref));
+            }
+        }
+        out
+    }
+
+    /// DTLS に使用する証明書を追加する。未指定の場合は PeerConnection 生成時に自動生成される。
+    pub fn add_certificate(&mut self, certificate: &RTCCertificate) {
+        unsafe {
+            ffi::webrtc_RTCCertificate_refcounted_vector_push_back(
+                self.certificates_ptr(),
+                certificate.as_refcounted_ptr(),
+            );
+        }
+    }
+
+    pub fn clear_certificates(&mut self) {
+        unsafe { ffi::webrtc_RTCCertificate_refcounted_vector_resize(self.certificates_ptr(), 0) };
+    }
+
+    fn certificates_ptr(&self) -> *mut ffi::webrtc_RTCCertificate_refcounted_vector {
+        let raw = unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_certificates(self.raw.as_ptr())
+        };
+        assert!(!raw.is_null());
+        raw
+    }
+
+    pub fn enable_dscp(&self) -> bool {
+        unsafe { ffi::webrtc_PeerConnectionInterface_RTCConfiguration_dscp(self.raw.as_ptr()) != 0 }
+    }
+
+    pub fn set_enable_dscp(&mut self, enable: bool) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_dscp(
+                self.raw.as_ptr(),
+                enable as i32,
+            );
+        }
+    }
+
+    /// ICE 接続が受信なしと判断されるまでの時間 (ミリ秒)。
+    pub fn ice_connection_receiving_timeout(&self) -> Option<i32> {
+        let mut has = 0;
+        let mut value = 0;
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_ice_connection_receiving_timeout(self.raw.as_ptr(), &mut has, &mut value);
+        }
+        if has == 0 { None } else { Some(value) }
+    }
+
+    pub fn set_ice_connection_receiving_timeout(&mut self, value: Option<i32>) {
+        match value {
+            Some(v) => unsafe {
+                ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_ice_connection_receiving_timeout(self.raw.as_ptr(), 1, &v);
+            },
+            None => unsafe {
+                ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_ice_connection_receiving_timeout(self.raw.as_ptr(), 0, std::ptr::null());
+            },
+        }
+    }
+
+    /// ICE 接続チェックの最小送信間隔 (ミリ秒)。
+    pub fn ice_check_min_interval(&self) -> Option<i32> {
+        let mut has = 0;
+        let mut value = 0;
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_ice_check_min_interval(
+                self.raw.as_ptr(),
+                &mut has,
+                &mut value,
+            );
+        }
+        if has == 0 { None } else { Some(value) }
+    }
+
+    pub fn set_ice_check_min_interval(&mut self, value: Option<i32>) {
+        match value {
+            Some(v) => unsafe {
+                ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_ice_check_min_interval(
+                    self.raw.as_ptr(),
+                    1,
+                    &v,
+                );
+            },
+            None => unsafe {
+                ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_ice_check_min_interval(
+                    self.raw.as_ptr(),
+                    0,
+                    std::ptr::null(),
+                );
+            },
+        }
+    }
+
+    /// STUN 候補のキープアライブ間隔 (ミリ秒)。
+    pub fn stun_candidate_keepalive_interval(&self) -> Option<i32> {
+        let mut has = 0;
+        let mut value = 0;
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_stun_candidate_keepalive_interval(self.raw.as_ptr(), &mut has, &mut value);
+        }
+        if has == 0 { None } else { Some(value) }
+    }
+
+    pub fn set_stun_candidate_keepalive_interval(&mut self, value: Option<i32>) {
+        match value {
+            Some(v) => unsafe {
+                ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_stun_candidate_keepalive_interval(self.raw.as_ptr(), 1, &v);
+            },
+            None => unsafe {
+                ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_stun_candidate_keepalive_interval(self.raw.as_ptr(), 0, std::ptr::null());
+            },
+        }
+    }
+
+    /// crypto_options への可変参照を取得する。寿命は self に束縛される。
+    pub fn crypto_options(&mut self) -> CryptoOptionsRef<'_> {
+        let raw = NonNull::new(unsafe { ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_crypto_options(self.raw.as_ptr()) })
+            .expect("BUG: webrtc_PeerConnectionInterface_RTCConfiguration_get_crypto_options が null を返しました");
+        CryptoOptionsRef::from_raw(raw)
+    }
+
+    /// offer に a=extmap-allow-mixed を含めるかどうか。
+    pub fn offer_extmap_allow_mixed(&self) -> bool {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_offer_extmap_allow_mixed(
+                self.raw.as_ptr(),
+            ) != 0
+        }
+    }
+
+    pub fn set_offer_extmap_allow_mixed(&mut self, enable: bool) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_offer_extmap_allow_mixed(
+                self.raw.as_ptr(),
+                enable as i32,
+            );
+        }
+    }
+
+    /// servers への可変参照を取得する。寿命は self に束縛される。
+    pub fn servers(&mut self) -> IceServerVectorRef<'_> {
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_servers(self.raw.as_ptr())
+        })
+        .expect(
+            "BUG: webrtc_PeerConnectionInterface_RTCConfiguration_get_servers が null を返しました",
+        );
+        IceServerVectorRef::from_raw(raw)
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_PeerConnectionInterface_RTCConfiguration {
+        self.raw.as_ptr()
+    }
+}
+
+impl Default for PeerConnectionRtcConfiguration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for PeerConnectionRtcConfiguration {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_PeerConnectionInterface_RTCConfiguration_delete(self.raw.as_ptr()) };
+    }
+}
+
+/// IceTransportsType のラッパー。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IceTransportsType {
+    None,
+    Relay,
+    NoHost,
+    All,
+    Unknown(i32),
+}
+
+impl IceTransportsType {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_PeerConnectionInterface_IceTransportsType_kNone {
+                IceTransportsType::None
+            } else if value == ffi::webrtc_PeerConnectionInterface_IceTransportsType_kRelay {
+                IceTransportsType::Relay
+            } else if value == ffi::webrtc_PeerConnectionInterface_IceTransportsType_kNoHost {
+                IceTransportsType::NoHost
+            } else if value == ffi::webrtc_PeerConnectionInterface_IceTransportsType_kAll {
+                IceTransportsType::All
+            } else {
+                IceTransportsType::Unknown(value)
+            }
+        }
+    }
+
+    pub fn to_int(self) -> i32 {
+        match self {
+            IceTransportsType::None => unsafe {
+                ffi::webrtc_PeerConnectionInterface_IceTransportsType_kNone
+            },
+            IceTransportsType::Relay => unsafe {
+                ffi::webrtc_PeerConnectionInterface_IceTransportsType_kRelay
+            },
+            IceTransportsType::NoHost => unsafe {
+                ffi::webrtc_PeerConnectionInterface_IceTransportsType_kNoHost
+            },
+            IceTransportsType::All => unsafe {
+                ffi::webrtc_PeerConnectionInterface_IceTransportsType_kAll
+            },
+            IceTransportsType::Unknown(v) => v,
+        }
+    }
+}
+
+/// BundlePolicy のラッパー。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundlePolicy {
+    Balanced,
+    MaxBundle,
+    MaxCompat,
+    Unknown(i32),
+}
+
+impl BundlePolicy {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_PeerConnectionInterface_BundlePolicy_kBundlePolicyBalanced {
+                BundlePolicy::Balanced
+            } else if value
+                == ffi::webrtc_PeerConnectionInterface_BundlePolicy_kBundlePolicyMaxBundle
+            {
+                BundlePolicy::MaxBundle
+            } else if value
+                == ffi::webrtc_PeerConnectionInterface_BundlePolicy_kBundlePolicyMaxCompat
+            {
+                BundlePolicy::MaxCompat
+            } else {
+                BundlePolicy::Unknown(value)
+            }
+        }
+    }
+
+    pub fn to_int(self) -> i32 {
+        match self {
+            BundlePolicy::Balanced => unsafe {
+                ffi::webrtc_PeerConnectionInterface_BundlePolicy_kBundlePolicyBalanced
+            },
+            BundlePolicy::MaxBundle => unsafe {
+                ffi::webrtc_PeerConnectionInterface_BundlePolicy_kBundlePolicyMaxBundle
+            },
+            BundlePolicy::MaxCompat => unsafe {
+                ffi::webrtc_PeerConnectionInterface_BundlePolicy_kBundlePolicyMaxCompat
+            },
+            BundlePolicy::Unknown(v) => v,
+        }
+    }
+}
+
+/// RtcpMuxPolicy のラッパー。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RtcpMuxPolicy {
+    Negotiate,
+    Require,
+    Unknown(i32),
+}
+
+impl RtcpMuxPolicy {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_PeerConnectionInterface_RtcpMuxPolicy_kRtcpMuxPolicyNegotiate {
+                RtcpMuxPolicy::Negotiate
+            } else if value
+                == ffi::webrtc_PeerConnectionInterface_RtcpMuxPolicy_kRtcpMuxPolicyRequire
+            {
+                RtcpMuxPolicy::Require
+            } else {
+                RtcpMuxPolicy::Unknown(value)
+            }
+        }
+    }
+
+    pub fn to_int(self) -> i32 {
+        match self {
+            RtcpMuxPolicy::Negotiate => unsafe {
+                ffi::webrtc_PeerConnectionInterface_RtcpMuxPolicy_kRtcpMuxPolicyNegotiate
+            },
+            RtcpMuxPolicy::Require => unsafe {
+                ffi::webrtc_PeerConnectionInterface_RtcpMuxPolicy_kRtcpMuxPolicyRequire
+            },
+            RtcpMuxPolicy::Unknown(v) => v,
+        }
+    }
+}
+
+/// ContinualGatheringPolicy のラッパー。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContinualGatheringPolicy {
+    GatherOnce,
+    GatherContinually,
+    Unknown(i32),
+}
+
+impl ContinualGatheringPolicy {
+    pub fn from_int(value: i32) -> Self {
         unsafe {
-            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_set_always_negotiate_data_channels(
-                self.raw.as_ptr(),
-                enable as i32,
-            );
+            if value == ffi::webrtc_PeerConnectionInterface_ContinualGatheringPolicy_GATHER_ONCE {
+                ContinualGatheringPolicy::GatherOnce
+            } else if value
+                == ffi::webrtc_PeerConnectionInterface_ContinualGatheringPolicy_GATHER_CONTINUALLY
+            {
+                ContinualGatheringPolicy::GatherContinually
+            } else {
+                ContinualGatheringPolicy::Unknown(value)
+            }
         }
     }
 
-    /// servers への可変参照を取得する。寿命は self に束縛される。
-    pub fn servers(&mut self) -> IceServerVectorRef<'_> {
-        let raw = NonNull::new(unsafe {
-            ffi::webrtc_PeerConnectionInterface_RTCConfiguration_get_servers(self.raw.as_ptr())
-        })
-        .expect(
-            "BUG: webrtc_PeerConnectionInterface_RTCConfiguration_get_servers が null を返しました",
-        );
-        IceServerVectorRef::from_raw(raw)
+    pub fn to_int(self) -> i32 {
+        match self {
+            ContinualGatheringPolicy::GatherOnce => unsafe {
+                ffi::webrtc_PeerConnectionInterface_ContinualGatheringPolicy_GATHER_ONCE
+            },
+            ContinualGatheringPolicy::GatherContinually => unsafe {
+                ffi::webrtc_PeerConnectionInterface_ContinualGatheringPolicy_GATHER_CONTINUALLY
+            },
+            ContinualGatheringPolicy::Unknown(v) => v,
+        }
     }
+}
 
-    pub fn as_ptr(&self) -> *mut ffi::webrtc_PeerConnectionInterface_RTCConfiguration {
-        self.raw.as_ptr()
-    }
+/// TcpCandidatePolicy のラッパー。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TcpCandidatePolicy {
+    Enabled,
+    Disabled,
+    Unknown(i32),
 }
 
-impl Default for PeerConnectionRtcConfiguration {
-    fn default() -> Self {
-        Self::new()
+impl TcpCandidatePolicy {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_PeerConnectionInterface_TcpCandidatePolicy_kTcpCandidatePolicyEnabled {
+                TcpCandidatePolicy::Enabled
+            } else if value == ffi::webrtc_PeerConnectionInterface_TcpCandidatePolicy_kTcpCandidatePolicyDisabled {
+                TcpCandidatePolicy::Disabled
+            } else {
+                TcpCandidatePolicy::Unknown(value)
+            }
+        }
     }
-}
 
-impl Drop for PeerConnectionRtcConfiguration {
-    fn drop(&mut self) {
-        unsafe { ffi::webrtc_PeerConnectionInterface_RTCConfiguration_delete(self.raw.as_ptr()) };
+    pub fn to_int(self) -> i32 {
+        match self {
+            TcpCandidatePolicy::Enabled => unsafe {
+                ffi::webrtc_PeerConnectionInterface_TcpCandidatePolicy_kTcpCandidatePolicyEnabled
+            },
+            TcpCandidatePolicy::Disabled => unsafe {
+                ffi::webrtc_PeerConnectionInterface_TcpCandidatePolicy_kTcpCandidatePolicyDisabled
+            },
+            TcpCandidatePolicy::Unknown(v) => v,
+        }
     }
 }
 
-/// IceTransportsType のラッパー。
+/// CandidateNetworkPolicy のラッパー。
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum IceTransportsType {
-    Relay,
+pub enum CandidateNetworkPolicy {
+    All,
+    LowCost,
     Unknown(i32),
 }
 
-impl IceTransportsType {
+impl CandidateNetworkPolicy {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_PeerConnectionInterface_CandidateNetworkPolicy_kCandidateNetworkPolicyAll {
+                CandidateNetworkPolicy::All
+            } else if value == ffi::webrtc_PeerConnectionInterface_CandidateNetworkPolicy_kCandidateNetworkPolicyLowCost {
+                CandidateNetworkPolicy::LowCost
+            } else {
+                CandidateNetworkPolicy::Unknown(value)
+            }
+        }
+    }
+
     pub fn to_int(self) -> i32 {
         match self {
-            IceTransportsType::Relay => unsafe {
-                ffi::webrtc_PeerConnectionInterface_IceTransportsType_kRelay
+            CandidateNetworkPolicy::All => unsafe {
+                ffi::webrtc_PeerConnectionInterface_CandidateNetworkPolicy_kCandidateNetworkPolicyAll
             },
-            IceTransportsType::Unknown(v) => v,
+            CandidateNetworkPolicy::LowCost => unsafe {
+                ffi::webrtc_PeerConnectionInterface_CandidateNetworkPolicy_kCandidateNetworkPolicyLowCost
+            },
+            CandidateNetworkPolicy::Unknown(v) => v,
         }
     }
 }
@@ -1156,6 +1796,64 @@ impl IceGatheringState {
     }
 }
 
+/// SignalingState のラッパー。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignalingState {
+    Stable,
+    HaveLocalOffer,
+    HaveLocalPrAnswer,
+    HaveRemoteOffer,
+    HaveRemotePrAnswer,
+    Closed,
+    Unknown(i32),
+}
+
+impl SignalingState {
+    pub fn from_int(v: i32) -> Self {
+        unsafe {
+            if v == ffi::webrtc_PeerConnectionInterface_SignalingState_kStable {
+                SignalingState::Stable
+            } else if v == ffi::webrtc_PeerConnectionInterface_SignalingState_kHaveLocalOffer {
+                SignalingState::HaveLocalOffer
+            } else if v == ffi::webrtc_PeerConnectionInterface_SignalingState_kHaveLocalPrAnswer {
+                SignalingState::HaveLocalPrAnswer
+            } else if v == ffi::webrtc_PeerConnectionInterface_SignalingState_kHaveRemoteOffer {
+                SignalingState::HaveRemoteOffer
+            } else if v == ffi::webrtc_PeerConnectionInterface_SignalingState_kHaveRemotePrAnswer {
+                SignalingState::HaveRemotePrAnswer
+            } else if v == ffi::webrtc_PeerConnectionInterface_SignalingState_kClosed {
+                SignalingState::Closed
+            } else {
+                SignalingState::Unknown(v)
+            }
+        }
+    }
+
+    pub fn to_int(self) -> i32 {
+        match self {
+            SignalingState::Stable => unsafe {
+                ffi::webrtc_PeerConnectionInterface_SignalingState_kStable
+            },
+            SignalingState::HaveLocalOffer => unsafe {
+                ffi::webrtc_PeerConnectionInterface_SignalingState_kHaveLocalOffer
+            },
+            SignalingState::HaveLocalPrAnswer => unsafe {
+                ffi::webrtc_PeerConnectionInterface_SignalingState_kHaveLocalPrAnswer
+            },
+            SignalingState::HaveRemoteOffer => unsafe {
+                ffi::webrtc_PeerConnectionInterface_SignalingState_kHaveRemoteOffer
+            },
+            SignalingState::HaveRemotePrAnswer => unsafe {
+                ffi::webrtc_PeerConnectionInterface_SignalingState_kHaveRemotePrAnswer
+            },
+            SignalingState::Closed => unsafe {
+                ffi::webrtc_PeerConnectionInterface_SignalingState_kClosed
+            },
+            SignalingState::Unknown(v) => v,
+        }
+    }
+}
+
 /// ICE candidate エラー情報。
 #[derive(Debug, Clone)]
 pub struct IceCandidateError {
@@ -1185,6 +1883,22 @@ pub trait PeerConnectionObserverHandler: Send {
     fn on_ice_candidate_error(&mut self, error: IceCandidateError) {}
     #[expect(unused_variables)]
     fn on_data_channel(&mut self, data_channel: DataChannel) {}
+    #[expect(unused_variables)]
+    fn on_signaling_change(&mut self, new_state: SignalingState) {}
+    #[expect(unused_variables)]
+    fn on_add_stream(&mut self, stream: MediaStream) {}
+    #[expect(unused_variables)]
+    fn on_remove_stream(&mut self, stream: MediaStream) {}
+    /// event_id は PeerConnection::should_fire_negotiation_needed_event で
+    /// まだ有効かどうかを確認してからネゴシエーションを開始すること。
+    #[expect(unused_variables)]
+    fn on_negotiation_needed_event(&mut self, event_id: u32) {}
+    #[expect(unused_variables)]
+    fn on_ice_connection_receiving_change(&mut self, receiving: bool) {}
+    #[expect(unused_variables)]
+    fn on_ice_candidates_removed(&mut self, candidates: &[CandidateRef<'_>]) {}
+    #[expect(unused_variables)]
+    fn on_ice_selected_candidate_pair_changed(&mut self, event: CandidatePairChangeEventRef<'_>) {}
 }
 
 struct PeerConnectionObserverHandlerState {
@@ -1314,6 +2028,88 @@ unsafe extern "C" fn observer_on_data_channel(
     state.handler.on_data_channel(data_channel);
 }
 
+unsafe extern "C" fn observer_on_signaling_change(new_state: i32, user_data: *mut c_void) {
+    assert!(!user_data.is_null());
+    let state = unsafe { &mut *(user_data as *mut PeerConnectionObserverHandlerState) };
+    state
+        .handler
+        .on_signaling_change(SignalingState::from_int(new_state));
+}
+
+unsafe extern "C" fn observer_on_add_stream(
+    stream: *mut ffi::webrtc_MediaStreamInterface_refcounted,
+    user_data: *mut c_void,
+) {
+    assert!(!user_data.is_null());
+    let state = unsafe { &mut *(user_data as *mut PeerConnectionObserverHandlerState) };
+    let raw_ref = ScopedRef::<MediaStreamHandle>::from_raw(
+        NonNull::new(stream).expect("BUG: stream が null"),
+    );
+    let stream = MediaStream::from_scoped_ref(raw_ref);
+    state.handler.on_add_stream(stream);
+}
+
+unsafe extern "C" fn observer_on_remove_stream(
+    stream: *mut ffi::webrtc_MediaStreamInterface_refcounted,
+    user_data: *mut c_void,
+) {
+    assert!(!user_data.is_null());
+    let state = unsafe { &mut *(user_data as *mut PeerConnectionObserverHandlerState) };
+    let raw_ref = ScopedRef::<MediaStreamHandle>::from_raw(
+        NonNull::new(stream).expect("BUG: stream が null"),
+    );
+    let stream = MediaStream::from_scoped_ref(raw_ref);
+    state.handler.on_remove_stream(stream);
+}
+
+unsafe extern "C" fn observer_on_negotiation_needed_event(event_id: u32, user_data: *mut c_void) {
+    assert!(!user_data.is_null());
+    let state = unsafe { &mut *(user_data as *mut PeerConnectionObserverHandlerState) };
+    state.handler.on_negotiation_needed_event(event_id);
+}
+
+unsafe extern "C" fn observer_on_ice_connection_receiving_change(
+    receiving: i32,
+    user_data: *mut c_void,
+) {
+    assert!(!user_data.is_null());
+    let state = unsafe { &mut *(user_data as *mut PeerConnectionObserverHandlerState) };
+    state
+        .handler
+        .on_ice_connection_receiving_change(receiving != 0);
+}
+
+unsafe extern "C" fn observer_on_ice_candidates_removed(
+    candidates: *mut ffi::webrtc_Candidate_vector,
+    user_data: *mut c_void,
+) {
+    assert!(!user_data.is_null());
+    assert!(!candidates.is_null());
+    let state = unsafe { &mut *(user_data as *mut PeerConnectionObserverHandlerState) };
+    let len = unsafe { ffi::webrtc_Candidate_vector_size(candidates) }.max(0) as usize;
+    let mut refs = Vec::with_capacity(len);
+    for i in 0..len {
+        let candidate = unsafe { ffi::webrtc_Candidate_vector_get(candidates, i as i32) };
+        refs.push(CandidateRef::from_raw(
+            NonNull::new(candidate).expect("BUG: webrtc_Candidate_vector_get が null を返しました"),
+        ));
+    }
+    state.handler.on_ice_candidates_removed(&refs);
+}
+
+unsafe extern "C" fn observer_on_ice_selected_candidate_pair_changed(
+    event: *const ffi::webrtc_CandidatePairChangeEvent,
+    user_data: *mut c_void,
+) {
+    assert!(!user_data.is_null());
+    let state = unsafe { &mut *(user_data as *mut PeerConnectionObserverHandlerState) };
+    let event = NonNull::new(event as *mut ffi::webrtc_CandidatePairChangeEvent)
+        .expect("BUG: event が null");
+    state
+        .handler
+        .on_ice_selected_candidate_pair_changed(CandidatePairChangeEventRef::from_raw(event));
+}
+
 unsafe extern "C" fn observer_on_destroy(user_data: *mut c_void) {
     assert!(
         !user_data.is_null(),
@@ -1343,6 +2139,15 @@ impl PeerConnectionObserver {
             OnDataChannel: Some(observer_on_data_channel),
             OnDestroy: Some(observer_on_destroy),
             OnIceGatheringChange: Some(observer_on_ice_gathering_change),
+            OnSignalingChange: Some(observer_on_signaling_change),
+            OnAddStream: Some(observer_on_add_stream),
+            OnRemoveStream: Some(observer_on_remove_stream),
+            OnNegotiationNeededEvent: Some(observer_on_negotiation_needed_event),
+            OnIceConnectionReceivingChange: Some(observer_on_ice_connection_receiving_change),
+            OnIceCandidatesRemoved: Some(observer_on_ice_candidates_removed),
+            OnIceSelectedCandidatePairChanged: Some(
+                observer_on_ice_selected_candidate_pair_changed,
+            ),
         };
         let raw = match NonNull::new(unsafe {
             ffi::webrtc_PeerConnectionObserver_new(&cbs, user_data)
@@ -1357,20 +2162,346 @@ impl PeerConnectionObserver {
         Self { raw }
     }
 
+    /// イベントをチャネル経由で受け取る PeerConnectionObserver を生成する。
+    ///
+    /// コールバックは signaling スレッドで呼ばれるが、ここではイベントをキューに積むだけで
+    /// ブロックしない。キューに capacity 件が溜まっている状態で届いたイベントは破棄され、
+    /// 破棄した位置で PeerConnectionEvent::Lagged としてその件数が通知される。
+    /// 破棄した件数の累計は PeerConnectionEventReceiver::dropped_events で取得できる。
+    /// capacity に 0 は指定できない。
+    pub fn new_with_channel(capacity: usize) -> (Self, PeerConnectionEventReceiver) {
+        assert!(capacity > 0, "capacity に 0 は指定できません");
+        let shared = Arc::new(PeerConnectionEventShared {
+            queue: Mutex::new(PeerConnectionEventQueue {
+                events: VecDeque::with_capacity(capacity),
+                capacity,
+                dropped: 0,
+                lagged: 0,
+                closed: false,
+                waker: None,
+            }),
+            cond: Condvar::new(),
+        });
+        let sender = PeerConnectionEventSender {
+            shared: shared.clone(),
+        };
+        let observer = Self::new_with_handler(Box::new(sender));
+        (observer, PeerConnectionEventReceiver { shared })
+    }
+
     pub fn as_ptr(&self) -> *mut ffi::webrtc_PeerConnectionObserver {
         self.raw.as_ptr()
     }
 }
 
-impl Drop for PeerConnectionObserver {
-    fn drop(&mut self) {
-        unsafe { ffi::webrtc_PeerConnectionObserver_delete(self.raw.as_ptr()) };
+impl Drop for PeerConnectionObserver {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_PeerConnectionObserver_delete(self.raw.as_ptr()) };
+    }
+}
+
+/// ICE candidate の所有データ。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IceCandidateInit {
+    pub sdp_mid: String,
+    pub sdp_mline_index: i32,
+    pub candidate: String,
+}
+
+impl IceCandidateInit {
+    /// IceCandidate を生成する。
+    pub fn to_ice_candidate(&self) -> Result<IceCandidate> {
+        IceCandidate::new(&self.sdp_mid, self.sdp_mline_index, &self.candidate)
+    }
+}
+
+/// PeerConnectionObserver::new_with_channel で通知されるイベント。
+pub enum PeerConnectionEvent {
+    ConnectionChange(PeerConnectionState),
+    StandardizedIceConnectionChange(IceConnectionState),
+    IceGatheringChange(IceGatheringState),
+    IceCandidate(IceCandidateInit),
+    /// on_ice_candidate で通知された candidate を IceCandidateInit に変換できなかった。
+    InvalidIceCandidate(Error),
+    IceCandidateError(IceCandidateError),
+    Track(RtpTransceiver),
+    RemoveTrack(RtpReceiver),
+    DataChannel(DataChannel),
+    SignalingChange(SignalingState),
+    AddStream(MediaStream),
+    RemoveStream(MediaStream),
+    /// PeerConnection::should_fire_negotiation_needed_event で有効性を確認すること。
+    NegotiationNeeded(u32),
+    IceConnectionReceivingChange(bool),
+    /// キューが満杯だったため、この位置でイベントを指定した件数だけ破棄した。
+    Lagged(u64),
+}
+
+struct PeerConnectionEventQueue {
+    events: VecDeque<PeerConnectionEvent>,
+    capacity: usize,
+    dropped: u64,
+    // まだ Lagged として通知していない破棄件数。
+    lagged: u64,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+impl PeerConnectionEventQueue {
+    fn pop(&mut self) -> Option<PeerConnectionEvent> {
+        if let Some(event) = self.events.pop_front() {
+            return Some(event);
+        }
+        if self.lagged > 0 {
+            return Some(PeerConnectionEvent::Lagged(std::mem::take(
+                &mut self.lagged,
+            )));
+        }
+        None
+    }
+}
+
+struct PeerConnectionEventShared {
+    queue: Mutex<PeerConnectionEventQueue>,
+    cond: Condvar,
+}
+
+impl PeerConnectionEventShared {
+    fn lock(&self) -> std::sync::MutexGuard<'_, PeerConnectionEventQueue> {
+        self.queue
+            .lock()
+            .expect("BUG: PeerConnectionEventQueue の lock に失敗しました")
+    }
+}
+
+// signaling スレッドからイベントをキューに積む。
+struct PeerConnectionEventSender {
+    shared: Arc<PeerConnectionEventShared>,
+}
+
+impl PeerConnectionEventSender {
+    fn send(&self, event: PeerConnectionEvent) {
+        let waker = {
+            let mut queue = self.shared.lock();
+            let mut pushed = false;
+            if queue.lagged > 0 && queue.events.len() < queue.capacity {
+                let lagged = std::mem::take(&mut queue.lagged);
+                queue.events.push_back(PeerConnectionEvent::Lagged(lagged));
+                pushed = true;
+            }
+            if queue.events.len() >= queue.capacity {
+                queue.dropped += 1;
+                queue.lagged += 1;
+                // Lagged を積んだ場合は待っている受信側を起こす。
+                if !pushed {
+                    return;
+                }
+            } else {
+                queue.events.push_back(event);
+            }
+            queue.waker.take()
+        };
+        self.shared.cond.notify_one();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl Drop for PeerConnectionEventSender {
+    fn drop(&mut self) {
+        let waker = {
+            let mut queue = self.shared.lock();
+            queue.closed = true;
+            queue.waker.take()
+        };
+        self.shared.cond.notify_all();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl PeerConnectionObserverHandler for PeerConnectionEventSender {
+    fn on_connection_change(&mut self, new_state: PeerConnectionState) {
+        self.send(PeerConnectionEvent::ConnectionChange(new_state));
+    }
+
+    fn on_standardized_ice_connection_change(&mut self, new_state: IceConnectionState) {
+        self.send(PeerConnectionEvent::StandardizedIceConnectionChange(
+            new_state,
+        ));
+    }
+
+    fn on_ice_gathering_change(&mut self, new_state: IceGatheringState) {
+        self.send(PeerConnectionEvent::IceGatheringChange(new_state));
+    }
+
+    fn on_track(&mut self, transceiver: RtpTransceiver) {
+        self.send(PeerConnectionEvent::Track(transceiver));
+    }
+
+    fn on_remove_track(&mut self, receiver: RtpReceiver) {
+        self.send(PeerConnectionEvent::RemoveTrack(receiver));
+    }
+
+    fn on_ice_candidate(&mut self, candidate: IceCandidateRef<'_>) {
+        let init = candidate.sdp_mid().and_then(|sdp_mid| {
+            Ok(IceCandidateInit {
+                sdp_mid,
+                sdp_mline_index: candidate.sdp_mline_index(),
+                candidate: candidate.to_string()?,
+            })
+        });
+        self.send(match init {
+            Ok(init) => PeerConnectionEvent::IceCandidate(init),
+            Err(e) => PeerConnectionEvent::InvalidIceCandidate(e),
+        });
+    }
+
+    fn on_ice_candidate_error(&mut self, error: IceCandidateError) {
+        self.send(PeerConnectionEvent::IceCandidateError(error));
+    }
+
+    fn on_data_channel(&mut self, data_channel: DataChannel) {
+        self.send(PeerConnectionEvent::DataChannel(data_channel));
+    }
+
+    fn on_signaling_change(&mut self, new_state: SignalingState) {
+        self.send(PeerConnectionEvent::SignalingChange(new_state));
+    }
+
+    fn on_add_stream(&mut self, stream: MediaStream) {
+        self.send(PeerConnectionEvent::AddStream(stream));
+    }
+
+    fn on_remove_stream(&mut self, stream: MediaStream) {
+        self.send(PeerConnectionEvent::RemoveStream(stream));
+    }
+
+    fn on_negotiation_needed_event(&mut self, event_id: u32) {
+        self.send(PeerConnectionEvent::NegotiationNeeded(event_id));
+    }
+
+    fn on_ice_connection_receiving_change(&mut self, receiving: bool) {
+        self.send(PeerConnectionEvent::IceConnectionReceivingChange(receiving));
+    }
+}
+
+/// PeerConnectionObserver::new_with_channel のイベント受信側。
+///
+/// PeerConnectionObserver が破棄され、キューが空になると受信はエラー (async の場合は None) を返す。
+pub struct PeerConnectionEventReceiver {
+    shared: Arc<PeerConnectionEventShared>,
+}
+
+impl PeerConnectionEventReceiver {
+    /// イベントを受信するまでブロックする。
+    pub fn recv(&self) -> std::result::Result<PeerConnectionEvent, RecvError> {
+        let mut queue = self.shared.lock();
+        loop {
+            if let Some(event) = queue.pop() {
+                return Ok(event);
+            }
+            if queue.closed {
+                return Err(RecvError);
+            }
+            queue = self
+                .shared
+                .cond
+                .wait(queue)
+                .expect("BUG: PeerConnectionEventQueue の wait に失敗しました");
+        }
+    }
+
+    /// タイムアウト付きでイベントを受信する。
+    pub fn recv_timeout(
+        &self,
+        timeout: Duration,
+    ) -> std::result::Result<PeerConnectionEvent, RecvTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        let mut queue = self.shared.lock();
+        loop {
+            if let Some(event) = queue.pop() {
+                return Ok(event);
+            }
+            if queue.closed {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(RecvTimeoutError::Timeout);
+            }
+            queue = self
+                .shared
+                .cond
+                .wait_timeout(queue, deadline - now)
+                .expect("BUG: PeerConnectionEventQueue の wait に失敗しました")
+                .0;
+        }
+    }
+
+    /// ブロックせずにイベントを受信する。
+    pub fn try_recv(&self) -> std::result::Result<PeerConnectionEvent, TryRecvError> {
+        let mut queue = self.shared.lock();
+        if let Some(event) = queue.pop() {
+            return Ok(event);
+        }
+        if queue.closed {
+            return Err(TryRecvError::Disconnected);
+        }
+        Err(TryRecvError::Empty)
+    }
+
+    /// 次のイベントを poll する。
+    ///
+    /// Stream::poll_next と同じ意味を持つ。stream feature を有効にすると
+    /// PeerConnectionEventReceiver は futures_core::Stream を実装する。
+    /// 待機できる Waker は最後に poll したもの 1 つだけである。
+    pub fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Option<PeerConnectionEvent>> {
+        let mut queue = self.shared.lock();
+        if let Some(event) = queue.pop() {
+            return Poll::Ready(Some(event));
+        }
+        if queue.closed {
+            return Poll::Ready(None);
+        }
+        queue.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    /// 次のイベントを待つ Future を返す。
+    pub fn recv_async(&self) -> impl Future<Output = Option<PeerConnectionEvent>> + Send + '_ {
+        std::future::poll_fn(|cx| self.poll_recv(cx))
+    }
+
+    /// キューが満杯だったために破棄したイベントの累計件数。
+    pub fn dropped_events(&self) -> u64 {
+        self.shared.lock().dropped
+    }
+}
+
+#[cfg(feature = "stream")]
+impl futures_core::Stream for PeerConnectionEventReceiver {
+    type Item = PeerConnectionEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_recv(cx)
     }
 }
 
 /// PeerConnectionDependencies のラッパー。
 pub struct PeerConnectionDependencies {
     raw: NonNull<ffi::webrtc_PeerConnectionDependencies>,
+    port_allocator_resources: Option<Arc<PortAllocatorResources>>,
+}
+
+/// BasicPortAllocator が参照する NetworkManager と PacketSocketFactory。
+///
+/// PortAllocator は PeerConnection の破棄時に破棄されるため、PeerConnection が保持する。
+struct PortAllocatorResources {
+    _network_manager: NetworkManager,
+    _socket_factory: PacketSocketFactory,
 }
 
 unsafe impl Send for PeerConnectionDependencies {}
@@ -1380,7 +2511,10 @@ impl PeerConnectionDependencies {
         let raw =
             NonNull::new(unsafe { ffi::webrtc_PeerConnectionDependencies_new(observer.as_ptr()) })
                 .expect("BUG: webrtc_PeerConnectionDependencies_new が null を返しました");
-        Self { raw }
+        Self {
+            raw,
+            port_allocator_resources: None,
+        }
     }
 
     pub fn as_ptr(&self) -> *mut ffi::webrtc_PeerConnectionDependencies {
@@ -1416,6 +2550,46 @@ impl PeerConnectionDependencies {
         }
     }
 
+    /// 指定した NetworkManager と PacketSocketFactory を使う BasicPortAllocator を設定する。
+    ///
+    /// env には PeerConnectionFactoryDependencies::set_env に渡したものと同じ Environment を渡す。
+    /// network_manager と socket_factory は生成した PeerConnection が保持する。
+    pub fn set_port_allocator(
+        &mut self,
+        env: &Environment,
+        network_manager: NetworkManager,
+        socket_factory: PacketSocketFactory,
+    ) {
+        let allocator = unsafe {
+            ffi::webrtc_BasicPortAllocator_new(
+                env.as_ptr(),
+                network_manager.as_ptr(),
+                socket_factory.as_ptr(),
+            )
+        };
+        assert!(
+            !allocator.is_null(),
+            "BUG: webrtc_BasicPortAllocator_new が null を返しました"
+        );
+        unsafe {
+            ffi::webrtc_PeerConnectionDependencies_set_allocator(self.raw.as_ptr(), allocator)
+        };
+        self.port_allocator_resources = Some(Arc::new(PortAllocatorResources {
+            _network_manager: network_manager,
+            _socket_factory: socket_factory,
+        }));
+    }
+
+    /// リモートの mDNS (.local) candidate の名前解決に使う。
+    pub fn set_async_dns_resolver_factory(&mut self, resolver_factory: AsyncDnsResolverFactory) {
+        unsafe {
+            ffi::webrtc_PeerConnectionDependencies_set_async_dns_resolver_factory(
+                self.raw.as_ptr(),
+                resolver_factory.into_raw(),
+            );
+        }
+    }
+
     pub fn set_tls_cert_verifier(&mut self, tls_cert_verifier: SSLCertificateVerifier) {
         let raw = tls_cert_verifier.into_raw();
         unsafe {
@@ -1665,9 +2839,147 @@ impl SetRemoteDescriptionObserver {
     }
 }
 
+// 非同期 API 用の完了状態。
+// observer のコールバックから結果を書き込み、待機中の Waker を起こす。
+struct CompletionState<T> {
+    completed: bool,
+    result: Option<Result<T>>,
+    waker: Option<Waker>,
+}
+
+// observer 側から完了を通知するためのハンドル。
+// コールバックが呼ばれないまま observer が破棄された場合はエラーで完了させる。
+struct CompletionSender<T> {
+    state: Arc<Mutex<CompletionState<T>>>,
+}
+
+impl<T> CompletionSender<T> {
+    fn complete(&self, result: Result<T>) {
+        let waker = {
+            let mut state = self
+                .state
+                .lock()
+                .expect("BUG: CompletionState の lock に失敗しました");
+            if state.completed {
+                return;
+            }
+            state.completed = true;
+            state.result = Some(result);
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for CompletionSender<T> {
+    fn drop(&mut self) {
+        self.complete(Err(Error::Message(
+            "observer が完了通知前に破棄されました".to_owned(),
+        )));
+    }
+}
+
+// 完了を待つ Future。特定の非同期ランタイムには依存しない。
+struct CompletionFuture<T> {
+    state: Arc<Mutex<CompletionState<T>>>,
+}
+
+impl<T> Future for CompletionFuture<T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self
+            .state
+            .lock()
+            .expect("BUG: CompletionState の lock に失敗しました");
+        if let Some(result) = state.result.take() {
+            return Poll::Ready(result);
+        }
+        if state.completed {
+            return Poll::Ready(Err(Error::Message(
+                "完了済みの Future が再度 poll されました".to_owned(),
+            )));
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+fn completion_channel<T>() -> (CompletionSender<T>, CompletionFuture<T>) {
+    let state = Arc::new(Mutex::new(CompletionState {
+        completed: false,
+        result: None,
+        waker: None,
+    }));
+    (
+        CompletionSender {
+            state: state.clone(),
+        },
+        CompletionFuture { state },
+    )
+}
+
+fn rtc_error_to_result(error: RtcError) -> Result<()> {
+    if error.ok() {
+        Ok(())
+    } else {
+        Err(Error::RtcError(error))
+    }
+}
+
+struct CreateSessionDescriptionCompletion {
+    sender: CompletionSender<SessionDescription>,
+}
+
+impl CreateSessionDescriptionObserverHandler for CreateSessionDescriptionCompletion {
+    fn on_success(&mut self, desc: SessionDescription) {
+        self.sender.complete(Ok(desc));
+    }
+
+    fn on_failure(&mut self, error: RtcError) {
+        self.sender.complete(Err(Error::RtcError(error)));
+    }
+}
+
+struct SetLocalDescriptionCompletion {
+    sender: CompletionSender<()>,
+}
+
+impl SetLocalDescriptionObserverHandler for SetLocalDescriptionCompletion {
+    fn on_set_local_description_complete(&mut self, error: RtcError) {
+        self.sender.complete(rtc_error_to_result(error));
+    }
+}
+
+struct SetRemoteDescriptionCompletion {
+    sender: CompletionSender<()>,
+}
+
+impl SetRemoteDescriptionObserverHandler for SetRemoteDescriptionCompletion {
+    fn on_set_remote_description_complete(&mut self, error: RtcError) {
+        self.sender.complete(rtc_error_to_result(error));
+    }
+}
+
+/// PeerConnection::handle_remote_description_async の結果。
+pub enum RemoteDescriptionOutcome {
+    /// impolite 側で offer が衝突したため、受け取った offer を無視した。
+    Ignored,
+    /// 受け取った answer を適用した。
+    Applied,
+    /// 受け取った offer を適用し、answer を local description に適用した。
+    Answered(SessionDescription),
+}
+
 /// PeerConnectionInterface のラッパー。
 pub struct PeerConnection {
     raw_ref: ScopedRef<PeerConnectionHandle>,
+    // raw_ref より後に破棄する。
+    // handle_remote_description_async の Future は raw_ref を複製して別スレッドで
+    // 使われうるため、Arc で共有して最後の参照が破棄されるまで保持する。
+    port_allocator_resources: Option<Arc<PortAllocatorResources>>,
 }
 
 unsafe impl Send for PeerConnection {}
@@ -1701,7 +3013,10 @@ impl PeerConnection {
 
         let pc = NonNull::new(out_pc).expect("BUG: out_pc と out_error が両方 null です");
         let raw_ref = ScopedRef::<PeerConnectionHandle>::from_raw(pc);
-        Ok(Self { raw_ref })
+        Ok(Self {
+            raw_ref,
+            port_allocator_resources: deps.port_allocator_resources.take(),
+        })
     }
 
     pub fn create_offer(
@@ -1762,6 +3077,156 @@ impl PeerConnection {
         };
     }
 
+    /// 引数なしの SetLocalDescription。
+    ///
+    /// signaling 状態に応じて offer または answer を生成して local description に適用する。
+    pub fn set_local_description_implicit(&self, observer: &SetLocalDescriptionObserver) {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_SetLocalDescriptionImplicit(
+                self.raw_ref.as_ptr(),
+                observer.as_refcounted_ptr(),
+            )
+        };
+    }
+
+    /// create_offer の Future 版。
+    ///
+    /// 特定の非同期ランタイムには依存せず、完了時は signaling スレッドから Waker を起こす。
+    pub fn create_offer_async(
+        &self,
+        options: &PeerConnectionOfferAnswerOptions,
+    ) -> impl Future<Output = Result<SessionDescription>> + Send + use<> {
+        let (sender, future) = completion_channel();
+        let observer = CreateSessionDescriptionObserver::new_with_handler(Box::new(
+            CreateSessionDescriptionCompletion { sender },
+        ));
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_CreateOffer(
+                self.raw_ref.as_ptr(),
+                observer.as_ptr(),
+                options.as_ptr(),
+            )
+        };
+        future
+    }
+
+    /// create_answer の Future 版。
+    ///
+    /// 特定の非同期ランタイムには依存せず、完了時は signaling スレッドから Waker を起こす。
+    pub fn create_answer_async(
+        &self,
+        options: &PeerConnectionOfferAnswerOptions,
+    ) -> impl Future<Output = Result<SessionDescription>> + Send + use<> {
+        let (sender, future) = completion_channel();
+        let observer = CreateSessionDescriptionObserver::new_with_handler(Box::new(
+            CreateSessionDescriptionCompletion { sender },
+        ));
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_CreateAnswer(
+                self.raw_ref.as_ptr(),
+                observer.as_ptr(),
+                options.as_ptr(),
+            )
+        };
+        future
+    }
+
+    /// set_local_description の Future 版。
+    ///
+    /// 完了通知の RTCError が ok でない場合は Error::RtcError を返す。
+    pub fn set_local_description_async(
+        &self,
+        desc: SessionDescription,
+    ) -> impl Future<Output = Result<()>> + Send + use<> {
+        let (sender, future) = completion_channel();
+        let observer = SetLocalDescriptionObserver::new_with_handler(Box::new(
+            SetLocalDescriptionCompletion { sender },
+        ));
+        self.set_local_description(desc, &observer);
+        future
+    }
+
+    /// set_local_description_implicit の Future 版。
+    ///
+    /// 完了通知の RTCError が ok でない場合は Error::RtcError を返す。
+    pub fn set_local_description_implicit_async(
+        &self,
+    ) -> impl Future<Output = Result<()>> + Send + use<> {
+        let (sender, future) = completion_channel();
+        let observer = SetLocalDescriptionObserver::new_with_handler(Box::new(
+            SetLocalDescriptionCompletion { sender },
+        ));
+        self.set_local_description_implicit(&observer);
+        future
+    }
+
+    /// set_remote_description の Future 版。
+    ///
+    /// 完了通知の RTCError が ok でない場合は Error::RtcError を返す。
+    pub fn set_remote_description_async(
+        &self,
+        desc: SessionDescription,
+    ) -> impl Future<Output = Result<()>> + Send + use<> {
+        let (sender, future) = completion_channel();
+        let observer = SetRemoteDescriptionObserver::new_with_handler(Box::new(
+            SetRemoteDescriptionCompletion { sender },
+        ));
+        self.set_remote_description(desc, &observer);
+        future
+    }
+
+    /// W3C の perfect negotiation パターンに従って、相手から受け取った description を適用する。
+    ///
+    /// making_offer には自身が offer を作成中 (create_offer から set_local_description の完了まで)
+    /// かどうかを示すフラグを渡し、アプリケーション側で更新し続けること。offer が衝突した場合、polite 側は自身の offer を rollback してから
+    /// 相手の offer を受け入れ、impolite 側は相手の offer を無視する。
+    /// offer を受け入れた場合は answer を生成して local description に適用し、
+    /// RemoteDescriptionOutcome::Answered で返すので相手に送信すること。
+    ///
+    /// 衝突の判定は返した Future を最初に poll した時点の making_offer と signaling state で行う。
+    /// polite 側では PeerConnectionRtcConfiguration::set_enable_implicit_rollback を
+    /// 有効にしておくこと。rollback が set_remote_description と不可分に行われるため、
+    /// 判定後に保留中の set_local_description が完了しても正しく rollback される。
+    /// 無効の場合は判定時点で offer を適用済みのときのみ明示的に rollback する。
+    pub fn handle_remote_description_async(
+        &self,
+        desc: SessionDescription,
+        polite: bool,
+        making_offer: &Arc<AtomicBool>,
+    ) -> impl Future<Output = Result<RemoteDescriptionOutcome>> + Send + use<> {
+        let pc = PeerConnection {
+            raw_ref: self.raw_ref.clone(),
+            port_allocator_resources: self.port_allocator_resources.clone(),
+        };
+        let making_offer = making_offer.clone();
+        async move {
+            let is_offer = desc.sdp_type() == SdpType::Offer;
+            let offer_collision = is_offer
+                && (making_offer.load(Ordering::SeqCst)
+                    || pc.signaling_state() != SignalingState::Stable);
+            if !polite && offer_collision {
+                return Ok(RemoteDescriptionOutcome::Ignored);
+            }
+            // stable 状態の rollback はエラーになるため、offer を適用済みの場合のみ行う。
+            if offer_collision
+                && !pc.get_configuration().enable_implicit_rollback()
+                && pc.signaling_state() != SignalingState::Stable
+            {
+                let rollback = SessionDescription::new(SdpType::Rollback, "")?;
+                pc.set_local_description_async(rollback).await?;
+            }
+            pc.set_remote_description_async(desc).await?;
+            if !is_offer {
+                return Ok(RemoteDescriptionOutcome::Applied);
+            }
+            pc.set_local_description_implicit_async().await?;
+            let answer = pc.local_description().ok_or(Error::NullPointer(
+                "webrtc_PeerConnectionInterface_local_description",
+            ))?;
+            Ok(RemoteDescriptionOutcome::Answered(answer))
+        }
+    }
+
     pub fn add_ice_candidate(&mut self, candidate: &IceCandidate) -> Result<()> {
         let ok = unsafe {
             ffi::webrtc_PeerConnectionInterface_AddIceCandidate(
@@ -1775,6 +3240,28 @@ impl PeerConnection {
         Ok(())
     }
 
+    /// 既に追加したリモート candidate を取り除く。
+    ///
+    /// candidate は transport_name (mid) と component / protocol / address で照合する。
+    pub fn remove_ice_candidates(&mut self, candidates: &[CandidateRef<'_>]) -> Result<()> {
+        let vec = unsafe { ffi::webrtc_Candidate_vector_new(0) };
+        assert!(
+            !vec.is_null(),
+            "BUG: webrtc_Candidate_vector_new が null を返しました"
+        );
+        for candidate in candidates {
+            unsafe { ffi::webrtc_Candidate_vector_push_back(vec, candidate.as_ptr()) };
+        }
+        let ok = unsafe {
+            ffi::webrtc_PeerConnectionInterface_RemoveIceCandidates(self.raw_ref.as_ptr(), vec)
+        };
+        unsafe { ffi::webrtc_Candidate_vector_delete(vec) };
+        if ok == 0 {
+            return Err(Error::InvalidIceCandidate);
+        }
+        Ok(())
+    }
+
     pub fn set_configuration(&mut self, config: &mut PeerConnectionRtcConfiguration) -> Result<()> {
         let mut out_error: *mut ffi::webrtc_RTCError_unique = std::ptr::null_mut();
         unsafe {
@@ -1791,6 +3278,15 @@ impl PeerConnection {
         Ok(())
     }
 
+    /// 現在適用されている設定のコピーを取得する。
+    pub fn get_configuration(&self) -> PeerConnectionRtcConfiguration {
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_PeerConnectionInterface_GetConfiguration(self.raw_ref.as_ptr())
+        })
+        .expect("BUG: webrtc_PeerConnectionInterface_GetConfiguration が null を返しました");
+        PeerConnectionRtcConfiguration::from_raw(raw)
+    }
+
     pub fn create_data_channel(
         &self,
         label: &str,
@@ -1928,6 +3424,63 @@ impl PeerConnection {
         };
     }
 
+    /// sender に関連する統計情報のみを取得する。
+    pub fn get_stats_for_sender<F>(&self, sender: &RtpSender, on_stats: F)
+    where
+        F: FnOnce(RTCStatsReport) + Send + 'static,
+    {
+        let state = Box::new(PeerConnectionStatsCallbackState {
+            on_stats: Box::new(on_stats),
+        });
+        let mut cbs = ffi::webrtc_RTCStatsCollectorCallback_cbs {
+            OnStatsDelivered: Some(peer_connection_on_stats),
+        };
+        let user_data = Box::into_raw(state) as *mut c_void;
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_GetStatsWithSender(
+                self.raw_ref.as_ptr(),
+                sender.as_refcounted_ptr(),
+                &mut cbs,
+                user_data,
+            )
+        };
+    }
+
+    /// receiver に関連する統計情報のみを取得する。
+    pub fn get_stats_for_receiver<F>(&self, receiver: &RtpReceiver, on_stats: F)
+    where
+        F: FnOnce(RTCStatsReport) + Send + 'static,
+    {
+        let state = Box::new(PeerConnectionStatsCallbackState {
+            on_stats: Box::new(on_stats),
+        });
+        let mut cbs = ffi::webrtc_RTCStatsCollectorCallback_cbs {
+            OnStatsDelivered: Some(peer_connection_on_stats),
+        };
+        let user_data = Box::into_raw(state) as *mut c_void;
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_GetStatsWithReceiver(
+                self.raw_ref.as_ptr(),
+                receiver.as_refcounted_ptr(),
+                &mut cbs,
+                user_data,
+            )
+        };
+    }
+
+    /// on_negotiation_needed_event で通知された event_id がまだ有効かどうかを返す。
+    ///
+    /// 通知後に状態が変わってネゴシエーションが不要になった場合や、より新しいイベントが
+    /// 通知されている場合は false を返す。
+    pub fn should_fire_negotiation_needed_event(&self, event_id: u32) -> bool {
+        unsafe {
+            ffi::webrtc_PeerConnectionInterface_ShouldFireNegotiationNeededEvent(
+                self.raw_ref.as_ptr(),
+                event_id,
+            ) != 0
+        }
+    }
+
     /// PeerConnection を閉じる。
     ///
     /// 全メディアの終了、トランスポートの切断、リソースの解放を行う不可逆な操作。
@@ -1936,6 +3489,190 @@ impl PeerConnection {
         unsafe { ffi::webrtc_PeerConnectionInterface_Close(self.raw_ref.as_ptr()) };
     }
 
+    /// RtcEventLog の記録を output に開始する。
+    ///
+    /// output_period ごとにまとめて書き出す。None の場合はイベントごとに即時に書き出す。
+    /// output_period はミリ秒単位で、1 ミリ秒未満の場合は失敗する。
+    /// PeerConnectionFactoryDependencies::set_event_log_factory を設定していない場合は失敗する。
+    pub fn start_rtc_event_log(
+        &self,
+        output: RtcEventLogOutput,
+        output_period: Option<Duration>,
+    ) -> Result<()> {
+        let output_period_ms = match output_period {
+            None => 0,
+            Some(period) => match i64::try_from(period.as_millis()) {
+                Ok(ms) if ms > 0 => ms,
+                _ => {
+                    return Err(Error::Message(format!(
+                        "output_period が不正です: {period:?}"
+                    )));
+                }
+            },
+        };
+        let ok = unsafe {
+            ffi::webrtc_PeerConnectionInterface_StartRtcEventLog(
+                self.raw_ref.as_ptr(),
+                output.into_raw(),
+                output_period_ms,
+            )
+        };
+        if ok == 0 {
+            return Err(Error::Message(
+                "RtcEventLog の開始に失敗しました".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// RtcEventLog の記録を停止し、残りのイベントを書き出す。
+    pub fn stop_rtc_event_log(&self) {
+        unsafe { ffi::webrtc_PeerConnectionInterface_StopRtcEventLog(self.raw_ref.as_ptr()) };
+    }
+
+    pub fn signaling_state(&self) -> SignalingState {
+        SignalingState::from_int(unsafe {
+            ffi::webrtc_PeerConnectionInterface_signaling_state(self.raw_ref.as_ptr())
+        })
+    }
+
+    pub fn ice_connection_state(&self) -> IceConnectionState {
+        IceConnectionState::from_int(unsafe {
+            ffi::webrtc_PeerConnectionInterface_ice_connection_state(self.raw_ref.as_ptr())
+        })
+    }
+
+    pub fn standardized_ice_connection_state(&self) -> IceConnectionState {
+        IceConnectionState::from_int(unsafe {
+            ffi::webrtc_PeerConnectionInterface_standardized_ice_connection_state(
+                self.raw_ref.as_ptr(),
+            )
+        })
+    }
+
+    pub fn peer_connection_state(&self) -> PeerConnectionState {
+        PeerConnectionState::from_int(unsafe {
+            ffi::webrtc_PeerConnectionInterface_peer_connection_state(self.raw_ref.as_ptr())
+        })
+    }
+
+    pub fn ice_gathering_state(&self) -> IceGatheringState {
+        IceGatheringState::from_int(unsafe {
+            ffi::webrtc_PeerConnectionInterface_ice_gathering_state(self.raw_ref.as_ptr())
+        })
+    }
+
+    /// 現在有効な local description のコピーを返す。pending があればそちらを優先する。
+    pub fn local_description(&self) -> Option<SessionDescription> {
+        let raw =
+            unsafe { ffi::webrtc_PeerConnectionInterface_local_description(self.raw_ref.as_ptr()) };
+        NonNull::new(raw).map(SessionDescription::from_unique_ptr)
+    }
+
+    /// 現在有効な remote description のコピーを返す。pending があればそちらを優先する。
+    pub fn remote_description(&self) -> Option<SessionDescription> {
+        let raw = unsafe {
+            ffi::webrtc_PeerConnectionInterface_remote_description(self.raw_ref.as_ptr())
+        };
+        NonNull::new(raw).map(SessionDescription::from_unique_ptr)
+    }
+
+    /// 最後に確定した local description のコピーを返す。
+    pub fn current_local_description(&self) -> Option<SessionDescription> {
+        let raw = unsafe {
+            ffi::webrtc_PeerConnectionInterface_current_local_description(self.raw_ref.as_ptr())
+        };
+        NonNull::new(raw).map(SessionDescription::from_unique_ptr)
+    }
+
+    /// 最後に確定した remote description のコピーを返す。
+    pub fn current_remote_description(&self) -> Option<SessionDescription> {
+        let raw = unsafe {
+            ffi::webrtc_PeerConnectionInterface_current_remote_description(self.raw_ref.as_ptr())
+        };
+        NonNull::new(raw).map(SessionDescription::from_unique_ptr)
+    }
+
+    /// ネゴシエーション中の local description のコピーを返す。
+    pub fn pending_local_description(&self) -> Option<SessionDescription> {
+        let raw = unsafe {
+            ffi::webrtc_PeerConnectionInterface_pending_local_description(self.raw_ref.as_ptr())
+        };
+        NonNull::new(raw).map(SessionDescription::from_unique_ptr)
+    }
+
+    /// ネゴシエーション中の remote description のコピーを返す。
+    pub fn pending_remote_description(&self) -> Option<SessionDescription> {
+        let raw = unsafe {
+            ffi::webrtc_PeerConnectionInterface_pending_remote_description(self.raw_ref.as_ptr())
+        };
+        NonNull::new(raw).map(SessionDescription::from_unique_ptr)
+    }
+
+    pub fn get_senders(&self) -> Vec<RtpSender> {
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_PeerConnectionInterface_GetSenders(self.raw_ref.as_ptr())
+        })
+        .expect("BUG: webrtc_PeerConnectionInterface_GetSenders が null を返しました");
+        let len = unsafe { ffi::webrtc_RtpSenderInterface_refcounted_vector_size(raw.as_ptr()) }
+            .max(0) as usize;
+        let mut out = Vec::with_capacity(len);
+        for i in 0..len {
+            let sender = unsafe {
+                ffi::webrtc_RtpSenderInterface_refcounted_vector_get(raw.as_ptr(), i as i32)
+            };
+            if let Some(sender) = NonNull::new(sender) {
+                let raw_ref = ScopedRef::<RtpSenderHandle>::from_raw(sender);
+                out.push(RtpSender::from_scoped_ref(raw_ref));
+            }
+        }
+        unsafe { ffi::webrtc_RtpSenderInterface_refcounted_vector_delete(raw.as_ptr()) };
+        out
+    }
+
+    pub fn get_receivers(&self) -> Vec<RtpReceiver> {
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_PeerConnectionInterface_GetReceivers(self.raw_ref.as_ptr())
+        })
+        .expect("BUG: webrtc_PeerConnectionInterface_GetReceivers が null を返しました");
+        let len = unsafe { ffi::webrtc_RtpReceiverInterface_refcounted_vector_size(raw.as_ptr()) }
+            .max(0) as usize;
+        let mut out = Vec::with_capacity(len);
+        for i in 0..len {
+            let receiver = unsafe {
+                ffi::webrtc_RtpReceiverInterface_refcounted_vector_get(raw.as_ptr(), i as i32)
+            };
+            if let Some(receiver) = NonNull::new(receiver) {
+                let raw_ref = ScopedRef::<RtpReceiverHandle>::from_raw(receiver);
+                out.push(RtpReceiver::from_scoped_ref(raw_ref));
+            }
+        }
+        unsafe { ffi::webrtc_RtpReceiverInterface_refcounted_vector_delete(raw.as_ptr()) };
+        out
+    }
+
+    pub fn get_transceivers(&self) -> Vec<RtpTransceiver> {
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_PeerConnectionInterface_GetTransceivers(self.raw_ref.as_ptr())
+        })
+        .expect("BUG: webrtc_PeerConnectionInterface_GetTransceivers が null を返しました");
+        let len =
+            unsafe { ffi::webrtc_RtpTransceiverInterface_refcounted_vector_size(raw.as_ptr()) }
+                .max(0) as usize;
+        let mut out = Vec::with_capacity(len);
+        for i in 0..len {
+            let transceiver = unsafe {
+                ffi::webrtc_RtpTransceiverInterface_refcounted_vector_get(raw.as_ptr(), i as i32)
+            };
+            if let Some(transceiver) = NonNull::new(transceiver) {
+                let raw_ref = ScopedRef::<RtpTransceiverHandle>::from_raw(transceiver);
+                out.push(RtpTransceiver::from_scoped_ref(raw_ref));
+            }
+        }
+        unsafe { ffi::webrtc_RtpTransceiverInterface_refcounted_vector_delete(raw.as_ptr()) };
+        out
+    }
+
     /// mid に対応する DtlsTransport を取得する。
     pub fn lookup_dtls_transport_by_mid(&self, mid: &str) -> Option<DtlsTransport> {
         let ptr = unsafe {
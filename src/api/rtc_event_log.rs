@@ -1,4 +1,6 @@
-use crate::ffi;
+use crate::{Error, Result, ffi};
+use std::num::NonZeroUsize;
+use std::os::raw::{c_char, c_void};
 use std::ptr::NonNull;
 
 /// webrtc::RtcEventLogFactory のラッパー。
@@ -35,3 +37,133 @@ impl Drop for RtcEventLogFactory {
         unsafe { ffi::webrtc_RtcEventLogFactory_unique_delete(self.raw_unique.as_ptr()) };
     }
 }
+
+/// RtcEventLog の出力先を Rust で実装する。
+///
+/// コールバックは RtcEventLog のタスクキューから呼ばれる。
+pub trait RtcEventLogOutputHandler: Send {
+    /// 書き込みを受け付ける場合は true を返す。
+    fn is_active(&mut self) -> bool {
+        true
+    }
+
+    /// 書き込みに成功した場合は true を返す。false を返すとログの出力を停止する。
+    fn write(&mut self, data: &[u8]) -> bool;
+
+    fn flush(&mut self) {}
+}
+
+struct RtcEventLogOutputHandlerState {
+    handler: Box<dyn RtcEventLogOutputHandler>,
+}
+
+unsafe extern "C" fn rtc_event_log_output_is_active(user_data: *mut c_void) -> i32 {
+    assert!(
+        !user_data.is_null(),
+        "rtc_event_log_output_is_active: user_data is null"
+    );
+    let state = unsafe { &mut *(user_data as *mut RtcEventLogOutputHandlerState) };
+    if state.handler.is_active() { 1 } else { 0 }
+}
+
+unsafe extern "C" fn rtc_event_log_output_write(
+    data: *const c_char,
+    len: usize,
+    user_data: *mut c_void,
+) -> i32 {
+    assert!(
+        !user_data.is_null(),
+        "rtc_event_log_output_write: user_data is null"
+    );
+    let state = unsafe { &mut *(user_data as *mut RtcEventLogOutputHandlerState) };
+    let data = if len == 0 {
+        &[][..]
+    } else {
+        assert!(!data.is_null());
+        unsafe { std::slice::from_raw_parts(data.cast::<u8>(), len) }
+    };
+    if state.handler.write(data) { 1 } else { 0 }
+}
+
+unsafe extern "C" fn rtc_event_log_output_flush(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "rtc_event_log_output_flush: user_data is null"
+    );
+    let state = unsafe { &mut *(user_data as *mut RtcEventLogOutputHandlerState) };
+    state.handler.flush();
+}
+
+unsafe extern "C" fn rtc_event_log_output_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "rtc_event_log_output_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut RtcEventLogOutputHandlerState) };
+}
+
+/// webrtc::RtcEventLogOutput のラッパー。
+///
+/// PeerConnection::start_rtc_event_log に渡す。
+pub struct RtcEventLogOutput {
+    raw_unique: NonNull<ffi::webrtc_RtcEventLogOutput_unique>,
+}
+
+unsafe impl Send for RtcEventLogOutput {}
+
+impl RtcEventLogOutput {
+    pub fn new_with_handler(handler: Box<dyn RtcEventLogOutputHandler>) -> Self {
+        let state = Box::new(RtcEventLogOutputHandlerState { handler });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_RtcEventLogOutput_cbs {
+            IsActive: Some(rtc_event_log_output_is_active),
+            Write: Some(rtc_event_log_output_write),
+            Flush: Some(rtc_event_log_output_flush),
+            OnDestroy: Some(rtc_event_log_output_on_destroy),
+        };
+        let raw = unsafe { ffi::webrtc_RtcEventLogOutput_new(&cbs, user_data) };
+        let raw_unique = match NonNull::new(raw) {
+            Some(raw_unique) => raw_unique,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut RtcEventLogOutputHandlerState) };
+                panic!("BUG: webrtc_RtcEventLogOutput_new が null を返しました");
+            }
+        };
+        Self { raw_unique }
+    }
+
+    /// file_name に書き出す出力先を生成する。
+    ///
+    /// ファイルサイズが max_size_bytes を超える書き込みは行わず、以降の出力を停止する。
+    /// None の場合は上限を設けない。
+    /// max_size_bytes が libwebrtc の上限 (1 GB) を超える場合とファイルを開けなかった場合は失敗する。
+    pub fn new_file(file_name: &str, max_size_bytes: Option<NonZeroUsize>) -> Result<Self> {
+        let raw = unsafe {
+            ffi::webrtc_RtcEventLogOutputFile_Create(
+                file_name.as_ptr() as *const c_char,
+                file_name.len(),
+                max_size_bytes.map_or(0, NonZeroUsize::get),
+            )
+        };
+        let raw_unique = NonNull::new(raw).ok_or_else(|| {
+            Error::Message(format!(
+                "RtcEventLogOutputFile の生成に失敗しました: {file_name}"
+            ))
+        })?;
+        Ok(Self { raw_unique })
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_RtcEventLogOutput {
+        unsafe { ffi::webrtc_RtcEventLogOutput_unique_get(self.raw_unique.as_ptr()) }
+    }
+
+    pub fn into_raw(self) -> *mut ffi::webrtc_RtcEventLogOutput_unique {
+        std::mem::ManuallyDrop::new(self).raw_unique.as_ptr()
+    }
+}
+
+impl Drop for RtcEventLogOutput {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_RtcEventLogOutput_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
@@ -1,11 +1,13 @@
 use crate::ref_count::{
-    AudioTrackHandle, MediaStreamTrackHandle, RtpReceiverHandle, RtpSenderHandle,
-    RtpTransceiverHandle, VideoTrackHandle,
+    AudioTrackHandle, DtlsTransportHandle, DtmfSenderHandle, MediaStreamTrackHandle,
+    RtpReceiverHandle, RtpSenderHandle, RtpTransceiverHandle, VideoTrackHandle,
 };
 use crate::{
-    AudioTrack, CxxString, CxxStringRef, Error, MapStringString, MediaType, Result, RtcError,
-    ScopedRef, StringVectorRef, VideoTrack, ffi,
+    AudioTrack, CxxString, CxxStringRef, DtlsTransport, DtmfSender, Error, FrameDecryptor,
+    FrameEncryptor, FrameTransformer, MapStringString, MediaType, Result, RtcError, ScopedRef,
+    StringVector, StringVectorRef, VideoTrack, ffi,
 };
+use std::ffi::c_void;
 use std::marker::PhantomData;
 use std::ptr::NonNull;
 
@@ -37,6 +39,15 @@ impl RtpCapabilities {
                 .expect("BUG: webrtc_RtpCapabilities_get_codecs が null を返しました");
         RtpCodecCapabilityVectorRef::from_raw(raw)
     }
+
+    /// header_extensions のベクタを借用する。
+    pub fn header_extensions(&self) -> RtpHeaderExtensionCapabilityVectorRef<'_> {
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_RtpCapabilities_get_header_extensions(self.raw.as_ptr())
+        })
+        .expect("BUG: webrtc_RtpCapabilities_get_header_extensions が null を返しました");
+        RtpHeaderExtensionCapabilityVectorRef::from_raw(raw)
+    }
 }
 
 impl Drop for RtpCapabilities {
@@ -347,6 +358,11 @@ impl RtpCodecCapabilityVector {
         Self { raw }
     }
 
+    /// webrtc 側で生成されたベクタを引き取る。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_RtpCodecCapability_vector>) -> Self {
+        Self { raw }
+    }
+
     pub fn len(&self) -> usize {
         self.as_ref().len()
     }
@@ -447,6 +463,205 @@ impl<'a> RtpCodecCapabilityVectorRef<'a> {
     }
 }
 
+/// webrtc::RtpHeaderExtensionCapability の借用ラッパー。
+pub struct RtpHeaderExtensionCapabilityRef<'a> {
+    raw: NonNull<ffi::webrtc_RtpHeaderExtensionCapability>,
+    _marker: PhantomData<&'a ffi::webrtc_RtpHeaderExtensionCapability_vector>,
+}
+
+unsafe impl<'a> Send for RtpHeaderExtensionCapabilityRef<'a> {}
+
+impl<'a> RtpHeaderExtensionCapabilityRef<'a> {
+    pub fn from_raw(raw: NonNull<ffi::webrtc_RtpHeaderExtensionCapability>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn uri(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe {
+            ffi::webrtc_RtpHeaderExtensionCapability_get_uri(self.raw.as_ptr(), &mut ptr, &mut len)
+        };
+        assert!(!ptr.is_null());
+        let bytes = unsafe { std::slice::from_raw_parts(ptr.cast::<u8>(), len) };
+        Ok(std::str::from_utf8(bytes)?.to_owned())
+    }
+
+    pub fn set_uri(&mut self, uri: &str) {
+        unsafe {
+            ffi::webrtc_RtpHeaderExtensionCapability_set_uri(
+                self.raw.as_ptr(),
+                uri.as_ptr() as *const _,
+                uri.len(),
+            )
+        };
+    }
+
+    pub fn preferred_id(&self) -> Option<i32> {
+        let mut has = 0;
+        let mut value = 0;
+        unsafe {
+            ffi::webrtc_RtpHeaderExtensionCapability_get_preferred_id(
+                self.raw.as_ptr(),
+                &mut has,
+                &mut value,
+            );
+        }
+        if has == 0 { None } else { Some(value) }
+    }
+
+    pub fn set_preferred_id(&mut self, value: Option<i32>) {
+        match value {
+            Some(v) => unsafe {
+                ffi::webrtc_RtpHeaderExtensionCapability_set_preferred_id(self.raw.as_ptr(), 1, &v);
+            },
+            None => unsafe {
+                ffi::webrtc_RtpHeaderExtensionCapability_set_preferred_id(
+                    self.raw.as_ptr(),
+                    0,
+                    std::ptr::null(),
+                );
+            },
+        }
+    }
+
+    pub fn preferred_encrypt(&self) -> bool {
+        unsafe {
+            ffi::webrtc_RtpHeaderExtensionCapability_get_preferred_encrypt(self.raw.as_ptr()) != 0
+        }
+    }
+
+    pub fn set_preferred_encrypt(&mut self, value: bool) {
+        unsafe {
+            ffi::webrtc_RtpHeaderExtensionCapability_set_preferred_encrypt(
+                self.raw.as_ptr(),
+                value as i32,
+            )
+        };
+    }
+
+    /// Stopped の拡張はネゴシエーション対象から外れる。
+    pub fn direction(&self) -> RtpTransceiverDirection {
+        RtpTransceiverDirection::from_int(unsafe {
+            ffi::webrtc_RtpHeaderExtensionCapability_get_direction(self.raw.as_ptr())
+        })
+    }
+
+    pub fn set_direction(&mut self, direction: RtpTransceiverDirection) {
+        unsafe {
+            ffi::webrtc_RtpHeaderExtensionCapability_set_direction(
+                self.raw.as_ptr(),
+                direction.to_int(),
+            )
+        };
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_RtpHeaderExtensionCapability {
+        self.raw.as_ptr()
+    }
+}
+
+/// std::vector<RtpHeaderExtensionCapability> の所有ラッパー。
+pub struct RtpHeaderExtensionCapabilityVector {
+    raw: NonNull<ffi::webrtc_RtpHeaderExtensionCapability_vector>,
+}
+
+unsafe impl Send for RtpHeaderExtensionCapabilityVector {}
+
+impl RtpHeaderExtensionCapabilityVector {
+    pub fn new(size: usize) -> Self {
+        let raw =
+            NonNull::new(unsafe { ffi::webrtc_RtpHeaderExtensionCapability_vector_new(size) })
+                .expect("BUG: webrtc_RtpHeaderExtensionCapability_vector_new が null を返しました");
+        Self { raw }
+    }
+
+    /// webrtc 側で生成されたベクタを引き取る。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_RtpHeaderExtensionCapability_vector>) -> Self {
+        Self { raw }
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_ref().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.as_ref().is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<RtpHeaderExtensionCapabilityRef<'_>> {
+        self.as_ref().get(index)
+    }
+
+    pub fn push(&mut self, cap: &RtpHeaderExtensionCapabilityRef<'_>) {
+        self.as_ref().push(cap);
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_RtpHeaderExtensionCapability_vector {
+        self.raw.as_ptr()
+    }
+
+    pub fn as_ref(&self) -> RtpHeaderExtensionCapabilityVectorRef<'_> {
+        RtpHeaderExtensionCapabilityVectorRef::from_raw(self.raw)
+    }
+}
+
+impl Drop for RtpHeaderExtensionCapabilityVector {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_RtpHeaderExtensionCapability_vector_delete(self.raw.as_ptr()) };
+    }
+}
+
+/// std::vector<RtpHeaderExtensionCapability> の借用ラッパー。
+pub struct RtpHeaderExtensionCapabilityVectorRef<'a> {
+    raw: NonNull<ffi::webrtc_RtpHeaderExtensionCapability_vector>,
+    _marker: PhantomData<&'a ffi::webrtc_RtpCapabilities>,
+}
+
+unsafe impl<'a> Send for RtpHeaderExtensionCapabilityVectorRef<'a> {}
+
+impl<'a> RtpHeaderExtensionCapabilityVectorRef<'a> {
+    pub fn from_raw(raw: NonNull<ffi::webrtc_RtpHeaderExtensionCapability_vector>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        let len =
+            unsafe { ffi::webrtc_RtpHeaderExtensionCapability_vector_size(self.raw.as_ptr()) };
+        len.max(0) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<RtpHeaderExtensionCapabilityRef<'a>> {
+        if index >= self.len() {
+            return None;
+        }
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_RtpHeaderExtensionCapability_vector_get(self.raw.as_ptr(), index as i32)
+        })
+        .expect("BUG: webrtc_RtpHeaderExtensionCapability_vector_get が null を返しました");
+        Some(RtpHeaderExtensionCapabilityRef::from_raw(raw))
+    }
+
+    pub fn push(&mut self, cap: &RtpHeaderExtensionCapabilityRef<'_>) {
+        unsafe {
+            ffi::webrtc_RtpHeaderExtensionCapability_vector_push_back(
+                self.raw.as_ptr(),
+                cap.as_ptr(),
+            );
+        }
+    }
+}
+
 pub struct Resolution {
     raw: NonNull<ffi::webrtc_Resolution>,
 }
@@ -1026,6 +1241,11 @@ impl RtpEncodingParametersVector {
         Self { raw }
     }
 
+    /// webrtc 側で生成されたベクタを引き取る。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_RtpEncodingParameters_vector>) -> Self {
+        Self { raw }
+    }
+
     pub fn len(&self) -> usize {
         let len = unsafe { ffi::webrtc_RtpEncodingParameters_vector_size(self.raw.as_ptr()) };
         len.max(0) as usize
@@ -1313,10 +1533,30 @@ pub enum RtpTransceiverDirection {
     SendRecv,
     SendOnly,
     RecvOnly,
+    Inactive,
+    Stopped,
     Unknown(i32),
 }
 
 impl RtpTransceiverDirection {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_RtpTransceiverDirection_kSendRecv {
+                RtpTransceiverDirection::SendRecv
+            } else if value == ffi::webrtc_RtpTransceiverDirection_kSendOnly {
+                RtpTransceiverDirection::SendOnly
+            } else if value == ffi::webrtc_RtpTransceiverDirection_kRecvOnly {
+                RtpTransceiverDirection::RecvOnly
+            } else if value == ffi::webrtc_RtpTransceiverDirection_kInactive {
+                RtpTransceiverDirection::Inactive
+            } else if value == ffi::webrtc_RtpTransceiverDirection_kStopped {
+                RtpTransceiverDirection::Stopped
+            } else {
+                RtpTransceiverDirection::Unknown(value)
+            }
+        }
+    }
+
     pub fn to_int(self) -> i32 {
         match self {
             RtpTransceiverDirection::SendRecv => unsafe {
@@ -1328,6 +1568,12 @@ impl RtpTransceiverDirection {
             RtpTransceiverDirection::RecvOnly => unsafe {
                 ffi::webrtc_RtpTransceiverDirection_kRecvOnly
             },
+            RtpTransceiverDirection::Inactive => unsafe {
+                ffi::webrtc_RtpTransceiverDirection_kInactive
+            },
+            RtpTransceiverDirection::Stopped => unsafe {
+                ffi::webrtc_RtpTransceiverDirection_kStopped
+            },
             RtpTransceiverDirection::Unknown(v) => v,
         }
     }
@@ -1414,6 +1660,162 @@ impl RtpTransceiver {
         RtpReceiver::from_scoped_ref(raw_ref)
     }
 
+    pub fn sender(&self) -> RtpSender {
+        let raw = unsafe { ffi::webrtc_RtpTransceiverInterface_sender(self.raw_ref.as_ptr()) };
+        let raw_ref = ScopedRef::<RtpSenderHandle>::from_raw(
+            NonNull::new(raw)
+                .expect("BUG: webrtc_RtpTransceiverInterface_sender が null を返しました"),
+        );
+        RtpSender::from_scoped_ref(raw_ref)
+    }
+
+    pub fn media_type(&self) -> MediaType {
+        MediaType::from_int(unsafe {
+            ffi::webrtc_RtpTransceiverInterface_media_type(self.raw_ref.as_ptr())
+        })
+    }
+
+    /// ネゴシエーション前など mid が未確定の場合は None を返す。
+    pub fn mid(&self) -> Result<Option<String>> {
+        let raw = unsafe { ffi::webrtc_RtpTransceiverInterface_mid(self.raw_ref.as_ptr()) };
+        match NonNull::new(raw) {
+            Some(raw) => Ok(Some(CxxString::from_unique(raw).to_string()?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn stopped(&self) -> bool {
+        unsafe { ffi::webrtc_RtpTransceiverInterface_stopped(self.raw_ref.as_ptr()) != 0 }
+    }
+
+    /// stop_standard を呼んだ後、ネゴシエーションが完了するまで true を返す。
+    pub fn stopping(&self) -> bool {
+        unsafe { ffi::webrtc_RtpTransceiverInterface_stopping(self.raw_ref.as_ptr()) != 0 }
+    }
+
+    /// アプリケーションが希望する方向を返す。
+    pub fn direction(&self) -> RtpTransceiverDirection {
+        RtpTransceiverDirection::from_int(unsafe {
+            ffi::webrtc_RtpTransceiverInterface_direction(self.raw_ref.as_ptr())
+        })
+    }
+
+    /// 希望する方向を変更する。反映には再ネゴシエーションが必要。
+    pub fn set_direction_with_error(&mut self, direction: RtpTransceiverDirection) -> Result<()> {
+        let mut err: *mut ffi::webrtc_RTCError_unique = std::ptr::null_mut();
+        unsafe {
+            ffi::webrtc_RtpTransceiverInterface_SetDirectionWithError(
+                self.raw_ref.as_ptr(),
+                direction.to_int(),
+                &mut err,
+            )
+        };
+        if !err.is_null() {
+            let rtc = RtcError::from_unique_ptr(NonNull::new(err).expect("BUG: error is null"));
+            return Err(Error::RtcError(rtc));
+        }
+        Ok(())
+    }
+
+    /// ネゴシエーション済みの方向を返す。未ネゴシエーションの場合は None。
+    pub fn current_direction(&self) -> Option<RtpTransceiverDirection> {
+        let mut has = 0;
+        let mut value = 0;
+        unsafe {
+            ffi::webrtc_RtpTransceiverInterface_current_direction(
+                self.raw_ref.as_ptr(),
+                &mut has,
+                &mut value,
+            )
+        };
+        (has != 0).then(|| RtpTransceiverDirection::from_int(value))
+    }
+
+    /// 最後に適用した description で on_track を発火させた方向を返す。
+    pub fn fired_direction(&self) -> Option<RtpTransceiverDirection> {
+        let mut has = 0;
+        let mut value = 0;
+        unsafe {
+            ffi::webrtc_RtpTransceiverInterface_fired_direction(
+                self.raw_ref.as_ptr(),
+                &mut has,
+                &mut value,
+            )
+        };
+        (has != 0).then(|| RtpTransceiverDirection::from_int(value))
+    }
+
+    /// W3C 仕様に従って transceiver を停止する。
+    pub fn stop_standard(&mut self) -> Result<()> {
+        let mut err: *mut ffi::webrtc_RTCError_unique = std::ptr::null_mut();
+        unsafe {
+            ffi::webrtc_RtpTransceiverInterface_StopStandard(self.raw_ref.as_ptr(), &mut err)
+        };
+        if !err.is_null() {
+            let rtc = RtcError::from_unique_ptr(NonNull::new(err).expect("BUG: error is null"));
+            return Err(Error::RtcError(rtc));
+        }
+        Ok(())
+    }
+
+    /// set_codec_preferences で設定したコーデックを返す。
+    pub fn codec_preferences(&self) -> RtpCodecCapabilityVector {
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_RtpTransceiverInterface_codec_preferences(self.raw_ref.as_ptr())
+        })
+        .expect("BUG: webrtc_RtpTransceiverInterface_codec_preferences が null を返しました");
+        RtpCodecCapabilityVector::from_raw(raw)
+    }
+
+    /// 次のネゴシエーションで提示するヘッダー拡張を返す。
+    pub fn header_extensions_to_negotiate(&self) -> RtpHeaderExtensionCapabilityVector {
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_RtpTransceiverInterface_GetHeaderExtensionsToNegotiate(
+                self.raw_ref.as_ptr(),
+            )
+        })
+        .expect(
+            "BUG: webrtc_RtpTransceiverInterface_GetHeaderExtensionsToNegotiate が null を返しました",
+        );
+        RtpHeaderExtensionCapabilityVector::from_raw(raw)
+    }
+
+    /// 各拡張の direction を変更して次のネゴシエーションに反映する。
+    ///
+    /// header_extensions_to_negotiate の結果と同じ URI の集合を渡す必要がある。
+    /// 無効にする拡張は Stopped を指定する。
+    pub fn set_header_extensions_to_negotiate(
+        &mut self,
+        extensions: &RtpHeaderExtensionCapabilityVector,
+    ) -> Result<()> {
+        let mut err: *mut ffi::webrtc_RTCError_unique = std::ptr::null_mut();
+        unsafe {
+            ffi::webrtc_RtpTransceiverInterface_SetHeaderExtensionsToNegotiate(
+                self.raw_ref.as_ptr(),
+                extensions.as_ptr(),
+                &mut err,
+            )
+        };
+        if !err.is_null() {
+            let rtc = RtcError::from_unique_ptr(NonNull::new(err).expect("BUG: error is null"));
+            return Err(Error::RtcError(rtc));
+        }
+        Ok(())
+    }
+
+    /// ネゴシエーション済みのヘッダー拡張を返す。ネゴシエーションされなかった拡張は Stopped になる。
+    pub fn negotiated_header_extensions(&self) -> RtpHeaderExtensionCapabilityVector {
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_RtpTransceiverInterface_GetNegotiatedHeaderExtensions(
+                self.raw_ref.as_ptr(),
+            )
+        })
+        .expect(
+            "BUG: webrtc_RtpTransceiverInterface_GetNegotiatedHeaderExtensions が null を返しました",
+        );
+        RtpHeaderExtensionCapabilityVector::from_raw(raw)
+    }
+
     pub fn set_codec_preferences(&mut self, codecs: &RtpCodecCapabilityVector) -> Result<()> {
         let mut err: *mut ffi::webrtc_RTCError_unique = std::ptr::null_mut();
         unsafe {
@@ -1431,17 +1833,162 @@ impl RtpTransceiver {
     }
 }
 
+/// webrtc::RtpSourceType のラッパー。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RtpSourceType {
+    Ssrc,
+    Csrc,
+    Unknown(i32),
+}
+
+impl RtpSourceType {
+    pub fn from_int(v: i32) -> Self {
+        unsafe {
+            if v == ffi::webrtc_RtpSourceType_SSRC {
+                RtpSourceType::Ssrc
+            } else if v == ffi::webrtc_RtpSourceType_CSRC {
+                RtpSourceType::Csrc
+            } else {
+                RtpSourceType::Unknown(v)
+            }
+        }
+    }
+
+    pub fn to_int(&self) -> i32 {
+        match self {
+            RtpSourceType::Ssrc => unsafe { ffi::webrtc_RtpSourceType_SSRC },
+            RtpSourceType::Csrc => unsafe { ffi::webrtc_RtpSourceType_CSRC },
+            RtpSourceType::Unknown(v) => *v,
+        }
+    }
+}
+
+/// webrtc::RtpSource の内容。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RtpSource {
+    pub source_id: u32,
+    pub source_type: RtpSourceType,
+    /// 最後にパケットを受信した時刻（ミリ秒）。
+    pub timestamp_ms: i64,
+    pub rtp_timestamp: u32,
+    /// 音声レベル（0-127）。音声以外や拡張ヘッダが無い場合は None。
+    pub audio_level: Option<u8>,
+}
+
+impl RtpSource {
+    fn from_raw(raw: NonNull<ffi::webrtc_RtpSource>) -> Self {
+        let mut has_audio_level = 0;
+        let mut audio_level = 0;
+        unsafe {
+            ffi::webrtc_RtpSource_audio_level(raw.as_ptr(), &mut has_audio_level, &mut audio_level)
+        };
+        Self {
+            source_id: unsafe { ffi::webrtc_RtpSource_source_id(raw.as_ptr()) },
+            source_type: RtpSourceType::from_int(unsafe {
+                ffi::webrtc_RtpSource_source_type(raw.as_ptr())
+            }),
+            timestamp_ms: unsafe { ffi::webrtc_RtpSource_timestamp_ms(raw.as_ptr()) },
+            rtp_timestamp: unsafe { ffi::webrtc_RtpSource_rtp_timestamp(raw.as_ptr()) },
+            audio_level: (has_audio_level != 0).then_some(audio_level as u8),
+        }
+    }
+}
+
+pub trait RtpReceiverObserverHandler: Send {
+    /// 最初のパケットを受信したときに呼ばれる。
+    #[expect(unused_variables)]
+    fn on_first_packet_received(&mut self, media_type: MediaType) {}
+}
+
+struct RtpReceiverObserverHandlerState {
+    handler: Box<dyn RtpReceiverObserverHandler>,
+}
+
+unsafe impl Send for RtpReceiverObserverHandlerState {}
+
+unsafe extern "C" fn rtp_receiver_observer_on_first_packet_received(
+    media_type: i32,
+    user_data: *mut c_void,
+) {
+    assert!(
+        !user_data.is_null(),
+        "rtp_receiver_observer_on_first_packet_received: user_data is null"
+    );
+    let state = unsafe { &mut *(user_data as *mut RtpReceiverObserverHandlerState) };
+    state
+        .handler
+        .on_first_packet_received(MediaType::from_int(media_type));
+}
+
+unsafe extern "C" fn rtp_receiver_observer_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "rtp_receiver_observer_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut RtpReceiverObserverHandlerState) };
+}
+
+/// webrtc::RtpReceiverObserverInterface のラッパー。
+pub struct RtpReceiverObserver {
+    raw: NonNull<ffi::webrtc_RtpReceiverObserverInterface>,
+}
+
+unsafe impl Send for RtpReceiverObserver {}
+
+impl RtpReceiverObserver {
+    pub fn new_with_handler(handler: Box<dyn RtpReceiverObserverHandler>) -> Self {
+        let state = Box::new(RtpReceiverObserverHandlerState { handler });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_RtpReceiverObserverInterface_cbs {
+            OnFirstPacketReceived: Some(rtp_receiver_observer_on_first_packet_received),
+            OnDestroy: Some(rtp_receiver_observer_on_destroy),
+        };
+        let raw = match NonNull::new(unsafe {
+            ffi::webrtc_RtpReceiverObserverInterface_new(&cbs, user_data)
+        }) {
+            Some(raw) => raw,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut RtpReceiverObserverHandlerState) };
+                panic!("BUG: webrtc_RtpReceiverObserverInterface_new が null を返しました");
+            }
+        };
+        Self { raw }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_RtpReceiverObserverInterface {
+        self.raw.as_ptr()
+    }
+}
+
+impl Drop for RtpReceiverObserver {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_RtpReceiverObserverInterface_delete(self.raw.as_ptr()) };
+    }
+}
+
 // 安全性: libwebrtc 側で参照カウント管理されたポインタのみを保持する。
 /// webrtc::RtpReceiverInterface のラッパー。
 pub struct RtpReceiver {
     raw_ref: ScopedRef<RtpReceiverHandle>,
+    observer: Option<RtpReceiverObserver>,
 }
 
 unsafe impl Send for RtpReceiver {}
 
 impl RtpReceiver {
     pub(crate) fn from_scoped_ref(raw_ref: ScopedRef<RtpReceiverHandle>) -> Self {
-        Self { raw_ref }
+        Self {
+            raw_ref,
+            observer: None,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_RtpReceiverInterface {
+        self.raw_ref.as_ptr()
+    }
+
+    pub fn as_refcounted_ptr(&self) -> *mut ffi::webrtc_RtpReceiverInterface_refcounted {
+        self.raw_ref.as_refcounted_ptr()
     }
 
     pub fn track(&self) -> MediaStreamTrack {
@@ -1451,6 +1998,121 @@ impl RtpReceiver {
         );
         MediaStreamTrack::from_scoped_ref(raw_ref)
     }
+
+    pub fn id(&self) -> Result<String> {
+        let raw = NonNull::new(unsafe { ffi::webrtc_RtpReceiverInterface_id(self.as_ptr()) })
+            .expect("BUG: webrtc_RtpReceiverInterface_id が null を返しました");
+        CxxString::from_unique(raw).to_string()
+    }
+
+    pub fn media_type(&self) -> MediaType {
+        MediaType::from_int(unsafe { ffi::webrtc_RtpReceiverInterface_media_type(self.as_ptr()) })
+    }
+
+    pub fn stream_ids(&self) -> Result<Vec<String>> {
+        let raw =
+            NonNull::new(unsafe { ffi::webrtc_RtpReceiverInterface_stream_ids(self.as_ptr()) })
+                .expect("BUG: webrtc_RtpReceiverInterface_stream_ids が null を返しました");
+        let stream_ids = StringVector::from_raw(raw);
+        (0..stream_ids.len()).map(|i| stream_ids.get(i)).collect()
+    }
+
+    pub fn get_parameters(&self) -> RtpParameters {
+        let raw =
+            NonNull::new(unsafe { ffi::webrtc_RtpReceiverInterface_GetParameters(self.as_ptr()) })
+                .expect("BUG: webrtc_RtpReceiverInterface_GetParameters が null を返しました");
+        RtpParameters::from_raw(raw)
+    }
+
+    /// 直近 10 秒以内にパケットを受信した SSRC / CSRC の一覧を返す。
+    pub fn get_sources(&self) -> Vec<RtpSource> {
+        let raw =
+            NonNull::new(unsafe { ffi::webrtc_RtpReceiverInterface_GetSources(self.as_ptr()) })
+                .expect("BUG: webrtc_RtpReceiverInterface_GetSources が null を返しました");
+        let len = unsafe { ffi::webrtc_RtpSource_vector_size(raw.as_ptr()) }.max(0) as usize;
+        let mut out = Vec::with_capacity(len);
+        for i in 0..len {
+            let source = unsafe { ffi::webrtc_RtpSource_vector_get(raw.as_ptr(), i as i32) };
+            if let Some(source) = NonNull::new(source) {
+                out.push(RtpSource::from_raw(source));
+            }
+        }
+        unsafe { ffi::webrtc_RtpSource_vector_delete(raw.as_ptr()) };
+        out
+    }
+
+    /// ネゴシエーション前など DTLS トランスポートが未確定の場合は None を返す。
+    pub fn dtls_transport(&self) -> Option<DtlsTransport> {
+        let raw = unsafe { ffi::webrtc_RtpReceiverInterface_dtls_transport(self.as_ptr()) };
+        NonNull::new(raw).map(|p| {
+            let raw_ref = ScopedRef::<DtlsTransportHandle>::from_raw(p);
+            DtlsTransport::from_scoped_ref(raw_ref)
+        })
+    }
+
+    /// ジッターバッファの最小遅延（秒）を設定する。None で既定値に戻す。
+    pub fn set_jitter_buffer_minimum_delay(&mut self, delay_seconds: Option<f64>) {
+        let (has, value) = match delay_seconds {
+            Some(v) => (1, v),
+            None => (0, 0.0),
+        };
+        unsafe {
+            ffi::webrtc_RtpReceiverInterface_SetJitterBufferMinimumDelay(self.as_ptr(), has, &value)
+        };
+    }
+
+    /// Observer を設定する。None で解除する。
+    ///
+    /// observer は置き換えられるか、このラッパーが破棄されるまで保持する。
+    pub fn set_observer(&mut self, observer: Option<RtpReceiverObserver>) {
+        let observer_ptr = match &observer {
+            Some(o) => o.as_ptr(),
+            None => std::ptr::null_mut(),
+        };
+        unsafe { ffi::webrtc_RtpReceiverInterface_SetObserver(self.as_ptr(), observer_ptr) };
+        // 差し替えが終わってから古い observer を破棄する。
+        self.observer = observer;
+    }
+
+    /// 受信したエンコード済みフレームをデコード前に変換する。
+    ///
+    /// libwebrtc は設定済みの transformer を解除できないため、受信側が破棄されるまで有効なまま。
+    pub fn set_frame_transformer(&mut self, frame_transformer: &FrameTransformer) {
+        unsafe {
+            ffi::webrtc_RtpReceiverInterface_SetFrameTransformer(
+                self.raw_ref.as_ptr(),
+                frame_transformer.as_refcounted_ptr(),
+            )
+        };
+    }
+
+    /// 受信したフレームを復号する。None で解除する。
+    pub fn set_frame_decryptor(&mut self, frame_decryptor: Option<&FrameDecryptor>) {
+        let frame_decryptor_ptr = match frame_decryptor {
+            Some(d) => d.as_refcounted_ptr(),
+            None => std::ptr::null_mut(),
+        };
+        unsafe {
+            ffi::webrtc_RtpReceiverInterface_SetFrameDecryptor(
+                self.raw_ref.as_ptr(),
+                frame_decryptor_ptr,
+            )
+        };
+    }
+}
+
+impl Drop for RtpReceiver {
+    fn drop(&mut self) {
+        // 保持している observer を破棄する前に libwebrtc 側の参照を外す。
+        if self.observer.is_some() {
+            unsafe {
+                ffi::webrtc_RtpReceiverInterface_SetObserver(
+                    self.raw_ref.as_ptr(),
+                    std::ptr::null_mut(),
+                )
+            };
+        }
+    }
 }
 
 // 安全性: libwebrtc 側で参照カウント管理されたポインタのみを保持する。
@@ -1474,6 +2136,68 @@ impl RtpSender {
         self.raw_ref.as_refcounted_ptr()
     }
 
+    /// トラックが未設定の場合は None を返す。
+    pub fn track(&self) -> Option<MediaStreamTrack> {
+        let raw = unsafe { ffi::webrtc_RtpSenderInterface_track(self.as_ptr()) };
+        NonNull::new(raw).map(|p| {
+            let raw_ref = ScopedRef::<MediaStreamTrackHandle>::from_raw(p);
+            MediaStreamTrack::from_scoped_ref(raw_ref)
+        })
+    }
+
+    /// 送信に使う SSRC を返す。ネゴシエーション前は 0。
+    pub fn ssrc(&self) -> u32 {
+        unsafe { ffi::webrtc_RtpSenderInterface_ssrc(self.as_ptr()) }
+    }
+
+    pub fn media_type(&self) -> MediaType {
+        MediaType::from_int(unsafe { ffi::webrtc_RtpSenderInterface_media_type(self.as_ptr()) })
+    }
+
+    pub fn id(&self) -> Result<String> {
+        let raw = NonNull::new(unsafe { ffi::webrtc_RtpSenderInterface_id(self.as_ptr()) })
+            .expect("BUG: webrtc_RtpSenderInterface_id が null を返しました");
+        CxxString::from_unique(raw).to_string()
+    }
+
+    pub fn stream_ids(&self) -> Result<Vec<String>> {
+        let raw = NonNull::new(unsafe { ffi::webrtc_RtpSenderInterface_stream_ids(self.as_ptr()) })
+            .expect("BUG: webrtc_RtpSenderInterface_stream_ids が null を返しました");
+        let stream_ids = StringVector::from_raw(raw);
+        (0..stream_ids.len()).map(|i| stream_ids.get(i)).collect()
+    }
+
+    pub fn set_streams(&mut self, stream_ids: &StringVector) {
+        unsafe { ffi::webrtc_RtpSenderInterface_SetStreams(self.as_ptr(), stream_ids.as_ptr()) };
+    }
+
+    /// ネゴシエーション前など DTLS トランスポートが未確定の場合は None を返す。
+    pub fn dtls_transport(&self) -> Option<DtlsTransport> {
+        let raw = unsafe { ffi::webrtc_RtpSenderInterface_dtls_transport(self.as_ptr()) };
+        NonNull::new(raw).map(|p| {
+            let raw_ref = ScopedRef::<DtlsTransportHandle>::from_raw(p);
+            DtlsTransport::from_scoped_ref(raw_ref)
+        })
+    }
+
+    /// AddTransceiver 時に RtpTransceiverInit で指定したエンコーディングを返す。
+    pub fn init_send_encodings(&self) -> RtpEncodingParametersVector {
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_RtpSenderInterface_init_send_encodings(self.as_ptr())
+        })
+        .expect("BUG: webrtc_RtpSenderInterface_init_send_encodings が null を返しました");
+        RtpEncodingParametersVector::from_raw(raw)
+    }
+
+    /// 音声以外の送信者では None を返す。
+    pub fn get_dtmf_sender(&self) -> Option<DtmfSender> {
+        let raw = unsafe { ffi::webrtc_RtpSenderInterface_GetDtmfSender(self.as_ptr()) };
+        NonNull::new(raw).map(|p| {
+            let raw_ref = ScopedRef::<DtmfSenderHandle>::from_raw(p);
+            DtmfSender::from_scoped_ref(raw_ref)
+        })
+    }
+
     pub fn get_parameters(&self) -> RtpParameters {
         let raw =
             NonNull::new(unsafe { ffi::webrtc_RtpSenderInterface_GetParameters(self.as_ptr()) })
@@ -1489,6 +2213,29 @@ impl RtpSender {
         unsafe { ffi::webrtc_RtpSenderInterface_SetTrack(self.as_ptr(), track_ptr) != 0 }
     }
 
+    /// エンコード済みフレームをパケット化前に変換する。
+    ///
+    /// libwebrtc は設定済みの transformer を解除できないため、送信側が破棄されるまで有効なまま。
+    pub fn set_frame_transformer(&mut self, frame_transformer: &FrameTransformer) {
+        unsafe {
+            ffi::webrtc_RtpSenderInterface_SetFrameTransformer(
+                self.as_ptr(),
+                frame_transformer.as_refcounted_ptr(),
+            )
+        };
+    }
+
+    /// 送信するフレームを暗号化する。None で解除する。
+    pub fn set_frame_encryptor(&mut self, frame_encryptor: Option<&FrameEncryptor>) {
+        let frame_encryptor_ptr = match frame_encryptor {
+            Some(e) => e.as_refcounted_ptr(),
+            None => std::ptr::null_mut(),
+        };
+        unsafe {
+            ffi::webrtc_RtpSenderInterface_SetFrameEncryptor(self.as_ptr(), frame_encryptor_ptr)
+        };
+    }
+
     pub fn set_parameters(&mut self, parameters: &RtpParameters) -> Result<()> {
         let mut err: *mut ffi::webrtc_RTCError_unique = std::ptr::null_mut();
         unsafe {
@@ -0,0 +1,554 @@
+use crate::{MapStringString, MediaType, Result, RtpTransceiverDirection, SSLFingerprintRef, ffi};
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+use std::ptr::NonNull;
+
+fn borrowed_string(ptr: *const c_char, len: usize) -> Result<String> {
+    assert!(!ptr.is_null());
+    let bytes = unsafe { std::slice::from_raw_parts(ptr.cast::<u8>(), len) };
+    Ok(std::str::from_utf8(bytes)?.to_owned())
+}
+
+/// DTLS の setup 属性 (a=setup)。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionRole {
+    None,
+    Active,
+    Passive,
+    Actpass,
+    Holdconn,
+    Unknown(i32),
+}
+
+impl ConnectionRole {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_ConnectionRole_CONNECTIONROLE_NONE {
+                ConnectionRole::None
+            } else if value == ffi::webrtc_ConnectionRole_CONNECTIONROLE_ACTIVE {
+                ConnectionRole::Active
+            } else if value == ffi::webrtc_ConnectionRole_CONNECTIONROLE_PASSIVE {
+                ConnectionRole::Passive
+            } else if value == ffi::webrtc_ConnectionRole_CONNECTIONROLE_ACTPASS {
+                ConnectionRole::Actpass
+            } else if value == ffi::webrtc_ConnectionRole_CONNECTIONROLE_HOLDCONN {
+                ConnectionRole::Holdconn
+            } else {
+                ConnectionRole::Unknown(value)
+            }
+        }
+    }
+}
+
+/// simulcast の 1 レイヤー (a=simulcast の rid)。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulcastLayer {
+    pub rid: String,
+    pub is_paused: bool,
+}
+
+impl SimulcastLayer {
+    fn from_raw(raw: *const ffi::webrtc_SimulcastLayer) -> Result<Self> {
+        assert!(!raw.is_null());
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_SimulcastLayer_get_rid(raw, &mut ptr, &mut len) };
+        Ok(Self {
+            rid: borrowed_string(ptr, len)?,
+            is_paused: unsafe { ffi::webrtc_SimulcastLayer_get_is_paused(raw) != 0 },
+        })
+    }
+}
+
+/// 代替 (a=simulcast の "," 区切り) も含めた全レイヤーを返す。
+fn simulcast_layers(list: *const ffi::webrtc_SimulcastLayerList) -> Result<Vec<SimulcastLayer>> {
+    assert!(!list.is_null());
+    let mut layers = Vec::new();
+    let size = unsafe { ffi::webrtc_SimulcastLayerList_size(list) };
+    for index in 0..size {
+        let alternatives =
+            unsafe { ffi::webrtc_SimulcastLayerList_get_alternatives_size(list, index) };
+        for alternative_index in 0..alternatives {
+            let raw = unsafe {
+                ffi::webrtc_SimulcastLayerList_get_alternative(list, index, alternative_index)
+            };
+            layers.push(SimulcastLayer::from_raw(raw)?);
+        }
+    }
+    Ok(layers)
+}
+
+/// webrtc::SimulcastDescription の借用ラッパー。
+pub struct SimulcastDescriptionRef<'a> {
+    raw: NonNull<ffi::webrtc_SimulcastDescription>,
+    _marker: PhantomData<&'a ffi::webrtc_SimulcastDescription>,
+}
+
+unsafe impl<'a> Send for SimulcastDescriptionRef<'a> {}
+
+impl<'a> SimulcastDescriptionRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_SimulcastDescription>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_SimulcastDescription {
+        self.raw.as_ptr()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        unsafe { ffi::webrtc_SimulcastDescription_empty(self.raw.as_ptr()) != 0 }
+    }
+
+    /// a=simulcast:send のレイヤー。
+    pub fn send_layers(&self) -> Result<Vec<SimulcastLayer>> {
+        simulcast_layers(unsafe { ffi::webrtc_SimulcastDescription_send_layers(self.raw.as_ptr()) })
+    }
+
+    /// a=simulcast:recv のレイヤー。
+    pub fn receive_layers(&self) -> Result<Vec<SimulcastLayer>> {
+        simulcast_layers(unsafe {
+            ffi::webrtc_SimulcastDescription_receive_layers(self.raw.as_ptr())
+        })
+    }
+}
+
+/// webrtc::Codec の借用ラッパー。
+pub struct CodecRef<'a> {
+    raw: NonNull<ffi::webrtc_Codec>,
+    _marker: PhantomData<&'a ffi::webrtc_Codec>,
+}
+
+unsafe impl<'a> Send for CodecRef<'a> {}
+
+impl<'a> CodecRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_Codec>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_Codec {
+        self.raw.as_ptr()
+    }
+
+    /// payload type。
+    pub fn id(&self) -> i32 {
+        unsafe { ffi::webrtc_Codec_get_id(self.raw.as_ptr()) }
+    }
+
+    pub fn name(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_Codec_get_name(self.raw.as_ptr(), &mut ptr, &mut len) };
+        borrowed_string(ptr, len)
+    }
+
+    pub fn clockrate(&self) -> i32 {
+        unsafe { ffi::webrtc_Codec_get_clockrate(self.raw.as_ptr()) }
+    }
+
+    /// 音声のチャンネル数。映像の場合は 0。
+    pub fn channels(&self) -> usize {
+        unsafe { ffi::webrtc_Codec_get_channels(self.raw.as_ptr()) }
+    }
+
+    /// a=fmtp のパラメータ。
+    pub fn parameters(&self) -> Vec<(String, String)> {
+        let raw = unsafe { ffi::webrtc_Codec_get_params(self.raw.as_ptr()) };
+        let map = MapStringString::from_raw(
+            NonNull::new(raw as *mut ffi::std_map_string_string)
+                .expect("BUG: webrtc_Codec_get_params が null を返しました"),
+        );
+        map.iter().collect()
+    }
+}
+
+/// webrtc::RtpExtension の借用ラッパー。
+pub struct RtpExtensionRef<'a> {
+    raw: NonNull<ffi::webrtc_RtpExtension>,
+    _marker: PhantomData<&'a ffi::webrtc_RtpExtension>,
+}
+
+unsafe impl<'a> Send for RtpExtensionRef<'a> {}
+
+impl<'a> RtpExtensionRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_RtpExtension>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_RtpExtension {
+        self.raw.as_ptr()
+    }
+
+    pub fn uri(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_RtpExtension_get_uri(self.raw.as_ptr(), &mut ptr, &mut len) };
+        borrowed_string(ptr, len)
+    }
+
+    pub fn id(&self) -> i32 {
+        unsafe { ffi::webrtc_RtpExtension_get_id(self.raw.as_ptr()) }
+    }
+
+    pub fn encrypt(&self) -> bool {
+        unsafe { ffi::webrtc_RtpExtension_get_encrypt(self.raw.as_ptr()) != 0 }
+    }
+}
+
+/// webrtc::SsrcGroup の借用ラッパー。
+pub struct SsrcGroupRef<'a> {
+    raw: NonNull<ffi::webrtc_SsrcGroup>,
+    _marker: PhantomData<&'a ffi::webrtc_SsrcGroup>,
+}
+
+unsafe impl<'a> Send for SsrcGroupRef<'a> {}
+
+impl<'a> SsrcGroupRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_SsrcGroup>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_SsrcGroup {
+        self.raw.as_ptr()
+    }
+
+    /// "FID" / "SIM" など。
+    pub fn semantics(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_SsrcGroup_get_semantics(self.raw.as_ptr(), &mut ptr, &mut len) };
+        borrowed_string(ptr, len)
+    }
+
+    pub fn ssrcs(&self) -> Vec<u32> {
+        let size = unsafe { ffi::webrtc_SsrcGroup_get_ssrcs_size(self.raw.as_ptr()) };
+        let mut ssrcs = vec![0u32; size];
+        let copied = unsafe {
+            ffi::webrtc_SsrcGroup_copy_ssrcs(self.raw.as_ptr(), ssrcs.as_mut_ptr(), ssrcs.len())
+        };
+        ssrcs.truncate(copied);
+        ssrcs
+    }
+}
+
+/// webrtc::StreamParams の借用ラッパー。
+pub struct StreamParamsRef<'a> {
+    raw: NonNull<ffi::webrtc_StreamParams>,
+    _marker: PhantomData<&'a ffi::webrtc_StreamParams>,
+}
+
+unsafe impl<'a> Send for StreamParamsRef<'a> {}
+
+impl<'a> StreamParamsRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_StreamParams>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_StreamParams {
+        self.raw.as_ptr()
+    }
+
+    /// トラック ID。
+    pub fn id(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_StreamParams_get_id(self.raw.as_ptr(), &mut ptr, &mut len) };
+        borrowed_string(ptr, len)
+    }
+
+    pub fn cname(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_StreamParams_get_cname(self.raw.as_ptr(), &mut ptr, &mut len) };
+        borrowed_string(ptr, len)
+    }
+
+    pub fn ssrcs(&self) -> Vec<u32> {
+        let size = unsafe { ffi::webrtc_StreamParams_get_ssrcs_size(self.raw.as_ptr()) };
+        let mut ssrcs = vec![0u32; size];
+        let copied = unsafe {
+            ffi::webrtc_StreamParams_copy_ssrcs(self.raw.as_ptr(), ssrcs.as_mut_ptr(), ssrcs.len())
+        };
+        ssrcs.truncate(copied);
+        ssrcs
+    }
+
+    pub fn ssrc_groups(&self) -> Vec<SsrcGroupRef<'a>> {
+        let size = unsafe { ffi::webrtc_StreamParams_get_ssrc_groups_size(self.raw.as_ptr()) };
+        (0..size)
+            .map(|index| {
+                let raw =
+                    unsafe { ffi::webrtc_StreamParams_get_ssrc_group(self.raw.as_ptr(), index) };
+                SsrcGroupRef::from_raw(
+                    NonNull::new(raw as *mut ffi::webrtc_SsrcGroup)
+                        .expect("BUG: webrtc_StreamParams_get_ssrc_group が null を返しました"),
+                )
+            })
+            .collect()
+    }
+}
+
+/// webrtc::TransportDescription の借用ラッパー。
+pub struct TransportDescriptionRef<'a> {
+    raw: NonNull<ffi::webrtc_TransportDescription>,
+    _marker: PhantomData<&'a ffi::webrtc_TransportDescription>,
+}
+
+unsafe impl<'a> Send for TransportDescriptionRef<'a> {}
+
+impl<'a> TransportDescriptionRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_TransportDescription>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_TransportDescription {
+        self.raw.as_ptr()
+    }
+
+    pub fn ice_ufrag(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe {
+            ffi::webrtc_TransportDescription_get_ice_ufrag(self.raw.as_ptr(), &mut ptr, &mut len)
+        };
+        borrowed_string(ptr, len)
+    }
+
+    pub fn ice_pwd(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe {
+            ffi::webrtc_TransportDescription_get_ice_pwd(self.raw.as_ptr(), &mut ptr, &mut len)
+        };
+        borrowed_string(ptr, len)
+    }
+
+    pub fn connection_role(&self) -> ConnectionRole {
+        ConnectionRole::from_int(unsafe {
+            ffi::webrtc_TransportDescription_get_connection_role(self.raw.as_ptr())
+        })
+    }
+
+    /// DTLS の fingerprint。a=fingerprint が無い場合は None。
+    pub fn identity_fingerprint(&self) -> Option<SSLFingerprintRef<'a>> {
+        let raw =
+            unsafe { ffi::webrtc_TransportDescription_get_identity_fingerprint(self.raw.as_ptr()) };
+        NonNull::new(raw as *mut ffi::webrtc_SSLFingerprint).map(SSLFingerprintRef::from_raw)
+    }
+}
+
+/// webrtc::MediaContentDescription の借用ラッパー。
+pub struct MediaContentDescriptionRef<'a> {
+    raw: NonNull<ffi::webrtc_MediaContentDescription>,
+    _marker: PhantomData<&'a ffi::webrtc_MediaContentDescription>,
+}
+
+unsafe impl<'a> Send for MediaContentDescriptionRef<'a> {}
+
+impl<'a> MediaContentDescriptionRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_MediaContentDescription>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_MediaContentDescription {
+        self.raw.as_ptr()
+    }
+
+    pub fn media_type(&self) -> MediaType {
+        MediaType::from_int(unsafe { ffi::webrtc_MediaContentDescription_type(self.raw.as_ptr()) })
+    }
+
+    pub fn direction(&self) -> RtpTransceiverDirection {
+        RtpTransceiverDirection::from_int(unsafe {
+            ffi::webrtc_MediaContentDescription_direction(self.raw.as_ptr())
+        })
+    }
+
+    pub fn rtcp_mux(&self) -> bool {
+        unsafe { ffi::webrtc_MediaContentDescription_rtcp_mux(self.raw.as_ptr()) != 0 }
+    }
+
+    pub fn codecs(&self) -> Vec<CodecRef<'a>> {
+        let size =
+            unsafe { ffi::webrtc_MediaContentDescription_get_codecs_size(self.raw.as_ptr()) };
+        (0..size)
+            .map(|index| {
+                let raw = unsafe {
+                    ffi::webrtc_MediaContentDescription_get_codec(self.raw.as_ptr(), index)
+                };
+                CodecRef::from_raw(
+                    NonNull::new(raw as *mut ffi::webrtc_Codec).expect(
+                        "BUG: webrtc_MediaContentDescription_get_codec が null を返しました",
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    pub fn rtp_header_extensions(&self) -> Vec<RtpExtensionRef<'a>> {
+        let size = unsafe {
+            ffi::webrtc_MediaContentDescription_get_rtp_header_extensions_size(self.raw.as_ptr())
+        };
+        (0..size)
+            .map(|index| {
+                let raw = unsafe {
+                    ffi::webrtc_MediaContentDescription_get_rtp_header_extension(
+                        self.raw.as_ptr(),
+                        index,
+                    )
+                };
+                RtpExtensionRef::from_raw(NonNull::new(raw as *mut ffi::webrtc_RtpExtension).expect(
+                    "BUG: webrtc_MediaContentDescription_get_rtp_header_extension が null を返しました",
+                ))
+            })
+            .collect()
+    }
+
+    pub fn streams(&self) -> Vec<StreamParamsRef<'a>> {
+        let size =
+            unsafe { ffi::webrtc_MediaContentDescription_get_streams_size(self.raw.as_ptr()) };
+        (0..size)
+            .map(|index| {
+                let raw = unsafe {
+                    ffi::webrtc_MediaContentDescription_get_stream(self.raw.as_ptr(), index)
+                };
+                StreamParamsRef::from_raw(
+                    NonNull::new(raw as *mut ffi::webrtc_StreamParams).expect(
+                        "BUG: webrtc_MediaContentDescription_get_stream が null を返しました",
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    pub fn simulcast_description(&self) -> SimulcastDescriptionRef<'a> {
+        let raw =
+            unsafe { ffi::webrtc_MediaContentDescription_simulcast_description(self.raw.as_ptr()) };
+        SimulcastDescriptionRef::from_raw(
+            NonNull::new(raw as *mut ffi::webrtc_SimulcastDescription).expect(
+                "BUG: webrtc_MediaContentDescription_simulcast_description が null を返しました",
+            ),
+        )
+    }
+}
+
+/// webrtc::ContentInfo (SDP の m= セクション) の借用ラッパー。
+pub struct ContentInfoRef<'a> {
+    raw: NonNull<ffi::webrtc_ContentInfo>,
+    _marker: PhantomData<&'a ffi::webrtc_ContentInfo>,
+}
+
+unsafe impl<'a> Send for ContentInfoRef<'a> {}
+
+impl<'a> ContentInfoRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_ContentInfo>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_ContentInfo {
+        self.raw.as_ptr()
+    }
+
+    pub fn mid(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_ContentInfo_mid(self.raw.as_ptr(), &mut ptr, &mut len) };
+        borrowed_string(ptr, len)
+    }
+
+    /// ポート 0 で拒否された m= セクションかどうか。
+    pub fn rejected(&self) -> bool {
+        unsafe { ffi::webrtc_ContentInfo_get_rejected(self.raw.as_ptr()) != 0 }
+    }
+
+    pub fn bundle_only(&self) -> bool {
+        unsafe { ffi::webrtc_ContentInfo_get_bundle_only(self.raw.as_ptr()) != 0 }
+    }
+
+    pub fn media_description(&self) -> Option<MediaContentDescriptionRef<'a>> {
+        let raw = unsafe { ffi::webrtc_ContentInfo_media_description(self.raw.as_ptr()) };
+        NonNull::new(raw as *mut ffi::webrtc_MediaContentDescription)
+            .map(MediaContentDescriptionRef::from_raw)
+    }
+}
+
+/// パース済みの webrtc::SessionDescription の借用ラッパー。
+pub struct ParsedSessionDescriptionRef<'a> {
+    raw: NonNull<ffi::webrtc_SessionDescription>,
+    _marker: PhantomData<&'a ffi::webrtc_SessionDescription>,
+}
+
+unsafe impl<'a> Send for ParsedSessionDescriptionRef<'a> {}
+
+impl<'a> ParsedSessionDescriptionRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_SessionDescription>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_SessionDescription {
+        self.raw.as_ptr()
+    }
+
+    /// m= セクションを SDP の順番で返す。
+    pub fn contents(&self) -> Vec<ContentInfoRef<'a>> {
+        let size = unsafe { ffi::webrtc_SessionDescription_get_contents_size(self.raw.as_ptr()) };
+        (0..size)
+            .map(|index| {
+                let raw =
+                    unsafe { ffi::webrtc_SessionDescription_get_content(self.raw.as_ptr(), index) };
+                ContentInfoRef::from_raw(
+                    NonNull::new(raw as *mut ffi::webrtc_ContentInfo)
+                        .expect("BUG: webrtc_SessionDescription_get_content が null を返しました"),
+                )
+            })
+            .collect()
+    }
+
+    /// mid に対応する ICE / DTLS のパラメータを返す。
+    pub fn transport_description(&self, mid: &str) -> Option<TransportDescriptionRef<'a>> {
+        let raw = unsafe {
+            ffi::webrtc_SessionDescription_GetTransportDescriptionByName(
+                self.raw.as_ptr(),
+                mid.as_ptr() as *const _,
+                mid.len(),
+            )
+        };
+        NonNull::new(raw as *mut ffi::webrtc_TransportDescription)
+            .map(TransportDescriptionRef::from_raw)
+    }
+}
@@ -1,5 +1,8 @@
 use crate::ref_count::RTCStatsReportHandle;
-use crate::{CxxString, Result, ScopedRef, ffi};
+use crate::{CxxString, Error, Result, ScopedRef, ffi};
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::marker::PhantomData;
 use std::ptr::NonNull;
 
 /// webrtc::RTCStatsReport のラッパー。
@@ -24,7 +27,582 @@ impl RTCStatsReport {
         json.to_string()
     }
 
+    /// レポートのタイムスタンプ (マイクロ秒)。
+    pub fn timestamp_us(&self) -> i64 {
+        unsafe { ffi::webrtc_RTCStatsReport_timestamp_us(self.raw().as_ptr()) }
+    }
+
+    pub fn len(&self) -> usize {
+        unsafe { ffi::webrtc_RTCStatsReport_size(self.raw().as_ptr()) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// id に対応する統計情報を取得する。
+    pub fn get(&self, id: &str) -> Option<RTCStatsRef<'_>> {
+        let raw = unsafe {
+            ffi::webrtc_RTCStatsReport_Get(self.raw().as_ptr(), id.as_ptr() as *const _, id.len())
+        };
+        NonNull::new(raw).map(RTCStatsRef::from_raw)
+    }
+
+    /// 全ての統計情報を走査するイテレータを返す。
+    pub fn iter(&self) -> RTCStatsIter<'_> {
+        let raw = self.raw();
+        let current = NonNull::new(unsafe { ffi::webrtc_RTCStatsReport_begin(raw.as_ptr()) })
+            .expect("BUG: webrtc_RTCStatsReport_begin が null を返しました");
+        let end = NonNull::new(unsafe { ffi::webrtc_RTCStatsReport_end(raw.as_ptr()) })
+            .expect("BUG: webrtc_RTCStatsReport_end が null を返しました");
+        RTCStatsIter {
+            current,
+            end,
+            _marker: PhantomData,
+        }
+    }
+
+    /// type が一致する統計情報のみを走査するイテレータを返す。
+    pub fn iter_by_type<'a>(
+        &'a self,
+        stats_type: &'a str,
+    ) -> impl Iterator<Item = RTCStatsRef<'a>> + 'a {
+        self.iter()
+            .filter(move |stats| stats.stats_type().is_ok_and(|t| t == stats_type))
+    }
+
+    /// RTCStatsReport::GetStatsOfType の移植。
+    pub fn stats_of_type<T: RTCStatsType>(&self) -> Result<Vec<T>> {
+        self.iter_by_type(T::TYPE)
+            .map(|stats| T::from_stats(&stats))
+            .collect()
+    }
+
     fn raw(&self) -> NonNull<ffi::webrtc_RTCStatsReport> {
         self.raw_ref.raw()
     }
 }
+
+/// RTCStatsReport のイテレータ。
+pub struct RTCStatsIter<'a> {
+    current: NonNull<ffi::webrtc_RTCStatsReport_ConstIterator>,
+    end: NonNull<ffi::webrtc_RTCStatsReport_ConstIterator>,
+    _marker: PhantomData<&'a RTCStatsReport>,
+}
+
+unsafe impl<'a> Send for RTCStatsIter<'a> {}
+
+impl<'a> Iterator for RTCStatsIter<'a> {
+    type Item = RTCStatsRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_end = unsafe {
+            ffi::webrtc_RTCStatsReport_ConstIterator_equals(
+                self.current.as_ptr(),
+                self.end.as_ptr(),
+            )
+        };
+        if is_end != 0 {
+            return None;
+        }
+        let raw = unsafe { ffi::webrtc_RTCStatsReport_ConstIterator_get(self.current.as_ptr()) };
+        let raw = NonNull::new(raw)
+            .expect("BUG: webrtc_RTCStatsReport_ConstIterator_get が null を返しました");
+        unsafe { ffi::webrtc_RTCStatsReport_ConstIterator_increment(self.current.as_ptr()) };
+        Some(RTCStatsRef::from_raw(raw))
+    }
+}
+
+impl<'a> Drop for RTCStatsIter<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::webrtc_RTCStatsReport_ConstIterator_delete(self.current.as_ptr());
+            ffi::webrtc_RTCStatsReport_ConstIterator_delete(self.end.as_ptr());
+        }
+    }
+}
+
+/// webrtc::RTCStats の借用ラッパー。
+pub struct RTCStatsRef<'a> {
+    raw: NonNull<ffi::webrtc_RTCStats>,
+    _marker: PhantomData<&'a RTCStatsReport>,
+}
+
+unsafe impl<'a> Send for RTCStatsRef<'a> {}
+
+impl<'a> RTCStatsRef<'a> {
+    fn from_raw(raw: NonNull<ffi::webrtc_RTCStats>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn id(&self) -> Result<String> {
+        let id = unsafe { ffi::webrtc_RTCStats_id(self.raw.as_ptr()) };
+        let id = NonNull::new(id).expect("BUG: webrtc_RTCStats_id が null を返しました");
+        CxxString::from_unique(id).to_string()
+    }
+
+    /// "inbound-rtp" などの統計情報の種類。
+    pub fn stats_type(&self) -> Result<&'a str> {
+        let ptr = unsafe { ffi::webrtc_RTCStats_type(self.raw.as_ptr()) };
+        assert!(
+            !ptr.is_null(),
+            "BUG: webrtc_RTCStats_type が null を返しました"
+        );
+        // type() は静的な文字列を返す。
+        let s = unsafe { CStr::from_ptr(ptr) }.to_str()?;
+        Ok(s)
+    }
+
+    /// 統計情報のタイムスタンプ (マイクロ秒)。
+    pub fn timestamp_us(&self) -> i64 {
+        unsafe { ffi::webrtc_RTCStats_timestamp_us(self.raw.as_ptr()) }
+    }
+
+    /// 全ての属性を取得する。
+    pub fn attributes(&self) -> Result<Vec<RTCStatsAttribute>> {
+        let raw = NonNull::new(unsafe { ffi::webrtc_RTCStats_Attributes(self.raw.as_ptr()) })
+            .expect("BUG: webrtc_RTCStats_Attributes が null を返しました");
+        let len = unsafe { ffi::webrtc_Attribute_vector_size(raw.as_ptr()) }.max(0) as usize;
+        let mut out = Vec::with_capacity(len);
+        let mut result = Ok(());
+        for i in 0..len {
+            let attr = unsafe { ffi::webrtc_Attribute_vector_get(raw.as_ptr(), i as i32) };
+            let attr = NonNull::new(attr).expect("BUG: webrtc_Attribute_vector_get が null");
+            match RTCStatsAttribute::from_raw(attr) {
+                Ok(attr) => out.push(attr),
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+        unsafe { ffi::webrtc_Attribute_vector_delete(raw.as_ptr()) };
+        result.map(|_| out)
+    }
+
+    /// name に一致する属性の値を取得する。値が未設定の場合は None を返す。
+    pub fn attribute(&self, name: &str) -> Result<Option<RTCStatsValue>> {
+        Ok(self
+            .attributes()?
+            .into_iter()
+            .find(|attr| attr.name == name)
+            .and_then(|attr| attr.value))
+    }
+
+    /// RTCStats::cast_to の移植。type が一致しない場合は None を返す。
+    pub fn cast_to<T: RTCStatsType>(&self) -> Result<Option<T>> {
+        if self.stats_type()? != T::TYPE {
+            return Ok(None);
+        }
+        T::from_stats(self).map(Some)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        let json = unsafe { ffi::webrtc_RTCStats_ToJson(self.raw.as_ptr()) };
+        let json = NonNull::new(json).expect("BUG: webrtc_RTCStats_ToJson が null を返しました");
+        CxxString::from_unique(json).to_string()
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_RTCStats {
+        self.raw.as_ptr()
+    }
+}
+
+/// webrtc::Attribute の値。
+#[derive(Debug, Clone, PartialEq)]
+pub enum RTCStatsValue {
+    Bool(bool),
+    Int32(i32),
+    Uint32(u32),
+    Int64(i64),
+    Uint64(u64),
+    Double(f64),
+    String(String),
+    /// 配列や map など上記以外の値。Attribute::ToString の結果を保持する。
+    Other(String),
+}
+
+impl RTCStatsValue {
+    /// 符号なし整数として取得する。負の値や整数以外の場合は None を返す。
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            RTCStatsValue::Int32(v) => u64::try_from(*v).ok(),
+            RTCStatsValue::Uint32(v) => Some(u64::from(*v)),
+            RTCStatsValue::Int64(v) => u64::try_from(*v).ok(),
+            RTCStatsValue::Uint64(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// 符号付き整数として取得する。範囲外や整数以外の場合は None を返す。
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            RTCStatsValue::Int32(v) => Some(i64::from(*v)),
+            RTCStatsValue::Uint32(v) => Some(i64::from(*v)),
+            RTCStatsValue::Int64(v) => Some(*v),
+            RTCStatsValue::Uint64(v) => i64::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            RTCStatsValue::Double(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            RTCStatsValue::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            RTCStatsValue::String(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+/// webrtc::Attribute の名前と値。
+#[derive(Debug, Clone, PartialEq)]
+pub struct RTCStatsAttribute {
+    pub name: String,
+    pub value: Option<RTCStatsValue>,
+}
+
+impl RTCStatsAttribute {
+    fn from_raw(raw: NonNull<ffi::webrtc_Attribute>) -> Result<Self> {
+        let p = raw.as_ptr();
+        let name = unsafe { ffi::webrtc_Attribute_name(p) };
+        assert!(
+            !name.is_null(),
+            "BUG: webrtc_Attribute_name が null を返しました"
+        );
+        let name = unsafe { CStr::from_ptr(name) }.to_str()?.to_owned();
+        if unsafe { ffi::webrtc_Attribute_has_value(p) } == 0 {
+            return Ok(Self { name, value: None });
+        }
+        let value = unsafe {
+            if ffi::webrtc_Attribute_holds_alternative_bool(p) != 0 {
+                RTCStatsValue::Bool(ffi::webrtc_Attribute_get_bool(p) != 0)
+            } else if ffi::webrtc_Attribute_holds_alternative_int32(p) != 0 {
+                RTCStatsValue::Int32(ffi::webrtc_Attribute_get_int32(p))
+            } else if ffi::webrtc_Attribute_holds_alternative_uint32(p) != 0 {
+                RTCStatsValue::Uint32(ffi::webrtc_Attribute_get_uint32(p))
+            } else if ffi::webrtc_Attribute_holds_alternative_int64(p) != 0 {
+                RTCStatsValue::Int64(ffi::webrtc_Attribute_get_int64(p))
+            } else if ffi::webrtc_Attribute_holds_alternative_uint64(p) != 0 {
+                RTCStatsValue::Uint64(ffi::webrtc_Attribute_get_uint64(p))
+            } else if ffi::webrtc_Attribute_holds_alternative_double(p) != 0 {
+                RTCStatsValue::Double(ffi::webrtc_Attribute_get_double(p))
+            } else if ffi::webrtc_Attribute_holds_alternative_string(p) != 0 {
+                let s = NonNull::new(ffi::webrtc_Attribute_get_string(p))
+                    .expect("BUG: webrtc_Attribute_get_string が null を返しました");
+                RTCStatsValue::String(CxxString::from_unique(s).to_string()?)
+            } else {
+                let s = NonNull::new(ffi::webrtc_Attribute_ToString(p))
+                    .expect("BUG: webrtc_Attribute_ToString が null を返しました");
+                RTCStatsValue::Other(CxxString::from_unique(s).to_string()?)
+            }
+        };
+        Ok(Self {
+            name,
+            value: Some(value),
+        })
+    }
+}
+
+/// 型付きの統計情報を表すトレイト。
+pub trait RTCStatsType: Sized {
+    /// RTCStats::type() の値。
+    const TYPE: &'static str;
+
+    fn from_stats(stats: &RTCStatsRef<'_>) -> Result<Self>;
+}
+
+// 属性名から値を引くための一時的な map。
+struct RTCStatsValues(HashMap<String, RTCStatsValue>);
+
+impl RTCStatsValues {
+    fn new(stats: &RTCStatsRef<'_>) -> Result<Self> {
+        let values = stats
+            .attributes()?
+            .into_iter()
+            .filter_map(|attr| attr.value.map(|value| (attr.name, value)))
+            .collect();
+        Ok(Self(values))
+    }
+
+    fn u64(&self, name: &str) -> Option<u64> {
+        self.0.get(name).and_then(RTCStatsValue::as_u64)
+    }
+
+    fn i64(&self, name: &str) -> Option<i64> {
+        self.0.get(name).and_then(RTCStatsValue::as_i64)
+    }
+
+    fn f64(&self, name: &str) -> Option<f64> {
+        self.0.get(name).and_then(RTCStatsValue::as_f64)
+    }
+
+    fn bool(&self, name: &str) -> Option<bool> {
+        self.0.get(name).and_then(RTCStatsValue::as_bool)
+    }
+
+    fn string(&self, name: &str) -> Option<String> {
+        self.0
+            .get(name)
+            .and_then(RTCStatsValue::as_str)
+            .map(str::to_owned)
+    }
+}
+
+macro_rules! define_rtc_stats {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident = $type:literal {
+            $($field:ident: $kind:ident = $attr:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, PartialEq)]
+        pub struct $name {
+            pub id: String,
+            pub timestamp_us: i64,
+            $(pub $field: Option<define_rtc_stats!(@type $kind)>,)*
+        }
+
+        impl RTCStatsType for $name {
+            const TYPE: &'static str = $type;
+
+            fn from_stats(stats: &RTCStatsRef<'_>) -> Result<Self> {
+                let stats_type = stats.stats_type()?;
+                if stats_type != Self::TYPE {
+                    return Err(Error::Message(format!(
+                        "{} ではなく {} の統計情報です",
+                        Self::TYPE,
+                        stats_type
+                    )));
+                }
+                let values = RTCStatsValues::new(stats)?;
+                Ok(Self {
+                    id: stats.id()?,
+                    timestamp_us: stats.timestamp_us(),
+                    $($field: values.$kind($attr),)*
+                })
+            }
+        }
+    };
+    (@type u64) => { u64 };
+    (@type i64) => { i64 };
+    (@type f64) => { f64 };
+    (@type bool) => { bool };
+    (@type string) => { String };
+}
+
+define_rtc_stats! {
+    /// RTCCodecStats ("codec")。
+    pub struct RTCCodecStats = "codec" {
+        transport_id: string = "transportId",
+        payload_type: u64 = "payloadType",
+        mime_type: string = "mimeType",
+        clock_rate: u64 = "clockRate",
+        channels: u64 = "channels",
+        sdp_fmtp_line: string = "sdpFmtpLine",
+    }
+}
+
+define_rtc_stats! {
+    /// RTCInboundRtpStreamStats ("inbound-rtp")。
+    pub struct RTCInboundRtpStreamStats = "inbound-rtp" {
+        ssrc: u64 = "ssrc",
+        kind: string = "kind",
+        transport_id: string = "transportId",
+        codec_id: string = "codecId",
+        jitter: f64 = "jitter",
+        packets_lost: i64 = "packetsLost",
+        track_identifier: string = "trackIdentifier",
+        mid: string = "mid",
+        remote_id: string = "remoteId",
+        packets_received: u64 = "packetsReceived",
+        packets_discarded: u64 = "packetsDiscarded",
+        fec_packets_received: u64 = "fecPacketsReceived",
+        bytes_received: u64 = "bytesReceived",
+        header_bytes_received: u64 = "headerBytesReceived",
+        last_packet_received_timestamp: f64 = "lastPacketReceivedTimestamp",
+        jitter_buffer_delay: f64 = "jitterBufferDelay",
+        jitter_buffer_target_delay: f64 = "jitterBufferTargetDelay",
+        jitter_buffer_emitted_count: u64 = "jitterBufferEmittedCount",
+        total_samples_received: u64 = "totalSamplesReceived",
+        concealed_samples: u64 = "concealedSamples",
+        concealment_events: u64 = "concealmentEvents",
+        audio_level: f64 = "audioLevel",
+        total_audio_energy: f64 = "totalAudioEnergy",
+        total_samples_duration: f64 = "totalSamplesDuration",
+        frames_received: u64 = "framesReceived",
+        frame_width: u64 = "frameWidth",
+        frame_height: u64 = "frameHeight",
+        frames_per_second: f64 = "framesPerSecond",
+        frames_decoded: u64 = "framesDecoded",
+        key_frames_decoded: u64 = "keyFramesDecoded",
+        frames_dropped: u64 = "framesDropped",
+        total_decode_time: f64 = "totalDecodeTime",
+        nack_count: u64 = "nackCount",
+        fir_count: u64 = "firCount",
+        pli_count: u64 = "pliCount",
+        decoder_implementation: string = "decoderImplementation",
+    }
+}
+
+define_rtc_stats! {
+    /// RTCOutboundRtpStreamStats ("outbound-rtp")。
+    pub struct RTCOutboundRtpStreamStats = "outbound-rtp" {
+        ssrc: u64 = "ssrc",
+        kind: string = "kind",
+        transport_id: string = "transportId",
+        codec_id: string = "codecId",
+        packets_sent: u64 = "packetsSent",
+        bytes_sent: u64 = "bytesSent",
+        media_source_id: string = "mediaSourceId",
+        remote_id: string = "remoteId",
+        mid: string = "mid",
+        rid: string = "rid",
+        header_bytes_sent: u64 = "headerBytesSent",
+        retransmitted_packets_sent: u64 = "retransmittedPacketsSent",
+        retransmitted_bytes_sent: u64 = "retransmittedBytesSent",
+        target_bitrate: f64 = "targetBitrate",
+        frames_encoded: u64 = "framesEncoded",
+        key_frames_encoded: u64 = "keyFramesEncoded",
+        total_encode_time: f64 = "totalEncodeTime",
+        frame_width: u64 = "frameWidth",
+        frame_height: u64 = "frameHeight",
+        frames_per_second: f64 = "framesPerSecond",
+        frames_sent: u64 = "framesSent",
+        total_packet_send_delay: f64 = "totalPacketSendDelay",
+        quality_limitation_reason: string = "qualityLimitationReason",
+        nack_count: u64 = "nackCount",
+        fir_count: u64 = "firCount",
+        pli_count: u64 = "pliCount",
+        encoder_implementation: string = "encoderImplementation",
+        active: bool = "active",
+        scalability_mode: string = "scalabilityMode",
+    }
+}
+
+define_rtc_stats! {
+    /// RTCRemoteInboundRtpStreamStats ("remote-inbound-rtp")。
+    pub struct RTCRemoteInboundRtpStreamStats = "remote-inbound-rtp" {
+        ssrc: u64 = "ssrc",
+        kind: string = "kind",
+        transport_id: string = "transportId",
+        codec_id: string = "codecId",
+        jitter: f64 = "jitter",
+        packets_lost: i64 = "packetsLost",
+        local_id: string = "localId",
+        round_trip_time: f64 = "roundTripTime",
+        total_round_trip_time: f64 = "totalRoundTripTime",
+        fraction_lost: f64 = "fractionLost",
+        round_trip_time_measurements: u64 = "roundTripTimeMeasurements",
+    }
+}
+
+define_rtc_stats! {
+    /// RTCIceCandidatePairStats ("candidate-pair")。
+    pub struct RTCIceCandidatePairStats = "candidate-pair" {
+        transport_id: string = "transportId",
+        local_candidate_id: string = "localCandidateId",
+        remote_candidate_id: string = "remoteCandidateId",
+        state: string = "state",
+        priority: u64 = "priority",
+        nominated: bool = "nominated",
+        writable: bool = "writable",
+        packets_sent: u64 = "packetsSent",
+        packets_received: u64 = "packetsReceived",
+        bytes_sent: u64 = "bytesSent",
+        bytes_received: u64 = "bytesReceived",
+        total_round_trip_time: f64 = "totalRoundTripTime",
+        current_round_trip_time: f64 = "currentRoundTripTime",
+        available_outgoing_bitrate: f64 = "availableOutgoingBitrate",
+        available_incoming_bitrate: f64 = "availableIncomingBitrate",
+        requests_received: u64 = "requestsReceived",
+        requests_sent: u64 = "requestsSent",
+        responses_received: u64 = "responsesReceived",
+        responses_sent: u64 = "responsesSent",
+        consent_requests_sent: u64 = "consentRequestsSent",
+        last_packet_received_timestamp: f64 = "lastPacketReceivedTimestamp",
+        last_packet_sent_timestamp: f64 = "lastPacketSentTimestamp",
+    }
+}
+
+define_rtc_stats! {
+    /// RTCLocalIceCandidateStats ("local-candidate")。
+    pub struct RTCLocalIceCandidateStats = "local-candidate" {
+        transport_id: string = "transportId",
+        is_remote: bool = "isRemote",
+        network_type: string = "networkType",
+        ip: string = "ip",
+        address: string = "address",
+        port: i64 = "port",
+        protocol: string = "protocol",
+        relay_protocol: string = "relayProtocol",
+        candidate_type: string = "candidateType",
+        priority: i64 = "priority",
+        url: string = "url",
+        foundation: string = "foundation",
+        related_address: string = "relatedAddress",
+        related_port: i64 = "relatedPort",
+        username_fragment: string = "usernameFragment",
+        tcp_type: string = "tcpType",
+        vpn: bool = "vpn",
+        network_adapter_type: string = "networkAdapterType",
+    }
+}
+
+define_rtc_stats! {
+    /// RTCTransportStats ("transport")。
+    pub struct RTCTransportStats = "transport" {
+        bytes_sent: u64 = "bytesSent",
+        packets_sent: u64 = "packetsSent",
+        bytes_received: u64 = "bytesReceived",
+        packets_received: u64 = "packetsReceived",
+        rtcp_transport_stats_id: string = "rtcpTransportStatsId",
+        dtls_state: string = "dtlsState",
+        selected_candidate_pair_id: string = "selectedCandidatePairId",
+        local_certificate_id: string = "localCertificateId",
+        remote_certificate_id: string = "remoteCertificateId",
+        tls_version: string = "tlsVersion",
+        dtls_cipher: string = "dtlsCipher",
+        dtls_role: string = "dtlsRole",
+        srtp_cipher: string = "srtpCipher",
+        selected_candidate_pair_changes: u64 = "selectedCandidatePairChanges",
+        ice_role: string = "iceRole",
+        ice_local_username_fragment: string = "iceLocalUsernameFragment",
+        ice_state: string = "iceState",
+    }
+}
+
+define_rtc_stats! {
+    /// RTCMediaSourceStats ("media-source")。
+    ///
+    /// 音声の場合は RTCAudioSourceStats、映像の場合は RTCVideoSourceStats の属性が設定される。
+    pub struct RTCMediaSourceStats = "media-source" {
+        track_identifier: string = "trackIdentifier",
+        kind: string = "kind",
+        audio_level: f64 = "audioLevel",
+        total_audio_energy: f64 = "totalAudioEnergy",
+        total_samples_duration: f64 = "totalSamplesDuration",
+        echo_return_loss: f64 = "echoReturnLoss",
+        echo_return_loss_enhancement: f64 = "echoReturnLossEnhancement",
+        width: u64 = "width",
+        height: u64 = "height",
+        frames: u64 = "frames",
+        frames_per_second: f64 = "framesPerSecond",
+    }
+}
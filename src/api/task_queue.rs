@@ -0,0 +1,522 @@
+use crate::ffi;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::marker::PhantomData;
+use std::os::raw::{c_char, c_void};
+use std::ptr::NonNull;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// TaskQueueFactory::Priority
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskQueuePriority {
+    Normal,
+    High,
+    Low,
+    Unknown(i32),
+}
+
+impl TaskQueuePriority {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_TaskQueueFactory_Priority_kNormal {
+                TaskQueuePriority::Normal
+            } else if value == ffi::webrtc_TaskQueueFactory_Priority_kHigh {
+                TaskQueuePriority::High
+            } else if value == ffi::webrtc_TaskQueueFactory_Priority_kLow {
+                TaskQueuePriority::Low
+            } else {
+                TaskQueuePriority::Unknown(value)
+            }
+        }
+    }
+
+    pub fn to_int(&self) -> i32 {
+        match self {
+            TaskQueuePriority::Normal => unsafe { ffi::webrtc_TaskQueueFactory_Priority_kNormal },
+            TaskQueuePriority::High => unsafe { ffi::webrtc_TaskQueueFactory_Priority_kHigh },
+            TaskQueuePriority::Low => unsafe { ffi::webrtc_TaskQueueFactory_Priority_kLow },
+            TaskQueuePriority::Unknown(v) => *v,
+        }
+    }
+}
+
+/// タスクキューに投入されたタスク。
+///
+/// run せずに破棄した場合、タスクは実行されない。
+pub struct QueuedTask {
+    raw_unique: NonNull<ffi::webrtc_TaskQueueTask_unique>,
+}
+
+unsafe impl Send for QueuedTask {}
+
+impl QueuedTask {
+    fn from_raw(raw: *mut ffi::webrtc_TaskQueueTask_unique) -> Self {
+        Self {
+            raw_unique: NonNull::new(raw).expect("BUG: task が null です"),
+        }
+    }
+
+    /// タスクを実行する。
+    ///
+    /// # Safety
+    /// 投入先のキューの TaskQueueHandler からのみ呼ぶこと。
+    /// 同じキューのタスクを同時に実行してはならず、キューの drop が戻った後に
+    /// 実行してはならない。
+    pub unsafe fn run(self) {
+        let raw = std::mem::ManuallyDrop::new(self).raw_unique.as_ptr();
+        unsafe { ffi::webrtc_TaskQueueTask_Run(raw) };
+    }
+}
+
+impl Drop for QueuedTask {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_TaskQueueTask_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
+
+/// Rust で実装するタスクキュー。
+///
+/// post_task / post_delayed_task は任意のスレッドから同時に呼ばれる。
+///
+/// # Safety
+/// 投入されたタスクは投入順に 1 つずつ実行し、同時に実行してはならない。
+/// drop されたら実行中のタスクの完了を待ち、以後タスクを実行してはならない。
+/// ただしキュー上のタスクから drop された場合は待たずに戻る。
+/// 実行しなかったタスクは drop してよい。
+pub unsafe trait TaskQueueHandler: Send + Sync {
+    fn post_task(&self, task: QueuedTask);
+
+    fn post_delayed_task(&self, task: QueuedTask, delay: Duration, high_precision: bool);
+}
+
+struct TaskQueueHandlerState {
+    handler: Box<dyn TaskQueueHandler>,
+}
+
+unsafe extern "C" fn task_queue_post_task(
+    task: *mut ffi::webrtc_TaskQueueTask_unique,
+    user_data: *mut c_void,
+) {
+    assert!(
+        !user_data.is_null(),
+        "task_queue_post_task: user_data is null"
+    );
+    let state = unsafe { &*(user_data as *const TaskQueueHandlerState) };
+    state.handler.post_task(QueuedTask::from_raw(task));
+}
+
+unsafe extern "C" fn task_queue_post_delayed_task(
+    task: *mut ffi::webrtc_TaskQueueTask_unique,
+    delay_us: i64,
+    high_precision: i32,
+    user_data: *mut c_void,
+) {
+    assert!(
+        !user_data.is_null(),
+        "task_queue_post_delayed_task: user_data is null"
+    );
+    let state = unsafe { &*(user_data as *const TaskQueueHandlerState) };
+    state.handler.post_delayed_task(
+        QueuedTask::from_raw(task),
+        Duration::from_micros(delay_us.max(0) as u64),
+        high_precision != 0,
+    );
+}
+
+unsafe extern "C" fn task_queue_delete(user_data: *mut c_void) {
+    assert!(!user_data.is_null(), "task_queue_delete: user_data is null");
+    let _ = unsafe { Box::from_raw(user_data as *mut TaskQueueHandlerState) };
+}
+
+/// libwebrtc がエンコーダーやペーシングに使うタスクキューを生成する。
+///
+/// create_task_queue は任意のスレッドから同時に呼ばれる。
+pub trait TaskQueueFactoryHandler: Send + Sync {
+    fn create_task_queue(
+        &self,
+        name: &str,
+        priority: TaskQueuePriority,
+    ) -> Box<dyn TaskQueueHandler>;
+}
+
+struct TaskQueueFactoryHandlerState {
+    handler: Box<dyn TaskQueueFactoryHandler>,
+}
+
+unsafe extern "C" fn task_queue_factory_create_task_queue(
+    name: *const c_char,
+    name_len: usize,
+    priority: ffi::webrtc_TaskQueueFactory_Priority,
+    user_data: *mut c_void,
+) -> *mut ffi::webrtc_TaskQueueBase {
+    assert!(
+        !user_data.is_null(),
+        "task_queue_factory_create_task_queue: user_data is null"
+    );
+    assert!(!name.is_null() || name_len == 0);
+    let state = unsafe { &*(user_data as *const TaskQueueFactoryHandlerState) };
+    let name = if name_len == 0 {
+        ""
+    } else {
+        let bytes = unsafe { std::slice::from_raw_parts(name.cast::<u8>(), name_len) };
+        std::str::from_utf8(bytes).expect("BUG: name が UTF-8 ではありません")
+    };
+    let handler = state
+        .handler
+        .create_task_queue(name, TaskQueuePriority::from_int(priority));
+    let queue_state = Box::new(TaskQueueHandlerState { handler });
+    let queue_user_data = Box::into_raw(queue_state) as *mut c_void;
+    let cbs = ffi::webrtc_TaskQueueBase_cbs {
+        PostTask: Some(task_queue_post_task),
+        PostDelayedTask: Some(task_queue_post_delayed_task),
+        Delete: Some(task_queue_delete),
+    };
+    let raw = unsafe { ffi::webrtc_TaskQueueBase_new(&cbs, queue_user_data) };
+    if raw.is_null() {
+        let _ = unsafe { Box::from_raw(queue_user_data as *mut TaskQueueHandlerState) };
+        panic!("BUG: webrtc_TaskQueueBase_new が null を返しました");
+    }
+    raw
+}
+
+unsafe extern "C" fn task_queue_factory_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "task_queue_factory_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut TaskQueueFactoryHandlerState) };
+}
+
+/// Rust で実装する webrtc::TaskQueueFactory。
+///
+/// EnvironmentBuilder::set_task_queue_factory で設定する。
+pub struct TaskQueueFactory {
+    raw_unique: NonNull<ffi::webrtc_TaskQueueFactory_unique>,
+}
+
+unsafe impl Send for TaskQueueFactory {}
+
+impl TaskQueueFactory {
+    pub fn new_with_handler(handler: Box<dyn TaskQueueFactoryHandler>) -> Self {
+        let state = Box::new(TaskQueueFactoryHandlerState { handler });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_TaskQueueFactory_cbs {
+            CreateTaskQueue: Some(task_queue_factory_create_task_queue),
+            OnDestroy: Some(task_queue_factory_on_destroy),
+        };
+        let raw = unsafe { ffi::webrtc_TaskQueueFactory_new(&cbs, user_data) };
+        let raw_unique = match NonNull::new(raw) {
+            Some(raw_unique) => raw_unique,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut TaskQueueFactoryHandlerState) };
+                panic!("BUG: webrtc_TaskQueueFactory_new が null を返しました");
+            }
+        };
+        Self { raw_unique }
+    }
+
+    /// キューごとに std::thread を 1 つ起動する TaskQueueFactory を生成する。
+    pub fn new_std_thread() -> Self {
+        Self::new_with_handler(Box::new(StdThreadTaskQueueFactory))
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_TaskQueueFactory {
+        unsafe { ffi::webrtc_TaskQueueFactory_unique_get(self.raw_unique.as_ptr()) }
+    }
+
+    pub fn into_raw(self) -> *mut ffi::webrtc_TaskQueueFactory_unique {
+        std::mem::ManuallyDrop::new(self).raw_unique.as_ptr()
+    }
+
+    pub fn as_ref(&self) -> TaskQueueFactoryRef<'_> {
+        let raw = NonNull::new(self.as_ptr())
+            .expect("BUG: webrtc_TaskQueueFactory_unique_get が null を返しました");
+        // Safety: raw は self の生存中は有効です。
+        unsafe { TaskQueueFactoryRef::from_raw(raw) }
+    }
+}
+
+impl Drop for TaskQueueFactory {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_TaskQueueFactory_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
+
+/// 借用した webrtc::TaskQueueFactory。
+pub struct TaskQueueFactoryRef<'a> {
+    raw: NonNull<ffi::webrtc_TaskQueueFactory>,
+    _marker: PhantomData<&'a ffi::webrtc_TaskQueueFactory>,
+}
+
+unsafe impl<'a> Send for TaskQueueFactoryRef<'a> {}
+
+impl<'a> TaskQueueFactoryRef<'a> {
+    /// # Safety
+    /// `raw` は `'a` の間有効な `webrtc_TaskQueueFactory` を指している必要があります。
+    pub unsafe fn from_raw(raw: NonNull<ffi::webrtc_TaskQueueFactory>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_TaskQueueFactory {
+        self.raw.as_ptr()
+    }
+
+    pub fn create_task_queue(&self, name: &str, priority: TaskQueuePriority) -> TaskQueue {
+        let raw = unsafe {
+            ffi::webrtc_TaskQueueFactory_CreateTaskQueue(
+                self.raw.as_ptr(),
+                name.as_ptr() as *const c_char,
+                name.len(),
+                priority.to_int(),
+            )
+        };
+        TaskQueue {
+            raw: NonNull::new(raw)
+                .expect("BUG: webrtc_TaskQueueFactory_CreateTaskQueue が null を返しました"),
+        }
+    }
+}
+
+unsafe extern "C" fn task_queue_task_run<F>(user_data: *mut c_void)
+where
+    F: FnOnce() + Send + 'static,
+{
+    assert!(
+        !user_data.is_null(),
+        "task_queue_task_run: user_data is null"
+    );
+    let slot = unsafe { &mut *(user_data as *mut Option<F>) };
+    if let Some(f) = slot.take() {
+        f();
+    }
+}
+
+unsafe extern "C" fn task_queue_task_on_destroy<F>(user_data: *mut c_void)
+where
+    F: FnOnce() + Send + 'static,
+{
+    assert!(
+        !user_data.is_null(),
+        "task_queue_task_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut Option<F>) };
+}
+
+/// TaskQueueFactory から生成した webrtc::TaskQueueBase。
+///
+/// drop すると実行中のタスクの完了を待ち、未実行のタスクは破棄する。
+pub struct TaskQueue {
+    raw: NonNull<ffi::webrtc_TaskQueueBase>,
+}
+
+unsafe impl Send for TaskQueue {}
+unsafe impl Sync for TaskQueue {}
+
+impl TaskQueue {
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_TaskQueueBase {
+        self.raw.as_ptr()
+    }
+
+    pub fn post_task<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let (cbs, user_data) = Self::task_cbs(f);
+        unsafe { ffi::webrtc_TaskQueueBase_PostTask(self.raw.as_ptr(), &cbs, user_data) };
+    }
+
+    pub fn post_delayed_task<F>(&self, f: F, delay: Duration)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let (cbs, user_data) = Self::task_cbs(f);
+        unsafe {
+            ffi::webrtc_TaskQueueBase_PostDelayedTask(
+                self.raw.as_ptr(),
+                &cbs,
+                user_data,
+                delay.as_micros() as i64,
+            )
+        };
+    }
+
+    fn task_cbs<F>(f: F) -> (ffi::webrtc_TaskQueueBase_Task_cbs, *mut c_void)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let cbs = ffi::webrtc_TaskQueueBase_Task_cbs {
+            Run: Some(task_queue_task_run::<F>),
+            OnDestroy: Some(task_queue_task_on_destroy::<F>),
+        };
+        (cbs, Box::into_raw(Box::new(Some(f))) as *mut c_void)
+    }
+}
+
+impl Drop for TaskQueue {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_TaskQueueBase_Delete(self.raw.as_ptr()) };
+    }
+}
+
+/// キューごとに std::thread を 1 つ起動する TaskQueueFactoryHandler。
+///
+/// 計測などのために独自の TaskQueueFactoryHandler から委譲して使える。
+/// priority は無視する。
+pub struct StdThreadTaskQueueFactory;
+
+impl TaskQueueFactoryHandler for StdThreadTaskQueueFactory {
+    fn create_task_queue(
+        &self,
+        name: &str,
+        _priority: TaskQueuePriority,
+    ) -> Box<dyn TaskQueueHandler> {
+        Box::new(StdThreadTaskQueue::new(name))
+    }
+}
+
+struct DelayedTask {
+    deadline: Instant,
+    seq: u64,
+    task: QueuedTask,
+}
+
+// BinaryHeap で deadline が最も早いものを先頭にするため逆順で比較する。
+impl Ord for DelayedTask {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.deadline, other.seq).cmp(&(self.deadline, self.seq))
+    }
+}
+
+impl PartialOrd for DelayedTask {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for DelayedTask {
+    fn eq(&self, other: &Self) -> bool {
+        (self.deadline, self.seq) == (other.deadline, other.seq)
+    }
+}
+
+impl Eq for DelayedTask {}
+
+#[derive(Default)]
+struct StdThreadTaskQueueState {
+    tasks: VecDeque<QueuedTask>,
+    delayed: BinaryHeap<DelayedTask>,
+    next_seq: u64,
+    stopped: bool,
+}
+
+#[derive(Default)]
+struct StdThreadTaskQueueShared {
+    state: Mutex<StdThreadTaskQueueState>,
+    cond: Condvar,
+}
+
+struct StdThreadTaskQueue {
+    shared: Arc<StdThreadTaskQueueShared>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl StdThreadTaskQueue {
+    fn new(name: &str) -> Self {
+        let shared = Arc::new(StdThreadTaskQueueShared::default());
+        let thread = std::thread::Builder::new()
+            .name(name.to_owned())
+            .spawn({
+                let shared = shared.clone();
+                move || run_std_thread_task_queue(&shared)
+            })
+            .expect("タスクキューのスレッドの起動に失敗しました");
+        Self {
+            shared,
+            thread: Some(thread),
+        }
+    }
+}
+
+fn run_std_thread_task_queue(shared: &StdThreadTaskQueueShared) {
+    let mut state = shared.state.lock().unwrap();
+    loop {
+        if state.stopped {
+            return;
+        }
+        let now = Instant::now();
+        while state.delayed.peek().is_some_and(|t| t.deadline <= now) {
+            let delayed = state.delayed.pop().expect("BUG: delayed が空です");
+            state.tasks.push_back(delayed.task);
+        }
+        if let Some(task) = state.tasks.pop_front() {
+            drop(state);
+            // Safety: このキューのタスクはこのスレッドでのみ 1 つずつ実行し、
+            // drop 後は stopped を見て実行せずに抜ける。
+            unsafe { task.run() };
+            state = shared.state.lock().unwrap();
+            continue;
+        }
+        state = match state.delayed.peek().map(|t| t.deadline - now) {
+            Some(timeout) => shared.cond.wait_timeout(state, timeout).unwrap().0,
+            None => shared.cond.wait(state).unwrap(),
+        };
+    }
+}
+
+unsafe impl TaskQueueHandler for StdThreadTaskQueue {
+    fn post_task(&self, task: QueuedTask) {
+        let mut state = self.shared.state.lock().unwrap();
+        if state.stopped {
+            // タスクの破棄で再入しうるため、ロックを外してから破棄する。
+            drop(state);
+            drop(task);
+            return;
+        }
+        state.tasks.push_back(task);
+        self.shared.cond.notify_one();
+    }
+
+    fn post_delayed_task(&self, task: QueuedTask, delay: Duration, _high_precision: bool) {
+        let mut state = self.shared.state.lock().unwrap();
+        if state.stopped {
+            drop(state);
+            drop(task);
+            return;
+        }
+        let seq = state.next_seq;
+        state.next_seq += 1;
+        state.delayed.push(DelayedTask {
+            deadline: Instant::now() + delay,
+            seq,
+            task,
+        });
+        self.shared.cond.notify_one();
+    }
+}
+
+impl Drop for StdThreadTaskQueue {
+    fn drop(&mut self) {
+        let (tasks, delayed) = {
+            let mut state = self.shared.state.lock().unwrap();
+            state.stopped = true;
+            (
+                std::mem::take(&mut state.tasks),
+                std::mem::take(&mut state.delayed),
+            )
+        };
+        self.shared.cond.notify_all();
+        drop(tasks);
+        drop(delayed);
+        if let Some(thread) = self.thread.take() {
+            // キュー上のタスクから破棄された場合は自分自身を join できない。
+            if thread.thread().id() != std::thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
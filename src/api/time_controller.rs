@@ -0,0 +1,324 @@
+use crate::{
+    Clock, ClockHandler, QueuedTask, TaskQueueFactory, TaskQueueFactoryHandler, TaskQueueHandler,
+    TaskQueuePriority,
+};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::sync::atomic::{AtomicI64, Ordering as AtomicOrdering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+struct SimulatedDelayedTask {
+    deadline_us: i64,
+    seq: u64,
+    queue_id: u64,
+    task: QueuedTask,
+}
+
+// BinaryHeap で deadline が最も早いものを先頭にするため逆順で比較する。
+impl Ord for SimulatedDelayedTask {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.deadline_us, other.seq).cmp(&(self.deadline_us, self.seq))
+    }
+}
+
+impl PartialOrd for SimulatedDelayedTask {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SimulatedDelayedTask {
+    fn eq(&self, other: &Self) -> bool {
+        (self.deadline_us, self.seq) == (other.deadline_us, other.seq)
+    }
+}
+
+impl Eq for SimulatedDelayedTask {}
+
+#[derive(Default)]
+struct SimulatedQueueSlot {
+    ready: VecDeque<QueuedTask>,
+    running: bool,
+    stopped: bool,
+}
+
+#[derive(Default)]
+struct SimulatedTimeState {
+    queues: HashMap<u64, SimulatedQueueSlot>,
+    delayed: BinaryHeap<SimulatedDelayedTask>,
+    next_seq: u64,
+    next_queue_id: u64,
+}
+
+impl SimulatedTimeState {
+    // 実行待ち・実行中のタスクがなければ true。
+    fn is_idle(&self) -> bool {
+        self.queues
+            .values()
+            .all(|q| !q.running && (q.stopped || q.ready.is_empty()))
+    }
+}
+
+struct SimulatedTimeShared {
+    now_us: AtomicI64,
+    state: Mutex<SimulatedTimeState>,
+    cond: Condvar,
+}
+
+impl SimulatedTimeShared {
+    fn now_us(&self) -> i64 {
+        self.now_us.load(AtomicOrdering::SeqCst)
+    }
+
+    fn wait_idle<'a>(
+        &self,
+        mut state: MutexGuard<'a, SimulatedTimeState>,
+    ) -> MutexGuard<'a, SimulatedTimeState> {
+        while !state.is_idle() {
+            state = self.cond.wait(state).unwrap();
+        }
+        state
+    }
+}
+
+/// 手動で時刻を進めるシミュレーション時間のコントローラー。
+///
+/// create_clock で生成した Clock と create_task_queue_factory で生成した
+/// TaskQueueFactory は同じ時刻を共有する。遅延タスクは advance_time で
+/// 時刻が期限に達したときに実行される。
+/// 試験で統計情報のタイムスタンプや、エンコーダー・ペーサーなど Environment の
+/// タスクキューで動くレート制御を早送りするのに使う。
+///
+/// libwebrtc の GlobalSimulatedTimeController と異なり、rtc::Thread
+/// (network / worker / signaling thread) の遅延タスクと time_millis が返すグローバルな
+/// 時計は実時間のまま。そのため ICE や DTLS のタイムアウト、統計情報の収集間隔は早送りできない。
+#[derive(Clone)]
+pub struct SimulatedTimeController {
+    shared: Arc<SimulatedTimeShared>,
+}
+
+impl SimulatedTimeController {
+    pub fn new(start_time: Duration) -> Self {
+        Self {
+            shared: Arc::new(SimulatedTimeShared {
+                now_us: AtomicI64::new(start_time.as_micros() as i64),
+                state: Mutex::new(SimulatedTimeState::default()),
+                cond: Condvar::new(),
+            }),
+        }
+    }
+
+    pub fn now(&self) -> Duration {
+        Duration::from_micros(self.shared.now_us() as u64)
+    }
+
+    /// 時刻を delta だけ進める。
+    ///
+    /// 期限に達した遅延タスクを期限の順に実行し、全タスクキューが空になるまで待ってから戻る。
+    /// タスクキュー上のタスクから呼んではならない。
+    pub fn advance_time(&self, delta: Duration) {
+        let target_us = self.shared.now_us() + delta.as_micros() as i64;
+        let mut state = self.shared.state.lock().unwrap();
+        loop {
+            state = self.shared.wait_idle(state);
+            let deadline_us = match state.delayed.peek() {
+                Some(t) if t.deadline_us <= target_us => t.deadline_us,
+                _ => break,
+            };
+            self.shared
+                .now_us
+                .fetch_max(deadline_us, AtomicOrdering::SeqCst);
+            let mut discarded = Vec::new();
+            while state
+                .delayed
+                .peek()
+                .is_some_and(|t| t.deadline_us <= deadline_us)
+            {
+                let delayed = state.delayed.pop().expect("BUG: delayed が空です");
+                match state.queues.get_mut(&delayed.queue_id) {
+                    Some(slot) if !slot.stopped => slot.ready.push_back(delayed.task),
+                    _ => discarded.push(delayed.task),
+                }
+            }
+            self.shared.cond.notify_all();
+            if !discarded.is_empty() {
+                // タスクの破棄で再入しうるため、ロックを外してから破棄する。
+                drop(state);
+                drop(discarded);
+                state = self.shared.state.lock().unwrap();
+            }
+        }
+        self.shared
+            .now_us
+            .fetch_max(target_us, AtomicOrdering::SeqCst);
+    }
+
+    /// この時刻を返す Clock を生成する。
+    pub fn create_clock(&self) -> Clock {
+        Clock::new_with_handler(Box::new(SimulatedClockHandler {
+            shared: self.shared.clone(),
+        }))
+    }
+
+    /// 遅延タスクをこの時刻で実行する TaskQueueFactory を生成する。
+    ///
+    /// キューごとに std::thread を 1 つ起動する。priority は無視する。
+    pub fn create_task_queue_factory(&self) -> TaskQueueFactory {
+        TaskQueueFactory::new_with_handler(Box::new(SimulatedTaskQueueFactory {
+            shared: self.shared.clone(),
+        }))
+    }
+}
+
+struct SimulatedClockHandler {
+    shared: Arc<SimulatedTimeShared>,
+}
+
+impl ClockHandler for SimulatedClockHandler {
+    fn current_time(&self) -> Duration {
+        Duration::from_micros(self.shared.now_us() as u64)
+    }
+}
+
+struct SimulatedTaskQueueFactory {
+    shared: Arc<SimulatedTimeShared>,
+}
+
+impl TaskQueueFactoryHandler for SimulatedTaskQueueFactory {
+    fn create_task_queue(
+        &self,
+        name: &str,
+        _priority: TaskQueuePriority,
+    ) -> Box<dyn TaskQueueHandler> {
+        Box::new(SimulatedTaskQueue::new(self.shared.clone(), name))
+    }
+}
+
+struct SimulatedTaskQueue {
+    shared: Arc<SimulatedTimeShared>,
+    id: u64,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl SimulatedTaskQueue {
+    fn new(shared: Arc<SimulatedTimeShared>, name: &str) -> Self {
+        let id = {
+            let mut state = shared.state.lock().unwrap();
+            let id = state.next_queue_id;
+            state.next_queue_id += 1;
+            state.queues.insert(id, SimulatedQueueSlot::default());
+            id
+        };
+        let thread = std::thread::Builder::new()
+            .name(name.to_owned())
+            .spawn({
+                let shared = shared.clone();
+                move || run_simulated_task_queue(&shared, id)
+            })
+            .expect("タスクキューのスレッドの起動に失敗しました");
+        Self {
+            shared,
+            id,
+            thread: Some(thread),
+        }
+    }
+}
+
+fn run_simulated_task_queue(shared: &SimulatedTimeShared, id: u64) {
+    let mut state = shared.state.lock().unwrap();
+    loop {
+        let slot = state.queues.get_mut(&id).expect("BUG: キューがありません");
+        if slot.stopped {
+            state.queues.remove(&id);
+            shared.cond.notify_all();
+            return;
+        }
+        if let Some(task) = slot.ready.pop_front() {
+            slot.running = true;
+            drop(state);
+            // Safety: このキューのタスクはこのスレッドでのみ 1 つずつ実行し、
+            // drop 後は stopped を見て実行せずに抜ける。
+            unsafe { task.run() };
+            state = shared.state.lock().unwrap();
+            state
+                .queues
+                .get_mut(&id)
+                .expect("BUG: キューがありません")
+                .running = false;
+            shared.cond.notify_all();
+            continue;
+        }
+        state = shared.cond.wait(state).unwrap();
+    }
+}
+
+unsafe impl TaskQueueHandler for SimulatedTaskQueue {
+    fn post_task(&self, task: QueuedTask) {
+        let mut state = self.shared.state.lock().unwrap();
+        let slot = state
+            .queues
+            .get_mut(&self.id)
+            .expect("BUG: キューがありません");
+        if slot.stopped {
+            // タスクの破棄で再入しうるため、ロックを外してから破棄する。
+            drop(state);
+            drop(task);
+            return;
+        }
+        slot.ready.push_back(task);
+        self.shared.cond.notify_all();
+    }
+
+    fn post_delayed_task(&self, task: QueuedTask, delay: Duration, _high_precision: bool) {
+        let mut state = self.shared.state.lock().unwrap();
+        let stopped = state
+            .queues
+            .get(&self.id)
+            .expect("BUG: キューがありません")
+            .stopped;
+        if stopped {
+            drop(state);
+            drop(task);
+            return;
+        }
+        let seq = state.next_seq;
+        state.next_seq += 1;
+        state.delayed.push(SimulatedDelayedTask {
+            deadline_us: self.shared.now_us() + delay.as_micros() as i64,
+            seq,
+            queue_id: self.id,
+            task,
+        });
+    }
+}
+
+impl Drop for SimulatedTaskQueue {
+    fn drop(&mut self) {
+        let (ready, delayed) = {
+            let mut state = self.shared.state.lock().unwrap();
+            let slot = state
+                .queues
+                .get_mut(&self.id)
+                .expect("BUG: キューがありません");
+            slot.stopped = true;
+            let ready = std::mem::take(&mut slot.ready);
+            let (delayed, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut state.delayed)
+                .into_vec()
+                .into_iter()
+                .partition(|t| t.queue_id == self.id);
+            state.delayed = rest.into();
+            (ready, delayed)
+        };
+        self.shared.cond.notify_all();
+        drop(ready);
+        drop(delayed);
+        if let Some(thread) = self.thread.take() {
+            // キュー上のタスクから破棄された場合は自分自身を join できない。
+            if thread.thread().id() != std::thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
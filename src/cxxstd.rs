@@ -167,6 +167,11 @@ impl StringVector {
         }
     }
 
+    /// webrtc 側で生成されたベクタを引き取る。
+    pub fn from_raw(raw: NonNull<ffi::std_string_vector>) -> Self {
+        Self { raw }
+    }
+
     /// 要素数を取得する。
     pub fn len(&self) -> usize {
         self.as_ref().len()
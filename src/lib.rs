@@ -1,4 +1,5 @@
 pub mod ffi;
+pub mod loopback;
 
 /// クレートのバージョンを返す
 pub fn version() -> &'static str {
@@ -26,7 +27,10 @@ pub use libyuv::{
 };
 pub use ref_count::{RefCountedHandle, ScopedRef};
 pub use rtc_base::{
-    SSLCertChainRef, SSLCertificateRef, SSLCertificateVerifier, SSLCertificateVerifierHandler,
-    SSLIdentity, Thread, TimestampAligner, log, random_bytes, random_string, rtc_log_format_file,
-    time_millis,
+    AdapterType, AsyncPacketSocket, AsyncPacketSocketHandler, AsyncPacketSocketNotifier,
+    AsyncPacketSocketState, ECCurve, KeyParams, MdnsResponder, MdnsResponderHandler, Network,
+    NetworkManager, NetworkManagerHandler, RTCCertificate, RTCCertificatePEM, SSLCertChainRef,
+    SSLCertificateRef, SSLCertificateVerifier, SSLCertificateVerifierHandler, SSLFingerprint,
+    SSLFingerprintRef, SSLIdentity, SocketAddress, SocketAddressRef, SocketOption, Thread,
+    TimestampAligner, log, random_bytes, random_string, rtc_log_format_file, time_millis,
 };
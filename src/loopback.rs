@@ -0,0 +1,436 @@
+//! 同一プロセス内の 2 つの PeerConnection を接続するためのヘルパー。
+//!
+//! ネットワークやシグナリングサーバーを使わずにメディアやデータの経路を試験する用途を想定している。
+
+use crate::{
+    CandidatePairChangeEventRef, CandidateRef, DataChannel, Error, IceCandidateError,
+    IceCandidateInit, IceCandidateRef, IceConnectionState, IceGatheringState, MediaStream,
+    PeerConnection, PeerConnectionDependencies, PeerConnectionFactory, PeerConnectionObserver,
+    PeerConnectionObserverHandler, PeerConnectionOfferAnswerOptions,
+    PeerConnectionRtcConfiguration, PeerConnectionState, Result, RtpReceiver, RtpTransceiver,
+    SdpType, SessionDescription, SignalingState,
+};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LoopbackSide {
+    Offerer,
+    Answerer,
+}
+
+/// 相手側へ渡していない candidate を片側につき保持する上限。
+pub const MAX_PENDING_CANDIDATES: usize = 64;
+
+struct LoopbackPeerState {
+    candidates: VecDeque<IceCandidateInit>,
+    // candidate を積めなかった理由。最初の 1 件だけ記録し、取り出すまで保持する。
+    error: Option<String>,
+    connection_state: PeerConnectionState,
+}
+
+impl LoopbackPeerState {
+    fn new() -> Self {
+        Self {
+            candidates: VecDeque::new(),
+            error: None,
+            connection_state: PeerConnectionState::New,
+        }
+    }
+
+    fn record_error(&mut self, message: String) {
+        self.error.get_or_insert(message);
+    }
+}
+
+struct LoopbackSignalingState {
+    offerer: LoopbackPeerState,
+    answerer: LoopbackPeerState,
+}
+
+impl LoopbackSignalingState {
+    fn peer_mut(&mut self, side: LoopbackSide) -> &mut LoopbackPeerState {
+        match side {
+            LoopbackSide::Offerer => &mut self.offerer,
+            LoopbackSide::Answerer => &mut self.answerer,
+        }
+    }
+}
+
+struct LoopbackShared {
+    state: Mutex<LoopbackSignalingState>,
+    cvar: Condvar,
+}
+
+/// candidate と接続状態を記録してから利用者のハンドラへ委譲する。
+struct LoopbackObserverHandler {
+    shared: Arc<LoopbackShared>,
+    side: LoopbackSide,
+    handler: Box<dyn PeerConnectionObserverHandler>,
+}
+
+impl PeerConnectionObserverHandler for LoopbackObserverHandler {
+    fn on_connection_change(&mut self, new_state: PeerConnectionState) {
+        {
+            let mut state = self.shared.state.lock().unwrap();
+            state.peer_mut(self.side).connection_state = new_state;
+        }
+        self.shared.cvar.notify_all();
+        self.handler.on_connection_change(new_state);
+    }
+
+    fn on_standardized_ice_connection_change(&mut self, new_state: IceConnectionState) {
+        self.handler
+            .on_standardized_ice_connection_change(new_state);
+    }
+
+    fn on_ice_gathering_change(&mut self, new_state: IceGatheringState) {
+        self.handler.on_ice_gathering_change(new_state);
+    }
+
+    fn on_track(&mut self, transceiver: RtpTransceiver) {
+        self.handler.on_track(transceiver);
+    }
+
+    fn on_remove_track(&mut self, receiver: RtpReceiver) {
+        self.handler.on_remove_track(receiver);
+    }
+
+    fn on_ice_candidate(&mut self, candidate: IceCandidateRef<'_>) {
+        // signaling スレッドから相手側の PeerConnection を直接操作するとデッドロックしうるため、
+        // キューに積んで Loopback::connect / forward_candidates を呼んだスレッドから渡す。
+        let init = candidate.sdp_mid().and_then(|sdp_mid| {
+            Ok(IceCandidateInit {
+                sdp_mid,
+                sdp_mline_index: candidate.sdp_mline_index(),
+                candidate: candidate.to_string()?,
+            })
+        });
+        {
+            let mut state = self.shared.state.lock().unwrap();
+            let peer = state.peer_mut(self.side);
+            match init {
+                Ok(_) if peer.candidates.len() >= MAX_PENDING_CANDIDATES => {
+                    peer.record_error(format!(
+                        "相手側へ渡していない candidate が上限 ({MAX_PENDING_CANDIDATES}) を超えました"
+                    ));
+                }
+                Ok(init) => peer.candidates.push_back(init),
+                Err(e) => peer.record_error(format!("candidate の変換に失敗しました: {e}")),
+            }
+        }
+        self.shared.cvar.notify_all();
+        self.handler.on_ice_candidate(candidate);
+    }
+
+    fn on_ice_candidate_error(&mut self, error: IceCandidateError) {
+        self.handler.on_ice_candidate_error(error);
+    }
+
+    fn on_data_channel(&mut self, data_channel: DataChannel) {
+        self.handler.on_data_channel(data_channel);
+    }
+
+    fn on_signaling_change(&mut self, new_state: SignalingState) {
+        self.handler.on_signaling_change(new_state);
+    }
+
+    fn on_add_stream(&mut self, stream: MediaStream) {
+        self.handler.on_add_stream(stream);
+    }
+
+    fn on_remove_stream(&mut self, stream: MediaStream) {
+        self.handler.on_remove_stream(stream);
+    }
+
+    fn on_negotiation_needed_event(&mut self, event_id: u32) {
+        self.handler.on_negotiation_needed_event(event_id);
+    }
+
+    fn on_ice_connection_receiving_change(&mut self, receiving: bool) {
+        self.handler.on_ice_connection_receiving_change(receiving);
+    }
+
+    fn on_ice_candidates_removed(&mut self, candidates: &[CandidateRef<'_>]) {
+        self.handler.on_ice_candidates_removed(candidates);
+    }
+
+    fn on_ice_selected_candidate_pair_changed(&mut self, event: CandidatePairChangeEventRef<'_>) {
+        self.handler.on_ice_selected_candidate_pair_changed(event);
+    }
+}
+
+struct NoopObserverHandler;
+
+impl PeerConnectionObserverHandler for NoopObserverHandler {}
+
+struct LoopbackPeer {
+    // PeerConnection を先に破棄するため、フィールドの宣言順を変えないこと。
+    pc: PeerConnection,
+    _deps: PeerConnectionDependencies,
+    _observer: PeerConnectionObserver,
+}
+
+impl LoopbackPeer {
+    fn new(
+        factory: &PeerConnectionFactory,
+        config: &mut PeerConnectionRtcConfiguration,
+        shared: &Arc<LoopbackShared>,
+        side: LoopbackSide,
+        handler: Box<dyn PeerConnectionObserverHandler>,
+    ) -> Result<Self> {
+        let observer =
+            PeerConnectionObserver::new_with_handler(Box::new(LoopbackObserverHandler {
+                shared: shared.clone(),
+                side,
+                handler,
+            }));
+        let mut deps = PeerConnectionDependencies::new(&observer);
+        let pc = PeerConnection::create(factory, config, &mut deps)?;
+        Ok(Self {
+            pc,
+            _deps: deps,
+            _observer: observer,
+        })
+    }
+}
+
+impl Drop for LoopbackPeer {
+    fn drop(&mut self) {
+        self.pc.close();
+    }
+}
+
+/// offer 側と answer 側の 2 つの PeerConnection を同一プロセス内で接続する。
+///
+/// トラックや DataChannel は connect を呼ぶ前に offerer / answerer へ追加しておくこと。
+pub struct Loopback {
+    shared: Arc<LoopbackShared>,
+    offerer: LoopbackPeer,
+    answerer: LoopbackPeer,
+}
+
+impl Loopback {
+    /// 同じファクトリーから 2 つの PeerConnection を生成する。
+    pub fn new(factory: &PeerConnectionFactory) -> Result<Self> {
+        Self::new_with_factories(factory, factory)
+    }
+
+    /// offer 側と answer 側で別々のファクトリーから PeerConnection を生成する。
+    pub fn new_with_factories(
+        offerer_factory: &PeerConnectionFactory,
+        answerer_factory: &PeerConnectionFactory,
+    ) -> Result<Self> {
+        Self::new_with_handlers(
+            offerer_factory,
+            &mut PeerConnectionRtcConfiguration::new(),
+            Box::new(NoopObserverHandler),
+            answerer_factory,
+            &mut PeerConnectionRtcConfiguration::new(),
+            Box::new(NoopObserverHandler),
+        )
+    }
+
+    /// 設定とハンドラを指定して生成する。
+    ///
+    /// ハンドラには candidate や接続状態を含む全てのイベントがそのまま通知される。
+    pub fn new_with_handlers(
+        offerer_factory: &PeerConnectionFactory,
+        offerer_config: &mut PeerConnectionRtcConfiguration,
+        offerer_handler: Box<dyn PeerConnectionObserverHandler>,
+        answerer_factory: &PeerConnectionFactory,
+        answerer_config: &mut PeerConnectionRtcConfiguration,
+        answerer_handler: Box<dyn PeerConnectionObserverHandler>,
+    ) -> Result<Self> {
+        let shared = Arc::new(LoopbackShared {
+            state: Mutex::new(LoopbackSignalingState {
+                offerer: LoopbackPeerState::new(),
+                answerer: LoopbackPeerState::new(),
+            }),
+            cvar: Condvar::new(),
+        });
+        let offerer = LoopbackPeer::new(
+            offerer_factory,
+            offerer_config,
+            &shared,
+            LoopbackSide::Offerer,
+            offerer_handler,
+        )?;
+        let answerer = LoopbackPeer::new(
+            answerer_factory,
+            answerer_config,
+            &shared,
+            LoopbackSide::Answerer,
+            answerer_handler,
+        )?;
+        Ok(Self {
+            shared,
+            offerer,
+            answerer,
+        })
+    }
+
+    pub fn offerer(&self) -> &PeerConnection {
+        &self.offerer.pc
+    }
+
+    pub fn answerer(&self) -> &PeerConnection {
+        &self.answerer.pc
+    }
+
+    /// offer / answer を交換し、双方が PeerConnectionState::Connected になるまで待つ。
+    ///
+    /// candidate はこの関数を呼んだスレッドから相手側へ渡す。
+    /// 接続後に収集された candidate (continual gathering など) は自動では渡されないため、
+    /// forward_candidates を定期的に呼ぶこと。未転送の candidate が片側で
+    /// MAX_PENDING_CANDIDATES を超えると以降の candidate は破棄され、次の呼び出しがエラーになる。
+    /// candidate の変換に失敗した場合もエラーを返す。
+    pub fn connect(&mut self, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        let opts = PeerConnectionOfferAnswerOptions::new();
+
+        let offer = wait(self.offerer.pc.create_offer_async(&opts), deadline)??;
+        wait(self.offerer.pc.set_local_description_async(offer), deadline)??;
+        let remote_offer = copy_local_description(&self.offerer.pc, SdpType::Offer)?;
+        wait(
+            self.answerer.pc.set_remote_description_async(remote_offer),
+            deadline,
+        )??;
+
+        let answer = wait(self.answerer.pc.create_answer_async(&opts), deadline)??;
+        wait(
+            self.answerer.pc.set_local_description_async(answer),
+            deadline,
+        )??;
+        let remote_answer = copy_local_description(&self.answerer.pc, SdpType::Answer)?;
+        wait(
+            self.offerer.pc.set_remote_description_async(remote_answer),
+            deadline,
+        )??;
+
+        // 双方の remote description を設定し終えてから candidate を渡し始める。
+        let mut state = self.shared.state.lock().unwrap();
+        loop {
+            let (to_answerer, to_offerer) = take_candidates(&mut state)?;
+            if !to_answerer.is_empty() || !to_offerer.is_empty() {
+                drop(state);
+                self.add_candidates(&to_answerer, &to_offerer)?;
+                state = self.shared.state.lock().unwrap();
+                continue;
+            }
+
+            let offerer_state = state.offerer.connection_state;
+            let answerer_state = state.answerer.connection_state;
+            if offerer_state == PeerConnectionState::Connected
+                && answerer_state == PeerConnectionState::Connected
+            {
+                return Ok(());
+            }
+            for s in [offerer_state, answerer_state] {
+                if matches!(s, PeerConnectionState::Failed | PeerConnectionState::Closed) {
+                    return Err(Error::Message(format!(
+                        "PeerConnection の接続に失敗しました: {s:?}"
+                    )));
+                }
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Error::Message(
+                    "PeerConnection の接続待ちがタイムアウトしました".to_string(),
+                ));
+            }
+            state = self
+                .shared
+                .cvar
+                .wait_timeout(state, deadline - now)
+                .unwrap()
+                .0;
+        }
+    }
+
+    /// connect の後に収集された candidate を相手側へ渡す。
+    ///
+    /// continual gathering などで接続後にも candidate が収集される場合に定期的に呼ぶ。
+    /// candidate の変換に失敗していた場合や、未転送の candidate が上限を超えて
+    /// 破棄されていた場合はエラーを返す。
+    pub fn forward_candidates(&mut self) -> Result<()> {
+        let (to_answerer, to_offerer) = take_candidates(&mut self.shared.state.lock().unwrap())?;
+        self.add_candidates(&to_answerer, &to_offerer)
+    }
+
+    fn add_candidates(
+        &mut self,
+        to_answerer: &[IceCandidateInit],
+        to_offerer: &[IceCandidateInit],
+    ) -> Result<()> {
+        for init in to_answerer {
+            self.answerer
+                .pc
+                .add_ice_candidate(&init.to_ice_candidate()?)?;
+        }
+        for init in to_offerer {
+            self.offerer
+                .pc
+                .add_ice_candidate(&init.to_ice_candidate()?)?;
+        }
+        Ok(())
+    }
+}
+
+// (answerer へ渡す candidate, offerer へ渡す candidate) を取り出す。
+// on_ice_candidate で記録したエラーがあればそれを返す。
+fn take_candidates(
+    state: &mut LoopbackSignalingState,
+) -> Result<(Vec<IceCandidateInit>, Vec<IceCandidateInit>)> {
+    for peer in [&mut state.offerer, &mut state.answerer] {
+        if let Some(message) = peer.error.take() {
+            return Err(Error::Message(message));
+        }
+    }
+    Ok((
+        state.offerer.candidates.drain(..).collect(),
+        state.answerer.candidates.drain(..).collect(),
+    ))
+}
+
+fn copy_local_description(pc: &PeerConnection, sdp_type: SdpType) -> Result<SessionDescription> {
+    let sdp = pc
+        .local_description()
+        .ok_or_else(|| Error::Message("local description が設定されていません".to_string()))?
+        .to_string()?;
+    SessionDescription::new(sdp_type, &sdp)
+}
+
+fn wait<F: Future>(future: F, deadline: Instant) -> Result<F::Output> {
+    block_on_until(future, deadline).ok_or_else(|| {
+        Error::Message("PeerConnection のネゴシエーションがタイムアウトしました".to_string())
+    })
+}
+
+// 非同期ランタイムに依存せずに Future の完了を待つ。deadline を過ぎた場合は None を返す。
+pub(crate) fn block_on_until<F: Future>(future: F, deadline: Instant) -> Option<F::Output> {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = std::task::Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = std::task::Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return Some(output);
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return None;
+        }
+        std::thread::park_timeout(deadline - now);
+    }
+}
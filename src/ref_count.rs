@@ -472,3 +472,99 @@ impl RefCountedHandle for DataChannelHandle {
         unsafe { ffi::webrtc_DataChannelInterface_Release(raw) };
     }
 }
+
+pub(crate) struct RTCCertificateHandle;
+impl RefCountedHandle for RTCCertificateHandle {
+    type Refcounted = ffi::webrtc_RTCCertificate_refcounted;
+    type Raw = ffi::webrtc_RTCCertificate;
+
+    unsafe fn get(raw_ref: *mut Self::Refcounted) -> *mut Self::Raw {
+        unsafe { ffi::webrtc_RTCCertificate_refcounted_get(raw_ref) }
+    }
+    unsafe fn add_ref(raw: *mut Self::Raw) {
+        unsafe { ffi::webrtc_RTCCertificate_AddRef(raw) };
+    }
+    unsafe fn release(raw: *mut Self::Raw) {
+        unsafe { ffi::webrtc_RTCCertificate_Release(raw) };
+    }
+}
+
+pub(crate) struct FrameTransformerHandle;
+impl RefCountedHandle for FrameTransformerHandle {
+    type Refcounted = ffi::webrtc_FrameTransformerInterface_refcounted;
+    type Raw = ffi::webrtc_FrameTransformerInterface;
+
+    unsafe fn get(raw_ref: *mut Self::Refcounted) -> *mut Self::Raw {
+        unsafe { ffi::webrtc_FrameTransformerInterface_refcounted_get(raw_ref) }
+    }
+    unsafe fn add_ref(raw: *mut Self::Raw) {
+        unsafe { ffi::webrtc_FrameTransformerInterface_AddRef(raw) };
+    }
+    unsafe fn release(raw: *mut Self::Raw) {
+        unsafe { ffi::webrtc_FrameTransformerInterface_Release(raw) };
+    }
+}
+
+pub(crate) struct TransformedFrameCallbackHandle;
+impl RefCountedHandle for TransformedFrameCallbackHandle {
+    type Refcounted = ffi::webrtc_TransformedFrameCallback_refcounted;
+    type Raw = ffi::webrtc_TransformedFrameCallback;
+
+    unsafe fn get(raw_ref: *mut Self::Refcounted) -> *mut Self::Raw {
+        unsafe { ffi::webrtc_TransformedFrameCallback_refcounted_get(raw_ref) }
+    }
+    unsafe fn add_ref(raw: *mut Self::Raw) {
+        unsafe { ffi::webrtc_TransformedFrameCallback_AddRef(raw) };
+    }
+    unsafe fn release(raw: *mut Self::Raw) {
+        unsafe { ffi::webrtc_TransformedFrameCallback_Release(raw) };
+    }
+}
+
+pub(crate) struct FrameEncryptorHandle;
+impl RefCountedHandle for FrameEncryptorHandle {
+    type Refcounted = ffi::webrtc_FrameEncryptorInterface_refcounted;
+    type Raw = ffi::webrtc_FrameEncryptorInterface;
+
+    unsafe fn get(raw_ref: *mut Self::Refcounted) -> *mut Self::Raw {
+        unsafe { ffi::webrtc_FrameEncryptorInterface_refcounted_get(raw_ref) }
+    }
+    unsafe fn add_ref(raw: *mut Self::Raw) {
+        unsafe { ffi::webrtc_FrameEncryptorInterface_AddRef(raw) };
+    }
+    unsafe fn release(raw: *mut Self::Raw) {
+        unsafe { ffi::webrtc_FrameEncryptorInterface_Release(raw) };
+    }
+}
+
+pub(crate) struct FrameDecryptorHandle;
+impl RefCountedHandle for FrameDecryptorHandle {
+    type Refcounted = ffi::webrtc_FrameDecryptorInterface_refcounted;
+    type Raw = ffi::webrtc_FrameDecryptorInterface;
+
+    unsafe fn get(raw_ref: *mut Self::Refcounted) -> *mut Self::Raw {
+        unsafe { ffi::webrtc_FrameDecryptorInterface_refcounted_get(raw_ref) }
+    }
+    unsafe fn add_ref(raw: *mut Self::Raw) {
+        unsafe { ffi::webrtc_FrameDecryptorInterface_AddRef(raw) };
+    }
+    unsafe fn release(raw: *mut Self::Raw) {
+        unsafe { ffi::webrtc_FrameDecryptorInterface_Release(raw) };
+    }
+}
+
+pub(crate) struct DtmfSenderHandle;
+impl RefCountedHandle for DtmfSenderHandle {
+    type Refcounted = ffi::webrtc_DtmfSenderInterface_refcounted;
+    type Raw = ffi::webrtc_DtmfSenderInterface;
+
+    unsafe fn get(raw_ref: *mut Self::Refcounted) -> *mut Self::Raw {
+        unsafe { ffi::webrtc_DtmfSenderInterface_refcounted_get(raw_ref) }
+    }
+    unsafe fn add_ref(raw: *mut Self::Raw) {
+        unsafe { ffi::webrtc_DtmfSenderInterface_AddRef(raw) };
+    }
+    unsafe fn release(raw: *mut Self::Raw) {
+        unsafe { ffi::webrtc_DtmfSenderInterface_Release(raw) };
+    }
+}
@@ -0,0 +1,417 @@
+use crate::{SocketAddress, SocketAddressRef, ffi};
+use std::os::raw::c_void;
+use std::ptr::NonNull;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+/// webrtc::Socket::Option。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SocketOption {
+    DontFragment,
+    RcvBuf,
+    SndBuf,
+    NoDelay,
+    Ipv6V6Only,
+    Dscp,
+    RtpSendtimeExtnId,
+    Unknown(i32),
+}
+
+impl SocketOption {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_Socket_Option_kOptDontFragment {
+                SocketOption::DontFragment
+            } else if value == ffi::webrtc_Socket_Option_kOptRcvBuf {
+                SocketOption::RcvBuf
+            } else if value == ffi::webrtc_Socket_Option_kOptSndBuf {
+                SocketOption::SndBuf
+            } else if value == ffi::webrtc_Socket_Option_kOptNoDelay {
+                SocketOption::NoDelay
+            } else if value == ffi::webrtc_Socket_Option_kOptIpv6V6Only {
+                SocketOption::Ipv6V6Only
+            } else if value == ffi::webrtc_Socket_Option_kOptDscp {
+                SocketOption::Dscp
+            } else if value == ffi::webrtc_Socket_Option_kOptRtpSendtimeExtnId {
+                SocketOption::RtpSendtimeExtnId
+            } else {
+                SocketOption::Unknown(value)
+            }
+        }
+    }
+
+    pub fn to_int(&self) -> i32 {
+        match self {
+            SocketOption::DontFragment => unsafe { ffi::webrtc_Socket_Option_kOptDontFragment },
+            SocketOption::RcvBuf => unsafe { ffi::webrtc_Socket_Option_kOptRcvBuf },
+            SocketOption::SndBuf => unsafe { ffi::webrtc_Socket_Option_kOptSndBuf },
+            SocketOption::NoDelay => unsafe { ffi::webrtc_Socket_Option_kOptNoDelay },
+            SocketOption::Ipv6V6Only => unsafe { ffi::webrtc_Socket_Option_kOptIpv6V6Only },
+            SocketOption::Dscp => unsafe { ffi::webrtc_Socket_Option_kOptDscp },
+            SocketOption::RtpSendtimeExtnId => unsafe {
+                ffi::webrtc_Socket_Option_kOptRtpSendtimeExtnId
+            },
+            SocketOption::Unknown(v) => *v,
+        }
+    }
+}
+
+/// AsyncPacketSocket の状態。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AsyncPacketSocketState {
+    Closed,
+    Binding,
+    Bound,
+    Connecting,
+    Connected,
+    Unknown(i32),
+}
+
+impl AsyncPacketSocketState {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_AsyncPacketSocket_State_kClosed {
+                AsyncPacketSocketState::Closed
+            } else if value == ffi::webrtc_AsyncPacketSocket_State_kBinding {
+                AsyncPacketSocketState::Binding
+            } else if value == ffi::webrtc_AsyncPacketSocket_State_kBound {
+                AsyncPacketSocketState::Bound
+            } else if value == ffi::webrtc_AsyncPacketSocket_State_kConnecting {
+                AsyncPacketSocketState::Connecting
+            } else if value == ffi::webrtc_AsyncPacketSocket_State_kConnected {
+                AsyncPacketSocketState::Connected
+            } else {
+                AsyncPacketSocketState::Unknown(value)
+            }
+        }
+    }
+
+    pub fn to_int(&self) -> i32 {
+        match self {
+            AsyncPacketSocketState::Closed => unsafe {
+                ffi::webrtc_AsyncPacketSocket_State_kClosed
+            },
+            AsyncPacketSocketState::Binding => unsafe {
+                ffi::webrtc_AsyncPacketSocket_State_kBinding
+            },
+            AsyncPacketSocketState::Bound => unsafe { ffi::webrtc_AsyncPacketSocket_State_kBound },
+            AsyncPacketSocketState::Connecting => unsafe {
+                ffi::webrtc_AsyncPacketSocket_State_kConnecting
+            },
+            AsyncPacketSocketState::Connected => unsafe {
+                ffi::webrtc_AsyncPacketSocket_State_kConnected
+            },
+            AsyncPacketSocketState::Unknown(v) => *v,
+        }
+    }
+}
+
+/// パケットソケットの実装。
+///
+/// コールバックは network thread から呼ばれる。
+/// 戻り値が i32 のものは webrtc::AsyncPacketSocket と同様に、失敗時に負の値を返す。
+pub trait AsyncPacketSocketHandler: Send {
+    /// ソケットがバインドされているローカルアドレスを返す。
+    fn local_address(&mut self) -> SocketAddress;
+
+    /// 接続先のアドレスを返す。UDP ソケットなど未接続の場合は None を返す。
+    fn remote_address(&mut self) -> Option<SocketAddress> {
+        None
+    }
+
+    /// 接続先へ送信し、送信したバイト数を返す。
+    #[expect(unused_variables)]
+    fn send(&mut self, data: &[u8], packet_id: i64) -> i32 {
+        -1
+    }
+
+    /// address へ送信し、送信したバイト数を返す。
+    fn send_to(&mut self, data: &[u8], address: SocketAddressRef<'_>, packet_id: i64) -> i32;
+
+    fn close(&mut self) -> i32 {
+        0
+    }
+
+    fn state(&mut self) -> AsyncPacketSocketState {
+        AsyncPacketSocketState::Bound
+    }
+
+    /// 値を取得できない場合は None を返す。
+    #[expect(unused_variables)]
+    fn get_option(&mut self, option: SocketOption) -> Option<i32> {
+        None
+    }
+
+    #[expect(unused_variables)]
+    fn set_option(&mut self, option: SocketOption, value: i32) -> i32 {
+        -1
+    }
+
+    fn error(&mut self) -> i32 {
+        0
+    }
+
+    #[expect(unused_variables)]
+    fn set_error(&mut self, error: i32) {}
+}
+
+struct AsyncPacketSocketHandlerState {
+    handler: Box<dyn AsyncPacketSocketHandler>,
+    socket: Arc<AtomicPtr<ffi::webrtc_AsyncPacketSocket>>,
+}
+
+unsafe impl Send for AsyncPacketSocketHandlerState {}
+
+fn async_packet_socket_state<'a>(
+    user_data: *mut c_void,
+    name: &str,
+) -> &'a mut AsyncPacketSocketHandlerState {
+    assert!(!user_data.is_null(), "{name}: user_data is null");
+    unsafe { &mut *(user_data as *mut AsyncPacketSocketHandlerState) }
+}
+
+fn async_packet_socket_data<'a>(data: *const u8, size: usize) -> &'a [u8] {
+    // 長さ 0 の場合は null が渡されることがあるため空スライスとして扱う。
+    if size == 0 {
+        &[]
+    } else {
+        assert!(!data.is_null());
+        unsafe { std::slice::from_raw_parts(data, size) }
+    }
+}
+
+unsafe extern "C" fn async_packet_socket_get_local_address(
+    user_data: *mut c_void,
+) -> *mut ffi::webrtc_SocketAddress {
+    let state = async_packet_socket_state(user_data, "async_packet_socket_get_local_address");
+    state.handler.local_address().into_raw()
+}
+
+unsafe extern "C" fn async_packet_socket_get_remote_address(
+    user_data: *mut c_void,
+) -> *mut ffi::webrtc_SocketAddress {
+    let state = async_packet_socket_state(user_data, "async_packet_socket_get_remote_address");
+    match state.handler.remote_address() {
+        Some(address) => address.into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn async_packet_socket_send(
+    data: *const u8,
+    size: usize,
+    packet_id: i64,
+    user_data: *mut c_void,
+) -> i32 {
+    let state = async_packet_socket_state(user_data, "async_packet_socket_send");
+    state
+        .handler
+        .send(async_packet_socket_data(data, size), packet_id)
+}
+
+unsafe extern "C" fn async_packet_socket_send_to(
+    data: *const u8,
+    size: usize,
+    address: *const ffi::webrtc_SocketAddress,
+    packet_id: i64,
+    user_data: *mut c_void,
+) -> i32 {
+    let state = async_packet_socket_state(user_data, "async_packet_socket_send_to");
+    let address =
+        NonNull::new(address as *mut ffi::webrtc_SocketAddress).expect("BUG: address が null です");
+    state.handler.send_to(
+        async_packet_socket_data(data, size),
+        SocketAddressRef::from_raw(address),
+        packet_id,
+    )
+}
+
+unsafe extern "C" fn async_packet_socket_close(user_data: *mut c_void) -> i32 {
+    let state = async_packet_socket_state(user_data, "async_packet_socket_close");
+    state.handler.close()
+}
+
+unsafe extern "C" fn async_packet_socket_get_state(user_data: *mut c_void) -> i32 {
+    let state = async_packet_socket_state(user_data, "async_packet_socket_get_state");
+    state.handler.state().to_int()
+}
+
+unsafe extern "C" fn async_packet_socket_get_option(
+    opt: i32,
+    value: *mut i32,
+    user_data: *mut c_void,
+) -> i32 {
+    let state = async_packet_socket_state(user_data, "async_packet_socket_get_option");
+    assert!(!value.is_null());
+    match state.handler.get_option(SocketOption::from_int(opt)) {
+        Some(v) => {
+            unsafe { *value = v };
+            0
+        }
+        None => -1,
+    }
+}
+
+unsafe extern "C" fn async_packet_socket_set_option(
+    opt: i32,
+    value: i32,
+    user_data: *mut c_void,
+) -> i32 {
+    let state = async_packet_socket_state(user_data, "async_packet_socket_set_option");
+    state.handler.set_option(SocketOption::from_int(opt), value)
+}
+
+unsafe extern "C" fn async_packet_socket_get_error(user_data: *mut c_void) -> i32 {
+    let state = async_packet_socket_state(user_data, "async_packet_socket_get_error");
+    state.handler.error()
+}
+
+unsafe extern "C" fn async_packet_socket_set_error(error: i32, user_data: *mut c_void) {
+    let state = async_packet_socket_state(user_data, "async_packet_socket_set_error");
+    state.handler.set_error(error);
+}
+
+unsafe extern "C" fn async_packet_socket_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "async_packet_socket_on_destroy: user_data is null"
+    );
+    let state = unsafe { Box::from_raw(user_data as *mut AsyncPacketSocketHandlerState) };
+    state.socket.store(std::ptr::null_mut(), Ordering::SeqCst);
+}
+
+/// Rust で実装する webrtc::AsyncPacketSocket。
+///
+/// PacketSocketFactoryHandler から返す。受信などの通知は notifier() で取得した
+/// AsyncPacketSocketNotifier から行う。
+pub struct AsyncPacketSocket {
+    raw_unique: NonNull<ffi::webrtc_AsyncPacketSocket_unique>,
+    notifier: AsyncPacketSocketNotifier,
+}
+
+unsafe impl Send for AsyncPacketSocket {}
+
+impl AsyncPacketSocket {
+    pub fn new_with_handler(handler: Box<dyn AsyncPacketSocketHandler>) -> Self {
+        let socket = Arc::new(AtomicPtr::new(std::ptr::null_mut()));
+        let state = Box::new(AsyncPacketSocketHandlerState {
+            handler,
+            socket: socket.clone(),
+        });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_AsyncPacketSocket_cbs {
+            GetLocalAddress: Some(async_packet_socket_get_local_address),
+            GetRemoteAddress: Some(async_packet_socket_get_remote_address),
+            Send: Some(async_packet_socket_send),
+            SendTo: Some(async_packet_socket_send_to),
+            Close: Some(async_packet_socket_close),
+            GetState: Some(async_packet_socket_get_state),
+            GetOption: Some(async_packet_socket_get_option),
+            SetOption: Some(async_packet_socket_set_option),
+            GetError: Some(async_packet_socket_get_error),
+            SetError: Some(async_packet_socket_set_error),
+            OnDestroy: Some(async_packet_socket_on_destroy),
+        };
+        let raw = unsafe { ffi::webrtc_AsyncPacketSocket_new(&cbs, user_data) };
+        let raw_unique = match NonNull::new(raw) {
+            Some(raw_unique) => raw_unique,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut AsyncPacketSocketHandlerState) };
+                panic!("BUG: webrtc_AsyncPacketSocket_new が null を返しました");
+            }
+        };
+        socket.store(
+            unsafe { ffi::webrtc_AsyncPacketSocket_unique_get(raw_unique.as_ptr()) },
+            Ordering::SeqCst,
+        );
+        Self {
+            raw_unique,
+            notifier: AsyncPacketSocketNotifier { socket },
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_AsyncPacketSocket {
+        unsafe { ffi::webrtc_AsyncPacketSocket_unique_get(self.raw_unique.as_ptr()) }
+    }
+
+    pub fn into_raw(self) -> *mut ffi::webrtc_AsyncPacketSocket_unique {
+        std::mem::ManuallyDrop::new(self).raw_unique.as_ptr()
+    }
+
+    /// ソケットの所有権を手放した後も通知できるよう、通知用のハンドルを返す。
+    pub fn notifier(&self) -> AsyncPacketSocketNotifier {
+        self.notifier.clone()
+    }
+}
+
+impl Drop for AsyncPacketSocket {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_AsyncPacketSocket_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
+
+/// AsyncPacketSocket へ受信やソケット状態の変化を通知するハンドル。
+///
+/// ソケットが既に破棄されている場合は何もせず false を返す。
+/// ソケットは network thread で破棄されるため、各メソッドは network thread からのみ
+/// 呼び出せる。
+#[derive(Clone)]
+pub struct AsyncPacketSocketNotifier {
+    socket: Arc<AtomicPtr<ffi::webrtc_AsyncPacketSocket>>,
+}
+
+impl AsyncPacketSocketNotifier {
+    /// arrival_time_us は受信時刻 (マイクロ秒)。不明な場合は None を渡す。
+    ///
+    /// # Safety
+    /// ソケットを生成した PacketSocketFactory の network thread から呼び出すこと。
+    /// AsyncPacketSocketHandler のコールバック内から同期的に呼び出してはならない。
+    pub unsafe fn notify_packet_received(
+        &self,
+        data: &[u8],
+        source_address: SocketAddressRef<'_>,
+        arrival_time_us: Option<i64>,
+    ) -> bool {
+        let socket = self.socket.load(Ordering::SeqCst);
+        if socket.is_null() {
+            return false;
+        }
+        let (has, value) = match arrival_time_us {
+            Some(v) => (1, v),
+            None => (0, 0),
+        };
+        unsafe {
+            ffi::webrtc_AsyncPacketSocket_NotifyPacketReceived(
+                socket,
+                data.as_ptr(),
+                data.len(),
+                source_address.as_ptr(),
+                has,
+                &value,
+            )
+        };
+        true
+    }
+
+    /// # Safety
+    /// notify_packet_received と同じ。
+    pub unsafe fn notify_ready_to_send(&self) -> bool {
+        let socket = self.socket.load(Ordering::SeqCst);
+        if socket.is_null() {
+            return false;
+        }
+        unsafe { ffi::webrtc_AsyncPacketSocket_NotifyReadyToSend(socket) };
+        true
+    }
+
+    /// # Safety
+    /// notify_packet_received と同じ。
+    pub unsafe fn notify_closed(&self, error: i32) -> bool {
+        let socket = self.socket.load(Ordering::SeqCst);
+        if socket.is_null() {
+            return false;
+        }
+        unsafe { ffi::webrtc_AsyncPacketSocket_NotifyClosed(socket, error) };
+        true
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{CxxString, ffi};
+use std::os::raw::{c_char, c_void};
+use std::ptr::NonNull;
+
+/// ホスト candidate の IP アドレスを隠蔽する mDNS 名を払い出す。
+///
+/// コールバックは network thread から呼ばれる。address は IP アドレス文字列。
+pub trait MdnsResponderHandler: Send {
+    /// address に割り当てる ".local" で終わる名前を返す。
+    fn create_name_for_address(&mut self, address: &str) -> String;
+
+    /// 名前を削除できた場合は true を返す。
+    fn remove_name_for_address(&mut self, address: &str) -> bool;
+}
+
+struct MdnsResponderHandlerState {
+    handler: Box<dyn MdnsResponderHandler>,
+}
+
+unsafe impl Send for MdnsResponderHandlerState {}
+
+fn mdns_responder_address<'a>(address: *const c_char, address_len: usize) -> &'a str {
+    assert!(!address.is_null());
+    let bytes = unsafe { std::slice::from_raw_parts(address.cast::<u8>(), address_len) };
+    std::str::from_utf8(bytes).expect("BUG: address が UTF-8 ではありません")
+}
+
+unsafe extern "C" fn mdns_responder_create_name_for_address(
+    address: *const c_char,
+    address_len: usize,
+    user_data: *mut c_void,
+) -> *mut ffi::std_string_unique {
+    assert!(
+        !user_data.is_null(),
+        "mdns_responder_create_name_for_address: user_data is null"
+    );
+    let state = unsafe { &mut *(user_data as *mut MdnsResponderHandlerState) };
+    let name = state
+        .handler
+        .create_name_for_address(mdns_responder_address(address, address_len));
+    CxxString::from_str(&name).into_raw()
+}
+
+unsafe extern "C" fn mdns_responder_remove_name_for_address(
+    address: *const c_char,
+    address_len: usize,
+    user_data: *mut c_void,
+) -> i32 {
+    assert!(
+        !user_data.is_null(),
+        "mdns_responder_remove_name_for_address: user_data is null"
+    );
+    let state = unsafe { &mut *(user_data as *mut MdnsResponderHandlerState) };
+    if state
+        .handler
+        .remove_name_for_address(mdns_responder_address(address, address_len))
+    {
+        1
+    } else {
+        0
+    }
+}
+
+unsafe extern "C" fn mdns_responder_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "mdns_responder_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut MdnsResponderHandlerState) };
+}
+
+/// Rust で実装する webrtc::MdnsResponderInterface。
+///
+/// NetworkManager::set_mdns_responder で設定すると、ホスト candidate の
+/// IP アドレスが mDNS 名で隠蔽される。
+pub struct MdnsResponder {
+    raw_unique: NonNull<ffi::webrtc_MdnsResponderInterface_unique>,
+}
+
+unsafe impl Send for MdnsResponder {}
+
+impl MdnsResponder {
+    pub fn new_with_handler(handler: Box<dyn MdnsResponderHandler>) -> Self {
+        let state = Box::new(MdnsResponderHandlerState { handler });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_MdnsResponderInterface_cbs {
+            CreateNameForAddress: Some(mdns_responder_create_name_for_address),
+            RemoveNameForAddress: Some(mdns_responder_remove_name_for_address),
+            OnDestroy: Some(mdns_responder_on_destroy),
+        };
+        let raw = unsafe { ffi::webrtc_MdnsResponderInterface_new(&cbs, user_data) };
+        let raw_unique = match NonNull::new(raw) {
+            Some(raw_unique) => raw_unique,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut MdnsResponderHandlerState) };
+                panic!("BUG: webrtc_MdnsResponderInterface_new が null を返しました");
+            }
+        };
+        Self { raw_unique }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_MdnsResponderInterface {
+        unsafe { ffi::webrtc_MdnsResponderInterface_unique_get(self.raw_unique.as_ptr()) }
+    }
+
+    pub fn into_raw(self) -> *mut ffi::webrtc_MdnsResponderInterface_unique {
+        std::mem::ManuallyDrop::new(self).raw_unique.as_ptr()
+    }
+}
+
+impl Drop for MdnsResponder {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_MdnsResponderInterface_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
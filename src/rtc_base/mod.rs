@@ -1,14 +1,26 @@
+mod async_packet_socket;
 mod crypto_random;
 mod logging;
+mod mdns_responder;
+mod network;
+mod rtc_certificate;
+mod socket_address;
 mod ssl_certificate;
+mod ssl_fingerprint;
 mod ssl_identity;
 mod thread;
 mod time_utils;
 mod timestamp_aligner;
 
+pub use async_packet_socket::*;
 pub use crypto_random::*;
 pub use logging::*;
+pub use mdns_responder::*;
+pub use network::*;
+pub use rtc_certificate::*;
+pub use socket_address::*;
 pub use ssl_certificate::*;
+pub use ssl_fingerprint::*;
 pub use ssl_identity::*;
 pub use thread::*;
 pub use time_utils::*;
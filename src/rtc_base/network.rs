@@ -0,0 +1,267 @@
+use crate::{Environment, Error, MdnsResponder, Result, Thread, ffi};
+use std::os::raw::{c_char, c_void};
+use std::ptr::NonNull;
+
+/// ネットワークアダプタの種類。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdapterType {
+    Ethernet,
+    Wifi,
+    Cellular,
+    Vpn,
+    Loopback,
+    Any,
+    Unknown(i32),
+}
+
+impl AdapterType {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_AdapterType_kEthernet {
+                AdapterType::Ethernet
+            } else if value == ffi::webrtc_AdapterType_kWifi {
+                AdapterType::Wifi
+            } else if value == ffi::webrtc_AdapterType_kCellular {
+                AdapterType::Cellular
+            } else if value == ffi::webrtc_AdapterType_kVpn {
+                AdapterType::Vpn
+            } else if value == ffi::webrtc_AdapterType_kLoopback {
+                AdapterType::Loopback
+            } else if value == ffi::webrtc_AdapterType_kAny {
+                AdapterType::Any
+            } else {
+                AdapterType::Unknown(value)
+            }
+        }
+    }
+
+    pub fn to_int(&self) -> i32 {
+        match self {
+            AdapterType::Ethernet => unsafe { ffi::webrtc_AdapterType_kEthernet },
+            AdapterType::Wifi => unsafe { ffi::webrtc_AdapterType_kWifi },
+            AdapterType::Cellular => unsafe { ffi::webrtc_AdapterType_kCellular },
+            AdapterType::Vpn => unsafe { ffi::webrtc_AdapterType_kVpn },
+            AdapterType::Loopback => unsafe { ffi::webrtc_AdapterType_kLoopback },
+            AdapterType::Any => unsafe { ffi::webrtc_AdapterType_kAny },
+            AdapterType::Unknown(v) => *v,
+        }
+    }
+}
+
+/// webrtc::Network のラッパー。
+///
+/// NetworkManagerHandler::networks から返す。
+pub struct Network {
+    raw_unique: NonNull<ffi::webrtc_Network_unique>,
+}
+
+unsafe impl Send for Network {}
+
+impl Network {
+    /// prefix は "192.168.0.0" のような IP 文字列で指定する。
+    pub fn new(
+        name: &str,
+        description: &str,
+        prefix: &str,
+        prefix_length: i32,
+        adapter_type: AdapterType,
+    ) -> Result<Self> {
+        let raw = unsafe {
+            ffi::webrtc_Network_new(
+                name.as_ptr() as *const c_char,
+                name.len(),
+                description.as_ptr() as *const c_char,
+                description.len(),
+                prefix.as_ptr() as *const c_char,
+                prefix.len(),
+                prefix_length,
+                adapter_type.to_int(),
+            )
+        };
+        let raw_unique = NonNull::new(raw)
+            .ok_or_else(|| Error::Message(format!("不正な prefix です: {prefix}")))?;
+        Ok(Self { raw_unique })
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_Network {
+        unsafe { ffi::webrtc_Network_unique_get(self.raw_unique.as_ptr()) }
+    }
+
+    pub fn into_raw(self) -> *mut ffi::webrtc_Network_unique {
+        std::mem::ManuallyDrop::new(self).raw_unique.as_ptr()
+    }
+
+    /// このネットワークに割り当てられた IP アドレスを追加する。
+    pub fn add_ip(&mut self, ip: &str) -> Result<()> {
+        let ok = unsafe {
+            ffi::webrtc_Network_AddIP(self.as_ptr(), ip.as_ptr() as *const c_char, ip.len())
+        };
+        if ok == 0 {
+            return Err(Error::Message(format!("不正な IP アドレスです: {ip}")));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Network {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_Network_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
+
+/// ICE で利用するネットワーク一覧を提供する。
+///
+/// コールバックは network thread から呼ばれる。
+pub trait NetworkManagerHandler: Send {
+    /// 更新開始時に呼ばれ、利用するネットワーク一覧を返す。
+    fn networks(&mut self) -> Vec<Network>;
+
+    fn stop_updating(&mut self) {}
+}
+
+struct NetworkManagerHandlerState {
+    handler: Box<dyn NetworkManagerHandler>,
+}
+
+unsafe impl Send for NetworkManagerHandlerState {}
+
+unsafe extern "C" fn network_manager_start_updating(
+    manager: *mut ffi::webrtc_NetworkManager,
+    user_data: *mut c_void,
+) {
+    assert!(
+        !user_data.is_null(),
+        "network_manager_start_updating: user_data is null"
+    );
+    assert!(!manager.is_null());
+    let state = unsafe { &mut *(user_data as *mut NetworkManagerHandlerState) };
+    let mut networks = state
+        .handler
+        .networks()
+        .into_iter()
+        .map(Network::into_raw)
+        .collect::<Vec<_>>();
+    unsafe {
+        ffi::webrtc_NetworkManager_UpdateNetworks(manager, networks.as_mut_ptr(), networks.len())
+    };
+}
+
+unsafe extern "C" fn network_manager_stop_updating(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "network_manager_stop_updating: user_data is null"
+    );
+    let state = unsafe { &mut *(user_data as *mut NetworkManagerHandlerState) };
+    state.handler.stop_updating();
+}
+
+unsafe extern "C" fn network_manager_on_destroy(user_data: *mut c_void) {
+    assert!(
+        !user_data.is_null(),
+        "network_manager_on_destroy: user_data is null"
+    );
+    let _ = unsafe { Box::from_raw(user_data as *mut NetworkManagerHandlerState) };
+}
+
+/// webrtc::NetworkManager のラッパー。
+///
+/// new_with_handler で Rust 実装を、new_basic で libwebrtc 既定の
+/// webrtc::BasicNetworkManager を生成する。
+/// PeerConnectionDependencies::set_port_allocator などで設定する。
+pub struct NetworkManager {
+    raw_unique: NonNull<ffi::webrtc_NetworkManager_unique>,
+    has_handler: bool,
+}
+
+unsafe impl Send for NetworkManager {}
+
+impl NetworkManager {
+    pub fn new_with_handler(handler: Box<dyn NetworkManagerHandler>) -> Self {
+        let state = Box::new(NetworkManagerHandlerState { handler });
+        let user_data = Box::into_raw(state) as *mut c_void;
+        let cbs = ffi::webrtc_NetworkManager_cbs {
+            StartUpdating: Some(network_manager_start_updating),
+            StopUpdating: Some(network_manager_stop_updating),
+            OnDestroy: Some(network_manager_on_destroy),
+        };
+        let raw = unsafe { ffi::webrtc_NetworkManager_new(&cbs, user_data) };
+        let raw_unique = match NonNull::new(raw) {
+            Some(raw_unique) => raw_unique,
+            None => {
+                let _ = unsafe { Box::from_raw(user_data as *mut NetworkManagerHandlerState) };
+                panic!("BUG: webrtc_NetworkManager_new が null を返しました");
+            }
+        };
+        Self {
+            raw_unique,
+            has_handler: true,
+        }
+    }
+
+    /// ConnectionContext が既定で使うものと同じ webrtc::BasicNetworkManager を生成する。
+    ///
+    /// mdns_responder を渡すとホスト candidate の IP アドレスを mDNS 名で隠蔽する。
+    /// None の場合は隠蔽しない。
+    /// PeerConnectionFactoryDependencies::set_network_manager で既定の NetworkManager を
+    /// 置き換えるのに使う。
+    ///
+    /// # Safety
+    ///
+    /// network_thread の SocketServer を参照するため、network_thread は生成した
+    /// NetworkManager (およびそれを所有する PeerConnectionFactory) より長く生存させること。
+    pub unsafe fn new_basic(
+        env: &Environment,
+        network_thread: &Thread,
+        mdns_responder: Option<MdnsResponder>,
+    ) -> Self {
+        let mdns_responder = match mdns_responder {
+            Some(r) => r.into_raw(),
+            None => std::ptr::null_mut(),
+        };
+        let raw = unsafe {
+            ffi::webrtc_BasicNetworkManager_new(
+                env.as_ptr(),
+                network_thread.raw().as_ptr(),
+                mdns_responder,
+            )
+        };
+        let raw_unique =
+            NonNull::new(raw).expect("BUG: webrtc_BasicNetworkManager_new が null を返しました");
+        Self {
+            raw_unique,
+            has_handler: false,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_NetworkManager {
+        unsafe { ffi::webrtc_NetworkManager_unique_get(self.raw_unique.as_ptr()) }
+    }
+
+    pub fn into_raw(self) -> *mut ffi::webrtc_NetworkManager_unique {
+        std::mem::ManuallyDrop::new(self).raw_unique.as_ptr()
+    }
+
+    /// 設定するとホスト candidate の IP アドレスを mDNS 名で隠蔽する。
+    /// 設定しない場合は隠蔽しない。
+    ///
+    /// new_with_handler で生成したものにのみ設定できる。
+    /// new_basic の場合は生成時に渡すこと。new_basic で生成したものに設定するとエラーを返す。
+    pub fn set_mdns_responder(&mut self, mdns_responder: MdnsResponder) -> Result<()> {
+        if !self.has_handler {
+            return Err(Error::Message(
+                "new_basic で生成した NetworkManager には set_mdns_responder を使えません"
+                    .to_string(),
+            ));
+        }
+        unsafe {
+            ffi::webrtc_NetworkManager_set_mdns_responder(self.as_ptr(), mdns_responder.into_raw())
+        };
+        Ok(())
+    }
+}
+
+impl Drop for NetworkManager {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_NetworkManager_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
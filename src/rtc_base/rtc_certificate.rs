@@ -0,0 +1,153 @@
+use crate::ref_count::{RTCCertificateHandle, ScopedRef};
+use crate::{Error, KeyParams, Result, SSLCertificateRef, SSLFingerprint, SSLIdentity, ffi};
+use std::ptr::NonNull;
+
+/// webrtc::RTCCertificatePEM 相当の PEM 文字列の組。
+///
+/// RTCCertificate::to_pem で書き出し、RTCCertificate::from_pem で復元する。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RTCCertificatePEM {
+    pub private_key: String,
+    pub certificate: String,
+}
+
+/// webrtc::RTCCertificate のラッパー。
+///
+/// PeerConnectionRtcConfiguration の certificates に設定して DTLS 証明書として使用する。
+pub struct RTCCertificate {
+    raw_ref: ScopedRef<RTCCertificateHandle>,
+}
+
+unsafe impl Send for RTCCertificate {}
+
+impl RTCCertificate {
+    /// SSLIdentity から RTCCertificate を生成する。
+    pub fn from_identity(identity: SSLIdentity) -> Self {
+        let raw = unsafe { ffi::webrtc_RTCCertificate_Create(identity.into_raw()) };
+        let raw_ref = ScopedRef::<RTCCertificateHandle>::from_raw(
+            NonNull::new(raw).expect("BUG: webrtc_RTCCertificate_Create が null を返しました"),
+        );
+        Self { raw_ref }
+    }
+
+    /// 指定した鍵パラメータで自己署名証明書を生成する。
+    ///
+    /// `expires_ms` は生成時点からの有効期間 (ミリ秒)。None の場合は libwebrtc の既定値を使う。
+    pub fn generate(key_params: &KeyParams, expires_ms: Option<u64>) -> Result<Self> {
+        let raw = unsafe {
+            match expires_ms {
+                Some(v) => ffi::webrtc_RTCCertificateGenerator_GenerateCertificate(
+                    key_params.as_ptr(),
+                    1,
+                    &v,
+                ),
+                None => ffi::webrtc_RTCCertificateGenerator_GenerateCertificate(
+                    key_params.as_ptr(),
+                    0,
+                    std::ptr::null(),
+                ),
+            }
+        };
+        let raw = NonNull::new(raw).ok_or(Error::NullPointer(
+            "webrtc_RTCCertificateGenerator_GenerateCertificate が null を返しました",
+        ))?;
+        Ok(Self {
+            raw_ref: ScopedRef::<RTCCertificateHandle>::from_raw(raw),
+        })
+    }
+
+    /// to_pem で書き出した PEM から RTCCertificate を復元する。
+    pub fn from_pem(pem: &RTCCertificatePEM) -> Option<Self> {
+        let raw_pem = NonNull::new(unsafe {
+            ffi::webrtc_RTCCertificatePEM_new(
+                pem.private_key.as_ptr() as *const _,
+                pem.private_key.len(),
+                pem.certificate.as_ptr() as *const _,
+                pem.certificate.len(),
+            )
+        })
+        .expect("BUG: webrtc_RTCCertificatePEM_new が null を返しました");
+        let raw = unsafe { ffi::webrtc_RTCCertificate_FromPEM(raw_pem.as_ptr()) };
+        unsafe { ffi::webrtc_RTCCertificatePEM_delete(raw_pem.as_ptr()) };
+        NonNull::new(raw).map(|raw| Self {
+            raw_ref: ScopedRef::<RTCCertificateHandle>::from_raw(raw),
+        })
+    }
+
+    /// 秘密鍵と証明書を PEM 形式で書き出す。
+    pub fn to_pem(&self) -> Result<RTCCertificatePEM> {
+        let raw_pem = NonNull::new(unsafe { ffi::webrtc_RTCCertificate_ToPEM(self.as_ptr()) })
+            .expect("BUG: webrtc_RTCCertificate_ToPEM が null を返しました");
+        let mut key_ptr = std::ptr::null();
+        let mut key_len = 0usize;
+        let mut cert_ptr = std::ptr::null();
+        let mut cert_len = 0usize;
+        unsafe {
+            ffi::webrtc_RTCCertificatePEM_private_key(raw_pem.as_ptr(), &mut key_ptr, &mut key_len);
+            ffi::webrtc_RTCCertificatePEM_certificate(
+                raw_pem.as_ptr(),
+                &mut cert_ptr,
+                &mut cert_len,
+            );
+        }
+        assert!(!key_ptr.is_null());
+        assert!(!cert_ptr.is_null());
+        let key = unsafe { std::slice::from_raw_parts(key_ptr.cast::<u8>(), key_len) };
+        let cert = unsafe { std::slice::from_raw_parts(cert_ptr.cast::<u8>(), cert_len) };
+        let private_key = std::str::from_utf8(key).map(str::to_owned);
+        let certificate = std::str::from_utf8(cert).map(str::to_owned);
+        unsafe { ffi::webrtc_RTCCertificatePEM_delete(raw_pem.as_ptr()) };
+        Ok(RTCCertificatePEM {
+            private_key: private_key?,
+            certificate: certificate?,
+        })
+    }
+
+    /// 証明書の有効期限 (UNIX エポックからのミリ秒)。
+    pub fn expires(&self) -> u64 {
+        unsafe { ffi::webrtc_RTCCertificate_Expires(self.as_ptr()) }
+    }
+
+    /// `now` (UNIX エポックからのミリ秒) の時点で有効期限が切れているかどうか。
+    pub fn has_expired(&self, now: u64) -> bool {
+        unsafe { ffi::webrtc_RTCCertificate_HasExpired(self.as_ptr(), now) != 0 }
+    }
+
+    /// 証明書への参照を取得する。寿命は self に束縛される。
+    pub fn ssl_certificate(&self) -> SSLCertificateRef<'_> {
+        let raw = unsafe { ffi::webrtc_RTCCertificate_GetSSLCertificate(self.as_ptr()) };
+        SSLCertificateRef::from_raw(
+            NonNull::new(raw as *mut ffi::webrtc_SSLCertificate)
+                .expect("BUG: webrtc_RTCCertificate_GetSSLCertificate が null を返しました"),
+        )
+    }
+
+    /// SDP の a=fingerprint に載る SHA-256 fingerprint ("AB:CD:..." 形式) を返す。
+    pub fn sha256_fingerprint(&self) -> Result<String> {
+        SSLFingerprint::create("sha-256", &self.ssl_certificate())
+            .ok_or(Error::NullPointer(
+                "webrtc_SSLFingerprint_Create が null を返しました",
+            ))?
+            .rfc4572_fingerprint()
+    }
+
+    pub(crate) fn from_scoped_ref(raw_ref: ScopedRef<RTCCertificateHandle>) -> Self {
+        Self { raw_ref }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_RTCCertificate {
+        self.raw_ref.as_ptr()
+    }
+
+    pub fn as_refcounted_ptr(&self) -> *mut ffi::webrtc_RTCCertificate_refcounted {
+        self.raw_ref.as_refcounted_ptr()
+    }
+}
+
+impl Clone for RTCCertificate {
+    fn clone(&self) -> Self {
+        Self {
+            raw_ref: self.raw_ref.clone(),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{CxxString, Result, ffi};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/// webrtc::SocketAddress の借用ラッパー。
+pub struct SocketAddressRef<'a> {
+    raw: NonNull<ffi::webrtc_SocketAddress>,
+    _marker: PhantomData<&'a ffi::webrtc_SocketAddress>,
+}
+
+unsafe impl<'a> Send for SocketAddressRef<'a> {}
+
+impl<'a> SocketAddressRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_SocketAddress>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_SocketAddress {
+        self.raw.as_ptr()
+    }
+
+    pub fn hostname(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_SocketAddress_hostname(self.raw.as_ptr(), &mut ptr, &mut len) };
+        assert!(!ptr.is_null());
+        let bytes = unsafe { std::slice::from_raw_parts(ptr.cast::<u8>(), len) };
+        Ok(std::str::from_utf8(bytes)?.to_owned())
+    }
+
+    pub fn port(&self) -> u16 {
+        unsafe { ffi::webrtc_SocketAddress_port(self.raw.as_ptr()) as u16 }
+    }
+
+    /// IP が未解決の場合は IPv4 / IPv6 どちらも false を返す。
+    pub fn is_ipv4(&self) -> bool {
+        unsafe { ffi::webrtc_SocketAddress_family(self.raw.as_ptr()) == ffi::webrtc_AF_INET }
+    }
+
+    pub fn is_ipv6(&self) -> bool {
+        unsafe { ffi::webrtc_SocketAddress_family(self.raw.as_ptr()) == ffi::webrtc_AF_INET6 }
+    }
+
+    pub fn is_any_ip(&self) -> bool {
+        unsafe { ffi::webrtc_SocketAddress_IsAnyIP(self.raw.as_ptr()) != 0 }
+    }
+
+    pub fn is_loopback_ip(&self) -> bool {
+        unsafe { ffi::webrtc_SocketAddress_IsLoopbackIP(self.raw.as_ptr()) != 0 }
+    }
+
+    pub fn is_private_ip(&self) -> bool {
+        unsafe { ffi::webrtc_SocketAddress_IsPrivateIP(self.raw.as_ptr()) != 0 }
+    }
+
+    /// ホスト名のみで IP が未解決かどうか。
+    pub fn is_unresolved_ip(&self) -> bool {
+        unsafe { ffi::webrtc_SocketAddress_IsUnresolvedIP(self.raw.as_ptr()) != 0 }
+    }
+
+    /// IPv6 の場合は "[...]" で囲んだホスト文字列を返す。
+    pub fn host_as_uri_string(&self) -> Result<String> {
+        let raw =
+            NonNull::new(unsafe { ffi::webrtc_SocketAddress_HostAsURIString(self.raw.as_ptr()) })
+                .expect("BUG: webrtc_SocketAddress_HostAsURIString が null を返しました");
+        CxxString::from_unique(raw).to_string()
+    }
+
+    /// "host:port" 形式の文字列を返す。
+    pub fn to_string(&self) -> Result<String> {
+        let raw = NonNull::new(unsafe { ffi::webrtc_SocketAddress_ToString(self.raw.as_ptr()) })
+            .expect("BUG: webrtc_SocketAddress_ToString が null を返しました");
+        CxxString::from_unique(raw).to_string()
+    }
+}
+
+/// webrtc::SocketAddress の所有ラッパー。
+pub struct SocketAddress {
+    raw: NonNull<ffi::webrtc_SocketAddress>,
+}
+
+unsafe impl Send for SocketAddress {}
+
+impl SocketAddress {
+    /// IP 文字列またはホスト名とポートから生成する。
+    pub fn new(hostname: &str, port: u16) -> Self {
+        let raw = unsafe {
+            ffi::webrtc_SocketAddress_new(
+                hostname.as_ptr() as *const _,
+                hostname.len(),
+                port as i32,
+            )
+        };
+        Self {
+            raw: NonNull::new(raw).expect("BUG: webrtc_SocketAddress_new が null を返しました"),
+        }
+    }
+
+    pub fn as_ref(&self) -> SocketAddressRef<'_> {
+        SocketAddressRef::from_raw(self.raw)
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_SocketAddress {
+        self.raw.as_ptr()
+    }
+
+    pub fn into_raw(self) -> *mut ffi::webrtc_SocketAddress {
+        std::mem::ManuallyDrop::new(self).raw.as_ptr()
+    }
+
+    pub fn hostname(&self) -> Result<String> {
+        self.as_ref().hostname()
+    }
+
+    pub fn port(&self) -> u16 {
+        self.as_ref().port()
+    }
+
+    pub fn to_string(&self) -> Result<String> {
+        self.as_ref().to_string()
+    }
+}
+
+impl Drop for SocketAddress {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_SocketAddress_delete(self.raw.as_ptr()) };
+    }
+}
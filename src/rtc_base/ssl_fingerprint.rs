@@ -0,0 +1,96 @@
+use crate::{CxxString, RTCCertificate, Result, SSLCertificateRef, ffi};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/// webrtc::SSLFingerprint のラッパー。
+pub struct SSLFingerprint {
+    raw_unique: NonNull<ffi::webrtc_SSLFingerprint_unique>,
+}
+
+unsafe impl Send for SSLFingerprint {}
+
+impl SSLFingerprint {
+    /// 指定したダイジェストアルゴリズム ("sha-256" など) で fingerprint を生成する。
+    pub fn create(algorithm: &str, cert: &SSLCertificateRef<'_>) -> Option<Self> {
+        let raw = unsafe {
+            ffi::webrtc_SSLFingerprint_Create(
+                algorithm.as_ptr() as *const _,
+                algorithm.len(),
+                cert.as_ptr(),
+            )
+        };
+        NonNull::new(raw).map(|raw_unique| Self { raw_unique })
+    }
+
+    /// 証明書の署名アルゴリズムに対応するダイジェストで fingerprint を生成する。
+    pub fn create_from_certificate(cert: &RTCCertificate) -> Option<Self> {
+        let raw = unsafe { ffi::webrtc_SSLFingerprint_CreateFromCertificate(cert.as_ptr()) };
+        NonNull::new(raw).map(|raw_unique| Self { raw_unique })
+    }
+
+    /// "AB:CD:..." 形式の fingerprint 文字列を返す。
+    pub fn rfc4572_fingerprint(&self) -> Result<String> {
+        self.as_ref().rfc4572_fingerprint()
+    }
+
+    pub fn algorithm(&self) -> Result<String> {
+        self.as_ref().algorithm()
+    }
+
+    pub fn as_ref(&self) -> SSLFingerprintRef<'_> {
+        SSLFingerprintRef::from_raw(
+            NonNull::new(self.as_ptr())
+                .expect("BUG: webrtc_SSLFingerprint_unique_get が null を返しました"),
+        )
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_SSLFingerprint {
+        unsafe { ffi::webrtc_SSLFingerprint_unique_get(self.raw_unique.as_ptr()) }
+    }
+}
+
+impl Drop for SSLFingerprint {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_SSLFingerprint_unique_delete(self.raw_unique.as_ptr()) };
+    }
+}
+
+/// webrtc::SSLFingerprint の借用ラッパー。
+pub struct SSLFingerprintRef<'a> {
+    raw: NonNull<ffi::webrtc_SSLFingerprint>,
+    _marker: PhantomData<&'a ffi::webrtc_SSLFingerprint>,
+}
+
+unsafe impl<'a> Send for SSLFingerprintRef<'a> {}
+
+impl<'a> SSLFingerprintRef<'a> {
+    /// 生ポインタから借用ラップする。
+    pub fn from_raw(raw: NonNull<ffi::webrtc_SSLFingerprint>) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_SSLFingerprint {
+        self.raw.as_ptr()
+    }
+
+    /// "AB:CD:..." 形式の fingerprint 文字列を返す。
+    pub fn rfc4572_fingerprint(&self) -> Result<String> {
+        let raw = NonNull::new(unsafe {
+            ffi::webrtc_SSLFingerprint_GetRfc4572Fingerprint(self.raw.as_ptr())
+        })
+        .expect("BUG: webrtc_SSLFingerprint_GetRfc4572Fingerprint が null を返しました");
+        CxxString::from_unique(raw).to_string()
+    }
+
+    pub fn algorithm(&self) -> Result<String> {
+        let mut ptr = std::ptr::null();
+        let mut len = 0usize;
+        unsafe { ffi::webrtc_SSLFingerprint_get_algorithm(self.raw.as_ptr(), &mut ptr, &mut len) };
+        assert!(!ptr.is_null());
+        let bytes = unsafe { std::slice::from_raw_parts(ptr.cast::<u8>(), len) };
+        Ok(std::str::from_utf8(bytes)?.to_owned())
+    }
+}
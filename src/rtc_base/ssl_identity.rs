@@ -1,6 +1,71 @@
 use crate::ffi;
 use std::ptr::NonNull;
 
+/// webrtc::ECCurve のラッパー。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ECCurve {
+    NistP256,
+    Unknown(i32),
+}
+
+impl ECCurve {
+    pub fn from_int(value: i32) -> Self {
+        unsafe {
+            if value == ffi::webrtc_ECCurve_EC_NIST_P256 {
+                ECCurve::NistP256
+            } else {
+                ECCurve::Unknown(value)
+            }
+        }
+    }
+
+    pub fn to_int(self) -> i32 {
+        match self {
+            ECCurve::NistP256 => unsafe { ffi::webrtc_ECCurve_EC_NIST_P256 },
+            ECCurve::Unknown(v) => v,
+        }
+    }
+}
+
+/// webrtc::KeyParams のラッパー。
+///
+/// RTCCertificate::generate で生成する鍵の種類を指定する。
+pub struct KeyParams {
+    raw: NonNull<ffi::webrtc_KeyParams>,
+}
+
+unsafe impl Send for KeyParams {}
+
+impl KeyParams {
+    /// RSA 鍵のパラメータを生成する。
+    pub fn rsa(mod_size: i32, pub_exp: i32) -> Self {
+        let raw = NonNull::new(unsafe { ffi::webrtc_KeyParams_RSA(mod_size, pub_exp) })
+            .expect("BUG: webrtc_KeyParams_RSA が null を返しました");
+        Self { raw }
+    }
+
+    /// ECDSA 鍵のパラメータを生成する。
+    pub fn ecdsa(curve: ECCurve) -> Self {
+        let raw = NonNull::new(unsafe { ffi::webrtc_KeyParams_ECDSA(curve.to_int()) })
+            .expect("BUG: webrtc_KeyParams_ECDSA が null を返しました");
+        Self { raw }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        unsafe { ffi::webrtc_KeyParams_IsValid(self.raw.as_ptr()) != 0 }
+    }
+
+    pub fn as_ptr(&self) -> *mut ffi::webrtc_KeyParams {
+        self.raw.as_ptr()
+    }
+}
+
+impl Drop for KeyParams {
+    fn drop(&mut self) {
+        unsafe { ffi::webrtc_KeyParams_delete(self.raw.as_ptr()) };
+    }
+}
+
 /// webrtc::SSLIdentity のラッパー。
 ///
 /// PEM 形式の秘密鍵と証明書からクライアント証明書用の SSLIdentity を生成し、
@@ -1,8 +1,9 @@
 use super::*;
+use std::num::NonZeroUsize;
 use std::ptr::NonNull;
 use std::sync::{
     Arc, Mutex,
-    atomic::{AtomicBool, Ordering},
+    atomic::{AtomicBool, AtomicUsize, Ordering},
     mpsc,
 };
 use std::time::Duration;
@@ -18,6 +19,12 @@ impl SetRemoteDescriptionObserverHandler for NoopHandler {}
 impl VideoEncoderHandler for NoopHandler {}
 impl VideoDecoderHandler for NoopHandler {}
 
+// 非同期ランタイムに依存せずに Future の完了を待つ。
+fn block_on_with_timeout<F: Future>(future: F, timeout: Duration) -> F::Output {
+    loopback::block_on_until(future, std::time::Instant::now() + timeout)
+        .expect("Future の完了待ちがタイムアウトしました")
+}
+
 #[test]
 fn create_and_drop_environment() {
     let _env = Environment::new();
@@ -129,6 +136,10 @@ fn media_type_constants() {
         MediaType::from_int(MediaType::Video.to_int()),
         MediaType::Video
     );
+    assert_eq!(
+        MediaType::from_int(MediaType::Data.to_int()),
+        MediaType::Data
+    );
 }
 
 #[test]
@@ -162,6 +173,155 @@ fn session_description_to_string() {
     );
 }
 
+#[test]
+fn session_description_exposes_parsed_contents() {
+    let fingerprint = "AB:CD:EF:01:23:45:67:89:AB:CD:EF:01:23:45:67:89:\
+                       AB:CD:EF:01:23:45:67:89:AB:CD:EF:01:23:45:67:89";
+    let sdp = format!(
+        "v=0\r\n\
+         o=- 0 0 IN IP4 127.0.0.1\r\n\
+         s=-\r\n\
+         t=0 0\r\n\
+         a=group:BUNDLE a0 v0 v1\r\n\
+         m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+         c=IN IP4 0.0.0.0\r\n\
+         a=ice-ufrag:ufrag0\r\n\
+         a=ice-pwd:password0password0password0\r\n\
+         a=fingerprint:sha-256 {fingerprint}\r\n\
+         a=setup:actpass\r\n\
+         a=mid:a0\r\n\
+         a=extmap:1 urn:ietf:params:rtp-hdrext:ssrc-audio-level\r\n\
+         a=sendrecv\r\n\
+         a=rtcp-mux\r\n\
+         a=rtpmap:111 opus/48000/2\r\n\
+         a=fmtp:111 minptime=10;useinbandfec=1\r\n\
+         a=ssrc:1001 cname:cname0\r\n\
+         m=video 9 UDP/TLS/RTP/SAVPF 96 97\r\n\
+         c=IN IP4 0.0.0.0\r\n\
+         a=ice-ufrag:ufrag0\r\n\
+         a=ice-pwd:password0password0password0\r\n\
+         a=fingerprint:sha-256 {fingerprint}\r\n\
+         a=setup:actpass\r\n\
+         a=mid:v0\r\n\
+         a=recvonly\r\n\
+         a=rtcp-mux\r\n\
+         a=rtpmap:96 VP8/90000\r\n\
+         a=rtpmap:97 rtx/90000\r\n\
+         a=fmtp:97 apt=96\r\n\
+         a=ssrc-group:FID 2001 2002\r\n\
+         a=ssrc:2001 cname:cname0\r\n\
+         a=ssrc:2002 cname:cname0\r\n\
+         m=video 9 UDP/TLS/RTP/SAVPF 96\r\n\
+         c=IN IP4 0.0.0.0\r\n\
+         a=ice-ufrag:ufrag0\r\n\
+         a=ice-pwd:password0password0password0\r\n\
+         a=fingerprint:sha-256 {fingerprint}\r\n\
+         a=setup:actpass\r\n\
+         a=mid:v1\r\n\
+         a=extmap:4 urn:ietf:params:rtp-hdrext:sdes:rtp-stream-id\r\n\
+         a=sendonly\r\n\
+         a=rtcp-mux\r\n\
+         a=rtpmap:96 VP8/90000\r\n\
+         a=rid:r0 send\r\n\
+         a=rid:r1 send\r\n\
+         a=simulcast:send r0;~r1\r\n"
+    );
+    let desc = SessionDescription::new(SdpType::Offer, &sdp)
+        .expect("SessionDescription の生成に失敗しました");
+    let parsed = desc.description().expect("description が None です");
+    let contents = parsed.contents();
+    assert_eq!(contents.len(), 3);
+
+    let audio = &contents[0];
+    assert_eq!(audio.mid().unwrap(), "a0");
+    assert!(!audio.rejected());
+    let audio_desc = audio
+        .media_description()
+        .expect("audio の description が None です");
+    assert_eq!(audio_desc.media_type(), MediaType::Audio);
+    assert_eq!(audio_desc.direction(), RtpTransceiverDirection::SendRecv);
+    assert!(audio_desc.rtcp_mux());
+    let codecs = audio_desc.codecs();
+    assert_eq!(codecs.len(), 1);
+    assert_eq!(codecs[0].id(), 111);
+    assert_eq!(codecs[0].name().unwrap(), "opus");
+    assert_eq!(codecs[0].clockrate(), 48000);
+    assert_eq!(codecs[0].channels(), 2);
+    let params = codecs[0].parameters();
+    assert!(params.contains(&("minptime".to_string(), "10".to_string())));
+    assert!(params.contains(&("useinbandfec".to_string(), "1".to_string())));
+    let extensions = audio_desc.rtp_header_extensions();
+    assert_eq!(extensions.len(), 1);
+    assert_eq!(extensions[0].id(), 1);
+    assert_eq!(
+        extensions[0].uri().unwrap(),
+        "urn:ietf:params:rtp-hdrext:ssrc-audio-level"
+    );
+    assert!(!extensions[0].encrypt());
+    let streams = audio_desc.streams();
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams[0].ssrcs(), vec![1001]);
+    assert_eq!(streams[0].cname().unwrap(), "cname0");
+
+    let video = contents[1]
+        .media_description()
+        .expect("video の description が None です");
+    assert_eq!(video.media_type(), MediaType::Video);
+    assert_eq!(video.direction(), RtpTransceiverDirection::RecvOnly);
+    let rtx = &video.codecs()[1];
+    assert_eq!(rtx.name().unwrap(), "rtx");
+    assert_eq!(
+        rtx.parameters(),
+        vec![("apt".to_string(), "96".to_string())]
+    );
+    let streams = video.streams();
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams[0].ssrcs(), vec![2001, 2002]);
+    let groups = streams[0].ssrc_groups();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].semantics().unwrap(), "FID");
+    assert_eq!(groups[0].ssrcs(), vec![2001, 2002]);
+    assert!(video.simulcast_description().is_empty());
+
+    let simulcast = contents[2]
+        .media_description()
+        .expect("simulcast の description が None です");
+    assert_eq!(simulcast.direction(), RtpTransceiverDirection::SendOnly);
+    let simulcast = simulcast.simulcast_description();
+    assert!(!simulcast.is_empty());
+    assert_eq!(
+        simulcast.send_layers().unwrap(),
+        vec![
+            SimulcastLayer {
+                rid: "r0".to_string(),
+                is_paused: false,
+            },
+            SimulcastLayer {
+                rid: "r1".to_string(),
+                is_paused: true,
+            },
+        ]
+    );
+    assert!(simulcast.receive_layers().unwrap().is_empty());
+
+    let transport = parsed
+        .transport_description("a0")
+        .expect("transport description が None です");
+    assert_eq!(transport.ice_ufrag().unwrap(), "ufrag0");
+    assert_eq!(transport.ice_pwd().unwrap(), "password0password0password0");
+    assert_eq!(transport.connection_role(), ConnectionRole::Actpass);
+    let identity = transport
+        .identity_fingerprint()
+        .expect("fingerprint が None です");
+    assert_eq!(identity.algorithm().unwrap(), "sha-256");
+    assert_eq!(identity.rfc4572_fingerprint().unwrap(), fingerprint);
+    assert!(parsed.transport_description("unknown").is_none());
+
+    let rollback = SessionDescription::new(SdpType::Rollback, "")
+        .expect("SessionDescription の生成に失敗しました");
+    assert!(rollback.description().is_none());
+}
+
 #[test]
 fn sdp_video_format_with_parameters() {
     let mut fmt = SdpVideoFormat::new_with_parameters(
@@ -2550,6 +2710,386 @@ fn rtc_configuration_and_ice_server() {
     assert_eq!(owned.len(), len_before + 1);
 }
 
+#[test]
+fn rtc_configuration_extended_fields_round_trip() {
+    let mut config = PeerConnectionRtcConfiguration::new();
+    config.set_type(IceTransportsType::NoHost);
+    config.set_bundle_policy(BundlePolicy::MaxBundle);
+    config.set_rtcp_mux_policy(RtcpMuxPolicy::Require);
+    config.set_continual_gathering_policy(ContinualGatheringPolicy::GatherContinually);
+    config.set_tcp_candidate_policy(TcpCandidatePolicy::Disabled);
+    config.set_candidate_network_policy(CandidateNetworkPolicy::LowCost);
+    config.set_ice_candidate_pool_size(2);
+    config.set_min_port(50000);
+    config.set_max_port(50100);
+    config.set_enable_dscp(true);
+    config.set_ice_connection_receiving_timeout(Some(1500));
+    config.set_ice_check_min_interval(Some(50));
+    config.set_stun_candidate_keepalive_interval(None);
+    config.set_offer_extmap_allow_mixed(false);
+    config
+        .crypto_options()
+        .srtp()
+        .set_enable_gcm_crypto_suites(true);
+    config
+        .crypto_options()
+        .sframe()
+        .set_require_frame_encryption(true);
+
+    assert_eq!(config.ice_type(), IceTransportsType::NoHost);
+    assert_eq!(config.bundle_policy(), BundlePolicy::MaxBundle);
+    assert_eq!(config.rtcp_mux_policy(), RtcpMuxPolicy::Require);
+    assert_eq!(
+        config.continual_gathering_policy(),
+        ContinualGatheringPolicy::GatherContinually
+    );
+    assert_eq!(config.tcp_candidate_policy(), TcpCandidatePolicy::Disabled);
+    assert_eq!(
+        config.candidate_network_policy(),
+        CandidateNetworkPolicy::LowCost
+    );
+    assert_eq!(config.ice_candidate_pool_size(), 2);
+    assert_eq!(config.min_port(), 50000);
+    assert_eq!(config.max_port(), 50100);
+    assert!(config.enable_dscp());
+    assert_eq!(config.ice_connection_receiving_timeout(), Some(1500));
+    assert_eq!(config.ice_check_min_interval(), Some(50));
+    assert_eq!(config.stun_candidate_keepalive_interval(), None);
+    assert!(!config.offer_extmap_allow_mixed());
+    assert!(config.crypto_options().srtp().enable_gcm_crypto_suites());
+    assert!(config.crypto_options().sframe().require_frame_encryption());
+    assert!(config.certificates().is_empty());
+    assert_eq!(
+        BundlePolicy::from_int(123456),
+        BundlePolicy::Unknown(123456)
+    );
+}
+
+#[test]
+fn peer_connection_get_configuration_reflects_applied_config() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut config = PeerConnectionRtcConfiguration::new();
+    config.set_bundle_policy(BundlePolicy::MaxBundle);
+    config.set_ice_candidate_pool_size(1);
+    config.set_ice_check_min_interval(Some(100));
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps = PeerConnectionDependencies::new(&observer);
+    let mut pc = PeerConnection::create(&factory, &mut config, &mut deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let current = pc.get_configuration();
+    assert_eq!(current.bundle_policy(), BundlePolicy::MaxBundle);
+    assert_eq!(current.ice_candidate_pool_size(), 1);
+    assert_eq!(current.ice_check_min_interval(), Some(100));
+
+    // 取得した設定を書き換えて再適用できることを確認する。
+    let mut updated = pc.get_configuration();
+    updated.set_ice_candidate_pool_size(3);
+    pc.set_configuration(&mut updated)
+        .expect("set_configuration に失敗しました");
+    assert_eq!(pc.get_configuration().ice_candidate_pool_size(), 3);
+
+    drop(pc);
+    drop(factory);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn rtc_certificate_generate_pem_round_trip_and_fingerprint() {
+    let params = KeyParams::ecdsa(ECCurve::NistP256);
+    assert!(params.is_valid());
+    let cert = RTCCertificate::generate(&params, Some(60_000))
+        .expect("RTCCertificate の生成に失敗しました");
+
+    // SHA-256 は 32 バイトなので "XX:" x 31 + "XX" の 95 文字になる。
+    let fingerprint = cert
+        .sha256_fingerprint()
+        .expect("fingerprint の取得に失敗しました");
+    assert_eq!(fingerprint.len(), 95);
+    assert_eq!(fingerprint.split(':').count(), 32);
+    let from_cert = SSLFingerprint::create_from_certificate(&cert)
+        .expect("SSLFingerprint の生成に失敗しました");
+    assert_eq!(from_cert.algorithm().unwrap(), "sha-256");
+    assert_eq!(from_cert.rfc4572_fingerprint().unwrap(), fingerprint);
+
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    assert!(!cert.has_expired(now_ms));
+    assert!(cert.has_expired(now_ms + 120_000));
+    assert!(cert.expires() <= now_ms + 120_000);
+
+    // PEM に書き出して復元しても同じ fingerprint になる。
+    let pem = cert.to_pem().expect("PEM の取得に失敗しました");
+    assert!(pem.private_key.contains("PRIVATE KEY"));
+    assert!(pem.certificate.contains("BEGIN CERTIFICATE"));
+    let restored = RTCCertificate::from_pem(&pem).expect("PEM からの復元に失敗しました");
+    assert_eq!(restored.sha256_fingerprint().unwrap(), fingerprint);
+    assert_eq!(restored.expires(), cert.expires());
+
+    let invalid = RTCCertificatePEM {
+        private_key: "invalid".to_string(),
+        certificate: "invalid".to_string(),
+    };
+    assert!(RTCCertificate::from_pem(&invalid).is_none());
+
+    let rsa = RTCCertificate::generate(&KeyParams::rsa(2048, 65537), None)
+        .expect("RSA 証明書の生成に失敗しました");
+    assert_ne!(rsa.sha256_fingerprint().unwrap(), fingerprint);
+}
+
+#[test]
+fn peer_connection_offer_uses_configured_certificate() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    // 再起動後も同じ fingerprint を使えるよう、PEM から復元した証明書を設定する。
+    let generated = RTCCertificate::generate(&KeyParams::ecdsa(ECCurve::NistP256), None)
+        .expect("RTCCertificate の生成に失敗しました");
+    let pem = generated.to_pem().expect("PEM の取得に失敗しました");
+    let cert = RTCCertificate::from_pem(&pem).expect("PEM からの復元に失敗しました");
+    let fingerprint = cert.sha256_fingerprint().unwrap();
+
+    let mut config = PeerConnectionRtcConfiguration::new();
+    config.set_always_negotiate_data_channels(true);
+    config.add_certificate(&cert);
+    assert_eq!(config.certificates().len(), 1);
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut config, &mut deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let opts = PeerConnectionOfferAnswerOptions::new();
+    let offer = block_on_with_timeout(pc.create_offer_async(&opts), Duration::from_secs(5))
+        .expect("create_offer_async が失敗しました");
+    let offer_sdp = offer.to_string().expect("offer SDP の取得に失敗しました");
+    assert!(offer_sdp.contains(&format!("a=fingerprint:sha-256 {fingerprint}")));
+
+    drop(pc);
+    drop(factory);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn ice_candidate_exposes_structured_fields() {
+    let sdp = "candidate:842163049 1 udp 1677729535 203.0.113.5 61665 typ srflx raddr 192.168.1.10 rport 61665 generation 0 ufrag EEtu network-id 3 network-cost 10";
+    let ice = IceCandidate::new("0", 0, sdp).expect("candidate のパースに失敗しました");
+    let candidate = ice.candidate();
+    assert_eq!(candidate.foundation().unwrap(), "842163049");
+    assert_eq!(candidate.component(), 1);
+    assert_eq!(candidate.protocol().unwrap(), "udp");
+    assert_eq!(candidate.priority(), 1677729535);
+    assert_eq!(candidate.candidate_type(), IceCandidateType::Srflx);
+    assert_eq!(candidate.address().hostname().unwrap(), "203.0.113.5");
+    assert_eq!(candidate.address().port(), 61665);
+    assert!(candidate.address().is_ipv4());
+    assert!(!candidate.address().is_private_ip());
+    assert_eq!(
+        candidate.related_address().hostname().unwrap(),
+        "192.168.1.10"
+    );
+    assert!(candidate.related_address().is_private_ip());
+    assert_eq!(candidate.tcptype().unwrap(), "");
+    assert_eq!(candidate.username().unwrap(), "EEtu");
+    assert_eq!(candidate.network_id(), 3);
+    assert_eq!(candidate.network_cost(), 10);
+
+    let sdp = "candidate:1 1 tcp 1518280447 2001:db8::1 9 typ host tcptype passive";
+    let ice = IceCandidate::new("0", 0, sdp).expect("candidate のパースに失敗しました");
+    let candidate = ice.candidate();
+    assert_eq!(candidate.candidate_type(), IceCandidateType::Host);
+    assert_eq!(candidate.tcptype().unwrap(), "passive");
+    assert!(candidate.address().is_ipv6());
+    assert_eq!(
+        candidate.address().host_as_uri_string().unwrap(),
+        "[2001:db8::1]"
+    );
+}
+
+#[test]
+fn ice_candidate_from_fields_round_trip() {
+    let mut candidate = Candidate::new();
+    candidate.set_foundation("4234997325");
+    candidate.set_component(1);
+    candidate.set_protocol("udp");
+    candidate.set_priority(2122260223);
+    candidate.set_address(&SocketAddress::new("192.0.2.1", 54400));
+    candidate.set_candidate_type(IceCandidateType::Relay);
+    candidate.set_related_address(&SocketAddress::new("198.51.100.7", 3478));
+    candidate.set_username("abcd");
+    candidate.set_network_cost(50);
+    candidate.set_transport_name("audio");
+    assert_eq!(candidate.as_ref().transport_name().unwrap(), "audio");
+
+    let ice = IceCandidate::from_candidate("audio", 0, &candidate);
+    assert_eq!(ice.sdp_mid().unwrap(), "audio");
+    assert_eq!(ice.sdp_mline_index(), 0);
+    let sdp = ice.to_string().expect("candidate の文字列化に失敗しました");
+    assert!(sdp.contains("typ relay"));
+    assert!(sdp.contains("raddr 198.51.100.7 rport 3478"));
+
+    // 文字列化した candidate を再度パースしても同じ値になる。
+    let parsed = IceCandidate::new("audio", 0, &sdp).expect("candidate のパースに失敗しました");
+    let parsed = parsed.candidate();
+    assert_eq!(parsed.foundation().unwrap(), "4234997325");
+    assert_eq!(parsed.priority(), 2122260223);
+    assert_eq!(parsed.candidate_type(), IceCandidateType::Relay);
+    assert_eq!(parsed.address().to_string().unwrap(), "192.0.2.1:54400");
+    assert_eq!(parsed.related_address().port(), 3478);
+    assert_eq!(parsed.username().unwrap(), "abcd");
+    assert_eq!(parsed.network_cost(), 50);
+    assert_eq!(
+        IceCandidateType::from_int(IceCandidateType::Prflx.to_int()),
+        IceCandidateType::Prflx
+    );
+}
+
+#[test]
+fn peer_connection_remove_ice_candidates() {
+    fn remote_sdp(pc: &PeerConnection) -> String {
+        pc.remote_description()
+            .expect("remote_description が None です")
+            .to_string()
+            .expect("remote SDP の取得に失敗しました")
+    }
+
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut config_offer = PeerConnectionRtcConfiguration::new();
+    let observer_offer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps_offer = PeerConnectionDependencies::new(&observer_offer);
+    let pc_offer = PeerConnection::create(&factory, &mut config_offer, &mut deps_offer)
+        .expect("offer 側 PeerConnection の生成に失敗しました");
+    let mut config_answer = PeerConnectionRtcConfiguration::new();
+    let observer_answer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps_answer = PeerConnectionDependencies::new(&observer_answer);
+    let mut pc_answer = PeerConnection::create(&factory, &mut config_answer, &mut deps_answer)
+        .expect("answer 側 PeerConnection の生成に失敗しました");
+
+    let mut init = RtpTransceiverInit::new();
+    init.set_direction(RtpTransceiverDirection::SendRecv);
+    let transceiver = pc_offer
+        .add_transceiver(MediaType::Audio, &mut init)
+        .expect("transceiver の追加に失敗しました");
+
+    let timeout = Duration::from_secs(5);
+    let opts = PeerConnectionOfferAnswerOptions::new();
+    let offer = block_on_with_timeout(pc_offer.create_offer_async(&opts), timeout)
+        .expect("create_offer_async が失敗しました");
+    let offer_sdp = offer.to_string().expect("offer SDP の取得に失敗しました");
+    let remote_offer = SessionDescription::new(SdpType::Offer, &offer_sdp)
+        .expect("offer SDP のパースに失敗しました");
+    block_on_with_timeout(
+        pc_answer.set_remote_description_async(remote_offer),
+        timeout,
+    )
+    .expect("set_remote_description_async が失敗しました");
+
+    let ice = IceCandidate::new(
+        "0",
+        0,
+        "candidate:1 1 udp 2122260223 192.0.2.10 50000 typ host",
+    )
+    .expect("candidate のパースに失敗しました");
+    // パースした candidate の transport_name は sdp_mid になる。
+    assert_eq!(ice.candidate().transport_name().unwrap(), "0");
+    assert!(!remote_sdp(&pc_answer).contains("192.0.2.10 50000"));
+
+    pc_answer
+        .add_ice_candidate(&ice)
+        .expect("add_ice_candidate が失敗しました");
+    assert!(remote_sdp(&pc_answer).contains("192.0.2.10 50000"));
+
+    pc_answer
+        .remove_ice_candidates(&[ice.candidate()])
+        .expect("remove_ice_candidates が失敗しました");
+    assert!(!remote_sdp(&pc_answer).contains("192.0.2.10 50000"));
+
+    drop(transceiver);
+    drop(pc_offer);
+    drop(pc_answer);
+    drop(deps_offer);
+    drop(deps_answer);
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
 #[test]
 fn tls_cert_policy_round_trip() {
     assert_eq!(
@@ -2855,11 +3395,23 @@ fn rtp_sender_get_set_parameters() {
 }
 
 #[test]
-fn peer_connection_create_and_transceiver() {
-    // Factory を組み立てる。
-    let dec = AudioDecoderFactory::builtin();
-    let enc = AudioEncoderFactory::builtin();
+fn frame_transformer_attach_to_sender_and_receiver() {
+    struct PassThrough {
+        dropped: Arc<AtomicBool>,
+    }
+    impl FrameTransformerHandler for PassThrough {}
+    impl Drop for PassThrough {
+        fn drop(&mut self) {
+            self.dropped.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let dec_audio = AudioDecoderFactory::builtin();
+    let enc_audio = AudioEncoderFactory::builtin();
+    let enc_video = VideoEncoderFactory::builtin();
+    let dec_video = VideoDecoderFactory::builtin();
     let apb = AudioProcessingBuilder::new_builtin();
+
     let mut deps_factory = PeerConnectionFactoryDependencies::new();
     let mut network = Thread::new();
     let mut worker = Thread::new();
@@ -2870,8 +3422,10 @@ fn peer_connection_create_and_transceiver() {
     deps_factory.set_network_thread(&network);
     deps_factory.set_worker_thread(&worker);
     deps_factory.set_signaling_thread(&signaling);
-    deps_factory.set_audio_encoder_factory(&enc);
-    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_encoder_factory(&enc_audio);
+    deps_factory.set_audio_decoder_factory(&dec_audio);
+    deps_factory.set_video_encoder_factory(enc_video);
+    deps_factory.set_video_decoder_factory(dec_video);
     deps_factory.set_audio_processing_builder(apb);
     let env = Environment::new();
     let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
@@ -2881,30 +3435,80 @@ fn peer_connection_create_and_transceiver() {
     let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
         .expect("PeerConnectionFactory の生成に失敗しました");
 
-    // PC 用の構成と observer/dependencies を準備する。
+    let source = AdaptedVideoTrackSource::new();
+    let vts = source.cast_to_video_track_source();
+    let track = factory
+        .create_video_track(&vts, "video-track-1")
+        .expect("VideoTrack の生成に失敗しました");
+
     let mut pc_config = PeerConnectionRtcConfiguration::new();
     let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
     let mut pc_deps = PeerConnectionDependencies::new(&observer);
-
-    // PeerConnection を生成し、取得できることを確認する。
     let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
         .expect("PeerConnection の生成に失敗しました");
-    assert!(!pc.as_ptr().is_null());
 
+    let dropped = Arc::new(AtomicBool::new(false));
+    let transformer = FrameTransformer::new_with_handler(Box::new(PassThrough {
+        dropped: dropped.clone(),
+    }));
+
+    let stream_track = track.cast_to_media_stream_track();
+    let mut stream_ids = StringVector::new(0);
+    stream_ids.push(&CxxString::from_str("stream-0"));
+    let mut sender = pc
+        .add_track(&stream_track, &stream_ids)
+        .expect("AddTrack が失敗しました");
+    sender.set_frame_transformer(&transformer);
+
+    let mut init = RtpTransceiverInit::new();
+    init.set_direction(RtpTransceiverDirection::RecvOnly);
+    let transceiver = pc
+        .add_transceiver(MediaType::Audio, &mut init)
+        .expect("transceiver の追加に失敗しました");
+    let mut receiver = transceiver.receiver();
+    receiver.set_frame_transformer(&transformer);
+
+    // 設定した transformer は libwebrtc 側が PeerConnection の破棄まで保持する。
+    drop(transformer);
+    assert!(!dropped.load(Ordering::SeqCst));
+
+    drop(receiver);
+    drop(transceiver);
+    drop(sender);
+    drop(stream_track);
     drop(pc);
+    drop(track);
+    drop(vts);
+    drop(source);
     drop(pc_deps);
     drop(factory);
     drop(deps_factory);
+    drop(adm);
+    drop(env);
     network.stop();
     worker.stop();
     signaling.stop();
+    assert!(dropped.load(Ordering::SeqCst));
 }
 
 #[test]
-fn peer_connection_lookup_dtls_transport() {
-    let dec = AudioDecoderFactory::builtin();
-    let enc = AudioEncoderFactory::builtin();
+fn rtp_receiver_properties_and_observer() {
+    struct DropFlag {
+        dropped: Arc<AtomicBool>,
+    }
+    impl RtpReceiverObserverHandler for DropFlag {}
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.dropped.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let dec_audio = AudioDecoderFactory::builtin();
+    let enc_audio = AudioEncoderFactory::builtin();
+    let enc_video = VideoEncoderFactory::builtin();
+    let dec_video = VideoDecoderFactory::builtin();
     let apb = AudioProcessingBuilder::new_builtin();
+
     let mut deps_factory = PeerConnectionFactoryDependencies::new();
     let mut network = Thread::new();
     let mut worker = Thread::new();
@@ -2915,8 +3519,10 @@ fn peer_connection_lookup_dtls_transport() {
     deps_factory.set_network_thread(&network);
     deps_factory.set_worker_thread(&worker);
     deps_factory.set_signaling_thread(&signaling);
-    deps_factory.set_audio_encoder_factory(&enc);
-    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_encoder_factory(&enc_audio);
+    deps_factory.set_audio_decoder_factory(&dec_audio);
+    deps_factory.set_video_encoder_factory(enc_video);
+    deps_factory.set_video_decoder_factory(dec_video);
     deps_factory.set_audio_processing_builder(apb);
     let env = Environment::new();
     let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
@@ -2932,37 +3538,3045 @@ fn peer_connection_lookup_dtls_transport() {
     let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
         .expect("PeerConnection の生成に失敗しました");
 
-    let mut transceiver_init = RtpTransceiverInit::new();
-    transceiver_init.set_direction(RtpTransceiverDirection::SendRecv);
-    let _ = pc
-        .add_transceiver(MediaType::Audio, &mut transceiver_init)
+    let mut init = RtpTransceiverInit::new();
+    init.set_direction(RtpTransceiverDirection::RecvOnly);
+    let transceiver = pc
+        .add_transceiver(MediaType::Audio, &mut init)
         .expect("transceiver の追加に失敗しました");
+    let mut receiver = transceiver.receiver();
 
-    if let Some(dtls_transport) = pc.lookup_dtls_transport_by_mid("0") {
-        let observer = DtlsTransportObserver::new_with_handler(Box::new(NoopHandler));
-        let _ = dtls_transport.state();
-        dtls_transport.register_observer(&observer);
-        dtls_transport.unregister_observer();
-    }
+    assert_eq!(receiver.media_type(), MediaType::Audio);
+    assert!(!receiver.id().expect("id の取得に失敗しました").is_empty());
+    assert!(
+        receiver
+            .stream_ids()
+            .expect("stream_ids の取得に失敗しました")
+            .is_empty()
+    );
+    let _parameters = receiver.get_parameters();
+    // ネゴシエーション前はパケットもトランスポートも存在しない。
+    assert!(receiver.get_sources().is_empty());
+    assert!(receiver.dtls_transport().is_none());
 
+    receiver.set_jitter_buffer_minimum_delay(Some(0.5));
+    receiver.set_jitter_buffer_minimum_delay(None);
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let receiver_observer = RtpReceiverObserver::new_with_handler(Box::new(DropFlag {
+        dropped: dropped.clone(),
+    }));
+    receiver.set_observer(Some(receiver_observer));
+    // 保持中の observer は破棄されない。
+    assert!(!dropped.load(Ordering::SeqCst));
+    receiver.set_observer(None);
+    assert!(dropped.load(Ordering::SeqCst));
+
+    // ラッパーの破棄時にも observer が解除・破棄される。
+    let dropped_on_receiver_drop = Arc::new(AtomicBool::new(false));
+    receiver.set_observer(Some(RtpReceiverObserver::new_with_handler(Box::new(
+        DropFlag {
+            dropped: dropped_on_receiver_drop.clone(),
+        },
+    ))));
+
+    drop(receiver);
+    assert!(dropped_on_receiver_drop.load(Ordering::SeqCst));
+    drop(transceiver);
     drop(pc);
     drop(pc_deps);
     drop(factory);
     drop(deps_factory);
+    drop(adm);
+    drop(env);
     network.stop();
     worker.stop();
     signaling.stop();
 }
 
 #[test]
-fn peer_connection_create_with_proxy_allocator() {
-    let dec = AudioDecoderFactory::builtin();
-    let enc = AudioEncoderFactory::builtin();
-    let apb = AudioProcessingBuilder::new_builtin();
-    let mut deps_factory = PeerConnectionFactoryDependencies::new();
-    let mut network = Thread::new();
-    let mut worker = Thread::new();
-    let mut signaling = Thread::new();
+fn rtp_sender_properties_and_dtmf_sender() {
+    struct DropFlag {
+        dropped: Arc<AtomicBool>,
+    }
+    impl DtmfSenderObserverHandler for DropFlag {}
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.dropped.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let dec_audio = AudioDecoderFactory::builtin();
+    let enc_audio = AudioEncoderFactory::builtin();
+    let enc_video = VideoEncoderFactory::builtin();
+    let dec_video = VideoDecoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc_audio);
+    deps_factory.set_audio_decoder_factory(&dec_audio);
+    deps_factory.set_video_encoder_factory(enc_video);
+    deps_factory.set_video_decoder_factory(dec_video);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut pc_config = PeerConnectionRtcConfiguration::new();
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut pc_deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let audio_source = factory
+        .create_audio_source()
+        .expect("AudioSource の生成に失敗しました");
+    let audio_track = factory
+        .create_audio_track(&audio_source, "audio-track-0")
+        .expect("AudioTrack の生成に失敗しました");
+    let audio_stream_track = audio_track.cast_to_media_stream_track();
+    let mut stream_ids = StringVector::new(0);
+    stream_ids.push(&CxxString::from_str("stream-0"));
+    let mut audio_sender = pc
+        .add_track(&audio_stream_track, &stream_ids)
+        .expect("AddTrack が失敗しました");
+
+    assert_eq!(audio_sender.media_type(), MediaType::Audio);
+    assert_eq!(
+        audio_sender.id().expect("id の取得に失敗しました"),
+        "audio-track-0"
+    );
+    let sender_track = audio_sender.track().expect("track が設定されていません");
+    assert_eq!(
+        sender_track.id().expect("track id の取得に失敗しました"),
+        "audio-track-0"
+    );
+    assert_eq!(
+        audio_sender
+            .stream_ids()
+            .expect("stream_ids の取得に失敗しました"),
+        vec!["stream-0".to_string()]
+    );
+    let mut new_stream_ids = StringVector::new(0);
+    new_stream_ids.push(&CxxString::from_str("stream-1"));
+    new_stream_ids.push(&CxxString::from_str("stream-2"));
+    audio_sender.set_streams(&new_stream_ids);
+    assert_eq!(
+        audio_sender
+            .stream_ids()
+            .expect("stream_ids の取得に失敗しました"),
+        vec!["stream-1".to_string(), "stream-2".to_string()]
+    );
+    // ネゴシエーション前はトランスポートが存在しない。
+    assert!(audio_sender.dtls_transport().is_none());
+    // AddTrack では送信エンコーディングを指定しない。
+    assert!(audio_sender.init_send_encodings().is_empty());
+    assert_eq!(audio_sender.ssrc(), 0);
+
+    let mut dtmf_sender = audio_sender
+        .get_dtmf_sender()
+        .expect("音声の送信者は DtmfSender を持つはずです");
+    let dropped = Arc::new(AtomicBool::new(false));
+    let dtmf_observer = DtmfSenderObserver::new_with_handler(Box::new(DropFlag {
+        dropped: dropped.clone(),
+    }));
+    dtmf_sender.register_observer(dtmf_observer);
+    // ネゴシエーション前は送信できない。
+    assert!(!dtmf_sender.can_insert_dtmf());
+    assert!(!dtmf_sender.insert_dtmf("1234#", 100, 70));
+    assert!(
+        dtmf_sender
+            .tones()
+            .expect("tones の取得に失敗しました")
+            .is_empty()
+    );
+    // 登録中の observer は破棄されず、解除すると破棄される。
+    assert!(!dropped.load(Ordering::SeqCst));
+    dtmf_sender.unregister_observer();
+    assert!(dropped.load(Ordering::SeqCst));
+
+    let video_source = AdaptedVideoTrackSource::new();
+    let vts = video_source.cast_to_video_track_source();
+    let video_track = factory
+        .create_video_track(&vts, "video-track-0")
+        .expect("VideoTrack の生成に失敗しました");
+    let video_stream_track = video_track.cast_to_media_stream_track();
+    let video_sender = pc
+        .add_track(&video_stream_track, &stream_ids)
+        .expect("AddTrack が失敗しました");
+    assert_eq!(video_sender.media_type(), MediaType::Video);
+    assert!(video_sender.get_dtmf_sender().is_none());
+
+    drop(video_sender);
+    drop(video_stream_track);
+    drop(video_track);
+    drop(vts);
+    drop(video_source);
+    drop(dtmf_sender);
+    drop(sender_track);
+    drop(audio_sender);
+    drop(audio_stream_track);
+    drop(audio_track);
+    drop(audio_source);
+    drop(pc);
+    drop(pc_deps);
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn dtmf_sender_sends_tones_over_loopback() {
+    struct ToneRecorder {
+        tx: mpsc::Sender<String>,
+    }
+    impl DtmfSenderObserverHandler for ToneRecorder {
+        fn on_tone_change(&mut self, tone: &str, _tone_buffer: &str) {
+            let _ = self.tx.send(tone.to_string());
+        }
+    }
+
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut loopback = loopback::Loopback::new(&factory).expect("Loopback の生成に失敗しました");
+    let audio_source = factory
+        .create_audio_source()
+        .expect("AudioSource の生成に失敗しました");
+    let audio_track = factory
+        .create_audio_track(&audio_source, "audio-track-0")
+        .expect("AudioTrack の生成に失敗しました");
+    let audio_stream_track = audio_track.cast_to_media_stream_track();
+    let mut stream_ids = StringVector::new(0);
+    stream_ids.push(&CxxString::from_str("stream-0"));
+    let audio_sender = loopback
+        .offerer()
+        .add_track(&audio_stream_track, &stream_ids)
+        .expect("AddTrack が失敗しました");
+
+    loopback
+        .connect(Duration::from_secs(10))
+        .expect("Loopback の接続に失敗しました");
+    assert_ne!(audio_sender.ssrc(), 0);
+
+    let mut dtmf_sender = audio_sender
+        .get_dtmf_sender()
+        .expect("音声の送信者は DtmfSender を持つはずです");
+    let (tx, rx) = mpsc::channel();
+    dtmf_sender.register_observer(DtmfSenderObserver::new_with_handler(Box::new(
+        ToneRecorder { tx },
+    )));
+    assert!(dtmf_sender.can_insert_dtmf());
+    assert!(dtmf_sender.insert_dtmf("12", 100, 70));
+    assert_eq!(dtmf_sender.duration(), 100);
+    assert_eq!(dtmf_sender.inter_tone_gap(), 70);
+
+    // 各トーンの送信開始と、全トーンの送信完了 (空文字列) が順に通知される。
+    for expected in ["1", "2", ""] {
+        let tone = rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("on_tone_change が呼ばれませんでした");
+        assert_eq!(tone, expected);
+    }
+    assert!(
+        dtmf_sender
+            .tones()
+            .expect("tones の取得に失敗しました")
+            .is_empty()
+    );
+
+    drop(dtmf_sender);
+    drop(audio_sender);
+    drop(audio_stream_track);
+    drop(audio_track);
+    drop(audio_source);
+    drop(loopback);
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn rtp_transceiver_direction_and_lifecycle() {
+    let dec_audio = AudioDecoderFactory::builtin();
+    let enc_audio = AudioEncoderFactory::builtin();
+    let enc_video = VideoEncoderFactory::builtin();
+    let dec_video = VideoDecoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc_audio);
+    deps_factory.set_audio_decoder_factory(&dec_audio);
+    deps_factory.set_video_encoder_factory(enc_video);
+    deps_factory.set_video_decoder_factory(dec_video);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut pc_config = PeerConnectionRtcConfiguration::new();
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut pc_deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let mut init = RtpTransceiverInit::new();
+    init.set_direction(RtpTransceiverDirection::SendRecv);
+    let mut transceiver = pc
+        .add_transceiver(MediaType::Audio, &mut init)
+        .expect("transceiver の追加に失敗しました");
+
+    assert_eq!(transceiver.media_type(), MediaType::Audio);
+    assert_eq!(transceiver.sender().media_type(), MediaType::Audio);
+    // ネゴシエーション前は mid も確定した方向も存在しない。
+    assert_eq!(transceiver.mid().expect("mid の取得に失敗しました"), None);
+    assert_eq!(transceiver.direction(), RtpTransceiverDirection::SendRecv);
+    assert_eq!(transceiver.current_direction(), None);
+    assert_eq!(transceiver.fired_direction(), None);
+    assert!(!transceiver.stopped());
+    assert!(!transceiver.stopping());
+
+    transceiver
+        .set_direction_with_error(RtpTransceiverDirection::SendOnly)
+        .expect("direction の変更に失敗しました");
+    assert_eq!(transceiver.direction(), RtpTransceiverDirection::SendOnly);
+    // Stopped は stop_standard でのみ設定できる。
+    assert!(
+        transceiver
+            .set_direction_with_error(RtpTransceiverDirection::Stopped)
+            .is_err()
+    );
+
+    assert!(transceiver.codec_preferences().is_empty());
+    let caps = factory.get_rtp_receiver_capabilities(MediaType::Audio);
+    let first = caps
+        .codecs()
+        .get(0)
+        .expect("先頭 codec の取得に失敗しました");
+    let mut preferences = RtpCodecCapabilityVector::new(0);
+    preferences.push(&first);
+    transceiver
+        .set_codec_preferences(&preferences)
+        .expect("codec preferences の設定に失敗しました");
+    let read_back = transceiver.codec_preferences();
+    assert_eq!(read_back.len(), 1);
+    assert_eq!(
+        read_back
+            .get(0)
+            .expect("codec の取得に失敗しました")
+            .name()
+            .expect("codec 名の取得に失敗しました"),
+        first.name().expect("codec 名の取得に失敗しました")
+    );
+
+    transceiver
+        .stop_standard()
+        .expect("stop_standard に失敗しました");
+    assert!(transceiver.stopping());
+    assert_eq!(transceiver.direction(), RtpTransceiverDirection::Stopped);
+    assert!(
+        transceiver
+            .set_direction_with_error(RtpTransceiverDirection::SendRecv)
+            .is_err()
+    );
+
+    drop(read_back);
+    drop(preferences);
+    drop(caps);
+    drop(transceiver);
+    drop(pc);
+    drop(pc_deps);
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn rtp_header_extensions_to_negotiate() {
+    const DEPENDENCY_DESCRIPTOR_URI: &str =
+        "https://aomedia.org/av1-rtp-spec/#dependency-descriptor-rtp-header-extension";
+
+    let dec_audio = AudioDecoderFactory::builtin();
+    let enc_audio = AudioEncoderFactory::builtin();
+    let enc_video = VideoEncoderFactory::builtin();
+    let dec_video = VideoDecoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc_audio);
+    deps_factory.set_audio_decoder_factory(&dec_audio);
+    deps_factory.set_video_encoder_factory(enc_video);
+    deps_factory.set_video_decoder_factory(dec_video);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let caps = factory.get_rtp_sender_capabilities(MediaType::Video);
+    let cap_extensions = caps.header_extensions();
+    assert!(!cap_extensions.is_empty());
+    for i in 0..cap_extensions.len() {
+        let ext = cap_extensions.get(i).expect("拡張の取得に失敗しました");
+        assert!(!ext.uri().expect("uri の取得に失敗しました").is_empty());
+    }
+
+    let mut pc_config = PeerConnectionRtcConfiguration::new();
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut pc_deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let mut init = RtpTransceiverInit::new();
+    init.set_direction(RtpTransceiverDirection::SendRecv);
+    let mut transceiver = pc
+        .add_transceiver(MediaType::Video, &mut init)
+        .expect("transceiver の追加に失敗しました");
+
+    let find = |extensions: &RtpHeaderExtensionCapabilityVector| {
+        (0..extensions.len()).find(|&i| {
+            extensions
+                .get(i)
+                .and_then(|ext| ext.uri().ok())
+                .is_some_and(|uri| uri == DEPENDENCY_DESCRIPTOR_URI)
+        })
+    };
+
+    let extensions = transceiver.header_extensions_to_negotiate();
+    let index = find(&extensions).expect("dependency descriptor が見つかりません");
+    // dependency descriptor は既定では無効になっている。
+    let mut dd = extensions.get(index).expect("拡張の取得に失敗しました");
+    assert_eq!(dd.direction(), RtpTransceiverDirection::Stopped);
+    dd.set_direction(RtpTransceiverDirection::SendRecv);
+    transceiver
+        .set_header_extensions_to_negotiate(&extensions)
+        .expect("ヘッダー拡張の設定に失敗しました");
+
+    let read_back = transceiver.header_extensions_to_negotiate();
+    let index = find(&read_back).expect("dependency descriptor が見つかりません");
+    assert_eq!(
+        read_back
+            .get(index)
+            .expect("拡張の取得に失敗しました")
+            .direction(),
+        RtpTransceiverDirection::SendRecv
+    );
+
+    // ネゴシエーション前は全ての拡張が Stopped になる。
+    let negotiated = transceiver.negotiated_header_extensions();
+    for i in 0..negotiated.len() {
+        let ext = negotiated.get(i).expect("拡張の取得に失敗しました");
+        assert_eq!(ext.direction(), RtpTransceiverDirection::Stopped);
+    }
+
+    drop(negotiated);
+    drop(read_back);
+    drop(extensions);
+    drop(transceiver);
+    drop(pc);
+    drop(pc_deps);
+    drop(caps);
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn loopback_connects_peers_and_delivers_data_channel() {
+    struct DataChannelForwarder {
+        tx: mpsc::Sender<DataChannel>,
+    }
+    impl PeerConnectionObserverHandler for DataChannelForwarder {
+        fn on_data_channel(&mut self, data_channel: DataChannel) {
+            let _ = self.tx.send(data_channel);
+        }
+    }
+
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let (tx, rx) = mpsc::channel();
+    let mut loopback = loopback::Loopback::new_with_handlers(
+        &factory,
+        &mut PeerConnectionRtcConfiguration::new(),
+        Box::new(NoopHandler),
+        &factory,
+        &mut PeerConnectionRtcConfiguration::new(),
+        Box::new(DataChannelForwarder { tx }),
+    )
+    .expect("Loopback の生成に失敗しました");
+    let dc = loopback
+        .offerer()
+        .create_data_channel("loopback", &mut DataChannelInit::new())
+        .expect("DataChannel の生成に失敗しました");
+
+    loopback
+        .connect(Duration::from_secs(10))
+        .expect("Loopback の接続に失敗しました");
+    assert_eq!(
+        loopback.offerer().peer_connection_state(),
+        PeerConnectionState::Connected
+    );
+    assert_eq!(
+        loopback.answerer().peer_connection_state(),
+        PeerConnectionState::Connected
+    );
+    let remote_dc = rx
+        .recv_timeout(Duration::from_secs(10))
+        .expect("answer 側に DataChannel が届きませんでした");
+    assert_eq!(remote_dc.label().unwrap(), "loopback");
+    // 接続後に収集された candidate も相手側へ渡せる。
+    loopback
+        .forward_candidates()
+        .expect("candidate の転送に失敗しました");
+    assert_eq!(
+        loopback.offerer().peer_connection_state(),
+        PeerConnectionState::Connected
+    );
+
+    drop(remote_dc);
+    drop(dc);
+    drop(loopback);
+    drop(factory);
+    drop(adm);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn network_emulation_connects_peers_over_impaired_link() {
+    let mut config = BuiltInNetworkBehaviorConfig::new();
+    assert_eq!(config.link_capacity_kbps(), None);
+    config.set_loss_percent(5);
+    config.set_link_capacity_kbps(Some(300));
+    config.set_queue_delay_ms(20);
+    config.set_delay_standard_deviation_ms(5);
+    assert_eq!(config.loss_percent(), 5);
+    assert_eq!(config.link_capacity_kbps(), Some(300));
+    assert_eq!(config.queue_delay_ms(), 20);
+    assert_eq!(config.delay_standard_deviation_ms(), 5);
+    // 往復時間の下限を確かめるため、遅延の揺らぎはなくしておく。
+    config.set_delay_standard_deviation_ms(0);
+
+    let emulation = NetworkEmulationManager::new();
+    let (network_offer, network_answer) =
+        emulation.create_endpoint_pair_with_two_way_routes(&config);
+
+    // エミュレートされたネットワークごとに PeerConnectionFactory を用意する。
+    let mut threads = Vec::new();
+    let mut factories = Vec::new();
+    let mut adms = Vec::new();
+    let env = Environment::new();
+    for network in [network_offer, network_answer] {
+        let dec = AudioDecoderFactory::builtin();
+        let enc = AudioEncoderFactory::builtin();
+        let apb = AudioProcessingBuilder::new_builtin();
+        let mut deps_factory = PeerConnectionFactoryDependencies::new();
+        let mut worker = Thread::new();
+        let mut signaling = Thread::new();
+        worker.start();
+        signaling.start();
+        // Safety: emulation はすべての factory と PeerConnection より後に破棄する。
+        unsafe { deps_factory.set_emulated_network(network) };
+        deps_factory.set_worker_thread(&worker);
+        deps_factory.set_signaling_thread(&signaling);
+        deps_factory.set_audio_encoder_factory(&enc);
+        deps_factory.set_audio_decoder_factory(&dec);
+        deps_factory.set_audio_processing_builder(apb);
+        let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+            .expect("AudioDeviceModule の生成に失敗しました");
+        deps_factory.set_audio_device_module(&adm);
+        deps_factory.enable_media();
+        let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+            .expect("PeerConnectionFactory の生成に失敗しました");
+        threads.push((worker, signaling));
+        factories.push(factory);
+        adms.push(adm);
+    }
+
+    let mut loopback = loopback::Loopback::new_with_factories(&factories[0], &factories[1])
+        .expect("Loopback の生成に失敗しました");
+    let dc = loopback
+        .offerer()
+        .create_data_channel("emulated", &mut DataChannelInit::new())
+        .expect("DataChannel の生成に失敗しました");
+    loopback
+        .connect(Duration::from_secs(20))
+        .expect("エミュレートされたネットワーク上での接続に失敗しました");
+    assert_eq!(
+        loopback.answerer().peer_connection_state(),
+        PeerConnectionState::Connected
+    );
+
+    // 設定した片道の遅延が candidate-pair の往復時間に反映される。
+    let deadline = std::time::Instant::now() + Duration::from_secs(10);
+    let rtt = loop {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "candidate-pair の往復時間を取得できませんでした"
+        );
+        let (tx, rx) = mpsc::channel();
+        loopback.offerer().get_stats(move |report| {
+            let _ = tx.send(report);
+        });
+        let report = rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("GetStats がタイムアウトしました");
+        let rtt = report
+            .stats_of_type::<RTCIceCandidatePairStats>()
+            .expect("candidate-pair の取得に失敗しました")
+            .into_iter()
+            .filter(|s| s.nominated == Some(true))
+            .find_map(|s| s.current_round_trip_time);
+        if let Some(rtt) = rtt {
+            break rtt;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    };
+    let min_rtt = 2.0 * config.queue_delay_ms() as f64 / 1000.0;
+    assert!(
+        rtt >= min_rtt,
+        "往復時間 {rtt} 秒が設定した遅延 {min_rtt} 秒より短いです"
+    );
+
+    drop(dc);
+    drop(loopback);
+    drop(factories);
+    drop(adms);
+    for (mut worker, mut signaling) in threads {
+        worker.stop();
+        signaling.stop();
+    }
+    drop(emulation);
+}
+
+#[test]
+fn peer_connection_uses_custom_port_allocator() {
+    struct TestNetworkManager {
+        dropped: Arc<AtomicBool>,
+    }
+
+    impl NetworkManagerHandler for TestNetworkManager {
+        fn networks(&mut self) -> Vec<Network> {
+            let mut network = Network::new("test0", "test", "192.0.2.0", 24, AdapterType::Ethernet)
+                .expect("Network の生成に失敗しました");
+            network
+                .add_ip("192.0.2.1")
+                .expect("IP アドレスの追加に失敗しました");
+            vec![network]
+        }
+    }
+
+    impl Drop for TestNetworkManager {
+        fn drop(&mut self) {
+            self.dropped.store(true, Ordering::SeqCst);
+        }
+    }
+
+    struct TestSocket {
+        host: String,
+    }
+
+    impl AsyncPacketSocketHandler for TestSocket {
+        fn local_address(&mut self) -> SocketAddress {
+            SocketAddress::new(&self.host, 50000)
+        }
+
+        fn send_to(&mut self, data: &[u8], _address: SocketAddressRef<'_>, _packet_id: i64) -> i32 {
+            data.len() as i32
+        }
+    }
+
+    struct TestSocketFactory {
+        tx: mpsc::Sender<String>,
+    }
+
+    impl PacketSocketFactoryHandler for TestSocketFactory {
+        fn create_udp_socket(
+            &mut self,
+            address: SocketAddressRef<'_>,
+            _min_port: u16,
+            _max_port: u16,
+        ) -> Option<AsyncPacketSocket> {
+            let host = address.host_as_uri_string().ok()?;
+            let _ = self.tx.send(host.clone());
+            Some(AsyncPacketSocket::new_with_handler(Box::new(TestSocket {
+                host,
+            })))
+        }
+    }
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    let env = Environment::new();
+    deps_factory.set_env(&env);
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let (tx, rx) = mpsc::channel();
+    let network_manager = NetworkManager::new_with_handler(Box::new(TestNetworkManager {
+        dropped: dropped.clone(),
+    }));
+    let socket_factory = PacketSocketFactory::new_with_handler(Box::new(TestSocketFactory { tx }));
+
+    // candidate pool を使い、offer を作らずに候補収集を開始させる。
+    let mut config = PeerConnectionRtcConfiguration::new();
+    config.set_ice_candidate_pool_size(1);
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps = PeerConnectionDependencies::new(&observer);
+    deps.set_port_allocator(&env, network_manager, socket_factory);
+    let pc = PeerConnection::create(&factory, &mut config, &mut deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let host = rx
+        .recv_timeout(Duration::from_secs(5))
+        .expect("create_udp_socket が呼ばれませんでした");
+    assert_eq!(host, "192.0.2.1");
+
+    drop(pc);
+    drop(deps);
+    drop(factory);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+    assert!(
+        dropped.load(Ordering::SeqCst),
+        "NetworkManagerHandler が解放されていません"
+    );
+}
+
+#[test]
+fn async_packet_socket_notifier_delivers_stun_response() {
+    struct TestNetworkManager;
+
+    impl NetworkManagerHandler for TestNetworkManager {
+        fn networks(&mut self) -> Vec<Network> {
+            let mut network = Network::new("test0", "test", "192.0.2.0", 24, AdapterType::Ethernet)
+                .expect("Network の生成に失敗しました");
+            network
+                .add_ip("192.0.2.1")
+                .expect("IP アドレスの追加に失敗しました");
+            vec![network]
+        }
+    }
+
+    struct TestSocket {
+        tx: mpsc::Sender<Vec<u8>>,
+    }
+
+    impl AsyncPacketSocketHandler for TestSocket {
+        fn local_address(&mut self) -> SocketAddress {
+            SocketAddress::new("192.0.2.1", 50000)
+        }
+
+        fn send_to(&mut self, data: &[u8], address: SocketAddressRef<'_>, _packet_id: i64) -> i32 {
+            if address.to_string().ok().as_deref() == Some("192.0.2.10:3478") {
+                let _ = self.tx.send(data.to_vec());
+            }
+            data.len() as i32
+        }
+    }
+
+    struct TestSocketFactory {
+        sent_tx: mpsc::Sender<Vec<u8>>,
+        notifier_tx: mpsc::Sender<AsyncPacketSocketNotifier>,
+    }
+
+    impl PacketSocketFactoryHandler for TestSocketFactory {
+        fn create_udp_socket(
+            &mut self,
+            _address: SocketAddressRef<'_>,
+            _min_port: u16,
+            _max_port: u16,
+        ) -> Option<AsyncPacketSocket> {
+            let socket = AsyncPacketSocket::new_with_handler(Box::new(TestSocket {
+                tx: self.sent_tx.clone(),
+            }));
+            let _ = self.notifier_tx.send(socket.notifier());
+            Some(socket)
+        }
+    }
+
+    struct CandidateHandler {
+        tx: mpsc::Sender<(IceCandidateType, String)>,
+    }
+
+    impl PeerConnectionObserverHandler for CandidateHandler {
+        fn on_ice_candidate(&mut self, candidate: IceCandidateRef<'_>) {
+            let candidate = candidate.candidate();
+            let address = candidate.address().to_string().unwrap_or_default();
+            let _ = self.tx.send((candidate.candidate_type(), address));
+        }
+    }
+
+    // XOR-MAPPED-ADDRESS に 203.0.113.5:40000 を入れた Binding Success Response を組み立てる。
+    fn stun_binding_response(request: &[u8]) -> Vec<u8> {
+        assert!(request.len() >= 20);
+        assert_eq!(
+            &request[0..2],
+            &[0x00, 0x01],
+            "Binding Request ではありません"
+        );
+        let mut response = vec![0x01, 0x01, 0x00, 0x0c];
+        // magic cookie とトランザクション ID はリクエストのものを使う。
+        response.extend_from_slice(&request[4..20]);
+        response.extend_from_slice(&[0x00, 0x20, 0x00, 0x08, 0x00, 0x01]);
+        response.extend_from_slice(&(40000u16 ^ 0x2112).to_be_bytes());
+        let address = u32::from_be_bytes([203, 0, 113, 5]) ^ 0x2112_a442;
+        response.extend_from_slice(&address.to_be_bytes());
+        response
+    }
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    let env = Environment::new();
+    deps_factory.set_env(&env);
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let (sent_tx, sent_rx) = mpsc::channel();
+    let (notifier_tx, notifier_rx) = mpsc::channel();
+    let (candidate_tx, candidate_rx) = mpsc::channel();
+    let network_manager = NetworkManager::new_with_handler(Box::new(TestNetworkManager));
+    let socket_factory = PacketSocketFactory::new_with_handler(Box::new(TestSocketFactory {
+        sent_tx,
+        notifier_tx,
+    }));
+
+    let mut config = PeerConnectionRtcConfiguration::new();
+    config.set_always_negotiate_data_channels(true);
+    let mut server = IceServer::new();
+    server.add_url("stun:192.0.2.10:3478");
+    config.servers().push(&server);
+    let observer =
+        PeerConnectionObserver::new_with_handler(Box::new(CandidateHandler { tx: candidate_tx }));
+    let mut deps = PeerConnectionDependencies::new(&observer);
+    deps.set_port_allocator(&env, network_manager, socket_factory);
+    let pc = PeerConnection::create(&factory, &mut config, &mut deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let timeout = Duration::from_secs(5);
+    let opts = PeerConnectionOfferAnswerOptions::new();
+    let offer = block_on_with_timeout(pc.create_offer_async(&opts), timeout)
+        .expect("create_offer_async が失敗しました");
+    block_on_with_timeout(pc.set_local_description_async(offer), timeout)
+        .expect("set_local_description_async が失敗しました");
+
+    let notifier = notifier_rx
+        .recv_timeout(timeout)
+        .expect("create_udp_socket が呼ばれませんでした");
+    let request = sent_rx
+        .recv_timeout(timeout)
+        .expect("STUN サーバーへ送信されませんでした");
+    let response = stun_binding_response(&request);
+    // STUN サーバーからの応答を network thread で受信させる。
+    let delivered = network.blocking_call(move || {
+        let source = SocketAddress::new("192.0.2.10", 3478);
+        // Safety: network thread 上で、ソケットのコールバックの外から通知する。
+        unsafe { notifier.notify_packet_received(&response, source.as_ref(), None) }
+    });
+    assert!(delivered, "ソケットが既に破棄されています");
+
+    // 応答の XOR-MAPPED-ADDRESS から srflx candidate が生成される。
+    let deadline = std::time::Instant::now() + timeout;
+    loop {
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        let (candidate_type, address) = candidate_rx
+            .recv_timeout(remaining)
+            .expect("srflx candidate が生成されませんでした");
+        if candidate_type == IceCandidateType::Srflx {
+            assert_eq!(address, "203.0.113.5:40000");
+            break;
+        }
+    }
+
+    drop(pc);
+    drop(deps);
+    drop(factory);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn peer_connection_uses_custom_dns_resolver_and_mdns_responder() {
+    struct TestNetworkManager;
+
+    impl NetworkManagerHandler for TestNetworkManager {
+        fn networks(&mut self) -> Vec<Network> {
+            let mut network = Network::new("test0", "test", "192.0.2.0", 24, AdapterType::Ethernet)
+                .expect("Network の生成に失敗しました");
+            network
+                .add_ip("192.0.2.1")
+                .expect("IP アドレスの追加に失敗しました");
+            vec![network]
+        }
+    }
+
+    struct TestMdnsResponder {
+        tx: mpsc::Sender<String>,
+    }
+
+    impl MdnsResponderHandler for TestMdnsResponder {
+        fn create_name_for_address(&mut self, address: &str) -> String {
+            let _ = self.tx.send(address.to_owned());
+            "fixture-host.local".to_owned()
+        }
+
+        fn remove_name_for_address(&mut self, _address: &str) -> bool {
+            true
+        }
+    }
+
+    struct TestResolver {
+        tx: mpsc::Sender<String>,
+    }
+
+    impl AsyncDnsResolverHandler for TestResolver {
+        fn resolve(
+            &mut self,
+            address: SocketAddressRef<'_>,
+            _family: AddressFamily,
+            request: AsyncDnsResolveRequest,
+        ) {
+            let hostname = address.hostname().unwrap_or_default();
+            let _ = self.tx.send(hostname.clone());
+            if hostname == "stun.example.test" {
+                request.resolve(&[std::net::Ipv4Addr::new(192, 0, 2, 10).into()]);
+            } else {
+                request.fail(-1);
+            }
+        }
+    }
+
+    struct TestSocket {
+        tx: mpsc::Sender<String>,
+    }
+
+    impl AsyncPacketSocketHandler for TestSocket {
+        fn local_address(&mut self) -> SocketAddress {
+            SocketAddress::new("192.0.2.1", 50000)
+        }
+
+        fn send_to(&mut self, data: &[u8], address: SocketAddressRef<'_>, _packet_id: i64) -> i32 {
+            let _ = self.tx.send(address.to_string().unwrap_or_default());
+            data.len() as i32
+        }
+    }
+
+    struct TestSocketFactory {
+        tx: mpsc::Sender<String>,
+    }
+
+    impl PacketSocketFactoryHandler for TestSocketFactory {
+        fn create_udp_socket(
+            &mut self,
+            _address: SocketAddressRef<'_>,
+            _min_port: u16,
+            _max_port: u16,
+        ) -> Option<AsyncPacketSocket> {
+            Some(AsyncPacketSocket::new_with_handler(Box::new(TestSocket {
+                tx: self.tx.clone(),
+            })))
+        }
+    }
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    let env = Environment::new();
+    deps_factory.set_env(&env);
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let (mdns_tx, mdns_rx) = mpsc::channel();
+    let (resolve_tx, resolve_rx) = mpsc::channel();
+    let (sent_tx, sent_rx) = mpsc::channel();
+    let mut network_manager = NetworkManager::new_with_handler(Box::new(TestNetworkManager));
+    network_manager
+        .set_mdns_responder(MdnsResponder::new_with_handler(Box::new(
+            TestMdnsResponder { tx: mdns_tx },
+        )))
+        .expect("mDNS responder の設定に失敗しました");
+    let mut socket_factory =
+        PacketSocketFactory::new_with_handler(Box::new(TestSocketFactory { tx: sent_tx }));
+    socket_factory.set_async_dns_resolver_factory(AsyncDnsResolverFactory::new_with_handler(
+        Box::new(TestResolver {
+            tx: resolve_tx.clone(),
+        }),
+    ));
+
+    let mut config = PeerConnectionRtcConfiguration::new();
+    config.set_ice_candidate_pool_size(1);
+    let mut server = IceServer::new();
+    server.add_url("stun:stun.example.test:3478");
+    config.servers().push(&server);
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps = PeerConnectionDependencies::new(&observer);
+    deps.set_port_allocator(&env, network_manager, socket_factory);
+    deps.set_async_dns_resolver_factory(AsyncDnsResolverFactory::new_with_handler(Box::new(
+        TestResolver { tx: resolve_tx },
+    )));
+    let pc = PeerConnection::create(&factory, &mut config, &mut deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    // ホスト candidate の IP アドレスに mDNS 名が払い出される。
+    let address = mdns_rx
+        .recv_timeout(Duration::from_secs(5))
+        .expect("create_name_for_address が呼ばれませんでした");
+    assert_eq!(address, "192.0.2.1");
+
+    // STUN サーバーのホスト名が handler で解決され、その IP アドレスへ送信される。
+    let hostname = resolve_rx
+        .recv_timeout(Duration::from_secs(5))
+        .expect("resolve が呼ばれませんでした");
+    assert_eq!(hostname, "stun.example.test");
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    loop {
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        let destination = sent_rx
+            .recv_timeout(remaining)
+            .expect("解決した STUN サーバーへ送信されませんでした");
+        if destination == "192.0.2.10:3478" {
+            break;
+        }
+    }
+
+    drop(pc);
+    drop(deps);
+    drop(factory);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn basic_network_manager_obfuscates_host_candidates_with_mdns() {
+    struct TestMdnsResponder {
+        tx: mpsc::Sender<String>,
+    }
+
+    impl MdnsResponderHandler for TestMdnsResponder {
+        fn create_name_for_address(&mut self, address: &str) -> String {
+            let _ = self.tx.send(address.to_owned());
+            "fixture-host.local".to_owned()
+        }
+
+        fn remove_name_for_address(&mut self, _address: &str) -> bool {
+            true
+        }
+    }
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    let env = Environment::new();
+    deps_factory.set_env(&env);
+    let (mdns_tx, mdns_rx) = mpsc::channel();
+    // SAFETY: network は factory を破棄してから停止・破棄する。
+    let mut network_manager = unsafe {
+        NetworkManager::new_basic(
+            &env,
+            &network,
+            Some(MdnsResponder::new_with_handler(Box::new(
+                TestMdnsResponder { tx: mdns_tx },
+            ))),
+        )
+    };
+    // new_basic で生成したものには後から mDNS responder を設定できない。
+    let (unused_tx, _unused_rx) = mpsc::channel();
+    assert!(
+        network_manager
+            .set_mdns_responder(MdnsResponder::new_with_handler(Box::new(
+                TestMdnsResponder { tx: unused_tx },
+            )))
+            .is_err()
+    );
+    deps_factory.set_network_manager(network_manager);
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut config = PeerConnectionRtcConfiguration::new();
+    config.set_ice_candidate_pool_size(1);
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut config, &mut deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    // 既定の NetworkManager でもホスト candidate の IP アドレスに mDNS 名が払い出される。
+    let address = mdns_rx
+        .recv_timeout(Duration::from_secs(5))
+        .expect("create_name_for_address が呼ばれませんでした");
+    assert!(address.parse::<std::net::IpAddr>().is_ok());
+
+    drop(pc);
+    drop(deps);
+    drop(factory);
+    drop(deps_factory);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn environment_builder_applies_field_trials() {
+    struct TestFieldTrials {
+        keys: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl FieldTrialsHandler for TestFieldTrials {
+        fn lookup(&self, key: &str) -> Option<String> {
+            self.keys.lock().unwrap().push(key.to_owned());
+            None
+        }
+    }
+
+    assert!(
+        EnvironmentBuilder::new()
+            .set_field_trials("WebRTC-Foo")
+            .is_err()
+    );
+    assert!(
+        EnvironmentBuilder::new()
+            .set_field_trials("/Enabled/")
+            .is_err()
+    );
+    let _env = EnvironmentBuilder::new()
+        .set_field_trials("WebRTC-Foo/Enabled/WebRTC-Bar/Disabled/")
+        .expect("field trial 文字列の検証に失敗しました")
+        .build();
+
+    let keys = Arc::new(Mutex::new(Vec::new()));
+    let env = EnvironmentBuilder::new()
+        .set_field_trials_handler(Box::new(TestFieldTrials { keys: keys.clone() }))
+        .build();
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_env(&env);
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut config = PeerConnectionRtcConfiguration::new();
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut config, &mut deps)
+        .expect("PeerConnection の生成に失敗しました");
+    assert!(
+        !keys.lock().unwrap().is_empty(),
+        "FieldTrialsHandler::lookup が呼ばれませんでした"
+    );
+
+    drop(pc);
+    drop(deps);
+    drop(factory);
+    drop(deps_factory);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+    assert_eq!(
+        Arc::strong_count(&keys),
+        1,
+        "FieldTrialsHandler が解放されていません"
+    );
+}
+
+#[test]
+fn environment_builder_uses_simulated_time_controller() {
+    let controller = SimulatedTimeController::new(Duration::from_secs(1000));
+    let webrtc_clock = controller.create_clock();
+    assert_eq!(webrtc_clock.current_time(), Duration::from_secs(1000));
+    controller.advance_time(Duration::from_millis(1500));
+    assert_eq!(controller.now(), Duration::from_millis(1_001_500));
+    assert_eq!(
+        webrtc_clock.current_time(),
+        Duration::from_millis(1_001_500)
+    );
+
+    let env = EnvironmentBuilder::new()
+        .set_clock(webrtc_clock)
+        .set_task_queue_factory(controller.create_task_queue_factory())
+        .build();
+
+    // 遅延タスクは時刻を期限まで進めたときに実行される。
+    let queue = env
+        .task_queue_factory()
+        .create_task_queue("simulated_test_queue", TaskQueuePriority::Normal);
+    let (task_tx, task_rx) = mpsc::channel();
+    let immediate_tx = task_tx.clone();
+    queue.post_task(move || {
+        let _ = immediate_tx.send("immediate");
+    });
+    assert_eq!(
+        task_rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("タスクが実行されませんでした"),
+        "immediate"
+    );
+    queue.post_delayed_task(
+        move || {
+            let _ = task_tx.send("delayed");
+        },
+        Duration::from_secs(60),
+    );
+    controller.advance_time(Duration::from_secs(59));
+    assert!(task_rx.try_recv().is_err());
+    // advance_time は期限に達したタスクの完了を待ってから戻る。
+    controller.advance_time(Duration::from_secs(1));
+    assert_eq!(task_rx.try_recv(), Ok("delayed"));
+    drop(queue);
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_env(&env);
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut config = PeerConnectionRtcConfiguration::new();
+    config.set_stats_timestamp_with_environment_clock(true);
+    assert!(config.stats_timestamp_with_environment_clock());
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut config, &mut deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let (tx, rx) = mpsc::channel();
+    pc.get_stats(move |report| {
+        let _ = tx.send(report);
+    });
+    let report = rx
+        .recv_timeout(Duration::from_secs(5))
+        .expect("GetStats がタイムアウトしました");
+    assert!(!report.is_empty());
+    // 統計情報のタイムスタンプはシミュレーション時刻になる。
+    assert_eq!(report.timestamp_us(), controller.now().as_micros() as i64);
+
+    drop(report);
+    drop(pc);
+    drop(deps);
+    drop(factory);
+    drop(deps_factory);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn environment_builder_uses_custom_task_queue_factory() {
+    struct CountingTaskQueue {
+        inner: Box<dyn TaskQueueHandler>,
+        posted: Arc<AtomicUsize>,
+        live: Arc<AtomicUsize>,
+    }
+
+    // Safety: 実行は StdThreadTaskQueue に委譲する。
+    unsafe impl TaskQueueHandler for CountingTaskQueue {
+        fn post_task(&self, task: QueuedTask) {
+            self.posted.fetch_add(1, Ordering::SeqCst);
+            self.inner.post_task(task);
+        }
+
+        fn post_delayed_task(&self, task: QueuedTask, delay: Duration, high_precision: bool) {
+            self.posted.fetch_add(1, Ordering::SeqCst);
+            self.inner.post_delayed_task(task, delay, high_precision);
+        }
+    }
+
+    impl Drop for CountingTaskQueue {
+        fn drop(&mut self) {
+            self.live.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    struct CountingTaskQueueFactory {
+        names: Arc<Mutex<Vec<String>>>,
+        posted: Arc<AtomicUsize>,
+        live: Arc<AtomicUsize>,
+    }
+
+    impl TaskQueueFactoryHandler for CountingTaskQueueFactory {
+        fn create_task_queue(
+            &self,
+            name: &str,
+            priority: TaskQueuePriority,
+        ) -> Box<dyn TaskQueueHandler> {
+            self.names.lock().unwrap().push(name.to_owned());
+            self.live.fetch_add(1, Ordering::SeqCst);
+            Box::new(CountingTaskQueue {
+                inner: StdThreadTaskQueueFactory.create_task_queue(name, priority),
+                posted: self.posted.clone(),
+                live: self.live.clone(),
+            })
+        }
+    }
+
+    let names = Arc::new(Mutex::new(Vec::new()));
+    let posted = Arc::new(AtomicUsize::new(0));
+    let live = Arc::new(AtomicUsize::new(0));
+    let env = EnvironmentBuilder::new()
+        .set_task_queue_factory(TaskQueueFactory::new_with_handler(Box::new(
+            CountingTaskQueueFactory {
+                names: names.clone(),
+                posted: posted.clone(),
+                live: live.clone(),
+            },
+        )))
+        .build();
+
+    let mut deps = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps.set_network_thread(&network);
+    deps.set_worker_thread(&worker);
+    deps.set_signaling_thread(&signaling);
+    deps.set_audio_encoder_factory(&AudioEncoderFactory::builtin());
+    deps.set_audio_decoder_factory(&AudioDecoderFactory::builtin());
+    deps.set_audio_processing_builder(AudioProcessingBuilder::new_builtin());
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps.set_audio_device_module(&adm);
+    deps.set_env(&env);
+    deps.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+    assert!(
+        !names.lock().unwrap().is_empty(),
+        "TaskQueueFactoryHandler::create_task_queue が呼ばれませんでした"
+    );
+
+    // Environment から生成したキューに投入したタスクが Rust のキューで実行される。
+    let queue = env
+        .task_queue_factory()
+        .create_task_queue("rust_test_queue", TaskQueuePriority::Normal);
+    assert!(names.lock().unwrap().iter().any(|n| n == "rust_test_queue"));
+    let posted_before = posted.load(Ordering::SeqCst);
+    let (tx, rx) = mpsc::channel();
+    queue.post_task({
+        let tx = tx.clone();
+        move || {
+            let _ = tx.send("task");
+        }
+    });
+    queue.post_delayed_task(
+        move || {
+            let _ = tx.send("delayed");
+        },
+        Duration::from_millis(10),
+    );
+    assert_eq!(rx.recv_timeout(Duration::from_secs(5)), Ok("task"));
+    assert_eq!(rx.recv_timeout(Duration::from_secs(5)), Ok("delayed"));
+    assert!(posted.load(Ordering::SeqCst) >= posted_before + 2);
+    drop(queue);
+
+    drop(factory);
+    drop(adm);
+    drop(deps);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+    assert_eq!(
+        live.load(Ordering::SeqCst),
+        0,
+        "TaskQueueHandler が解放されていません"
+    );
+    assert_eq!(
+        Arc::strong_count(&names),
+        1,
+        "TaskQueueFactoryHandler が解放されていません"
+    );
+}
+
+#[test]
+fn peer_connection_writes_rtc_event_log() {
+    struct TestOutput {
+        data: Arc<Mutex<Vec<u8>>>,
+        write_lens: Arc<Mutex<Vec<usize>>>,
+        dropped: Arc<AtomicBool>,
+    }
+
+    impl RtcEventLogOutputHandler for TestOutput {
+        fn write(&mut self, data: &[u8]) -> bool {
+            self.data.lock().unwrap().extend_from_slice(data);
+            self.write_lens.lock().unwrap().push(data.len());
+            true
+        }
+    }
+
+    impl Drop for TestOutput {
+        fn drop(&mut self) {
+            self.dropped.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_event_log_factory(RtcEventLogFactory::new());
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut config = PeerConnectionRtcConfiguration::new();
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut config, &mut deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let data = Arc::new(Mutex::new(Vec::new()));
+    let write_lens = Arc::new(Mutex::new(Vec::new()));
+    let dropped = Arc::new(AtomicBool::new(false));
+    let output = RtcEventLogOutput::new_with_handler(Box::new(TestOutput {
+        data: data.clone(),
+        write_lens: write_lens.clone(),
+        dropped: dropped.clone(),
+    }));
+    pc.start_rtc_event_log(output, None)
+        .expect("RtcEventLog の開始に失敗しました");
+    pc.stop_rtc_event_log();
+    assert!(
+        !data.lock().unwrap().is_empty(),
+        "RtcEventLog が書き出されていません"
+    );
+    assert!(
+        dropped.load(Ordering::SeqCst),
+        "RtcEventLogOutputHandler が解放されていません"
+    );
+
+    let path = std::env::temp_dir().join(format!(
+        "shiguredo_webrtc_rtc_event_log_{}.log",
+        std::process::id()
+    ));
+    let path_str = path
+        .to_str()
+        .expect("一時ファイルのパスが UTF-8 ではありません");
+    let output = RtcEventLogOutput::new_file(path_str, NonZeroUsize::new(1024 * 1024))
+        .expect("RtcEventLogOutput の生成に失敗しました");
+    pc.start_rtc_event_log(output, Some(Duration::from_millis(100)))
+        .expect("RtcEventLog の開始に失敗しました");
+    pc.stop_rtc_event_log();
+    let len = std::fs::metadata(&path)
+        .expect("RtcEventLog のファイルがありません")
+        .len();
+    let _ = std::fs::remove_file(&path);
+    assert!(len > 0, "RtcEventLog のファイルが空です");
+
+    // 上限なしで書き出した量の半分を上限にすると、上限に収まる先頭の書き込みだけが残る。
+    let uncapped_len = data.lock().unwrap().len();
+    let max_size_bytes =
+        NonZeroUsize::new(uncapped_len / 2).expect("RtcEventLog の出力が小さすぎます");
+    let first_write_len = write_lens.lock().unwrap()[0];
+    assert!(
+        first_write_len <= max_size_bytes.get(),
+        "先頭の書き込みが上限を超えています: {first_write_len} > {max_size_bytes}"
+    );
+    let output = RtcEventLogOutput::new_file(path_str, Some(max_size_bytes))
+        .expect("RtcEventLogOutput の生成に失敗しました");
+    pc.start_rtc_event_log(output, None)
+        .expect("RtcEventLog の開始に失敗しました");
+    pc.stop_rtc_event_log();
+    let len = std::fs::metadata(&path)
+        .expect("RtcEventLog のファイルがありません")
+        .len();
+    let _ = std::fs::remove_file(&path);
+    assert!(len > 0, "上限付きの RtcEventLog のファイルが空です");
+    assert!(
+        len <= max_size_bytes.get() as u64,
+        "RtcEventLog のファイルが上限を超えています: {len} > {max_size_bytes}"
+    );
+
+    assert!(RtcEventLogOutput::new_file("/nonexistent-dir/rtc_event_log.log", None).is_err());
+    assert!(RtcEventLogOutput::new_file(path_str, NonZeroUsize::new(usize::MAX)).is_err());
+    let _ = std::fs::remove_file(&path);
+
+    // 1 ミリ秒未満の output_period は受け付けない。
+    let output = RtcEventLogOutput::new_with_handler(Box::new(TestOutput {
+        data: Arc::new(Mutex::new(Vec::new())),
+        dropped: Arc::new(AtomicBool::new(false)),
+    }));
+    assert!(
+        pc.start_rtc_event_log(output, Some(Duration::from_micros(500)))
+            .is_err()
+    );
+
+    drop(pc);
+    drop(deps);
+    drop(factory);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn frame_encryptor_and_decryptor_round_trip_over_loopback() {
+    // VP8 のペイロードヘッダーを残すため先頭 10 バイトは暗号化しない。
+    const CLEAR_BYTES: usize = 10;
+    const KEY: u8 = 0x5a;
+    const TRAILER: u8 = 0xe2;
+
+    fn hash_bytes(data: &[u8]) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        data.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    struct XorEncryptor {
+        sent: Arc<Mutex<std::collections::HashSet<u64>>>,
+    }
+    impl FrameEncryptorHandler for XorEncryptor {
+        fn encrypt(
+            &mut self,
+            media_type: MediaType,
+            _ssrc: u32,
+            _additional_data: &[u8],
+            frame: &[u8],
+            encrypted_frame: &mut [u8],
+        ) -> Option<usize> {
+            assert_eq!(media_type, MediaType::Video);
+            self.sent.lock().unwrap().insert(hash_bytes(frame));
+            for (i, (dst, src)) in encrypted_frame.iter_mut().zip(frame).enumerate() {
+                *dst = if i < CLEAR_BYTES { *src } else { *src ^ KEY };
+            }
+            encrypted_frame[frame.len()] = TRAILER;
+            Some(frame.len() + 1)
+        }
+
+        fn get_max_ciphertext_byte_size(
+            &mut self,
+            _media_type: MediaType,
+            frame_size: usize,
+        ) -> usize {
+            frame_size + 1
+        }
+    }
+
+    struct XorDecryptor {
+        sent: Arc<Mutex<std::collections::HashSet<u64>>>,
+        matched: Arc<AtomicUsize>,
+        mismatched: Arc<AtomicUsize>,
+    }
+    impl FrameDecryptorHandler for XorDecryptor {
+        fn decrypt(
+            &mut self,
+            _media_type: MediaType,
+            _csrcs: &[u32],
+            _additional_data: &[u8],
+            encrypted_frame: &[u8],
+            frame: &mut [u8],
+        ) -> FrameDecryptorResult {
+            let Some((&TRAILER, body)) = encrypted_frame.split_last() else {
+                self.mismatched.fetch_add(1, Ordering::SeqCst);
+                return FrameDecryptorResult::failed_to_decrypt();
+            };
+            for (i, (dst, src)) in frame.iter_mut().zip(body).enumerate() {
+                *dst = if i < CLEAR_BYTES { *src } else { *src ^ KEY };
+            }
+            let plaintext = &frame[..body.len()];
+            if self.sent.lock().unwrap().contains(&hash_bytes(plaintext)) {
+                self.matched.fetch_add(1, Ordering::SeqCst);
+            } else {
+                self.mismatched.fetch_add(1, Ordering::SeqCst);
+            }
+            FrameDecryptorResult::ok(body.len())
+        }
+
+        fn get_max_plaintext_byte_size(
+            &mut self,
+            _media_type: MediaType,
+            encrypted_frame_size: usize,
+        ) -> usize {
+            encrypted_frame_size
+        }
+    }
+
+    let dec_audio = AudioDecoderFactory::builtin();
+    let enc_audio = AudioEncoderFactory::builtin();
+    let enc_video = VideoEncoderFactory::builtin();
+    let dec_video = VideoDecoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc_audio);
+    deps_factory.set_audio_decoder_factory(&dec_audio);
+    deps_factory.set_video_encoder_factory(enc_video);
+    deps_factory.set_video_decoder_factory(dec_video);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut loopback = loopback::Loopback::new(&factory).expect("Loopback の生成に失敗しました");
+    let mut source = AdaptedVideoTrackSource::new();
+    let vts = source.cast_to_video_track_source();
+    let track = factory
+        .create_video_track(&vts, "video-track-0")
+        .expect("VideoTrack の生成に失敗しました");
+    let stream_track = track.cast_to_media_stream_track();
+    let mut stream_ids = StringVector::new(0);
+    stream_ids.push(&CxxString::from_str("stream-0"));
+    let mut sender = loopback
+        .offerer()
+        .add_track(&stream_track, &stream_ids)
+        .expect("AddTrack が失敗しました");
+
+    let sent = Arc::new(Mutex::new(std::collections::HashSet::new()));
+    let matched = Arc::new(AtomicUsize::new(0));
+    let mismatched = Arc::new(AtomicUsize::new(0));
+    let encryptor = FrameEncryptor::new_with_handler(Box::new(XorEncryptor { sent: sent.clone() }));
+    sender.set_frame_encryptor(Some(&encryptor));
+
+    loopback
+        .connect(Duration::from_secs(10))
+        .expect("Loopback の接続に失敗しました");
+
+    // フレームを流し始める前に受信側の復号処理を設定する。
+    let decryptor = FrameDecryptor::new_with_handler(Box::new(XorDecryptor {
+        sent: sent.clone(),
+        matched: matched.clone(),
+        mismatched: mismatched.clone(),
+    }));
+    let mut receivers = loopback.answerer().get_receivers();
+    assert_eq!(receivers.len(), 1);
+    receivers[0].set_frame_decryptor(Some(&decryptor));
+
+    // 接続後にフレームを投入し、復号結果が暗号化前のフレームと一致することを確認する。
+    let buffer = I420Buffer::new(320, 240);
+    let frame_buffer = buffer.cast_to_video_frame_buffer();
+    let deadline = std::time::Instant::now() + Duration::from_secs(10);
+    while matched.load(Ordering::SeqCst) < 10 {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "復号されたフレームが届きませんでした"
+        );
+        let frame = VideoFrame::builder(&frame_buffer)
+            .set_timestamp_us(time_millis() * 1000)
+            .build();
+        source.on_frame(&frame);
+        std::thread::sleep(Duration::from_millis(33));
+    }
+    assert_eq!(mismatched.load(Ordering::SeqCst), 0);
+
+    drop(receivers);
+    drop(sender);
+    drop(loopback);
+    drop(decryptor);
+    drop(encryptor);
+    drop(stream_track);
+    drop(track);
+    drop(vts);
+    drop(source);
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn frame_transformer_modifies_and_drops_frames_over_loopback() {
+    const TRAILER: u8 = 0xe5;
+
+    #[derive(Default)]
+    struct TransformStats {
+        frames: AtomicUsize,
+        key_frames: AtomicUsize,
+        trailers: AtomicUsize,
+        mismatched: AtomicUsize,
+    }
+
+    // 送信側はフレーム末尾に 1 バイト追加する。
+    struct AppendTrailer {
+        stats: Arc<TransformStats>,
+        metadata: Arc<Mutex<Option<TransformableVideoFrameMetadata>>>,
+    }
+    impl FrameTransformerHandler for AppendTrailer {
+        fn transform(&mut self, mut frame: TransformableFrame) -> Option<TransformableFrame> {
+            if frame.direction() != TransformableFrameDirection::Sender
+                || frame.mime_type().ok().as_deref() != Some("video/VP8")
+            {
+                self.stats.mismatched.fetch_add(1, Ordering::SeqCst);
+                return Some(frame);
+            }
+            self.stats.frames.fetch_add(1, Ordering::SeqCst);
+            if frame.is_key_frame() == Some(true) {
+                self.stats.key_frames.fetch_add(1, Ordering::SeqCst);
+                *self.metadata.lock().unwrap() = frame.video_metadata();
+            }
+            let mut data = frame.data().to_vec();
+            data.push(TRAILER);
+            frame.set_data(&data);
+            Some(frame)
+        }
+    }
+
+    // 受信側は追加されたバイトを取り除く。drop が true の間はフレームを破棄する。
+    struct StripTrailer {
+        stats: Arc<TransformStats>,
+        drop_frames: Arc<AtomicBool>,
+    }
+    impl FrameTransformerHandler for StripTrailer {
+        fn transform(&mut self, mut frame: TransformableFrame) -> Option<TransformableFrame> {
+            if frame.direction() != TransformableFrameDirection::Receiver || !frame.is_video() {
+                self.stats.mismatched.fetch_add(1, Ordering::SeqCst);
+                return Some(frame);
+            }
+            self.stats.frames.fetch_add(1, Ordering::SeqCst);
+            if frame.is_key_frame() == Some(true) {
+                self.stats.key_frames.fetch_add(1, Ordering::SeqCst);
+            }
+            let data = frame.data().to_vec();
+            match data.split_last() {
+                Some((&TRAILER, body)) => {
+                    self.stats.trailers.fetch_add(1, Ordering::SeqCst);
+                    frame.set_data(body);
+                }
+                _ => {
+                    self.stats.mismatched.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+            if self.drop_frames.load(Ordering::SeqCst) {
+                return None;
+            }
+            Some(frame)
+        }
+    }
+
+    struct CountingSink {
+        decoded: Arc<AtomicUsize>,
+    }
+    impl VideoSinkHandler for CountingSink {
+        fn on_frame(&mut self, _frame: VideoFrameRef<'_>) {
+            self.decoded.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let dec_audio = AudioDecoderFactory::builtin();
+    let enc_audio = AudioEncoderFactory::builtin();
+    let enc_video = VideoEncoderFactory::builtin();
+    let dec_video = VideoDecoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new_with_socket_server();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc_audio);
+    deps_factory.set_audio_decoder_factory(&dec_audio);
+    deps_factory.set_video_encoder_factory(enc_video);
+    deps_factory.set_video_decoder_factory(dec_video);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut loopback = loopback::Loopback::new(&factory).expect("Loopback の生成に失敗しました");
+    let mut source = AdaptedVideoTrackSource::new();
+    let vts = source.cast_to_video_track_source();
+    let track = factory
+        .create_video_track(&vts, "video-track-0")
+        .expect("VideoTrack の生成に失敗しました");
+    let stream_track = track.cast_to_media_stream_track();
+    let mut stream_ids = StringVector::new(0);
+    stream_ids.push(&CxxString::from_str("stream-0"));
+    let mut sender = loopback
+        .offerer()
+        .add_track(&stream_track, &stream_ids)
+        .expect("AddTrack が失敗しました");
+
+    let sender_stats = Arc::new(TransformStats::default());
+    let metadata = Arc::new(Mutex::new(None));
+    let sender_transformer = FrameTransformer::new_with_handler(Box::new(AppendTrailer {
+        stats: sender_stats.clone(),
+        metadata: metadata.clone(),
+    }));
+    sender.set_frame_transformer(&sender_transformer);
+
+    loopback
+        .connect(Duration::from_secs(10))
+        .expect("Loopback の接続に失敗しました");
+
+    // フレームを流し始める前に受信側の変換処理を設定する。
+    let receiver_stats = Arc::new(TransformStats::default());
+    let drop_frames = Arc::new(AtomicBool::new(false));
+    let receiver_transformer = FrameTransformer::new_with_handler(Box::new(StripTrailer {
+        stats: receiver_stats.clone(),
+        drop_frames: drop_frames.clone(),
+    }));
+    let mut receivers = loopback.answerer().get_receivers();
+    assert_eq!(receivers.len(), 1);
+    receivers[0].set_frame_transformer(&receiver_transformer);
+    let decoded = Arc::new(AtomicUsize::new(0));
+    let sink = VideoSink::new_with_handler(Box::new(CountingSink {
+        decoded: decoded.clone(),
+    }));
+    let mut remote_track = receivers[0].track().cast_to_video_track();
+    remote_track.add_or_update_sink(&sink, &VideoSinkWants::new());
+
+    let buffer = I420Buffer::new(320, 240);
+    let frame_buffer = buffer.cast_to_video_frame_buffer();
+    let mut push_frames_until = |what: &str, done: &dyn Fn() -> bool| {
+        let deadline = std::time::Instant::now() + Duration::from_secs(10);
+        while !done() {
+            assert!(std::time::Instant::now() < deadline, "{what}");
+            let frame = VideoFrame::builder(&frame_buffer)
+                .set_timestamp_us(time_millis() * 1000)
+                .build();
+            source.on_frame(&frame);
+            std::thread::sleep(Duration::from_millis(33));
+        }
+    };
+
+    // 送信側で追加したバイトが受信側に届き、取り除いたフレームがデコードされる。
+    push_frames_until(
+        "デコードされたフレームが届きませんでした",
+        &|| decoded.load(Ordering::SeqCst) >= 10,
+    );
+    assert!(sender_stats.frames.load(Ordering::SeqCst) >= 10);
+    assert!(sender_stats.key_frames.load(Ordering::SeqCst) >= 1);
+    assert!(receiver_stats.key_frames.load(Ordering::SeqCst) >= 1);
+    assert!(receiver_stats.trailers.load(Ordering::SeqCst) >= 10);
+    assert_eq!(sender_stats.mismatched.load(Ordering::SeqCst), 0);
+    assert_eq!(receiver_stats.mismatched.load(Ordering::SeqCst), 0);
+    let metadata = metadata
+        .lock()
+        .unwrap()
+        .clone()
+        .expect("キーフレームのメタデータがありません");
+    assert!(metadata.width > 0 && metadata.height > 0);
+    assert_eq!(metadata.width as u32 * 3, metadata.height as u32 * 4);
+
+    // 受信側で破棄したフレームはデコーダーに届かない。
+    drop_frames.store(true, Ordering::SeqCst);
+    let dropped_from = receiver_stats.frames.load(Ordering::SeqCst) + 5;
+    push_frames_until(
+        "受信側の変換処理にフレームが届きませんでした",
+        &|| receiver_stats.frames.load(Ordering::SeqCst) >= dropped_from,
+    );
+    let decoded_before = decoded.load(Ordering::SeqCst);
+    let received_before = receiver_stats.frames.load(Ordering::SeqCst);
+    push_frames_until(
+        "受信側の変換処理にフレームが届きませんでした",
+        &|| receiver_stats.frames.load(Ordering::SeqCst) >= received_before + 10,
+    );
+    assert_eq!(decoded.load(Ordering::SeqCst), decoded_before);
+
+    remote_track.remove_sink(&sink);
+    drop(remote_track);
+    drop(sink);
+    drop(receivers);
+    drop(sender);
+    drop(loopback);
+    drop(receiver_transformer);
+    drop(sender_transformer);
+    drop(stream_track);
+    drop(track);
+    drop(vts);
+    drop(source);
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn peer_connection_create_and_transceiver() {
+    // Factory を組み立てる。
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    // PC 用の構成と observer/dependencies を準備する。
+    let mut pc_config = PeerConnectionRtcConfiguration::new();
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut pc_deps = PeerConnectionDependencies::new(&observer);
+
+    // PeerConnection を生成し、取得できることを確認する。
+    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
+        .expect("PeerConnection の生成に失敗しました");
+    assert!(!pc.as_ptr().is_null());
+
+    drop(pc);
+    drop(pc_deps);
+    drop(factory);
+    drop(deps_factory);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn peer_connection_lookup_dtls_transport() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut pc_config = PeerConnectionRtcConfiguration::new();
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut pc_deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let mut transceiver_init = RtpTransceiverInit::new();
+    transceiver_init.set_direction(RtpTransceiverDirection::SendRecv);
+    let _ = pc
+        .add_transceiver(MediaType::Audio, &mut transceiver_init)
+        .expect("transceiver の追加に失敗しました");
+
+    if let Some(dtls_transport) = pc.lookup_dtls_transport_by_mid("0") {
+        let observer = DtlsTransportObserver::new_with_handler(Box::new(NoopHandler));
+        let _ = dtls_transport.state();
+        dtls_transport.register_observer(&observer);
+        dtls_transport.unregister_observer();
+    }
+
+    drop(pc);
+    drop(pc_deps);
+    drop(factory);
+    drop(deps_factory);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn peer_connection_create_with_proxy_allocator() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let (factory, context) = PeerConnectionFactory::create_modular_with_context(&mut deps_factory)
+        .expect("PeerConnectionFactory と ConnectionContext の生成に失敗しました");
+
+    let network_manager = context.default_network_manager();
+    let socket_factory = context.default_socket_factory();
+    assert!(!network_manager.as_ptr().is_null());
+    assert!(!socket_factory.as_ptr().is_null());
+
+    let mut pc_config = PeerConnectionRtcConfiguration::new();
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut pc_deps = PeerConnectionDependencies::new(&observer);
+    pc_deps.set_proxy(
+        network_manager,
+        socket_factory,
+        "127.0.0.1",
+        8080,
+        "user",
+        "pass",
+        "shiguredo_webrtc test",
+    );
+    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
+        .expect("Proxy 設定付き PeerConnection の生成に失敗しました");
+    assert!(!pc.as_ptr().is_null());
+
+    drop(pc);
+    drop(pc_deps);
+    drop(context);
+    drop(factory);
+    drop(deps_factory);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn video_track_and_transceiver_with_track() {
+    let dec_audio = AudioDecoderFactory::builtin();
+    let enc_audio = AudioEncoderFactory::builtin();
+    let enc_video = VideoEncoderFactory::builtin();
+    let dec_video = VideoDecoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc_audio);
+    deps_factory.set_audio_decoder_factory(&dec_audio);
+    deps_factory.set_video_encoder_factory(enc_video);
+    deps_factory.set_video_decoder_factory(dec_video);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    // VideoTrack を生成する。
+    let mut source = AdaptedVideoTrackSource::new();
+    let vts = source.cast_to_video_track_source();
+    let track = factory
+        .create_video_track(&vts, "video-track-0")
+        .expect("VideoTrack の生成に失敗しました");
+    // ついでにフレーム投入 API も呼んでおく。
+    let buf = I420Buffer::new(2, 2);
+    let frame_buffer = buf.cast_to_video_frame_buffer();
+    let frame = VideoFrame::builder(&frame_buffer)
+        .set_timestamp_us(1_000_000)
+        .set_timestamp_rtp(0)
+        .build();
+    source.on_frame(&frame);
+
+    // PeerConnection を作成し、トラック付きで transceiver を追加する。
+    let mut pc_config = PeerConnectionRtcConfiguration::new();
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut pc_deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let mut init = RtpTransceiverInit::new();
+    init.set_direction(RtpTransceiverDirection::SendOnly);
+    pc.add_transceiver_with_track(&track, &mut init)
+        .expect("AddTransceiverWithTrack が失敗しました");
+
+    // webrtc オブジェクトを先に解放してからスレッドを停止する。
+    drop(pc);
+    drop(track);
+    drop(vts);
+    drop(source);
+    drop(pc_deps);
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn peer_connection_observer_and_dependencies() {
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let deps = PeerConnectionDependencies::new(&observer);
+    assert!(!deps.as_ptr().is_null());
+    drop(deps);
+}
+
+#[test]
+fn peer_connection_dependencies_set_tls_cert_verifier() {
+    struct TestVerifier {
+        dropped: Arc<AtomicBool>,
+    }
+
+    impl SSLCertificateVerifierHandler for TestVerifier {
+        fn verify_chain(&mut self, _chain: SSLCertChainRef<'_>) -> bool {
+            true
+        }
+    }
+
+    impl Drop for TestVerifier {
+        fn drop(&mut self) {
+            self.dropped.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps = PeerConnectionDependencies::new(&observer);
+    let verifier = SSLCertificateVerifier::new_with_handler(Box::new(TestVerifier {
+        dropped: dropped.clone(),
+    }));
+    deps.set_tls_cert_verifier(verifier);
+
+    drop(deps);
+    assert!(
+        dropped.load(Ordering::SeqCst),
+        "SSLCertificateVerifierHandler が解放されていません"
+    );
+}
+
+#[test]
+fn create_and_set_local_description_observers() {
+    let _create_obs = CreateSessionDescriptionObserver::new_with_handler(Box::new(NoopHandler));
+    let _set_local = SetLocalDescriptionObserver::new_with_handler(Box::new(NoopHandler));
+    let _set_remote = SetRemoteDescriptionObserver::new_with_handler(Box::new(NoopHandler));
+}
+
+#[test]
+fn always_negotiate_data_channels_adds_data_section() {
+    struct OfferHandler {
+        tx: mpsc::Sender<Result<String>>,
+    }
+
+    impl CreateSessionDescriptionObserverHandler for OfferHandler {
+        fn on_success(&mut self, desc: SessionDescription) {
+            let sdp = desc.to_string();
+            let _ = self.tx.send(sdp);
+        }
+
+        fn on_failure(&mut self, err: RtcError) {
+            let _ = self.tx.send(Err(err.into()));
+        }
+    }
+
+    fn create_offer_sdp(
+        factory: &PeerConnectionFactory,
+        config: &mut PeerConnectionRtcConfiguration,
+    ) -> String {
+        let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+        let mut pc_deps = PeerConnectionDependencies::new(&observer);
+        let pc = PeerConnection::create(factory, config, &mut pc_deps)
+            .expect("PeerConnection の生成に失敗しました");
+
+        let mut opts = PeerConnectionOfferAnswerOptions::new();
+        let (tx, rx) = mpsc::channel::<Result<String>>();
+        let mut obs =
+            CreateSessionDescriptionObserver::new_with_handler(Box::new(OfferHandler { tx }));
+        pc.create_offer(&mut obs, &mut opts);
+        let sdp = rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("createOffer がタイムアウトしました")
+            .expect("createOffer が失敗しました");
+
+        drop(obs);
+        drop(pc);
+        drop(pc_deps);
+        sdp
+    }
+
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    // always_negotiate_data_channels=true かつ DataChannel 未生成でも m=application が含まれる。
+    let mut pc_config_on = PeerConnectionRtcConfiguration::new();
+    pc_config_on.set_always_negotiate_data_channels(true);
+    let sdp_on = create_offer_sdp(&factory, &mut pc_config_on);
+    assert!(
+        sdp_on.contains("m=application"),
+        "always_negotiate_data_channels=true で SDP に m=application が含まれません: {sdp_on}"
+    );
+
+    // 対照実験: デフォルト (false) で DataChannel 未生成なら m=application は含まれない。
+    let mut pc_config_off = PeerConnectionRtcConfiguration::new();
+    let sdp_off = create_offer_sdp(&factory, &mut pc_config_off);
+    assert!(
+        !sdp_off.contains("m=application"),
+        "always_negotiate_data_channels=false で SDP に m=application が含まれました: {sdp_off}"
+    );
+
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn offer_answer_async_round_trip() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut config_offer = PeerConnectionRtcConfiguration::new();
+    let observer_offer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps_offer = PeerConnectionDependencies::new(&observer_offer);
+    let pc_offer = PeerConnection::create(&factory, &mut config_offer, &mut deps_offer)
+        .expect("offer 側 PeerConnection の生成に失敗しました");
+    let mut config_answer = PeerConnectionRtcConfiguration::new();
+    let observer_answer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps_answer = PeerConnectionDependencies::new(&observer_answer);
+    let pc_answer = PeerConnection::create(&factory, &mut config_answer, &mut deps_answer)
+        .expect("answer 側 PeerConnection の生成に失敗しました");
+
+    let mut init = RtpTransceiverInit::new();
+    init.set_direction(RtpTransceiverDirection::SendRecv);
+    pc_offer
+        .add_transceiver(MediaType::Audio, &mut init)
+        .expect("transceiver の追加に失敗しました");
+
+    let timeout = Duration::from_secs(5);
+    let opts = PeerConnectionOfferAnswerOptions::new();
+    let offer = block_on_with_timeout(pc_offer.create_offer_async(&opts), timeout)
+        .expect("create_offer_async が失敗しました");
+    assert_eq!(offer.sdp_type(), SdpType::Offer);
+    let offer_sdp = offer.to_string().expect("offer SDP の取得に失敗しました");
+    assert!(offer_sdp.contains("m=audio"));
+    block_on_with_timeout(pc_offer.set_local_description_async(offer), timeout)
+        .expect("set_local_description_async が失敗しました");
+
+    let remote_offer = SessionDescription::new(SdpType::Offer, &offer_sdp)
+        .expect("offer SDP のパースに失敗しました");
+    block_on_with_timeout(
+        pc_answer.set_remote_description_async(remote_offer),
+        timeout,
+    )
+    .expect("set_remote_description_async が失敗しました");
+    let answer = block_on_with_timeout(pc_answer.create_answer_async(&opts), timeout)
+        .expect("create_answer_async が失敗しました");
+    assert_eq!(answer.sdp_type(), SdpType::Answer);
+    let answer_sdp = answer.to_string().expect("answer SDP の取得に失敗しました");
+    block_on_with_timeout(pc_answer.set_local_description_async(answer), timeout)
+        .expect("set_local_description_async が失敗しました");
+
+    let remote_answer = SessionDescription::new(SdpType::Answer, &answer_sdp)
+        .expect("answer SDP のパースに失敗しました");
+    block_on_with_timeout(
+        pc_offer.set_remote_description_async(remote_answer),
+        timeout,
+    )
+    .expect("set_remote_description_async が失敗しました");
+
+    // stable 状態で answer を適用すると RTCError が Err として返る。
+    let unexpected_answer = SessionDescription::new(SdpType::Answer, &answer_sdp)
+        .expect("answer SDP のパースに失敗しました");
+    let result = block_on_with_timeout(
+        pc_offer.set_remote_description_async(unexpected_answer),
+        timeout,
+    );
+    assert!(
+        matches!(result, Err(Error::RtcError(_))),
+        "stable 状態での answer 適用が成功しました"
+    );
+
+    drop(pc_offer);
+    drop(pc_answer);
+    drop(deps_offer);
+    drop(deps_answer);
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn peer_connection_state_getters_follow_negotiation() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut config_offer = PeerConnectionRtcConfiguration::new();
+    let observer_offer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps_offer = PeerConnectionDependencies::new(&observer_offer);
+    let pc_offer = PeerConnection::create(&factory, &mut config_offer, &mut deps_offer)
+        .expect("offer 側 PeerConnection の生成に失敗しました");
+    let mut config_answer = PeerConnectionRtcConfiguration::new();
+    let observer_answer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps_answer = PeerConnectionDependencies::new(&observer_answer);
+    let pc_answer = PeerConnection::create(&factory, &mut config_answer, &mut deps_answer)
+        .expect("answer 側 PeerConnection の生成に失敗しました");
+
+    assert_eq!(pc_offer.signaling_state(), SignalingState::Stable);
+    assert_eq!(pc_offer.ice_connection_state(), IceConnectionState::New);
+    assert_eq!(
+        pc_offer.standardized_ice_connection_state(),
+        IceConnectionState::New
+    );
+    assert_eq!(pc_offer.peer_connection_state(), PeerConnectionState::New);
+    assert_eq!(pc_offer.ice_gathering_state(), IceGatheringState::New);
+    assert!(pc_offer.local_description().is_none());
+    assert!(pc_offer.remote_description().is_none());
+    assert!(pc_offer.get_transceivers().is_empty());
+    assert!(pc_offer.get_senders().is_empty());
+    assert!(pc_offer.get_receivers().is_empty());
+
+    let mut init = RtpTransceiverInit::new();
+    init.set_direction(RtpTransceiverDirection::SendRecv);
+    pc_offer
+        .add_transceiver(MediaType::Audio, &mut init)
+        .expect("transceiver の追加に失敗しました");
+    assert_eq!(pc_offer.get_transceivers().len(), 1);
+    assert_eq!(pc_offer.get_senders().len(), 1);
+    assert_eq!(pc_offer.get_receivers().len(), 1);
+
+    let timeout = Duration::from_secs(5);
+    let opts = PeerConnectionOfferAnswerOptions::new();
+    let offer = block_on_with_timeout(pc_offer.create_offer_async(&opts), timeout)
+        .expect("create_offer_async が失敗しました");
+    let offer_sdp = offer.to_string().expect("offer SDP の取得に失敗しました");
+    block_on_with_timeout(pc_offer.set_local_description_async(offer), timeout)
+        .expect("set_local_description_async が失敗しました");
+    assert_eq!(pc_offer.signaling_state(), SignalingState::HaveLocalOffer);
+    let pending = pc_offer
+        .pending_local_description()
+        .expect("pending_local_description が None です");
+    assert_eq!(pending.sdp_type(), SdpType::Offer);
+    assert!(pc_offer.current_local_description().is_none());
+    let local = pc_offer
+        .local_description()
+        .expect("local_description が None です");
+    assert_eq!(
+        local.to_string().expect("local SDP の取得に失敗しました"),
+        offer_sdp
+    );
+
+    let remote_offer = SessionDescription::new(SdpType::Offer, &offer_sdp)
+        .expect("offer SDP のパースに失敗しました");
+    block_on_with_timeout(
+        pc_answer.set_remote_description_async(remote_offer),
+        timeout,
+    )
+    .expect("set_remote_description_async が失敗しました");
+    assert_eq!(pc_answer.signaling_state(), SignalingState::HaveRemoteOffer);
+    assert!(pc_answer.pending_remote_description().is_some());
+    assert_eq!(pc_answer.get_transceivers().len(), 1);
+
+    let answer = block_on_with_timeout(pc_answer.create_answer_async(&opts), timeout)
+        .expect("create_answer_async が失敗しました");
+    let answer_sdp = answer.to_string().expect("answer SDP の取得に失敗しました");
+    block_on_with_timeout(pc_answer.set_local_description_async(answer), timeout)
+        .expect("set_local_description_async が失敗しました");
+    assert_eq!(pc_answer.signaling_state(), SignalingState::Stable);
+    assert!(pc_answer.current_local_description().is_some());
+    assert!(pc_answer.current_remote_description().is_some());
+    assert!(pc_answer.pending_remote_description().is_none());
+
+    let remote_answer = SessionDescription::new(SdpType::Answer, &answer_sdp)
+        .expect("answer SDP のパースに失敗しました");
+    block_on_with_timeout(
+        pc_offer.set_remote_description_async(remote_answer),
+        timeout,
+    )
+    .expect("set_remote_description_async が失敗しました");
+    assert_eq!(pc_offer.signaling_state(), SignalingState::Stable);
+    assert!(pc_offer.pending_local_description().is_none());
+    let current_remote = pc_offer
+        .current_remote_description()
+        .expect("current_remote_description が None です");
+    assert_eq!(current_remote.sdp_type(), SdpType::Answer);
+
+    pc_offer.close();
+    assert_eq!(pc_offer.signaling_state(), SignalingState::Closed);
+    assert_eq!(
+        pc_offer.peer_connection_state(),
+        PeerConnectionState::Closed
+    );
+
+    drop(pc_offer);
+    drop(pc_answer);
+    drop(deps_offer);
+    drop(deps_answer);
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn peer_connection_event_receiver_disconnects_when_observer_dropped() {
+    let (observer, receiver) = PeerConnectionObserver::new_with_channel(8);
+    assert!(matches!(
+        receiver.try_recv(),
+        Err(mpsc::TryRecvError::Empty)
+    ));
+    assert!(matches!(
+        receiver.recv_timeout(Duration::from_millis(10)),
+        Err(mpsc::RecvTimeoutError::Timeout)
+    ));
+
+    drop(observer);
+    assert!(receiver.recv().is_err());
+    assert!(block_on_with_timeout(receiver.recv_async(), Duration::from_secs(1)).is_none());
+    assert_eq!(receiver.dropped_events(), 0);
+}
+
+#[cfg(feature = "stream")]
+#[test]
+fn peer_connection_event_receiver_is_stream() {
+    fn assert_stream<S: futures_core::Stream<Item = PeerConnectionEvent> + Unpin>(_: &S) {}
+
+    let (observer, receiver) = PeerConnectionObserver::new_with_channel(8);
+    assert_stream(&receiver);
+    drop(observer);
+    let mut receiver = std::pin::pin!(receiver);
+    let next = std::future::poll_fn(|cx| futures_core::Stream::poll_next(receiver.as_mut(), cx));
+    assert!(block_on_with_timeout(next, Duration::from_secs(1)).is_none());
+}
+
+#[test]
+fn peer_connection_event_channel_delivers_ice_gathering_events() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut pc_config = PeerConnectionRtcConfiguration::new();
+    pc_config.set_always_negotiate_data_channels(true);
+    let (observer, receiver) = PeerConnectionObserver::new_with_channel(64);
+    let mut pc_deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let timeout = Duration::from_secs(5);
+    let opts = PeerConnectionOfferAnswerOptions::new();
+    let offer = block_on_with_timeout(pc.create_offer_async(&opts), timeout)
+        .expect("create_offer_async が失敗しました");
+    block_on_with_timeout(pc.set_local_description_async(offer), timeout)
+        .expect("set_local_description_async が失敗しました");
+
+    // setLocalDescription により ICE の収集が始まり、完了まで通知される。
+    let mut gathering = false;
+    loop {
+        let event = block_on_with_timeout(receiver.recv_async(), Duration::from_secs(10))
+            .expect("イベントチャネルが閉じられました");
+        match event {
+            PeerConnectionEvent::IceGatheringChange(IceGatheringState::Gathering) => {
+                gathering = true;
+            }
+            PeerConnectionEvent::IceGatheringChange(IceGatheringState::Complete) => break,
+            PeerConnectionEvent::IceCandidate(candidate) => {
+                assert!(candidate.candidate.starts_with("candidate:"));
+                candidate
+                    .to_ice_candidate()
+                    .expect("IceCandidateInit から IceCandidate を生成できません");
+            }
+            _ => {}
+        }
+    }
+    assert!(gathering, "Gathering への遷移が通知されていません");
+    assert_eq!(receiver.dropped_events(), 0);
+
+    drop(pc);
+    drop(pc_deps);
+    drop(observer);
+    // observer 破棄後は残りのイベントを取り出し終えると切断が通知される。
+    while receiver.recv().is_ok() {}
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn peer_connection_event_channel_reports_lagged_events() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    // capacity 1 のキューを読まずに放置して、イベントを溢れさせる。
+    let mut pc_config = PeerConnectionRtcConfiguration::new();
+    pc_config.set_always_negotiate_data_channels(true);
+    let (observer, receiver) = PeerConnectionObserver::new_with_channel(1);
+    let mut pc_deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    let timeout = Duration::from_secs(5);
+    let opts = PeerConnectionOfferAnswerOptions::new();
+    let offer = block_on_with_timeout(pc.create_offer_async(&opts), timeout)
+        .expect("create_offer_async が失敗しました");
+    block_on_with_timeout(pc.set_local_description_async(offer), timeout)
+        .expect("set_local_description_async が失敗しました");
+    let deadline = std::time::Instant::now() + Duration::from_secs(10);
+    while pc.ice_gathering_state() != IceGatheringState::Complete {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "ICE の収集が完了しませんでした"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    let dropped = receiver.dropped_events();
+    assert!(dropped > 0, "イベントが破棄されていません");
+
+    // 破棄した件数は Lagged として通知され、その合計は dropped_events と一致する。
+    drop(pc);
+    drop(pc_deps);
+    drop(observer);
+    let mut lagged = 0;
+    while let Ok(event) = receiver.recv() {
+        if let PeerConnectionEvent::Lagged(n) = event {
+            assert!(n > 0);
+            lagged += n;
+        }
+    }
+    assert_eq!(lagged, receiver.dropped_events());
+    assert!(lagged >= dropped);
+
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn peer_connection_event_channel_delivers_negotiation_and_signaling_events() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut pc_config = PeerConnectionRtcConfiguration::new();
+    let (observer, receiver) = PeerConnectionObserver::new_with_channel(64);
+    let mut pc_deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
+        .expect("PeerConnection の生成に失敗しました");
+
+    // transceiver を追加するとネゴシエーションが必要になる。
+    let mut init = RtpTransceiverInit::new();
+    init.set_direction(RtpTransceiverDirection::SendRecv);
+    pc.add_transceiver(MediaType::Audio, &mut init)
+        .expect("transceiver の追加に失敗しました");
+    let event_id = loop {
+        let event = receiver
+            .recv_timeout(Duration::from_secs(5))
+            .expect("NegotiationNeeded が通知されませんでした");
+        if let PeerConnectionEvent::NegotiationNeeded(event_id) = event {
+            break event_id;
+        }
+    };
+    assert!(pc.should_fire_negotiation_needed_event(event_id));
+
+    let timeout = Duration::from_secs(5);
+    let opts = PeerConnectionOfferAnswerOptions::new();
+    let offer = block_on_with_timeout(pc.create_offer_async(&opts), timeout)
+        .expect("create_offer_async が失敗しました");
+    block_on_with_timeout(pc.set_local_description_async(offer), timeout)
+        .expect("set_local_description_async が失敗しました");
+    loop {
+        let event = receiver
+            .recv_timeout(Duration::from_secs(5))
+            .expect("SignalingChange が通知されませんでした");
+        if let PeerConnectionEvent::SignalingChange(state) = event {
+            assert_eq!(state, SignalingState::HaveLocalOffer);
+            break;
+        }
+    }
+    // stable 以外の signaling 状態では発火不要と判定される。
+    assert!(!pc.should_fire_negotiation_needed_event(event_id));
+
+    drop(pc);
+    drop(pc_deps);
+    drop(observer);
+    while receiver.recv().is_ok() {}
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn session_description_rollback_round_trip() {
+    let rollback =
+        SessionDescription::new(SdpType::Rollback, "").expect("rollback の生成に失敗しました");
+    assert_eq!(rollback.sdp_type(), SdpType::Rollback);
+    let sdp = rollback
+        .to_string()
+        .expect("rollback の文字列化に失敗しました");
+    assert_eq!(sdp, "");
+    let again =
+        SessionDescription::new(SdpType::Rollback, &sdp).expect("rollback の再生成に失敗しました");
+    assert_eq!(again.sdp_type(), SdpType::Rollback);
+}
+
+#[test]
+fn perfect_negotiation_resolves_glare() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
+
+    let mut config_polite = PeerConnectionRtcConfiguration::new();
+    config_polite.set_enable_implicit_rollback(true);
+    assert!(config_polite.enable_implicit_rollback());
+    let observer_polite = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps_polite = PeerConnectionDependencies::new(&observer_polite);
+    let polite = PeerConnection::create(&factory, &mut config_polite, &mut deps_polite)
+        .expect("polite 側 PeerConnection の生成に失敗しました");
+    let mut config_impolite = PeerConnectionRtcConfiguration::new();
+    let observer_impolite = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
+    let mut deps_impolite = PeerConnectionDependencies::new(&observer_impolite);
+    let impolite = PeerConnection::create(&factory, &mut config_impolite, &mut deps_impolite)
+        .expect("impolite 側 PeerConnection の生成に失敗しました");
+
+    for pc in [&polite, &impolite] {
+        let mut init = RtpTransceiverInit::new();
+        init.set_direction(RtpTransceiverDirection::SendRecv);
+        pc.add_transceiver(MediaType::Audio, &mut init)
+            .expect("transceiver の追加に失敗しました");
+    }
+
+    let polite_making_offer = Arc::new(AtomicBool::new(false));
+    let impolite_making_offer = Arc::new(AtomicBool::new(false));
+
+    // 両側が同時に offer を作成して適用する。
+    let timeout = Duration::from_secs(5);
+    let polite_offer_sdp = {
+        block_on_with_timeout(polite.set_local_description_implicit_async(), timeout)
+            .expect("polite 側の set_local_description_implicit_async が失敗しました");
+        polite
+            .local_description()
+            .expect("polite 側の local_description が None です")
+            .to_string()
+            .expect("offer SDP の取得に失敗しました")
+    };
+    // 衝突の判定は poll 時に行うため、impolite 側が offer を適用する前に
+    // Future を作っておいても衝突として扱われる。
+    let offer = SessionDescription::new(SdpType::Offer, &polite_offer_sdp)
+        .expect("offer SDP のパースに失敗しました");
+    let impolite_handle =
+        impolite.handle_remote_description_async(offer, false, &impolite_making_offer);
+    let impolite_offer_sdp = {
+        block_on_with_timeout(impolite.set_local_description_implicit_async(), timeout)
+            .expect("impolite 側の set_local_description_implicit_async が失敗しました");
+        impolite
+            .local_description()
+            .expect("impolite 側の local_description が None です")
+            .to_string()
+            .expect("offer SDP の取得に失敗しました")
+    };
+    assert_eq!(polite.signaling_state(), SignalingState::HaveLocalOffer);
+    assert_eq!(impolite.signaling_state(), SignalingState::HaveLocalOffer);
+
+    // impolite 側は衝突した offer を無視する。
+    let outcome = block_on_with_timeout(impolite_handle, timeout)
+        .expect("impolite 側の handle_remote_description_async が失敗しました");
+    assert!(matches!(outcome, RemoteDescriptionOutcome::Ignored));
+    assert_eq!(impolite.signaling_state(), SignalingState::HaveLocalOffer);
+
+    // polite 側は自身の offer を暗黙に rollback して相手の offer を受け入れる。
+    let offer = SessionDescription::new(SdpType::Offer, &impolite_offer_sdp)
+        .expect("offer SDP のパースに失敗しました");
+    let outcome = block_on_with_timeout(
+        polite.handle_remote_description_async(offer, true, &polite_making_offer),
+        timeout,
+    )
+    .expect("polite 側の handle_remote_description_async が失敗しました");
+    let RemoteDescriptionOutcome::Answered(answer) = outcome else {
+        panic!("polite 側で answer が生成されませんでした");
+    };
+    assert_eq!(answer.sdp_type(), SdpType::Answer);
+    assert_eq!(polite.signaling_state(), SignalingState::Stable);
+
+    let answer_sdp = answer.to_string().expect("answer SDP の取得に失敗しました");
+    let answer = SessionDescription::new(SdpType::Answer, &answer_sdp)
+        .expect("answer SDP のパースに失敗しました");
+    let outcome = block_on_with_timeout(
+        impolite.handle_remote_description_async(answer, false, &impolite_making_offer),
+        timeout,
+    )
+    .expect("impolite 側の answer 適用に失敗しました");
+    assert!(matches!(outcome, RemoteDescriptionOutcome::Applied));
+    assert_eq!(impolite.signaling_state(), SignalingState::Stable);
+    assert!(impolite.current_remote_description().is_some());
+
+    drop(polite);
+    drop(impolite);
+    drop(deps_polite);
+    drop(deps_impolite);
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
+}
+
+#[test]
+fn perfect_negotiation_reads_making_offer_when_polled() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
     network.start();
     worker.start();
     signaling.start();
@@ -2977,48 +6591,79 @@ fn peer_connection_create_with_proxy_allocator() {
         .expect("AudioDeviceModule の生成に失敗しました");
     deps_factory.set_audio_device_module(&adm);
     deps_factory.enable_media();
-    let (factory, context) = PeerConnectionFactory::create_modular_with_context(&mut deps_factory)
-        .expect("PeerConnectionFactory と ConnectionContext の生成に失敗しました");
-
-    let network_manager = context.default_network_manager();
-    let socket_factory = context.default_socket_factory();
-    assert!(!network_manager.as_ptr().is_null());
-    assert!(!socket_factory.as_ptr().is_null());
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
 
-    let mut pc_config = PeerConnectionRtcConfiguration::new();
-    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
-    let mut pc_deps = PeerConnectionDependencies::new(&observer);
-    pc_deps.set_proxy(
-        network_manager,
-        socket_factory,
-        "127.0.0.1",
-        8080,
-        "user",
-        "pass",
-        "shiguredo_webrtc test",
+    let loopback = loopback::Loopback::new(&factory).expect("Loopback の生成に失敗しました");
+    let mut init = RtpTransceiverInit::new();
+    init.set_direction(RtpTransceiverDirection::SendRecv);
+    loopback
+        .offerer()
+        .add_transceiver(MediaType::Audio, &mut init)
+        .expect("transceiver の追加に失敗しました");
+    let timeout = Duration::from_secs(5);
+    block_on_with_timeout(
+        loopback.offerer().set_local_description_implicit_async(),
+        timeout,
+    )
+    .expect("set_local_description_implicit_async が失敗しました");
+    let offer_sdp = loopback
+        .offerer()
+        .local_description()
+        .expect("local_description が None です")
+        .to_string()
+        .expect("offer SDP の取得に失敗しました");
+
+    // Future を作った後に offer の作成を始めた場合も、poll 時のフラグで衝突と判定する。
+    let making_offer = Arc::new(AtomicBool::new(false));
+    let offer = SessionDescription::new(SdpType::Offer, &offer_sdp)
+        .expect("offer SDP のパースに失敗しました");
+    let handle = loopback
+        .answerer()
+        .handle_remote_description_async(offer, false, &making_offer);
+    making_offer.store(true, Ordering::SeqCst);
+    let outcome = block_on_with_timeout(handle, timeout)
+        .expect("impolite 側の handle_remote_description_async が失敗しました");
+    assert!(matches!(outcome, RemoteDescriptionOutcome::Ignored));
+    assert_eq!(
+        loopback.answerer().signaling_state(),
+        SignalingState::Stable
+    );
+    assert!(loopback.answerer().remote_description().is_none());
+
+    // offer の作成が終わっていれば衝突ではないため受け入れる。
+    let offer = SessionDescription::new(SdpType::Offer, &offer_sdp)
+        .expect("offer SDP のパースに失敗しました");
+    let handle = loopback
+        .answerer()
+        .handle_remote_description_async(offer, false, &making_offer);
+    making_offer.store(false, Ordering::SeqCst);
+    let outcome = block_on_with_timeout(handle, timeout)
+        .expect("handle_remote_description_async が失敗しました");
+    let RemoteDescriptionOutcome::Answered(answer) = outcome else {
+        panic!("answer が生成されませんでした");
+    };
+    assert_eq!(answer.sdp_type(), SdpType::Answer);
+    assert_eq!(
+        loopback.answerer().signaling_state(),
+        SignalingState::Stable
     );
-    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
-        .expect("Proxy 設定付き PeerConnection の生成に失敗しました");
-    assert!(!pc.as_ptr().is_null());
 
-    drop(pc);
-    drop(pc_deps);
-    drop(context);
+    drop(loopback);
     drop(factory);
     drop(deps_factory);
+    drop(adm);
+    drop(env);
     network.stop();
     worker.stop();
     signaling.stop();
 }
 
 #[test]
-fn video_track_and_transceiver_with_track() {
-    let dec_audio = AudioDecoderFactory::builtin();
-    let enc_audio = AudioEncoderFactory::builtin();
-    let enc_video = VideoEncoderFactory::builtin();
-    let dec_video = VideoDecoderFactory::builtin();
+fn perfect_negotiation_rolls_back_explicitly_without_implicit_rollback() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
     let apb = AudioProcessingBuilder::new_builtin();
-
     let mut deps_factory = PeerConnectionFactoryDependencies::new();
     let mut network = Thread::new();
     let mut worker = Thread::new();
@@ -3029,10 +6674,8 @@ fn video_track_and_transceiver_with_track() {
     deps_factory.set_network_thread(&network);
     deps_factory.set_worker_thread(&worker);
     deps_factory.set_signaling_thread(&signaling);
-    deps_factory.set_audio_encoder_factory(&enc_audio);
-    deps_factory.set_audio_decoder_factory(&dec_audio);
-    deps_factory.set_video_encoder_factory(enc_video);
-    deps_factory.set_video_decoder_factory(dec_video);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
     deps_factory.set_audio_processing_builder(apb);
     let env = Environment::new();
     let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
@@ -3042,39 +6685,66 @@ fn video_track_and_transceiver_with_track() {
     let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
         .expect("PeerConnectionFactory の生成に失敗しました");
 
-    // VideoTrack を生成する。
-    let mut source = AdaptedVideoTrackSource::new();
-    let vts = source.cast_to_video_track_source();
-    let track = factory
-        .create_video_track(&vts, "video-track-0")
-        .expect("VideoTrack の生成に失敗しました");
-    // ついでにフレーム投入 API も呼んでおく。
-    let buf = I420Buffer::new(2, 2);
-    let frame_buffer = buf.cast_to_video_frame_buffer();
-    let frame = VideoFrame::builder(&frame_buffer)
-        .set_timestamp_us(1_000_000)
-        .set_timestamp_rtp(0)
-        .build();
-    source.on_frame(&frame);
-
-    // PeerConnection を作成し、トラック付きで transceiver を追加する。
-    let mut pc_config = PeerConnectionRtcConfiguration::new();
-    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
-    let mut pc_deps = PeerConnectionDependencies::new(&observer);
-    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
-        .expect("PeerConnection の生成に失敗しました");
-
-    let mut init = RtpTransceiverInit::new();
-    init.set_direction(RtpTransceiverDirection::SendOnly);
-    pc.add_transceiver_with_track(&track, &mut init)
-        .expect("AddTransceiverWithTrack が失敗しました");
+    // polite 側 (answerer) は implicit rollback を無効のままにする。
+    let mut config_polite = PeerConnectionRtcConfiguration::new();
+    assert!(!config_polite.enable_implicit_rollback());
+    let loopback = loopback::Loopback::new_with_handlers(
+        &factory,
+        &mut PeerConnectionRtcConfiguration::new(),
+        Box::new(NoopHandler),
+        &factory,
+        &mut config_polite,
+        Box::new(NoopHandler),
+    )
+    .expect("Loopback の生成に失敗しました");
+    let impolite = loopback.offerer();
+    let polite = loopback.answerer();
+    let polite_making_offer = Arc::new(AtomicBool::new(false));
+    let impolite_making_offer = Arc::new(AtomicBool::new(false));
+
+    let timeout = Duration::from_secs(5);
+    let mut offer_sdps = Vec::new();
+    for pc in [impolite, polite] {
+        let mut init = RtpTransceiverInit::new();
+        init.set_direction(RtpTransceiverDirection::SendRecv);
+        pc.add_transceiver(MediaType::Audio, &mut init)
+            .expect("transceiver の追加に失敗しました");
+        block_on_with_timeout(pc.set_local_description_implicit_async(), timeout)
+            .expect("set_local_description_implicit_async が失敗しました");
+        assert_eq!(pc.signaling_state(), SignalingState::HaveLocalOffer);
+        offer_sdps.push(
+            pc.local_description()
+                .expect("local_description が None です")
+                .to_string()
+                .expect("offer SDP の取得に失敗しました"),
+        );
+    }
 
-    // webrtc オブジェクトを先に解放してからスレッドを停止する。
-    drop(pc);
-    drop(track);
-    drop(vts);
-    drop(source);
-    drop(pc_deps);
+    // polite 側は自身の offer を明示的に rollback して相手の offer を受け入れる。
+    let offer = SessionDescription::new(SdpType::Offer, &offer_sdps[0])
+        .expect("offer SDP のパースに失敗しました");
+    let outcome = block_on_with_timeout(
+        polite.handle_remote_description_async(offer, true, &polite_making_offer),
+        timeout,
+    )
+    .expect("polite 側の handle_remote_description_async が失敗しました");
+    let RemoteDescriptionOutcome::Answered(answer) = outcome else {
+        panic!("polite 側で answer が生成されませんでした");
+    };
+    assert_eq!(polite.signaling_state(), SignalingState::Stable);
+
+    let answer_sdp = answer.to_string().expect("answer SDP の取得に失敗しました");
+    let answer = SessionDescription::new(SdpType::Answer, &answer_sdp)
+        .expect("answer SDP のパースに失敗しました");
+    let outcome = block_on_with_timeout(
+        impolite.handle_remote_description_async(answer, false, &impolite_making_offer),
+        timeout,
+    )
+    .expect("impolite 側の answer 適用に失敗しました");
+    assert!(matches!(outcome, RemoteDescriptionOutcome::Applied));
+    assert_eq!(impolite.signaling_state(), SignalingState::Stable);
+
+    drop(loopback);
     drop(factory);
     drop(deps_factory);
     drop(adm);
@@ -3085,100 +6755,108 @@ fn video_track_and_transceiver_with_track() {
 }
 
 #[test]
-fn peer_connection_observer_and_dependencies() {
-    let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
-    let deps = PeerConnectionDependencies::new(&observer);
-    assert!(!deps.as_ptr().is_null());
-    drop(deps);
-}
-
-#[test]
-fn peer_connection_dependencies_set_tls_cert_verifier() {
-    struct TestVerifier {
-        dropped: Arc<AtomicBool>,
-    }
-
-    impl SSLCertificateVerifierHandler for TestVerifier {
-        fn verify_chain(&mut self, _chain: SSLCertChainRef<'_>) -> bool {
-            true
-        }
-    }
-
-    impl Drop for TestVerifier {
-        fn drop(&mut self) {
-            self.dropped.store(true, Ordering::SeqCst);
-        }
-    }
+fn rtc_stats_report_iterate_and_lookup() {
+    let dec = AudioDecoderFactory::builtin();
+    let enc = AudioEncoderFactory::builtin();
+    let apb = AudioProcessingBuilder::new_builtin();
+    let mut deps_factory = PeerConnectionFactoryDependencies::new();
+    let mut network = Thread::new();
+    let mut worker = Thread::new();
+    let mut signaling = Thread::new();
+    network.start();
+    worker.start();
+    signaling.start();
+    deps_factory.set_network_thread(&network);
+    deps_factory.set_worker_thread(&worker);
+    deps_factory.set_signaling_thread(&signaling);
+    deps_factory.set_audio_encoder_factory(&enc);
+    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_processing_builder(apb);
+    let env = Environment::new();
+    let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
+        .expect("AudioDeviceModule の生成に失敗しました");
+    deps_factory.set_audio_device_module(&adm);
+    deps_factory.enable_media();
+    let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
+        .expect("PeerConnectionFactory の生成に失敗しました");
 
-    let dropped = Arc::new(AtomicBool::new(false));
+    let mut pc_config = PeerConnectionRtcConfiguration::new();
     let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
-    let mut deps = PeerConnectionDependencies::new(&observer);
-    let verifier = SSLCertificateVerifier::new_with_handler(Box::new(TestVerifier {
-        dropped: dropped.clone(),
-    }));
-    deps.set_tls_cert_verifier(verifier);
+    let mut pc_deps = PeerConnectionDependencies::new(&observer);
+    let pc = PeerConnection::create(&factory, &mut pc_config, &mut pc_deps)
+        .expect("PeerConnection の生成に失敗しました");
 
-    drop(deps);
+    let (tx, rx) = mpsc::channel();
+    pc.get_stats(move |report| {
+        let _ = tx.send(report);
+    });
+    let report = rx
+        .recv_timeout(Duration::from_secs(5))
+        .expect("GetStats がタイムアウトしました");
+
+    assert!(!report.is_empty());
+    assert!(report.timestamp_us() > 0);
+    assert_eq!(report.iter().count(), report.len());
+
+    // peer-connection の統計情報は常に含まれる。
+    let pc_stats = report
+        .iter_by_type("peer-connection")
+        .next()
+        .expect("peer-connection の統計情報がありません");
+    let id = pc_stats.id().expect("id の取得に失敗しました");
+    let found = report.get(&id).expect("id で統計情報を取得できません");
+    assert_eq!(
+        found.stats_type().expect("type の取得に失敗しました"),
+        "peer-connection"
+    );
+    let opened = found
+        .attribute("dataChannelsOpened")
+        .expect("属性の取得に失敗しました")
+        .expect("dataChannelsOpened が設定されていません");
+    assert_eq!(opened.as_u64(), Some(0));
     assert!(
-        dropped.load(Ordering::SeqCst),
-        "SSLCertificateVerifierHandler が解放されていません"
+        found
+            .cast_to::<RTCCodecStats>()
+            .expect("cast_to に失敗しました")
+            .is_none()
     );
-}
+    assert!(report.get("not-found").is_none());
 
-#[test]
-fn create_and_set_local_description_observers() {
-    let _create_obs = CreateSessionDescriptionObserver::new_with_handler(Box::new(NoopHandler));
-    let _set_local = SetLocalDescriptionObserver::new_with_handler(Box::new(NoopHandler));
-    let _set_remote = SetRemoteDescriptionObserver::new_with_handler(Box::new(NoopHandler));
+    // 交渉前なので inbound-rtp は存在しない。
+    let inbound = report
+        .stats_of_type::<RTCInboundRtpStreamStats>()
+        .expect("inbound-rtp の取得に失敗しました");
+    assert!(inbound.is_empty());
+
+    drop(report);
+    drop(pc);
+    drop(pc_deps);
+    drop(factory);
+    drop(deps_factory);
+    drop(adm);
+    drop(env);
+    network.stop();
+    worker.stop();
+    signaling.stop();
 }
 
 #[test]
-fn always_negotiate_data_channels_adds_data_section() {
-    struct OfferHandler {
-        tx: mpsc::Sender<Result<String>>,
-    }
-
-    impl CreateSessionDescriptionObserverHandler for OfferHandler {
-        fn on_success(&mut self, desc: SessionDescription) {
-            let sdp = desc.to_string();
-            let _ = self.tx.send(sdp);
-        }
-
-        fn on_failure(&mut self, err: RtcError) {
-            let _ = self.tx.send(Err(err.into()));
-        }
-    }
-
-    fn create_offer_sdp(
-        factory: &PeerConnectionFactory,
-        config: &mut PeerConnectionRtcConfiguration,
-    ) -> String {
-        let observer = PeerConnectionObserver::new_with_handler(Box::new(NoopHandler));
-        let mut pc_deps = PeerConnectionDependencies::new(&observer);
-        let pc = PeerConnection::create(factory, config, &mut pc_deps)
-            .expect("PeerConnection の生成に失敗しました");
-
-        let mut opts = PeerConnectionOfferAnswerOptions::new();
-        let (tx, rx) = mpsc::channel::<Result<String>>();
-        let mut obs =
-            CreateSessionDescriptionObserver::new_with_handler(Box::new(OfferHandler { tx }));
-        pc.create_offer(&mut obs, &mut opts);
-        let sdp = rx
-            .recv_timeout(Duration::from_secs(5))
-            .expect("createOffer がタイムアウトしました")
-            .expect("createOffer が失敗しました");
-
-        drop(obs);
-        drop(pc);
-        drop(pc_deps);
-        sdp
+fn peer_connection_get_stats_for_sender_and_receiver() {
+    fn get_report(request: impl FnOnce(mpsc::Sender<RTCStatsReport>)) -> RTCStatsReport {
+        let (tx, rx) = mpsc::channel();
+        request(tx);
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("GetStats がタイムアウトしました")
     }
 
-    let dec = AudioDecoderFactory::builtin();
-    let enc = AudioEncoderFactory::builtin();
+    let dec_audio = AudioDecoderFactory::builtin();
+    let enc_audio = AudioEncoderFactory::builtin();
+    let enc_video = VideoEncoderFactory::builtin();
+    let dec_video = VideoDecoderFactory::builtin();
     let apb = AudioProcessingBuilder::new_builtin();
+
     let mut deps_factory = PeerConnectionFactoryDependencies::new();
-    let mut network = Thread::new();
+    let mut network = Thread::new_with_socket_server();
     let mut worker = Thread::new();
     let mut signaling = Thread::new();
     network.start();
@@ -3187,8 +6865,10 @@ fn always_negotiate_data_channels_adds_data_section() {
     deps_factory.set_network_thread(&network);
     deps_factory.set_worker_thread(&worker);
     deps_factory.set_signaling_thread(&signaling);
-    deps_factory.set_audio_encoder_factory(&enc);
-    deps_factory.set_audio_decoder_factory(&dec);
+    deps_factory.set_audio_encoder_factory(&enc_audio);
+    deps_factory.set_audio_decoder_factory(&dec_audio);
+    deps_factory.set_video_encoder_factory(enc_video);
+    deps_factory.set_video_decoder_factory(dec_video);
     deps_factory.set_audio_processing_builder(apb);
     let env = Environment::new();
     let adm = AudioDeviceModule::new(&env, AudioDeviceModuleAudioLayer::Dummy)
@@ -3198,23 +6878,122 @@ fn always_negotiate_data_channels_adds_data_section() {
     let factory = PeerConnectionFactory::create_modular(&mut deps_factory)
         .expect("PeerConnectionFactory の生成に失敗しました");
 
-    // always_negotiate_data_channels=true かつ DataChannel 未生成でも m=application が含まれる。
-    let mut pc_config_on = PeerConnectionRtcConfiguration::new();
-    pc_config_on.set_always_negotiate_data_channels(true);
-    let sdp_on = create_offer_sdp(&factory, &mut pc_config_on);
+    let mut source = AdaptedVideoTrackSource::new();
+    let vts = source.cast_to_video_track_source();
+    let track = factory
+        .create_video_track(&vts, "video-track-stats")
+        .expect("VideoTrack の生成に失敗しました");
+
+    let mut loopback = loopback::Loopback::new(&factory).expect("Loopback の生成に失敗しました");
+    let stream_track = track.cast_to_media_stream_track();
+    let mut stream_ids = StringVector::new(0);
+    stream_ids.push(&CxxString::from_str("stream-0"));
+    let sender = loopback
+        .offerer()
+        .add_track(&stream_track, &stream_ids)
+        .expect("AddTrack が失敗しました");
+    loopback
+        .connect(Duration::from_secs(10))
+        .expect("Loopback の接続に失敗しました");
+    let receiver = loopback
+        .answerer()
+        .get_receivers()
+        .into_iter()
+        .next()
+        .expect("answer 側に RtpReceiver がありません");
+
+    // 受信側に inbound-rtp が現れるまでフレームを送り続ける。
+    let buffer = I420Buffer::new(320, 240);
+    let frame_buffer = buffer.cast_to_video_frame_buffer();
+    let deadline = std::time::Instant::now() + Duration::from_secs(10);
+    let receiver_report = loop {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "receiver の統計情報に inbound-rtp が現れませんでした"
+        );
+        for _ in 0..3 {
+            let frame = VideoFrame::builder(&frame_buffer)
+                .set_timestamp_us(time_millis() * 1000)
+                .build();
+            source.on_frame(&frame);
+            std::thread::sleep(Duration::from_millis(33));
+        }
+        let report = get_report(|tx| {
+            loopback
+                .answerer()
+                .get_stats_for_receiver(&receiver, move |report| {
+                    let _ = tx.send(report);
+                })
+        });
+        let received = report
+            .stats_of_type::<RTCInboundRtpStreamStats>()
+            .expect("inbound-rtp の取得に失敗しました")
+            .iter()
+            .any(|s| s.packets_received.unwrap_or(0) > 0);
+        if received {
+            break report;
+        }
+    };
+    let inbound = receiver_report
+        .stats_of_type::<RTCInboundRtpStreamStats>()
+        .expect("inbound-rtp の取得に失敗しました");
+    assert_eq!(inbound.len(), 1);
+    assert_eq!(inbound[0].kind.as_deref(), Some("video"));
     assert!(
-        sdp_on.contains("m=application"),
-        "always_negotiate_data_channels=true で SDP に m=application が含まれません: {sdp_on}"
+        receiver_report
+            .stats_of_type::<RTCOutboundRtpStreamStats>()
+            .expect("outbound-rtp の取得に失敗しました")
+            .is_empty(),
+        "receiver の統計情報に outbound-rtp が含まれています"
+    );
+
+    // sender の統計情報には送信した track の outbound-rtp と media-source が含まれる。
+    let sender_report = get_report(|tx| {
+        loopback
+            .offerer()
+            .get_stats_for_sender(&sender, move |report| {
+                let _ = tx.send(report);
+            })
+    });
+    let outbound = sender_report
+        .stats_of_type::<RTCOutboundRtpStreamStats>()
+        .expect("outbound-rtp の取得に失敗しました");
+    assert_eq!(outbound.len(), 1);
+    assert_eq!(outbound[0].kind.as_deref(), Some("video"));
+    assert_eq!(outbound[0].ssrc, Some(sender.ssrc() as u64));
+    let media_source_id = outbound[0]
+        .media_source_id
+        .clone()
+        .expect("outbound-rtp に mediaSourceId がありません");
+    let media_sources = sender_report
+        .stats_of_type::<RTCMediaSourceStats>()
+        .expect("media-source の取得に失敗しました");
+    let media_source = media_sources
+        .iter()
+        .find(|s| s.id == media_source_id)
+        .expect("sender の統計情報に media-source が含まれていません");
+    assert_eq!(
+        media_source.track_identifier.as_deref(),
+        Some("video-track-stats")
     );
-
-    // 対照実験: デフォルト (false) で DataChannel 未生成なら m=application は含まれない。
-    let mut pc_config_off = PeerConnectionRtcConfiguration::new();
-    let sdp_off = create_offer_sdp(&factory, &mut pc_config_off);
+    assert_eq!(media_source.kind.as_deref(), Some("video"));
     assert!(
-        !sdp_off.contains("m=application"),
-        "always_negotiate_data_channels=false で SDP に m=application が含まれました: {sdp_off}"
+        sender_report
+            .stats_of_type::<RTCInboundRtpStreamStats>()
+            .expect("inbound-rtp の取得に失敗しました")
+            .is_empty(),
+        "sender の統計情報に inbound-rtp が含まれています"
     );
 
+    drop(sender_report);
+    drop(receiver_report);
+    drop(receiver);
+    drop(sender);
+    drop(stream_track);
+    drop(loopback);
+    drop(track);
+    drop(vts);
+    drop(source);
     drop(factory);
     drop(deps_factory);
     drop(adm);
@@ -1,13 +1,21 @@
 #pragma once
 
 // IWYU pragma: begin_exports
+#include "webrtc_c/api/async_dns_resolver.h"
 #include "webrtc_c/api/audio/audio_device.h"
 #include "webrtc_c/api/audio/audio_processing.h"
 #include "webrtc_c/api/audio_codecs/audio_decoder_factory.h"
 #include "webrtc_c/api/audio_codecs/audio_encoder_factory.h"
+#include "webrtc_c/api/candidate.h"
+#include "webrtc_c/api/crypto/crypto_options.h"
+#include "webrtc_c/api/crypto/frame_decryptor_interface.h"
+#include "webrtc_c/api/crypto/frame_encryptor_interface.h"
+#include "webrtc_c/api/dtmf_sender_interface.h"
 #include "webrtc_c/api/environment.h"
+#include "webrtc_c/api/frame_transformer_interface.h"
 #include "webrtc_c/api/jsep.h"
 #include "webrtc_c/api/media_stream_interface.h"
+#include "webrtc_c/api/packet_socket_factory.h"
 #include "webrtc_c/api/media_types.h"
 #include "webrtc_c/api/peer_connection_interface.h"
 #include "webrtc_c/api/priority.h"
@@ -21,8 +29,13 @@
 #include "webrtc_c/api/rtp_transceiver_interface.h"
 #include "webrtc_c/api/set_local_description_observer_interface.h"
 #include "webrtc_c/api/set_remote_description_observer_interface.h"
+#include "webrtc_c/api/stats/attribute.h"
+#include "webrtc_c/api/stats/rtc_stats.h"
 #include "webrtc_c/api/stats/rtc_stats_collector_callback.h"
 #include "webrtc_c/api/stats/rtc_stats_report.h"
+#include "webrtc_c/api/task_queue/task_queue_factory.h"
+#include "webrtc_c/api/test/network_emulation_manager.h"
+#include "webrtc_c/api/transport/rtp/rtp_source.h"
 #include "webrtc_c/api/video/encoded_image.h"
 #include "webrtc_c/api/video/color_space.h"
 #include "webrtc_c/api/video/i420_buffer.h"
@@ -42,14 +55,28 @@
 #include "webrtc_c/common.h"
 #include "webrtc_c/libyuv.h"
 #include "webrtc_c/media/base/adapted_video_track_source.h"
+#include "webrtc_c/media/base/codec.h"
+#include "webrtc_c/media/base/stream_params.h"
 #include "webrtc_c/media/engine/simulcast_encoder_adapter.h"
 #include "webrtc_c/modules/video_coding/codecs/interface/common_constants.h"
 #include "webrtc_c/modules/video_coding/include/video_codec_interface.h"
+#include "webrtc_c/p2p/base/candidate_pair_interface.h"
+#include "webrtc_c/p2p/base/transport_description.h"
+#include "webrtc_c/p2p/client/basic_port_allocator.h"
 #include "webrtc_c/pc/connection_context.h"
+#include "webrtc_c/pc/session_description.h"
+#include "webrtc_c/pc/simulcast_description.h"
+#include "webrtc_c/rtc_base/async_packet_socket.h"
 #include "webrtc_c/rtc_base/crypto_random.h"
 #include "webrtc_c/rtc_base/logging.h"
+#include "webrtc_c/rtc_base/mdns_responder_interface.h"
+#include "webrtc_c/rtc_base/network.h"
+#include "webrtc_c/rtc_base/rtc_certificate.h"
+#include "webrtc_c/rtc_base/rtc_certificate_generator.h"
+#include "webrtc_c/rtc_base/socket_address.h"
 #include "webrtc_c/rtc_base/ssl_adapter.h"
 #include "webrtc_c/rtc_base/ssl_certificate.h"
+#include "webrtc_c/rtc_base/ssl_fingerprint.h"
 #include "webrtc_c/rtc_base/ssl_identity.h"
 #include "webrtc_c/rtc_base/thread.h"
 #include "webrtc_c/rtc_base/time_utils.h"
@@ -59,4 +86,5 @@
 #include "webrtc_c/sdk/objc/native/api/video_decoder_factory.h"
 #include "webrtc_c/sdk/objc/native/api/video_encoder_factory.h"
 #include "webrtc_c/std.h"
+#include "webrtc_c/system_wrappers/clock.h"
 // IWYU pragma: end_exports
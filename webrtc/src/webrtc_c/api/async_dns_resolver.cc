@@ -0,0 +1,295 @@
+#include "async_dns_resolver.h"
+
+#include <assert.h>
+#include <stdint.h>
+#include <string.h>
+#include <memory>
+#include <utility>
+#include <vector>
+
+// WebRTC
+#include <absl/functional/any_invocable.h>
+#include <api/async_dns_resolver.h>
+#include <api/scoped_refptr.h>
+#include <api/task_queue/pending_task_safety_flag.h>
+#include <api/task_queue/task_queue_base.h>
+#include <rtc_base/ip_address.h>
+#include <rtc_base/socket_address.h>
+#include <rtc_base/synchronization/mutex.h>
+#include <rtc_base/thread_annotations.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+#include "../rtc_base/socket_address.h"
+
+// -------------------------
+// webrtc::AsyncDnsResolverInterface
+// -------------------------
+
+namespace {
+
+class AsyncDnsResolverResultImpl : public webrtc::AsyncDnsResolverResult {
+ public:
+  bool GetResolvedAddress(int family,
+                          webrtc::SocketAddress* addr) const override {
+    for (const auto& ip : addresses_) {
+      if (ip.family() == family) {
+        *addr = address_;
+        addr->SetResolvedIP(ip);
+        return true;
+      }
+    }
+    return false;
+  }
+
+  int GetError() const override { return error_; }
+
+  webrtc::SocketAddress address_;
+  std::vector<webrtc::IPAddress> addresses_;
+  int error_ = 0;
+};
+
+// コールバックと user_data を factory と resolver で共有し、
+// 全て破棄された時点で OnDestroy を呼ぶ。
+class AsyncDnsResolverCallbacks {
+ public:
+  AsyncDnsResolverCallbacks(
+      const struct webrtc_AsyncDnsResolverFactory_cbs* cbs,
+      void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->Resolve != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~AsyncDnsResolverCallbacks() { cbs_.OnDestroy(user_data_); }
+
+  void Resolve(const webrtc::SocketAddress& address,
+               int family,
+               struct webrtc_AsyncDnsResolveRequest_unique* request) {
+    cbs_.Resolve(reinterpret_cast<const struct webrtc_SocketAddress*>(&address),
+                 family, request, user_data_);
+  }
+
+ private:
+  webrtc_AsyncDnsResolverFactory_cbs cbs_{};
+  void* user_data_;
+};
+
+class AsyncDnsResolverImpl;
+
+// 名前解決を開始したタスクキューと resolver への参照。
+// resolver の破棄時に無効化し、以降に完了した要求の結果は捨てる。
+// resolver はタスクキューより先に破棄されるため、無効化前であれば
+// タスクキューへ投げられる。
+class AsyncDnsResolveTarget {
+ public:
+  AsyncDnsResolveTarget(webrtc::TaskQueueBase* task_queue,
+                        AsyncDnsResolverImpl* resolver)
+      : task_queue_(task_queue), resolver_(resolver) {}
+
+  void Post(std::vector<webrtc::IPAddress> addresses, int error);
+
+  void Invalidate() {
+    webrtc::MutexLock lock(&mutex_);
+    task_queue_ = nullptr;
+    resolver_ = nullptr;
+  }
+
+ private:
+  webrtc::Mutex mutex_;
+  webrtc::TaskQueueBase* task_queue_ RTC_GUARDED_BY(mutex_);
+  AsyncDnsResolverImpl* resolver_ RTC_GUARDED_BY(mutex_);
+};
+
+class AsyncDnsResolveRequest {
+ public:
+  explicit AsyncDnsResolveRequest(std::shared_ptr<AsyncDnsResolveTarget> target)
+      : target_(std::move(target)) {}
+
+  ~AsyncDnsResolveRequest() {
+    if (!completed_) {
+      Complete(-1);
+    }
+  }
+
+  void AddAddress(const webrtc::IPAddress& address) {
+    addresses_.push_back(address);
+  }
+
+  void Complete(int error) {
+    completed_ = true;
+    target_->Post(std::move(addresses_), error);
+  }
+
+ private:
+  std::shared_ptr<AsyncDnsResolveTarget> target_;
+  std::vector<webrtc::IPAddress> addresses_;
+  bool completed_ = false;
+};
+
+class AsyncDnsResolverImpl : public webrtc::AsyncDnsResolverInterface {
+ public:
+  explicit AsyncDnsResolverImpl(
+      std::shared_ptr<AsyncDnsResolverCallbacks> callbacks)
+      : callbacks_(std::move(callbacks)) {}
+
+  ~AsyncDnsResolverImpl() override {
+    if (target_) {
+      target_->Invalidate();
+    }
+  }
+
+  void Start(const webrtc::SocketAddress& addr,
+             absl::AnyInvocable<void()> callback) override {
+    Start(addr, 0, std::move(callback));
+  }
+
+  void Start(const webrtc::SocketAddress& addr,
+             int family,
+             absl::AnyInvocable<void()> callback) override {
+    auto task_queue = webrtc::TaskQueueBase::Current();
+    assert(task_queue != nullptr);
+    // 前回の要求の結果は受け取らない。
+    if (target_) {
+      target_->Invalidate();
+    }
+    result_.address_ = addr;
+    callback_ = std::move(callback);
+    target_ = std::make_shared<AsyncDnsResolveTarget>(task_queue, this);
+    auto request = std::make_unique<AsyncDnsResolveRequest>(target_);
+    callbacks_->Resolve(
+        addr, family,
+        reinterpret_cast<struct webrtc_AsyncDnsResolveRequest_unique*>(
+            request.release()));
+  }
+
+  const webrtc::AsyncDnsResolverResult& result() const override {
+    return result_;
+  }
+
+  void OnResolved(std::vector<webrtc::IPAddress> addresses, int error) {
+    result_.addresses_ = std::move(addresses);
+    result_.error_ = error;
+    if (callback_) {
+      callback_();
+    }
+  }
+
+  webrtc::scoped_refptr<webrtc::PendingTaskSafetyFlag> safety_flag() {
+    return safety_.flag();
+  }
+
+ private:
+  std::shared_ptr<AsyncDnsResolverCallbacks> callbacks_;
+  std::shared_ptr<AsyncDnsResolveTarget> target_;
+  AsyncDnsResolverResultImpl result_;
+  absl::AnyInvocable<void()> callback_;
+  webrtc::ScopedTaskSafety safety_;
+};
+
+void AsyncDnsResolveTarget::Post(std::vector<webrtc::IPAddress> addresses,
+                                 int error) {
+  webrtc::MutexLock lock(&mutex_);
+  if (task_queue_ == nullptr) {
+    return;
+  }
+  // resolver はタスクキュー上で破棄されるため、ここで取得したフラグで
+  // 実行時に生存を確認する。
+  task_queue_->PostTask(webrtc::SafeTask(
+      resolver_->safety_flag(),
+      [resolver = resolver_, addresses = std::move(addresses),
+       error]() mutable {
+        resolver->OnResolved(std::move(addresses), error);
+      }));
+}
+
+// -------------------------
+// webrtc::AsyncDnsResolverFactoryInterface
+// -------------------------
+
+class AsyncDnsResolverFactoryImpl
+    : public webrtc::AsyncDnsResolverFactoryInterface {
+ public:
+  AsyncDnsResolverFactoryImpl(
+      const struct webrtc_AsyncDnsResolverFactory_cbs* cbs,
+      void* user_data)
+      : callbacks_(
+            std::make_shared<AsyncDnsResolverCallbacks>(cbs, user_data)) {}
+
+  std::unique_ptr<webrtc::AsyncDnsResolverInterface> CreateAndResolve(
+      const webrtc::SocketAddress& addr,
+      absl::AnyInvocable<void()> callback) override {
+    auto resolver = Create();
+    resolver->Start(addr, std::move(callback));
+    return resolver;
+  }
+
+  std::unique_ptr<webrtc::AsyncDnsResolverInterface> CreateAndResolve(
+      const webrtc::SocketAddress& addr,
+      int family,
+      absl::AnyInvocable<void()> callback) override {
+    auto resolver = Create();
+    resolver->Start(addr, family, std::move(callback));
+    return resolver;
+  }
+
+  std::unique_ptr<webrtc::AsyncDnsResolverInterface> Create() override {
+    return std::make_unique<AsyncDnsResolverImpl>(callbacks_);
+  }
+
+ private:
+  std::shared_ptr<AsyncDnsResolverCallbacks> callbacks_;
+};
+
+}  // namespace
+
+extern "C" {
+
+WEBRTC_DEFINE_UNIQUE(webrtc_AsyncDnsResolveRequest, AsyncDnsResolveRequest);
+
+WEBRTC_EXPORT void webrtc_AsyncDnsResolveRequest_AddIPv4Address(
+    struct webrtc_AsyncDnsResolveRequest* self,
+    const uint8_t* address) {
+  assert(self != nullptr);
+  assert(address != nullptr);
+  in_addr addr;
+  memcpy(&addr, address, sizeof(addr));
+  reinterpret_cast<AsyncDnsResolveRequest*>(self)->AddAddress(
+      webrtc::IPAddress(addr));
+}
+
+WEBRTC_EXPORT void webrtc_AsyncDnsResolveRequest_AddIPv6Address(
+    struct webrtc_AsyncDnsResolveRequest* self,
+    const uint8_t* address) {
+  assert(self != nullptr);
+  assert(address != nullptr);
+  in6_addr addr;
+  memcpy(&addr, address, sizeof(addr));
+  reinterpret_cast<AsyncDnsResolveRequest*>(self)->AddAddress(
+      webrtc::IPAddress(addr));
+}
+
+WEBRTC_EXPORT void webrtc_AsyncDnsResolveRequest_Complete(
+    struct webrtc_AsyncDnsResolveRequest_unique* self,
+    int error) {
+  assert(self != nullptr);
+  std::unique_ptr<AsyncDnsResolveRequest> request(
+      reinterpret_cast<AsyncDnsResolveRequest*>(self));
+  request->Complete(error);
+}
+
+WEBRTC_DEFINE_UNIQUE(webrtc_AsyncDnsResolverFactory,
+                     webrtc::AsyncDnsResolverFactoryInterface);
+
+WEBRTC_EXPORT struct webrtc_AsyncDnsResolverFactory_unique*
+webrtc_AsyncDnsResolverFactory_new(
+    const struct webrtc_AsyncDnsResolverFactory_cbs* cbs,
+    void* user_data) {
+  auto factory = std::make_unique<AsyncDnsResolverFactoryImpl>(cbs, user_data);
+  return reinterpret_cast<struct webrtc_AsyncDnsResolverFactory_unique*>(
+      static_cast<webrtc::AsyncDnsResolverFactoryInterface*>(
+          factory.release()));
+}
+}
@@ -0,0 +1,64 @@
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#include "../common.h"
+#include "../rtc_base/socket_address.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::AsyncDnsResolverInterface
+// -------------------------
+
+// 1 回の名前解決要求。
+// webrtc_AsyncDnsResolveRequest_Complete を呼ばずに破棄した場合は
+// 解決失敗として扱う。
+WEBRTC_DECLARE_UNIQUE(webrtc_AsyncDnsResolveRequest);
+
+// 解決したアドレスを追加する。address はネットワークバイトオーダーで、
+// IPv4 は 4 バイト、IPv6 は 16 バイト。
+WEBRTC_EXPORT void webrtc_AsyncDnsResolveRequest_AddIPv4Address(
+    struct webrtc_AsyncDnsResolveRequest* self,
+    const uint8_t* address);
+WEBRTC_EXPORT void webrtc_AsyncDnsResolveRequest_AddIPv6Address(
+    struct webrtc_AsyncDnsResolveRequest* self,
+    const uint8_t* address);
+// 名前解決の結果を通知する。任意のスレッドから呼び出せる。
+// self の所有権は関数へ移る。error が 0 以外の場合は解決失敗として扱う。
+// 結果は名前解決を開始したスレッドへ非同期で通知される。
+// resolver が破棄された後や次の名前解決を開始した後に完了した場合、
+// 結果は捨てられる。
+WEBRTC_EXPORT void webrtc_AsyncDnsResolveRequest_Complete(
+    struct webrtc_AsyncDnsResolveRequest_unique* self,
+    int error);
+
+// -------------------------
+// webrtc::AsyncDnsResolverFactoryInterface
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_AsyncDnsResolverFactory);
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// Resolve は network thread から呼ばれる。family が 0 の場合は
+// アドレスファミリーを問わない。request の所有権はコールバック側へ移る。
+struct webrtc_AsyncDnsResolverFactory_cbs {
+  void (*Resolve)(const struct webrtc_SocketAddress* address,
+                  int family,
+                  struct webrtc_AsyncDnsResolveRequest_unique* request,
+                  void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_AsyncDnsResolverFactory_unique*
+webrtc_AsyncDnsResolverFactory_new(
+    const struct webrtc_AsyncDnsResolverFactory_cbs* cbs,
+    void* user_data);
+
+#if defined(__cplusplus)
+}
+#endif
@@ -0,0 +1,234 @@
+#include "candidate.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <memory>
+#include <string>
+#include <vector>
+
+// WebRTC
+#include <absl/strings/string_view.h>
+#include <api/candidate.h>
+#include <rtc_base/socket_address.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+#include "../rtc_base/socket_address.h"
+#include "../std.h"
+
+// -------------------------
+// webrtc::Candidate
+// -------------------------
+
+extern "C" {
+WEBRTC_DEFINE_VECTOR(webrtc_Candidate, webrtc::Candidate);
+
+WEBRTC_EXPORT struct webrtc_Candidate* webrtc_Candidate_new() {
+  auto candidate = new webrtc::Candidate();
+  return reinterpret_cast<struct webrtc_Candidate*>(candidate);
+}
+WEBRTC_EXPORT void webrtc_Candidate_delete(struct webrtc_Candidate* self) {
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  delete candidate;
+}
+WEBRTC_EXPORT struct std_string_unique* webrtc_Candidate_ToString(
+    const struct webrtc_Candidate* self) {
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  auto str = std::make_unique<std::string>(candidate->ToString());
+  return reinterpret_cast<struct std_string_unique*>(str.release());
+}
+WEBRTC_EXPORT void webrtc_Candidate_foundation(
+    const struct webrtc_Candidate* self,
+    const char** out_foundation,
+    size_t* out_foundation_len) {
+  assert(out_foundation != nullptr);
+  assert(out_foundation_len != nullptr);
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  const auto& foundation = candidate->foundation();
+  *out_foundation = foundation.data();
+  *out_foundation_len = foundation.size();
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_foundation(
+    struct webrtc_Candidate* self,
+    const char* foundation,
+    size_t foundation_len) {
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_foundation(absl::string_view(foundation, foundation_len));
+}
+WEBRTC_EXPORT int webrtc_Candidate_component(
+    const struct webrtc_Candidate* self) {
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  return candidate->component();
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_component(
+    struct webrtc_Candidate* self,
+    int component) {
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_component(component);
+}
+WEBRTC_EXPORT void webrtc_Candidate_protocol(
+    const struct webrtc_Candidate* self,
+    const char** out_protocol,
+    size_t* out_protocol_len) {
+  assert(out_protocol != nullptr);
+  assert(out_protocol_len != nullptr);
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  const auto& protocol = candidate->protocol();
+  *out_protocol = protocol.data();
+  *out_protocol_len = protocol.size();
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_protocol(struct webrtc_Candidate* self,
+                                                 const char* protocol,
+                                                 size_t protocol_len) {
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_protocol(absl::string_view(protocol, protocol_len));
+}
+WEBRTC_EXPORT uint32_t webrtc_Candidate_priority(
+    const struct webrtc_Candidate* self) {
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  return candidate->priority();
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_priority(struct webrtc_Candidate* self,
+                                                 uint32_t priority) {
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_priority(priority);
+}
+WEBRTC_EXPORT const struct webrtc_SocketAddress* webrtc_Candidate_address(
+    const struct webrtc_Candidate* self) {
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  return reinterpret_cast<const struct webrtc_SocketAddress*>(
+      &candidate->address());
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_address(
+    struct webrtc_Candidate* self,
+    const struct webrtc_SocketAddress* address) {
+  assert(address != nullptr);
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_address(
+      *reinterpret_cast<const webrtc::SocketAddress*>(address));
+}
+WEBRTC_EXPORT extern const int webrtc_IceCandidateType_kHost =
+    static_cast<int>(webrtc::IceCandidateType::kHost);
+WEBRTC_EXPORT extern const int webrtc_IceCandidateType_kSrflx =
+    static_cast<int>(webrtc::IceCandidateType::kSrflx);
+WEBRTC_EXPORT extern const int webrtc_IceCandidateType_kPrflx =
+    static_cast<int>(webrtc::IceCandidateType::kPrflx);
+WEBRTC_EXPORT extern const int webrtc_IceCandidateType_kRelay =
+    static_cast<int>(webrtc::IceCandidateType::kRelay);
+WEBRTC_EXPORT webrtc_IceCandidateType webrtc_Candidate_type(
+    const struct webrtc_Candidate* self) {
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  return static_cast<int>(candidate->type());
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_type(struct webrtc_Candidate* self,
+                                             webrtc_IceCandidateType type) {
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_type(static_cast<webrtc::IceCandidateType>(type));
+}
+WEBRTC_EXPORT const struct webrtc_SocketAddress*
+webrtc_Candidate_related_address(const struct webrtc_Candidate* self) {
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  return reinterpret_cast<const struct webrtc_SocketAddress*>(
+      &candidate->related_address());
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_related_address(
+    struct webrtc_Candidate* self,
+    const struct webrtc_SocketAddress* related_address) {
+  assert(related_address != nullptr);
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_related_address(
+      *reinterpret_cast<const webrtc::SocketAddress*>(related_address));
+}
+WEBRTC_EXPORT void webrtc_Candidate_tcptype(
+    const struct webrtc_Candidate* self,
+    const char** out_tcptype,
+    size_t* out_tcptype_len) {
+  assert(out_tcptype != nullptr);
+  assert(out_tcptype_len != nullptr);
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  const auto& tcptype = candidate->tcptype();
+  *out_tcptype = tcptype.data();
+  *out_tcptype_len = tcptype.size();
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_tcptype(struct webrtc_Candidate* self,
+                                                const char* tcptype,
+                                                size_t tcptype_len) {
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_tcptype(absl::string_view(tcptype, tcptype_len));
+}
+WEBRTC_EXPORT uint16_t webrtc_Candidate_network_id(
+    const struct webrtc_Candidate* self) {
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  return candidate->network_id();
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_network_id(
+    struct webrtc_Candidate* self,
+    uint16_t network_id) {
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_network_id(network_id);
+}
+WEBRTC_EXPORT uint16_t webrtc_Candidate_network_cost(
+    const struct webrtc_Candidate* self) {
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  return candidate->network_cost();
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_network_cost(
+    struct webrtc_Candidate* self,
+    uint16_t network_cost) {
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_network_cost(network_cost);
+}
+WEBRTC_EXPORT void webrtc_Candidate_username(
+    const struct webrtc_Candidate* self,
+    const char** out_username,
+    size_t* out_username_len) {
+  assert(out_username != nullptr);
+  assert(out_username_len != nullptr);
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  const auto& username = candidate->username();
+  *out_username = username.data();
+  *out_username_len = username.size();
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_username(struct webrtc_Candidate* self,
+                                                 const char* username,
+                                                 size_t username_len) {
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_username(absl::string_view(username, username_len));
+}
+WEBRTC_EXPORT void webrtc_Candidate_transport_name(
+    const struct webrtc_Candidate* self,
+    const char** out_transport_name,
+    size_t* out_transport_name_len) {
+  assert(out_transport_name != nullptr);
+  assert(out_transport_name_len != nullptr);
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  const auto& transport_name = candidate->transport_name();
+  *out_transport_name = transport_name.data();
+  *out_transport_name_len = transport_name.size();
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_transport_name(
+    struct webrtc_Candidate* self,
+    const char* transport_name,
+    size_t transport_name_len) {
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_transport_name(
+      absl::string_view(transport_name, transport_name_len));
+}
+WEBRTC_EXPORT void webrtc_Candidate_url(const struct webrtc_Candidate* self,
+                                        const char** out_url,
+                                        size_t* out_url_len) {
+  assert(out_url != nullptr);
+  assert(out_url_len != nullptr);
+  auto candidate = reinterpret_cast<const webrtc::Candidate*>(self);
+  const auto& url = candidate->url();
+  *out_url = url.data();
+  *out_url_len = url.size();
+}
+WEBRTC_EXPORT void webrtc_Candidate_set_url(struct webrtc_Candidate* self,
+                                            const char* url,
+                                            size_t url_len) {
+  auto candidate = reinterpret_cast<webrtc::Candidate*>(self);
+  candidate->set_url(absl::string_view(url, url_len));
+}
+}
@@ -0,0 +1,108 @@
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#include "../common.h"
+#include "../rtc_base/socket_address.h"
+#include "../std.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::Candidate
+// -------------------------
+
+WEBRTC_DECLARE_VECTOR(webrtc_Candidate);
+
+WEBRTC_EXPORT struct webrtc_Candidate* webrtc_Candidate_new();
+WEBRTC_EXPORT void webrtc_Candidate_delete(struct webrtc_Candidate* self);
+WEBRTC_EXPORT struct std_string_unique* webrtc_Candidate_ToString(
+    const struct webrtc_Candidate* self);
+WEBRTC_EXPORT void webrtc_Candidate_foundation(
+    const struct webrtc_Candidate* self,
+    const char** out_foundation,
+    size_t* out_foundation_len);
+WEBRTC_EXPORT void webrtc_Candidate_set_foundation(
+    struct webrtc_Candidate* self,
+    const char* foundation,
+    size_t foundation_len);
+WEBRTC_EXPORT int webrtc_Candidate_component(
+    const struct webrtc_Candidate* self);
+WEBRTC_EXPORT void webrtc_Candidate_set_component(
+    struct webrtc_Candidate* self,
+    int component);
+WEBRTC_EXPORT void webrtc_Candidate_protocol(
+    const struct webrtc_Candidate* self,
+    const char** out_protocol,
+    size_t* out_protocol_len);
+WEBRTC_EXPORT void webrtc_Candidate_set_protocol(struct webrtc_Candidate* self,
+                                                 const char* protocol,
+                                                 size_t protocol_len);
+WEBRTC_EXPORT uint32_t webrtc_Candidate_priority(
+    const struct webrtc_Candidate* self);
+WEBRTC_EXPORT void webrtc_Candidate_set_priority(struct webrtc_Candidate* self,
+                                                 uint32_t priority);
+WEBRTC_EXPORT const struct webrtc_SocketAddress* webrtc_Candidate_address(
+    const struct webrtc_Candidate* self);
+WEBRTC_EXPORT void webrtc_Candidate_set_address(
+    struct webrtc_Candidate* self,
+    const struct webrtc_SocketAddress* address);
+typedef int webrtc_IceCandidateType;
+WEBRTC_EXPORT extern const int webrtc_IceCandidateType_kHost;
+WEBRTC_EXPORT extern const int webrtc_IceCandidateType_kSrflx;
+WEBRTC_EXPORT extern const int webrtc_IceCandidateType_kPrflx;
+WEBRTC_EXPORT extern const int webrtc_IceCandidateType_kRelay;
+WEBRTC_EXPORT webrtc_IceCandidateType webrtc_Candidate_type(
+    const struct webrtc_Candidate* self);
+WEBRTC_EXPORT void webrtc_Candidate_set_type(struct webrtc_Candidate* self,
+                                             webrtc_IceCandidateType type);
+WEBRTC_EXPORT const struct webrtc_SocketAddress*
+webrtc_Candidate_related_address(const struct webrtc_Candidate* self);
+WEBRTC_EXPORT void webrtc_Candidate_set_related_address(
+    struct webrtc_Candidate* self,
+    const struct webrtc_SocketAddress* related_address);
+WEBRTC_EXPORT void webrtc_Candidate_tcptype(
+    const struct webrtc_Candidate* self,
+    const char** out_tcptype,
+    size_t* out_tcptype_len);
+WEBRTC_EXPORT void webrtc_Candidate_set_tcptype(struct webrtc_Candidate* self,
+                                                const char* tcptype,
+                                                size_t tcptype_len);
+WEBRTC_EXPORT uint16_t webrtc_Candidate_network_id(
+    const struct webrtc_Candidate* self);
+WEBRTC_EXPORT void webrtc_Candidate_set_network_id(
+    struct webrtc_Candidate* self,
+    uint16_t network_id);
+WEBRTC_EXPORT uint16_t webrtc_Candidate_network_cost(
+    const struct webrtc_Candidate* self);
+WEBRTC_EXPORT void webrtc_Candidate_set_network_cost(
+    struct webrtc_Candidate* self,
+    uint16_t network_cost);
+WEBRTC_EXPORT void webrtc_Candidate_username(
+    const struct webrtc_Candidate* self,
+    const char** out_username,
+    size_t* out_username_len);
+WEBRTC_EXPORT void webrtc_Candidate_set_username(struct webrtc_Candidate* self,
+                                                 const char* username,
+                                                 size_t username_len);
+WEBRTC_EXPORT void webrtc_Candidate_transport_name(
+    const struct webrtc_Candidate* self,
+    const char** out_transport_name,
+    size_t* out_transport_name_len);
+WEBRTC_EXPORT void webrtc_Candidate_set_transport_name(
+    struct webrtc_Candidate* self,
+    const char* transport_name,
+    size_t transport_name_len);
+WEBRTC_EXPORT void webrtc_Candidate_url(const struct webrtc_Candidate* self,
+                                        const char** out_url,
+                                        size_t* out_url_len);
+WEBRTC_EXPORT void webrtc_Candidate_set_url(struct webrtc_Candidate* self,
+                                            const char* url,
+                                            size_t url_len);
+
+#if defined(__cplusplus)
+}
+#endif
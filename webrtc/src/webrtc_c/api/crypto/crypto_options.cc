@@ -0,0 +1,92 @@
+#include "crypto_options.h"
+
+// WebRTC
+#include <api/crypto/crypto_options.h>
+
+#include "../../common.h"
+#include "../../common.impl.h"
+
+// -------------------------
+// webrtc::CryptoOptions
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT struct webrtc_CryptoOptions* webrtc_CryptoOptions_new() {
+  auto options = new webrtc::CryptoOptions();
+  return reinterpret_cast<struct webrtc_CryptoOptions*>(options);
+}
+WEBRTC_EXPORT void webrtc_CryptoOptions_delete(
+    struct webrtc_CryptoOptions* self) {
+  auto options = reinterpret_cast<webrtc::CryptoOptions*>(self);
+  delete options;
+}
+WEBRTC_EXPORT struct webrtc_CryptoOptions_Srtp* webrtc_CryptoOptions_get_srtp(
+    struct webrtc_CryptoOptions* self) {
+  auto options = reinterpret_cast<webrtc::CryptoOptions*>(self);
+  return reinterpret_cast<struct webrtc_CryptoOptions_Srtp*>(&options->srtp);
+}
+WEBRTC_EXPORT struct webrtc_CryptoOptions_SFrame*
+webrtc_CryptoOptions_get_sframe(struct webrtc_CryptoOptions* self) {
+  auto options = reinterpret_cast<webrtc::CryptoOptions*>(self);
+  return reinterpret_cast<struct webrtc_CryptoOptions_SFrame*>(
+      &options->sframe);
+}
+
+// -------------------------
+// webrtc::CryptoOptions::Srtp
+// -------------------------
+
+WEBRTC_EXPORT int webrtc_CryptoOptions_Srtp_get_enable_gcm_crypto_suites(
+    struct webrtc_CryptoOptions_Srtp* self) {
+  auto srtp = reinterpret_cast<webrtc::CryptoOptions::Srtp*>(self);
+  return srtp->enable_gcm_crypto_suites ? 1 : 0;
+}
+WEBRTC_EXPORT void webrtc_CryptoOptions_Srtp_set_enable_gcm_crypto_suites(
+    struct webrtc_CryptoOptions_Srtp* self,
+    int value) {
+  auto srtp = reinterpret_cast<webrtc::CryptoOptions::Srtp*>(self);
+  srtp->enable_gcm_crypto_suites = value != 0;
+}
+WEBRTC_EXPORT int
+webrtc_CryptoOptions_Srtp_get_enable_aes128_sha1_32_crypto_cipher(
+    struct webrtc_CryptoOptions_Srtp* self) {
+  auto srtp = reinterpret_cast<webrtc::CryptoOptions::Srtp*>(self);
+  return srtp->enable_aes128_sha1_32_crypto_cipher ? 1 : 0;
+}
+WEBRTC_EXPORT void
+webrtc_CryptoOptions_Srtp_set_enable_aes128_sha1_32_crypto_cipher(
+    struct webrtc_CryptoOptions_Srtp* self,
+    int value) {
+  auto srtp = reinterpret_cast<webrtc::CryptoOptions::Srtp*>(self);
+  srtp->enable_aes128_sha1_32_crypto_cipher = value != 0;
+}
+WEBRTC_EXPORT int
+webrtc_CryptoOptions_Srtp_get_enable_encrypted_rtp_header_extensions(
+    struct webrtc_CryptoOptions_Srtp* self) {
+  auto srtp = reinterpret_cast<webrtc::CryptoOptions::Srtp*>(self);
+  return srtp->enable_encrypted_rtp_header_extensions ? 1 : 0;
+}
+WEBRTC_EXPORT void
+webrtc_CryptoOptions_Srtp_set_enable_encrypted_rtp_header_extensions(
+    struct webrtc_CryptoOptions_Srtp* self,
+    int value) {
+  auto srtp = reinterpret_cast<webrtc::CryptoOptions::Srtp*>(self);
+  srtp->enable_encrypted_rtp_header_extensions = value != 0;
+}
+
+// -------------------------
+// webrtc::CryptoOptions::SFrame
+// -------------------------
+
+WEBRTC_EXPORT int webrtc_CryptoOptions_SFrame_get_require_frame_encryption(
+    struct webrtc_CryptoOptions_SFrame* self) {
+  auto sframe = reinterpret_cast<webrtc::CryptoOptions::SFrame*>(self);
+  return sframe->require_frame_encryption ? 1 : 0;
+}
+WEBRTC_EXPORT void webrtc_CryptoOptions_SFrame_set_require_frame_encryption(
+    struct webrtc_CryptoOptions_SFrame* self,
+    int value) {
+  auto sframe = reinterpret_cast<webrtc::CryptoOptions::SFrame*>(self);
+  sframe->require_frame_encryption = value != 0;
+}
+}
@@ -0,0 +1,60 @@
+#pragma once
+
+#include "../../common.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::CryptoOptions
+// -------------------------
+
+struct webrtc_CryptoOptions;
+struct webrtc_CryptoOptions_Srtp;
+struct webrtc_CryptoOptions_SFrame;
+WEBRTC_EXPORT struct webrtc_CryptoOptions* webrtc_CryptoOptions_new();
+WEBRTC_EXPORT void webrtc_CryptoOptions_delete(
+    struct webrtc_CryptoOptions* self);
+WEBRTC_EXPORT struct webrtc_CryptoOptions_Srtp* webrtc_CryptoOptions_get_srtp(
+    struct webrtc_CryptoOptions* self);
+WEBRTC_EXPORT struct webrtc_CryptoOptions_SFrame*
+webrtc_CryptoOptions_get_sframe(struct webrtc_CryptoOptions* self);
+
+// -------------------------
+// webrtc::CryptoOptions::Srtp
+// -------------------------
+
+WEBRTC_EXPORT int webrtc_CryptoOptions_Srtp_get_enable_gcm_crypto_suites(
+    struct webrtc_CryptoOptions_Srtp* self);
+WEBRTC_EXPORT void webrtc_CryptoOptions_Srtp_set_enable_gcm_crypto_suites(
+    struct webrtc_CryptoOptions_Srtp* self,
+    int value);
+WEBRTC_EXPORT int
+webrtc_CryptoOptions_Srtp_get_enable_aes128_sha1_32_crypto_cipher(
+    struct webrtc_CryptoOptions_Srtp* self);
+WEBRTC_EXPORT void
+webrtc_CryptoOptions_Srtp_set_enable_aes128_sha1_32_crypto_cipher(
+    struct webrtc_CryptoOptions_Srtp* self,
+    int value);
+WEBRTC_EXPORT int
+webrtc_CryptoOptions_Srtp_get_enable_encrypted_rtp_header_extensions(
+    struct webrtc_CryptoOptions_Srtp* self);
+WEBRTC_EXPORT void
+webrtc_CryptoOptions_Srtp_set_enable_encrypted_rtp_header_extensions(
+    struct webrtc_CryptoOptions_Srtp* self,
+    int value);
+
+// -------------------------
+// webrtc::CryptoOptions::SFrame
+// -------------------------
+
+WEBRTC_EXPORT int webrtc_CryptoOptions_SFrame_get_require_frame_encryption(
+    struct webrtc_CryptoOptions_SFrame* self);
+WEBRTC_EXPORT void webrtc_CryptoOptions_SFrame_set_require_frame_encryption(
+    struct webrtc_CryptoOptions_SFrame* self,
+    int value);
+
+#if defined(__cplusplus)
+}
+#endif
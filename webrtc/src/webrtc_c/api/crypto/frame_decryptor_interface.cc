@@ -0,0 +1,89 @@
+#include "frame_decryptor_interface.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <vector>
+
+// WebRTC
+#include <api/array_view.h>
+#include <api/crypto/frame_decryptor_interface.h>
+#include <api/make_ref_counted.h>
+#include <api/media_types.h>
+
+#include "../../common.h"
+#include "../../common.impl.h"
+
+// -------------------------
+// webrtc::FrameDecryptorInterface
+// -------------------------
+
+namespace {
+
+class FrameDecryptorInterfaceImpl : public webrtc::FrameDecryptorInterface {
+ public:
+  FrameDecryptorInterfaceImpl(
+      const struct webrtc_FrameDecryptorInterface_cbs* cbs,
+      void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->Decrypt != nullptr);
+    assert(cbs->GetMaxPlaintextByteSize != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~FrameDecryptorInterfaceImpl() override { cbs_.OnDestroy(user_data_); }
+
+  Result Decrypt(webrtc::MediaType media_type,
+                 const std::vector<uint32_t>& csrcs,
+                 webrtc::ArrayView<const uint8_t> additional_data,
+                 webrtc::ArrayView<const uint8_t> encrypted_frame,
+                 webrtc::ArrayView<uint8_t> frame) override {
+    size_t bytes_written = 0;
+    int status = cbs_.Decrypt(
+        static_cast<int>(media_type), csrcs.data(), csrcs.size(),
+        additional_data.data(), additional_data.size(), encrypted_frame.data(),
+        encrypted_frame.size(), frame.data(), frame.size(), &bytes_written,
+        user_data_);
+    return Result(static_cast<Status>(status), bytes_written);
+  }
+
+  size_t GetMaxPlaintextByteSize(webrtc::MediaType media_type,
+                                 size_t encrypted_frame_size) override {
+    return cbs_.GetMaxPlaintextByteSize(static_cast<int>(media_type),
+                                        encrypted_frame_size, user_data_);
+  }
+
+ private:
+  webrtc_FrameDecryptorInterface_cbs cbs_{};
+  void* user_data_;
+};
+
+}  // namespace
+
+extern "C" {
+WEBRTC_DEFINE_REFCOUNTED(webrtc_FrameDecryptorInterface,
+                         webrtc::FrameDecryptorInterface);
+
+WEBRTC_EXPORT extern const int webrtc_FrameDecryptorInterface_Status_kOk =
+    static_cast<int>(webrtc::FrameDecryptorInterface::Status::kOk);
+WEBRTC_EXPORT extern const int
+    webrtc_FrameDecryptorInterface_Status_kRecoverable = static_cast<int>(
+        webrtc::FrameDecryptorInterface::Status::kRecoverable);
+WEBRTC_EXPORT extern const int
+    webrtc_FrameDecryptorInterface_Status_kFailedToDecrypt = static_cast<int>(
+        webrtc::FrameDecryptorInterface::Status::kFailedToDecrypt);
+WEBRTC_EXPORT extern const int webrtc_FrameDecryptorInterface_Status_kUnknown =
+    static_cast<int>(webrtc::FrameDecryptorInterface::Status::kUnknown);
+
+WEBRTC_EXPORT struct webrtc_FrameDecryptorInterface_refcounted*
+webrtc_FrameDecryptorInterface_make_ref_counted(
+    const struct webrtc_FrameDecryptorInterface_cbs* cbs,
+    void* user_data) {
+  auto impl =
+      webrtc::make_ref_counted<FrameDecryptorInterfaceImpl>(cbs, user_data);
+  return reinterpret_cast<struct webrtc_FrameDecryptorInterface_refcounted*>(
+      impl.release());
+}
+}
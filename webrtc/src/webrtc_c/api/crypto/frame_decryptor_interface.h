@@ -0,0 +1,56 @@
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#include "../../common.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::FrameDecryptorInterface
+// -------------------------
+
+WEBRTC_DECLARE_REFCOUNTED(webrtc_FrameDecryptorInterface);
+
+// Status 定数
+typedef int webrtc_FrameDecryptorInterface_Status;
+WEBRTC_EXPORT extern const int webrtc_FrameDecryptorInterface_Status_kOk;
+WEBRTC_EXPORT extern const int
+    webrtc_FrameDecryptorInterface_Status_kRecoverable;
+WEBRTC_EXPORT extern const int
+    webrtc_FrameDecryptorInterface_Status_kFailedToDecrypt;
+WEBRTC_EXPORT extern const int webrtc_FrameDecryptorInterface_Status_kUnknown;
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// media_type は webrtc_MediaType_* の値。
+struct webrtc_FrameDecryptorInterface_cbs {
+  webrtc_FrameDecryptorInterface_Status (*Decrypt)(
+      int media_type,
+      const uint32_t* csrcs,
+      size_t csrcs_size,
+      const uint8_t* additional_data,
+      size_t additional_data_size,
+      const uint8_t* encrypted_frame,
+      size_t encrypted_frame_size,
+      uint8_t* frame,
+      size_t frame_size,
+      size_t* bytes_written,
+      void* user_data);
+  size_t (*GetMaxPlaintextByteSize)(int media_type,
+                                    size_t encrypted_frame_size,
+                                    void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_FrameDecryptorInterface_refcounted*
+webrtc_FrameDecryptorInterface_make_ref_counted(
+    const struct webrtc_FrameDecryptorInterface_cbs* cbs,
+    void* user_data);
+
+#if defined(__cplusplus)
+}
+#endif
@@ -0,0 +1,75 @@
+#include "frame_encryptor_interface.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// WebRTC
+#include <api/array_view.h>
+#include <api/crypto/frame_encryptor_interface.h>
+#include <api/make_ref_counted.h>
+#include <api/media_types.h>
+
+#include "../../common.h"
+#include "../../common.impl.h"
+
+// -------------------------
+// webrtc::FrameEncryptorInterface
+// -------------------------
+
+namespace {
+
+class FrameEncryptorInterfaceImpl : public webrtc::FrameEncryptorInterface {
+ public:
+  FrameEncryptorInterfaceImpl(
+      const struct webrtc_FrameEncryptorInterface_cbs* cbs,
+      void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->Encrypt != nullptr);
+    assert(cbs->GetMaxCiphertextByteSize != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~FrameEncryptorInterfaceImpl() override { cbs_.OnDestroy(user_data_); }
+
+  int Encrypt(webrtc::MediaType media_type,
+              uint32_t ssrc,
+              webrtc::ArrayView<const uint8_t> additional_data,
+              webrtc::ArrayView<const uint8_t> frame,
+              webrtc::ArrayView<uint8_t> encrypted_frame,
+              size_t* bytes_written) override {
+    return cbs_.Encrypt(static_cast<int>(media_type), ssrc,
+                        additional_data.data(), additional_data.size(),
+                        frame.data(), frame.size(), encrypted_frame.data(),
+                        encrypted_frame.size(), bytes_written, user_data_);
+  }
+
+  size_t GetMaxCiphertextByteSize(webrtc::MediaType media_type,
+                                  size_t frame_size) override {
+    return cbs_.GetMaxCiphertextByteSize(static_cast<int>(media_type),
+                                         frame_size, user_data_);
+  }
+
+ private:
+  webrtc_FrameEncryptorInterface_cbs cbs_{};
+  void* user_data_;
+};
+
+}  // namespace
+
+extern "C" {
+WEBRTC_DEFINE_REFCOUNTED(webrtc_FrameEncryptorInterface,
+                         webrtc::FrameEncryptorInterface);
+
+WEBRTC_EXPORT struct webrtc_FrameEncryptorInterface_refcounted*
+webrtc_FrameEncryptorInterface_make_ref_counted(
+    const struct webrtc_FrameEncryptorInterface_cbs* cbs,
+    void* user_data) {
+  auto impl =
+      webrtc::make_ref_counted<FrameEncryptorInterfaceImpl>(cbs, user_data);
+  return reinterpret_cast<struct webrtc_FrameEncryptorInterface_refcounted*>(
+      impl.release());
+}
+}
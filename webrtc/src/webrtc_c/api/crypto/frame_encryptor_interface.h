@@ -0,0 +1,45 @@
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#include "../../common.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::FrameEncryptorInterface
+// -------------------------
+
+WEBRTC_DECLARE_REFCOUNTED(webrtc_FrameEncryptorInterface);
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// media_type は webrtc_MediaType_* の値。Encrypt は成功時に 0 を返す。
+struct webrtc_FrameEncryptorInterface_cbs {
+  int (*Encrypt)(int media_type,
+                 uint32_t ssrc,
+                 const uint8_t* additional_data,
+                 size_t additional_data_size,
+                 const uint8_t* frame,
+                 size_t frame_size,
+                 uint8_t* encrypted_frame,
+                 size_t encrypted_frame_size,
+                 size_t* bytes_written,
+                 void* user_data);
+  size_t (*GetMaxCiphertextByteSize)(int media_type,
+                                     size_t frame_size,
+                                     void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_FrameEncryptorInterface_refcounted*
+webrtc_FrameEncryptorInterface_make_ref_counted(
+    const struct webrtc_FrameEncryptorInterface_cbs* cbs,
+    void* user_data);
+
+#if defined(__cplusplus)
+}
+#endif
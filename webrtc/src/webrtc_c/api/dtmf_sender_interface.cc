@@ -0,0 +1,118 @@
+#include "dtmf_sender_interface.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <memory>
+#include <string>
+
+// WebRTC
+#include <api/dtmf_sender_interface.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+#include "../std.h"
+
+// -------------------------
+// webrtc::DtmfSenderObserverInterface
+// -------------------------
+
+namespace {
+
+class DtmfSenderObserverInterfaceImpl
+    : public webrtc::DtmfSenderObserverInterface {
+ public:
+  DtmfSenderObserverInterfaceImpl(
+      const struct webrtc_DtmfSenderObserverInterface_cbs* cbs,
+      void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->OnToneChange != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~DtmfSenderObserverInterfaceImpl() override { cbs_.OnDestroy(user_data_); }
+
+  using webrtc::DtmfSenderObserverInterface::OnToneChange;
+  void OnToneChange(const std::string& tone,
+                    const std::string& tone_buffer) override {
+    cbs_.OnToneChange(tone.c_str(), tone.size(), tone_buffer.c_str(),
+                      tone_buffer.size(), user_data_);
+  }
+
+ private:
+  webrtc_DtmfSenderObserverInterface_cbs cbs_{};
+  void* user_data_;
+};
+
+}  // namespace
+
+extern "C" {
+WEBRTC_EXPORT struct webrtc_DtmfSenderObserverInterface*
+webrtc_DtmfSenderObserverInterface_new(
+    const struct webrtc_DtmfSenderObserverInterface_cbs* cbs,
+    void* user_data) {
+  auto impl = new DtmfSenderObserverInterfaceImpl(cbs, user_data);
+  return reinterpret_cast<struct webrtc_DtmfSenderObserverInterface*>(impl);
+}
+WEBRTC_EXPORT void webrtc_DtmfSenderObserverInterface_delete(
+    struct webrtc_DtmfSenderObserverInterface* self) {
+  auto impl = reinterpret_cast<DtmfSenderObserverInterfaceImpl*>(self);
+  delete impl;
+}
+
+// -------------------------
+// webrtc::DtmfSenderInterface
+// -------------------------
+
+WEBRTC_DEFINE_REFCOUNTED(webrtc_DtmfSenderInterface,
+                         webrtc::DtmfSenderInterface);
+
+WEBRTC_EXPORT void webrtc_DtmfSenderInterface_RegisterObserver(
+    struct webrtc_DtmfSenderInterface* self,
+    struct webrtc_DtmfSenderObserverInterface* observer) {
+  assert(observer != nullptr);
+  auto dtmf = reinterpret_cast<webrtc::DtmfSenderInterface*>(self);
+  auto obs = reinterpret_cast<DtmfSenderObserverInterfaceImpl*>(observer);
+  dtmf->RegisterObserver(obs);
+}
+WEBRTC_EXPORT void webrtc_DtmfSenderInterface_UnregisterObserver(
+    struct webrtc_DtmfSenderInterface* self) {
+  auto dtmf = reinterpret_cast<webrtc::DtmfSenderInterface*>(self);
+  dtmf->UnregisterObserver();
+}
+WEBRTC_EXPORT int webrtc_DtmfSenderInterface_CanInsertDtmf(
+    struct webrtc_DtmfSenderInterface* self) {
+  auto dtmf = reinterpret_cast<webrtc::DtmfSenderInterface*>(self);
+  return dtmf->CanInsertDtmf() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_DtmfSenderInterface_InsertDtmf(
+    struct webrtc_DtmfSenderInterface* self,
+    const char* tones,
+    size_t tones_len,
+    int duration,
+    int inter_tone_gap) {
+  assert(tones != nullptr || tones_len == 0);
+  auto dtmf = reinterpret_cast<webrtc::DtmfSenderInterface*>(self);
+  return dtmf->InsertDtmf(std::string(tones, tones_len), duration,
+                          inter_tone_gap)
+             ? 1
+             : 0;
+}
+WEBRTC_EXPORT struct std_string_unique* webrtc_DtmfSenderInterface_tones(
+    struct webrtc_DtmfSenderInterface* self) {
+  auto dtmf = reinterpret_cast<webrtc::DtmfSenderInterface*>(self);
+  auto tones = std::make_unique<std::string>(dtmf->tones());
+  return reinterpret_cast<struct std_string_unique*>(tones.release());
+}
+WEBRTC_EXPORT int webrtc_DtmfSenderInterface_duration(
+    struct webrtc_DtmfSenderInterface* self) {
+  auto dtmf = reinterpret_cast<webrtc::DtmfSenderInterface*>(self);
+  return dtmf->duration();
+}
+WEBRTC_EXPORT int webrtc_DtmfSenderInterface_inter_tone_gap(
+    struct webrtc_DtmfSenderInterface* self) {
+  auto dtmf = reinterpret_cast<webrtc::DtmfSenderInterface*>(self);
+  return dtmf->inter_tone_gap();
+}
+}
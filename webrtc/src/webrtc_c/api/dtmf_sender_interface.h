@@ -0,0 +1,66 @@
+#pragma once
+
+#include <stddef.h>
+
+#include "../common.h"
+#include "../std.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::DtmfSenderObserverInterface
+// -------------------------
+
+struct webrtc_DtmfSenderObserverInterface;
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// 全トーンの送信が終わると tone は空文字列で呼ばれる。
+struct webrtc_DtmfSenderObserverInterface_cbs {
+  void (*OnToneChange)(const char* tone,
+                       size_t tone_len,
+                       const char* tone_buffer,
+                       size_t tone_buffer_len,
+                       void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_DtmfSenderObserverInterface*
+webrtc_DtmfSenderObserverInterface_new(
+    const struct webrtc_DtmfSenderObserverInterface_cbs* cbs,
+    void* user_data);
+WEBRTC_EXPORT void webrtc_DtmfSenderObserverInterface_delete(
+    struct webrtc_DtmfSenderObserverInterface* self);
+
+// -------------------------
+// webrtc::DtmfSenderInterface
+// -------------------------
+
+WEBRTC_DECLARE_REFCOUNTED(webrtc_DtmfSenderInterface);
+
+// observer は UnregisterObserver するまで破棄しないこと。
+WEBRTC_EXPORT void webrtc_DtmfSenderInterface_RegisterObserver(
+    struct webrtc_DtmfSenderInterface* self,
+    struct webrtc_DtmfSenderObserverInterface* observer);
+WEBRTC_EXPORT void webrtc_DtmfSenderInterface_UnregisterObserver(
+    struct webrtc_DtmfSenderInterface* self);
+WEBRTC_EXPORT int webrtc_DtmfSenderInterface_CanInsertDtmf(
+    struct webrtc_DtmfSenderInterface* self);
+WEBRTC_EXPORT int webrtc_DtmfSenderInterface_InsertDtmf(
+    struct webrtc_DtmfSenderInterface* self,
+    const char* tones,
+    size_t tones_len,
+    int duration,
+    int inter_tone_gap);
+WEBRTC_EXPORT struct std_string_unique* webrtc_DtmfSenderInterface_tones(
+    struct webrtc_DtmfSenderInterface* self);
+WEBRTC_EXPORT int webrtc_DtmfSenderInterface_duration(
+    struct webrtc_DtmfSenderInterface* self);
+WEBRTC_EXPORT int webrtc_DtmfSenderInterface_inter_tone_gap(
+    struct webrtc_DtmfSenderInterface* self);
+
+#if defined(__cplusplus)
+}
+#endif
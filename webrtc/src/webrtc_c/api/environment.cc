@@ -1,13 +1,26 @@
 #include "environment.h"
 
+#include <assert.h>
 #include <stdarg.h>
 #include <stddef.h>
+#include <memory>
+#include <string>
+#include <utility>
 
 // WebRTC
+#include <absl/strings/string_view.h>
 #include <api/environment/environment.h>
 #include <api/environment/environment_factory.h>
+#include <api/field_trials.h>
+#include <api/field_trials_view.h>
+#include <api/task_queue/task_queue_factory.h>
+#include <system_wrappers/include/clock.h>
 
 #include "../common.h"
+#include "../common.impl.h"
+#include "../std.h"
+#include "../system_wrappers/clock.h"
+#include "task_queue/task_queue_factory.h"
 
 // -------------------------
 // webrtc::Environment
@@ -22,4 +35,116 @@ WEBRTC_EXPORT void webrtc_Environment_delete(struct webrtc_Environment* self) {
   auto env = reinterpret_cast<webrtc::Environment*>(self);
   delete env;
 }
+WEBRTC_EXPORT struct webrtc_TaskQueueFactory*
+webrtc_Environment_task_queue_factory(struct webrtc_Environment* self) {
+  auto env = reinterpret_cast<webrtc::Environment*>(self);
+  return reinterpret_cast<struct webrtc_TaskQueueFactory*>(
+      &env->task_queue_factory());
+}
+}
+
+// -------------------------
+// webrtc::FieldTrialsView
+// -------------------------
+
+namespace {
+
+class FieldTrialsViewImpl : public webrtc::FieldTrialsView {
+ public:
+  FieldTrialsViewImpl(const struct webrtc_FieldTrialsView_cbs* cbs,
+                      void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->Lookup != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~FieldTrialsViewImpl() override { cbs_.OnDestroy(user_data_); }
+
+  std::string Lookup(absl::string_view key) const override {
+    std::unique_ptr<std::string> value(reinterpret_cast<std::string*>(
+        cbs_.Lookup(key.data(), key.size(), user_data_)));
+    if (value == nullptr) {
+      return std::string();
+    }
+    return std::move(*value);
+  }
+
+ private:
+  webrtc_FieldTrialsView_cbs cbs_{};
+  void* user_data_;
+};
+
+}  // namespace
+
+extern "C" {
+
+WEBRTC_DEFINE_UNIQUE(webrtc_FieldTrialsView, webrtc::FieldTrialsView);
+
+WEBRTC_EXPORT struct webrtc_FieldTrialsView_unique* webrtc_FieldTrialsView_new(
+    const struct webrtc_FieldTrialsView_cbs* cbs,
+    void* user_data) {
+  auto field_trials = std::make_unique<FieldTrialsViewImpl>(cbs, user_data);
+  return reinterpret_cast<struct webrtc_FieldTrialsView_unique*>(
+      static_cast<webrtc::FieldTrialsView*>(field_trials.release()));
+}
+
+WEBRTC_EXPORT struct webrtc_FieldTrialsView_unique* webrtc_FieldTrials_Create(
+    const char* s,
+    size_t s_len) {
+  assert(s != nullptr || s_len == 0);
+  std::unique_ptr<webrtc::FieldTrials> field_trials =
+      webrtc::FieldTrials::Create(absl::string_view(s, s_len));
+  if (field_trials == nullptr) {
+    return nullptr;
+  }
+  return reinterpret_cast<struct webrtc_FieldTrialsView_unique*>(
+      static_cast<webrtc::FieldTrialsView*>(field_trials.release()));
+}
+
+// -------------------------
+// webrtc::EnvironmentFactory
+// -------------------------
+
+WEBRTC_EXPORT struct webrtc_EnvironmentFactory*
+webrtc_EnvironmentFactory_new() {
+  auto factory = new webrtc::EnvironmentFactory();
+  return reinterpret_cast<struct webrtc_EnvironmentFactory*>(factory);
+}
+WEBRTC_EXPORT void webrtc_EnvironmentFactory_delete(
+    struct webrtc_EnvironmentFactory* self) {
+  auto factory = reinterpret_cast<webrtc::EnvironmentFactory*>(self);
+  delete factory;
+}
+WEBRTC_EXPORT void webrtc_EnvironmentFactory_set_field_trials(
+    struct webrtc_EnvironmentFactory* self,
+    struct webrtc_FieldTrialsView_unique* field_trials) {
+  assert(field_trials != nullptr);
+  auto factory = reinterpret_cast<webrtc::EnvironmentFactory*>(self);
+  factory->Set(std::unique_ptr<const webrtc::FieldTrialsView>(
+      reinterpret_cast<webrtc::FieldTrialsView*>(field_trials)));
+}
+WEBRTC_EXPORT void webrtc_EnvironmentFactory_set_clock(
+    struct webrtc_EnvironmentFactory* self,
+    struct webrtc_Clock_unique* clock) {
+  assert(clock != nullptr);
+  auto factory = reinterpret_cast<webrtc::EnvironmentFactory*>(self);
+  factory->Set(std::unique_ptr<webrtc::Clock>(
+      reinterpret_cast<webrtc::Clock*>(clock)));
+}
+WEBRTC_EXPORT void webrtc_EnvironmentFactory_set_task_queue_factory(
+    struct webrtc_EnvironmentFactory* self,
+    struct webrtc_TaskQueueFactory_unique* task_queue_factory) {
+  assert(task_queue_factory != nullptr);
+  auto factory = reinterpret_cast<webrtc::EnvironmentFactory*>(self);
+  factory->Set(std::unique_ptr<webrtc::TaskQueueFactory>(
+      reinterpret_cast<webrtc::TaskQueueFactory*>(task_queue_factory)));
+}
+WEBRTC_EXPORT struct webrtc_Environment* webrtc_EnvironmentFactory_Create(
+    struct webrtc_EnvironmentFactory* self) {
+  auto factory = reinterpret_cast<webrtc::EnvironmentFactory*>(self);
+  auto env = new webrtc::Environment(factory->Create());
+  return reinterpret_cast<struct webrtc_Environment*>(env);
+}
 }
@@ -1,6 +1,11 @@
 #pragma once
 
+#include <stddef.h>
+
 #include "../common.h"
+#include "../std.h"
+#include "../system_wrappers/clock.h"
+#include "task_queue/task_queue_factory.h"
 
 #if defined(__cplusplus)
 extern "C" {
@@ -13,6 +18,58 @@ extern "C" {
 struct webrtc_Environment;
 WEBRTC_EXPORT struct webrtc_Environment* webrtc_CreateEnvironment();
 WEBRTC_EXPORT void webrtc_Environment_delete(struct webrtc_Environment* self);
+WEBRTC_EXPORT struct webrtc_TaskQueueFactory*
+webrtc_Environment_task_queue_factory(struct webrtc_Environment* self);
+
+// -------------------------
+// webrtc::FieldTrialsView
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_FieldTrialsView);
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// Lookup は任意のスレッドから同時に呼ばれうる。
+struct webrtc_FieldTrialsView_cbs {
+  // key に対応する値を返す。未設定の場合は null を返してよい。
+  struct std_string_unique* (*Lookup)(const char* key,
+                                      size_t key_len,
+                                      void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_FieldTrialsView_unique* webrtc_FieldTrialsView_new(
+    const struct webrtc_FieldTrialsView_cbs* cbs,
+    void* user_data);
+
+// "Key1/Value1/Key2/Value2/" 形式の文字列から webrtc::FieldTrials を生成する。
+// libwebrtc の規則で不正な文字列の場合は null を返す。
+WEBRTC_EXPORT struct webrtc_FieldTrialsView_unique* webrtc_FieldTrials_Create(
+    const char* s,
+    size_t s_len);
+
+// -------------------------
+// webrtc::EnvironmentFactory
+// -------------------------
+
+struct webrtc_EnvironmentFactory;
+WEBRTC_EXPORT struct webrtc_EnvironmentFactory* webrtc_EnvironmentFactory_new();
+WEBRTC_EXPORT void webrtc_EnvironmentFactory_delete(
+    struct webrtc_EnvironmentFactory* self);
+// field_trials の所有権は self に移る。
+WEBRTC_EXPORT void webrtc_EnvironmentFactory_set_field_trials(
+    struct webrtc_EnvironmentFactory* self,
+    struct webrtc_FieldTrialsView_unique* field_trials);
+// clock の所有権は self に移る。
+WEBRTC_EXPORT void webrtc_EnvironmentFactory_set_clock(
+    struct webrtc_EnvironmentFactory* self,
+    struct webrtc_Clock_unique* clock);
+// task_queue_factory の所有権は self に移る。
+WEBRTC_EXPORT void webrtc_EnvironmentFactory_set_task_queue_factory(
+    struct webrtc_EnvironmentFactory* self,
+    struct webrtc_TaskQueueFactory_unique* task_queue_factory);
+WEBRTC_EXPORT struct webrtc_Environment* webrtc_EnvironmentFactory_Create(
+    struct webrtc_EnvironmentFactory* self);
 
 #if defined(__cplusplus)
 }
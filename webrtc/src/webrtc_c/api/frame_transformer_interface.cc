@@ -0,0 +1,241 @@
+#include "frame_transformer_interface.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <memory>
+#include <string>
+#include <utility>
+
+// WebRTC
+#include <api/array_view.h>
+#include <api/frame_transformer_interface.h>
+#include <api/make_ref_counted.h>
+#include <api/scoped_refptr.h>
+#include <api/video/video_frame_metadata.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+#include "../std.h"
+#include "../std.impl.h"
+
+// -------------------------
+// webrtc::FrameTransformerInterface
+// -------------------------
+
+namespace {
+
+class FrameTransformerInterfaceImpl : public webrtc::FrameTransformerInterface {
+ public:
+  FrameTransformerInterfaceImpl(
+      const struct webrtc_FrameTransformerInterface_cbs* cbs,
+      void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->Transform != nullptr);
+    assert(cbs->RegisterTransformedFrameCallback != nullptr);
+    assert(cbs->RegisterTransformedFrameSinkCallback != nullptr);
+    assert(cbs->UnregisterTransformedFrameCallback != nullptr);
+    assert(cbs->UnregisterTransformedFrameSinkCallback != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~FrameTransformerInterfaceImpl() override { cbs_.OnDestroy(user_data_); }
+
+  void Transform(std::unique_ptr<webrtc::TransformableFrameInterface>
+                     transformable_frame) override {
+    cbs_.Transform(
+        reinterpret_cast<struct webrtc_TransformableFrameInterface_unique*>(
+            transformable_frame.release()),
+        user_data_);
+  }
+
+  void RegisterTransformedFrameCallback(
+      webrtc::scoped_refptr<webrtc::TransformedFrameCallback> callback)
+      override {
+    cbs_.RegisterTransformedFrameCallback(
+        reinterpret_cast<struct webrtc_TransformedFrameCallback_refcounted*>(
+            callback.release()),
+        user_data_);
+  }
+
+  void RegisterTransformedFrameSinkCallback(
+      webrtc::scoped_refptr<webrtc::TransformedFrameCallback> callback,
+      uint32_t ssrc) override {
+    cbs_.RegisterTransformedFrameSinkCallback(
+        reinterpret_cast<struct webrtc_TransformedFrameCallback_refcounted*>(
+            callback.release()),
+        ssrc, user_data_);
+  }
+
+  void UnregisterTransformedFrameCallback() override {
+    cbs_.UnregisterTransformedFrameCallback(user_data_);
+  }
+
+  void UnregisterTransformedFrameSinkCallback(uint32_t ssrc) override {
+    cbs_.UnregisterTransformedFrameSinkCallback(ssrc, user_data_);
+  }
+
+ private:
+  webrtc_FrameTransformerInterface_cbs cbs_{};
+  void* user_data_;
+};
+
+}  // namespace
+
+extern "C" {
+
+// -------------------------
+// webrtc::TransformableFrameInterface
+// -------------------------
+
+WEBRTC_DEFINE_UNIQUE(webrtc_TransformableFrameInterface,
+                     webrtc::TransformableFrameInterface);
+
+WEBRTC_EXPORT extern const int
+    webrtc_TransformableFrameInterface_Direction_kUnknown = static_cast<int>(
+        webrtc::TransformableFrameInterface::Direction::kUnknown);
+WEBRTC_EXPORT extern const int
+    webrtc_TransformableFrameInterface_Direction_kReceiver = static_cast<int>(
+        webrtc::TransformableFrameInterface::Direction::kReceiver);
+WEBRTC_EXPORT extern const int
+    webrtc_TransformableFrameInterface_Direction_kSender = static_cast<int>(
+        webrtc::TransformableFrameInterface::Direction::kSender);
+
+WEBRTC_EXPORT void webrtc_TransformableFrameInterface_GetData(
+    struct webrtc_TransformableFrameInterface* self,
+    const uint8_t** out_data,
+    size_t* out_size) {
+  assert(out_data != nullptr);
+  assert(out_size != nullptr);
+  auto frame = reinterpret_cast<webrtc::TransformableFrameInterface*>(self);
+  auto data = frame->GetData();
+  *out_data = data.data();
+  *out_size = data.size();
+}
+WEBRTC_EXPORT void webrtc_TransformableFrameInterface_SetData(
+    struct webrtc_TransformableFrameInterface* self,
+    const uint8_t* data,
+    size_t size) {
+  assert(data != nullptr || size == 0);
+  auto frame = reinterpret_cast<webrtc::TransformableFrameInterface*>(self);
+  frame->SetData(webrtc::ArrayView<const uint8_t>(data, size));
+}
+WEBRTC_EXPORT int webrtc_TransformableFrameInterface_GetPayloadType(
+    struct webrtc_TransformableFrameInterface* self) {
+  auto frame = reinterpret_cast<webrtc::TransformableFrameInterface*>(self);
+  return frame->GetPayloadType();
+}
+WEBRTC_EXPORT uint32_t webrtc_TransformableFrameInterface_GetSsrc(
+    struct webrtc_TransformableFrameInterface* self) {
+  auto frame = reinterpret_cast<webrtc::TransformableFrameInterface*>(self);
+  return frame->GetSsrc();
+}
+WEBRTC_EXPORT uint32_t webrtc_TransformableFrameInterface_GetTimestamp(
+    struct webrtc_TransformableFrameInterface* self) {
+  auto frame = reinterpret_cast<webrtc::TransformableFrameInterface*>(self);
+  return frame->GetTimestamp();
+}
+WEBRTC_EXPORT void webrtc_TransformableFrameInterface_SetRTPTimestamp(
+    struct webrtc_TransformableFrameInterface* self,
+    uint32_t timestamp) {
+  auto frame = reinterpret_cast<webrtc::TransformableFrameInterface*>(self);
+  frame->SetRTPTimestamp(timestamp);
+}
+WEBRTC_EXPORT webrtc_TransformableFrameInterface_Direction
+webrtc_TransformableFrameInterface_GetDirection(
+    struct webrtc_TransformableFrameInterface* self) {
+  auto frame = reinterpret_cast<webrtc::TransformableFrameInterface*>(self);
+  return static_cast<int>(frame->GetDirection());
+}
+WEBRTC_EXPORT struct std_string_unique*
+webrtc_TransformableFrameInterface_GetMimeType(
+    struct webrtc_TransformableFrameInterface* self) {
+  auto frame = reinterpret_cast<webrtc::TransformableFrameInterface*>(self);
+  auto mime_type = std::make_unique<std::string>(frame->GetMimeType());
+  return reinterpret_cast<struct std_string_unique*>(mime_type.release());
+}
+
+// -------------------------
+// webrtc::TransformableVideoFrameInterface
+// -------------------------
+
+WEBRTC_DEFINE_CAST(webrtc_TransformableFrameInterface,
+                   webrtc_TransformableVideoFrameInterface,
+                   webrtc::TransformableFrameInterface,
+                   webrtc::TransformableVideoFrameInterface);
+
+WEBRTC_EXPORT int webrtc_TransformableVideoFrameInterface_IsKeyFrame(
+    struct webrtc_TransformableVideoFrameInterface* self) {
+  auto frame =
+      reinterpret_cast<webrtc::TransformableVideoFrameInterface*>(self);
+  return frame->IsKeyFrame() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_TransformableVideoFrameInterface_GetWidth(
+    struct webrtc_TransformableVideoFrameInterface* self) {
+  auto frame =
+      reinterpret_cast<webrtc::TransformableVideoFrameInterface*>(self);
+  return frame->Metadata().GetWidth();
+}
+WEBRTC_EXPORT int webrtc_TransformableVideoFrameInterface_GetHeight(
+    struct webrtc_TransformableVideoFrameInterface* self) {
+  auto frame =
+      reinterpret_cast<webrtc::TransformableVideoFrameInterface*>(self);
+  return frame->Metadata().GetHeight();
+}
+WEBRTC_EXPORT void webrtc_TransformableVideoFrameInterface_GetFrameId(
+    struct webrtc_TransformableVideoFrameInterface* self,
+    int* out_has,
+    int64_t* out_value) {
+  auto frame =
+      reinterpret_cast<webrtc::TransformableVideoFrameInterface*>(self);
+  webrtc_c::OptionalGet(frame->Metadata().GetFrameId(), out_has, out_value);
+}
+WEBRTC_EXPORT int webrtc_TransformableVideoFrameInterface_GetSpatialIndex(
+    struct webrtc_TransformableVideoFrameInterface* self) {
+  auto frame =
+      reinterpret_cast<webrtc::TransformableVideoFrameInterface*>(self);
+  return frame->Metadata().GetSpatialIndex();
+}
+WEBRTC_EXPORT int webrtc_TransformableVideoFrameInterface_GetTemporalIndex(
+    struct webrtc_TransformableVideoFrameInterface* self) {
+  auto frame =
+      reinterpret_cast<webrtc::TransformableVideoFrameInterface*>(self);
+  return frame->Metadata().GetTemporalIndex();
+}
+
+// -------------------------
+// webrtc::TransformedFrameCallback
+// -------------------------
+
+WEBRTC_DEFINE_REFCOUNTED(webrtc_TransformedFrameCallback,
+                         webrtc::TransformedFrameCallback);
+
+WEBRTC_EXPORT void webrtc_TransformedFrameCallback_OnTransformedFrame(
+    struct webrtc_TransformedFrameCallback* self,
+    struct webrtc_TransformableFrameInterface_unique* frame) {
+  assert(frame != nullptr);
+  auto callback = reinterpret_cast<webrtc::TransformedFrameCallback*>(self);
+  callback->OnTransformedFrame(
+      std::unique_ptr<webrtc::TransformableFrameInterface>(
+          reinterpret_cast<webrtc::TransformableFrameInterface*>(frame)));
+}
+
+// -------------------------
+// webrtc::FrameTransformerInterface
+// -------------------------
+
+WEBRTC_DEFINE_REFCOUNTED(webrtc_FrameTransformerInterface,
+                         webrtc::FrameTransformerInterface);
+
+WEBRTC_EXPORT struct webrtc_FrameTransformerInterface_refcounted*
+webrtc_FrameTransformerInterface_make_ref_counted(
+    const struct webrtc_FrameTransformerInterface_cbs* cbs,
+    void* user_data) {
+  auto impl =
+      webrtc::make_ref_counted<FrameTransformerInterfaceImpl>(cbs, user_data);
+  return reinterpret_cast<
+      struct webrtc_FrameTransformerInterface_refcounted*>(impl.release());
+}
+}
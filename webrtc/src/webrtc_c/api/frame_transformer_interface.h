@@ -0,0 +1,121 @@
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#include "../common.h"
+#include "../std.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::TransformableFrameInterface
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_TransformableFrameInterface);
+
+// Direction 定数
+typedef int webrtc_TransformableFrameInterface_Direction;
+WEBRTC_EXPORT extern const int
+    webrtc_TransformableFrameInterface_Direction_kUnknown;
+WEBRTC_EXPORT extern const int
+    webrtc_TransformableFrameInterface_Direction_kReceiver;
+WEBRTC_EXPORT extern const int
+    webrtc_TransformableFrameInterface_Direction_kSender;
+
+// out_data は self が生きている間かつ SetData するまで有効。
+WEBRTC_EXPORT void webrtc_TransformableFrameInterface_GetData(
+    struct webrtc_TransformableFrameInterface* self,
+    const uint8_t** out_data,
+    size_t* out_size);
+WEBRTC_EXPORT void webrtc_TransformableFrameInterface_SetData(
+    struct webrtc_TransformableFrameInterface* self,
+    const uint8_t* data,
+    size_t size);
+WEBRTC_EXPORT int webrtc_TransformableFrameInterface_GetPayloadType(
+    struct webrtc_TransformableFrameInterface* self);
+WEBRTC_EXPORT uint32_t webrtc_TransformableFrameInterface_GetSsrc(
+    struct webrtc_TransformableFrameInterface* self);
+WEBRTC_EXPORT uint32_t webrtc_TransformableFrameInterface_GetTimestamp(
+    struct webrtc_TransformableFrameInterface* self);
+WEBRTC_EXPORT void webrtc_TransformableFrameInterface_SetRTPTimestamp(
+    struct webrtc_TransformableFrameInterface* self,
+    uint32_t timestamp);
+WEBRTC_EXPORT webrtc_TransformableFrameInterface_Direction
+webrtc_TransformableFrameInterface_GetDirection(
+    struct webrtc_TransformableFrameInterface* self);
+WEBRTC_EXPORT struct std_string_unique*
+webrtc_TransformableFrameInterface_GetMimeType(
+    struct webrtc_TransformableFrameInterface* self);
+
+// -------------------------
+// webrtc::TransformableVideoFrameInterface
+// -------------------------
+
+struct webrtc_TransformableVideoFrameInterface;
+
+// 映像フレーム（MIME タイプが "video/" で始まる）に対してのみ呼び出すこと。
+WEBRTC_DECLARE_CAST(webrtc_TransformableFrameInterface,
+                    webrtc_TransformableVideoFrameInterface);
+
+WEBRTC_EXPORT int webrtc_TransformableVideoFrameInterface_IsKeyFrame(
+    struct webrtc_TransformableVideoFrameInterface* self);
+WEBRTC_EXPORT int webrtc_TransformableVideoFrameInterface_GetWidth(
+    struct webrtc_TransformableVideoFrameInterface* self);
+WEBRTC_EXPORT int webrtc_TransformableVideoFrameInterface_GetHeight(
+    struct webrtc_TransformableVideoFrameInterface* self);
+WEBRTC_EXPORT void webrtc_TransformableVideoFrameInterface_GetFrameId(
+    struct webrtc_TransformableVideoFrameInterface* self,
+    int* out_has,
+    int64_t* out_value);
+WEBRTC_EXPORT int webrtc_TransformableVideoFrameInterface_GetSpatialIndex(
+    struct webrtc_TransformableVideoFrameInterface* self);
+WEBRTC_EXPORT int webrtc_TransformableVideoFrameInterface_GetTemporalIndex(
+    struct webrtc_TransformableVideoFrameInterface* self);
+
+// -------------------------
+// webrtc::TransformedFrameCallback
+// -------------------------
+
+WEBRTC_DECLARE_REFCOUNTED(webrtc_TransformedFrameCallback);
+
+// frame の所有権は libwebrtc 側へ移る。
+WEBRTC_EXPORT void webrtc_TransformedFrameCallback_OnTransformedFrame(
+    struct webrtc_TransformedFrameCallback* self,
+    struct webrtc_TransformableFrameInterface_unique* frame);
+
+// -------------------------
+// webrtc::FrameTransformerInterface
+// -------------------------
+
+WEBRTC_DECLARE_REFCOUNTED(webrtc_FrameTransformerInterface);
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// frame と callback の所有権はコールバック側へ移る。
+struct webrtc_FrameTransformerInterface_cbs {
+  void (*Transform)(struct webrtc_TransformableFrameInterface_unique* frame,
+                    void* user_data);
+  void (*RegisterTransformedFrameCallback)(
+      struct webrtc_TransformedFrameCallback_refcounted* callback,
+      void* user_data);
+  void (*RegisterTransformedFrameSinkCallback)(
+      struct webrtc_TransformedFrameCallback_refcounted* callback,
+      uint32_t ssrc,
+      void* user_data);
+  void (*UnregisterTransformedFrameCallback)(void* user_data);
+  void (*UnregisterTransformedFrameSinkCallback)(uint32_t ssrc,
+                                                 void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_FrameTransformerInterface_refcounted*
+webrtc_FrameTransformerInterface_make_ref_counted(
+    const struct webrtc_FrameTransformerInterface_cbs* cbs,
+    void* user_data);
+
+#if defined(__cplusplus)
+}
+#endif
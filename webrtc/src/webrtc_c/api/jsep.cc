@@ -11,9 +11,11 @@
 #include <api/make_ref_counted.h>
 #include <api/rtc_error.h>
 #include <api/scoped_refptr.h>
+#include <pc/session_description.h>
 
 #include "../common.h"
 #include "../common.impl.h"
+#include "../pc/session_description.h"
 #include "../std.h"
 #include "rtc_error.h"
 
@@ -78,6 +80,14 @@ WEBRTC_EXPORT int webrtc_SessionDescriptionInterface_ToString(
   *out_sdp = reinterpret_cast<struct std_string_unique*>(out.release());
   return 1;
 }
+WEBRTC_EXPORT const struct webrtc_SessionDescription*
+webrtc_SessionDescriptionInterface_description(
+    const struct webrtc_SessionDescriptionInterface* self) {
+  auto desc =
+      reinterpret_cast<const webrtc::SessionDescriptionInterface*>(self);
+  return reinterpret_cast<const struct webrtc_SessionDescription*>(
+      desc->description());
+}
 }
 
 extern "C" {
@@ -124,11 +134,28 @@ WEBRTC_EXPORT struct webrtc_IceCandidate* webrtc_CreateIceCandidate(
   }
   return reinterpret_cast<struct webrtc_IceCandidate*>(ice_candidate);
 }
+WEBRTC_EXPORT struct webrtc_IceCandidate* webrtc_IceCandidate_new(
+    const char* sdp_mid,
+    size_t sdp_mid_len,
+    int sdp_mline_index,
+    const struct webrtc_Candidate* candidate) {
+  assert(candidate != nullptr);
+  auto cpp_candidate = reinterpret_cast<const webrtc::Candidate*>(candidate);
+  auto ice_candidate = new webrtc::IceCandidate(
+      std::string(sdp_mid, sdp_mid_len), sdp_mline_index, *cpp_candidate);
+  return reinterpret_cast<struct webrtc_IceCandidate*>(ice_candidate);
+}
 WEBRTC_EXPORT void webrtc_IceCandidate_delete(
     struct webrtc_IceCandidate* self) {
   auto candidate = reinterpret_cast<webrtc::IceCandidate*>(self);
   delete candidate;
 }
+WEBRTC_EXPORT const struct webrtc_Candidate* webrtc_IceCandidate_candidate(
+    const struct webrtc_IceCandidate* self) {
+  auto ice_candidate = reinterpret_cast<const webrtc::IceCandidate*>(self);
+  return reinterpret_cast<const struct webrtc_Candidate*>(
+      &ice_candidate->candidate());
+}
 WEBRTC_EXPORT void webrtc_IceCandidate_sdp_mid(
     const struct webrtc_IceCandidate* self,
     struct std_string_unique** out) {
@@ -3,7 +3,9 @@
 #include <stddef.h>
 
 #include "../common.h"
+#include "../pc/session_description.h"
 #include "../std.h"
+#include "candidate.h"
 #include "rtc_error.h"
 
 #if defined(__cplusplus)
@@ -34,6 +36,10 @@ WEBRTC_EXPORT int webrtc_SessionDescriptionInterface_GetType(
 WEBRTC_EXPORT int webrtc_SessionDescriptionInterface_ToString(
     struct webrtc_SessionDescriptionInterface* self,
     struct std_string_unique** out_sdp);
+// パース済みの内容を返す。rollback の場合は null を返す
+WEBRTC_EXPORT const struct webrtc_SessionDescription*
+webrtc_SessionDescriptionInterface_description(
+    const struct webrtc_SessionDescriptionInterface* self);
 
 // -------------------------
 // webrtc::IceCandidate
@@ -55,7 +61,14 @@ WEBRTC_EXPORT struct webrtc_IceCandidate* webrtc_CreateIceCandidate(
     const char* sdp,
     size_t sdp_len,
     struct webrtc_SdpParseError_unique** out_error);
+WEBRTC_EXPORT struct webrtc_IceCandidate* webrtc_IceCandidate_new(
+    const char* sdp_mid,
+    size_t sdp_mid_len,
+    int sdp_mline_index,
+    const struct webrtc_Candidate* candidate);
 WEBRTC_EXPORT void webrtc_IceCandidate_delete(struct webrtc_IceCandidate* self);
+WEBRTC_EXPORT const struct webrtc_Candidate* webrtc_IceCandidate_candidate(
+    const struct webrtc_IceCandidate* self);
 WEBRTC_EXPORT void webrtc_IceCandidate_sdp_mid(
     const struct webrtc_IceCandidate* self,
     struct std_string_unique** out);
@@ -17,4 +17,6 @@ WEBRTC_EXPORT extern const int webrtc_MediaType_AUDIO =
     static_cast<int>(webrtc::MediaType::AUDIO);
 WEBRTC_EXPORT extern const int webrtc_MediaType_VIDEO =
     static_cast<int>(webrtc::MediaType::VIDEO);
+WEBRTC_EXPORT extern const int webrtc_MediaType_DATA =
+    static_cast<int>(webrtc::MediaType::DATA);
 }
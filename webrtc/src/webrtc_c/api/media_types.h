@@ -12,6 +12,7 @@ extern "C" {
 
 WEBRTC_EXPORT extern const int webrtc_MediaType_AUDIO;
 WEBRTC_EXPORT extern const int webrtc_MediaType_VIDEO;
+WEBRTC_EXPORT extern const int webrtc_MediaType_DATA;
 
 #if defined(__cplusplus)
 }
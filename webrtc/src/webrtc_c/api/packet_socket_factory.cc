@@ -0,0 +1,114 @@
+#include "packet_socket_factory.h"
+
+#include <assert.h>
+#include <stdint.h>
+#include <memory>
+#include <utility>
+
+// WebRTC
+#include <api/async_dns_resolver.h>
+#include <api/environment/environment.h>
+#include <api/packet_socket_factory.h>
+#include <rtc_base/async_dns_resolver.h>
+#include <rtc_base/async_packet_socket.h>
+#include <rtc_base/socket_address.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+#include "../rtc_base/async_packet_socket.h"
+#include "../rtc_base/socket_address.h"
+#include "async_dns_resolver.h"
+
+// -------------------------
+// webrtc::PacketSocketFactory
+// -------------------------
+
+namespace {
+
+class PacketSocketFactoryImpl : public webrtc::PacketSocketFactory {
+ public:
+  PacketSocketFactoryImpl(const struct webrtc_PacketSocketFactory_cbs* cbs,
+                          void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->CreateUdpSocket != nullptr);
+    assert(cbs->CreateClientTcpSocket != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~PacketSocketFactoryImpl() override { cbs_.OnDestroy(user_data_); }
+
+  std::unique_ptr<webrtc::AsyncPacketSocket> CreateUdpSocket(
+      const webrtc::Environment& env,
+      const webrtc::SocketAddress& address,
+      uint16_t min_port,
+      uint16_t max_port) override {
+    return Take(cbs_.CreateUdpSocket(
+        reinterpret_cast<const struct webrtc_SocketAddress*>(&address),
+        min_port, max_port, user_data_));
+  }
+
+  std::unique_ptr<webrtc::AsyncPacketSocket> CreateClientTcpSocket(
+      const webrtc::Environment& env,
+      const webrtc::SocketAddress& local_address,
+      const webrtc::SocketAddress& remote_address,
+      const webrtc::PacketSocketTcpOptions& tcp_options) override {
+    return Take(cbs_.CreateClientTcpSocket(
+        reinterpret_cast<const struct webrtc_SocketAddress*>(&local_address),
+        reinterpret_cast<const struct webrtc_SocketAddress*>(&remote_address),
+        user_data_));
+  }
+
+  std::unique_ptr<webrtc::AsyncDnsResolverInterface> CreateAsyncDnsResolver()
+      override {
+    if (resolver_factory_) {
+      return resolver_factory_->Create();
+    }
+    return std::make_unique<webrtc::AsyncDnsResolver>();
+  }
+
+  void set_resolver_factory(
+      std::unique_ptr<webrtc::AsyncDnsResolverFactoryInterface>
+          resolver_factory) {
+    resolver_factory_ = std::move(resolver_factory);
+  }
+
+ private:
+  static std::unique_ptr<webrtc::AsyncPacketSocket> Take(
+      struct webrtc_AsyncPacketSocket_unique* socket) {
+    return std::unique_ptr<webrtc::AsyncPacketSocket>(
+        reinterpret_cast<webrtc::AsyncPacketSocket*>(socket));
+  }
+
+  webrtc_PacketSocketFactory_cbs cbs_{};
+  void* user_data_;
+  std::unique_ptr<webrtc::AsyncDnsResolverFactoryInterface> resolver_factory_;
+};
+
+}  // namespace
+
+extern "C" {
+
+WEBRTC_DEFINE_UNIQUE(webrtc_PacketSocketFactory, webrtc::PacketSocketFactory);
+
+WEBRTC_EXPORT struct webrtc_PacketSocketFactory_unique*
+webrtc_PacketSocketFactory_new(
+    const struct webrtc_PacketSocketFactory_cbs* cbs,
+    void* user_data) {
+  auto factory = std::make_unique<PacketSocketFactoryImpl>(cbs, user_data);
+  return reinterpret_cast<struct webrtc_PacketSocketFactory_unique*>(
+      static_cast<webrtc::PacketSocketFactory*>(factory.release()));
+}
+
+WEBRTC_EXPORT void webrtc_PacketSocketFactory_set_async_dns_resolver_factory(
+    struct webrtc_PacketSocketFactory* self,
+    struct webrtc_AsyncDnsResolverFactory_unique* resolver_factory) {
+  auto factory = static_cast<PacketSocketFactoryImpl*>(
+      reinterpret_cast<webrtc::PacketSocketFactory*>(self));
+  factory->set_resolver_factory(
+      std::unique_ptr<webrtc::AsyncDnsResolverFactoryInterface>(
+          reinterpret_cast<webrtc::AsyncDnsResolverFactoryInterface*>(
+              resolver_factory)));
+}
+}
@@ -0,0 +1,52 @@
+#pragma once
+
+#include <stdint.h>
+
+#include "../common.h"
+#include "async_dns_resolver.h"
+#include "../rtc_base/async_packet_socket.h"
+#include "../rtc_base/socket_address.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::PacketSocketFactory
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_PacketSocketFactory);
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// コールバックは network thread から呼ばれる。
+// ソケットを生成できない場合は null を返す。
+struct webrtc_PacketSocketFactory_cbs {
+  struct webrtc_AsyncPacketSocket_unique* (*CreateUdpSocket)(
+      const struct webrtc_SocketAddress* address,
+      uint16_t min_port,
+      uint16_t max_port,
+      void* user_data);
+  struct webrtc_AsyncPacketSocket_unique* (*CreateClientTcpSocket)(
+      const struct webrtc_SocketAddress* local_address,
+      const struct webrtc_SocketAddress* remote_address,
+      void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_PacketSocketFactory_unique*
+webrtc_PacketSocketFactory_new(
+    const struct webrtc_PacketSocketFactory_cbs* cbs,
+    void* user_data);
+
+// webrtc_PacketSocketFactory_new で生成したものに対してのみ呼び出せる。
+// STUN / TURN サーバーのホスト名解決に resolver_factory を使う。
+// 設定しない場合は組み込みの resolver を使う。
+// resolver_factory の所有権は self へ移る。
+WEBRTC_EXPORT void webrtc_PacketSocketFactory_set_async_dns_resolver_factory(
+    struct webrtc_PacketSocketFactory* self,
+    struct webrtc_AsyncDnsResolverFactory_unique* resolver_factory);
+
+#if defined(__cplusplus)
+}
+#endif
@@ -2,6 +2,7 @@
 
 #include <stdarg.h>
 #include <stddef.h>
+#include <stdint.h>
 #include <cassert>
 #include <exception>
 #include <memory>
@@ -10,6 +11,7 @@
 #include <vector>
 
 // WebRTC
+#include <api/async_dns_resolver.h>
 #include <api/audio/audio_device.h>
 #include <api/audio/audio_processing.h>
 #include <api/audio_codecs/audio_decoder_factory.h>
@@ -17,9 +19,12 @@
 
 // WebRTC C
 #include <api/audio_options.h>
+#include <api/candidate.h>
 #include <api/create_modular_peer_connection_factory.h>
+#include <api/crypto/crypto_options.h>
 #include <api/data_channel_interface.h>
 #include <api/enable_media.h>
+#include <api/environment/environment.h>
 #include <api/environment/environment_factory.h>
 #include <api/jsep.h>
 #include <api/make_ref_counted.h>
@@ -29,6 +34,7 @@
 #include <api/peer_connection_interface.h>
 #include <api/rtc_error.h>
 #include <api/rtc_event_log/rtc_event_log_factory_interface.h>
+#include <api/rtc_event_log_output.h>
 #include <api/rtp_parameters.h>
 #include <api/rtp_receiver_interface.h>
 #include <api/rtp_transceiver_interface.h>
@@ -39,6 +45,8 @@
 #include <api/stats/rtc_stats_report.h>
 #include <api/video_codecs/video_decoder_factory.h>
 #include <api/video_codecs/video_encoder_factory.h>
+#include <p2p/base/candidate_pair_interface.h>
+#include <p2p/base/port_allocator.h>
 #include <p2p/client/basic_port_allocator.h>
 #include <pc/connection_context.h>
 #include <pc/media_factory.h>
@@ -48,27 +56,37 @@
 #include <rtc_base/crypt_string_revive.h>
 #include <rtc_base/network.h>
 #include <rtc_base/proxy_info_revive.h>
+#include <rtc_base/rtc_certificate.h>
 #include <rtc_base/socket_address.h>
+#include <rtc_base/socket_factory.h>
 #include <rtc_base/ssl_identity.h>
 #include <rtc_base/ssl_stream_adapter.h>
 #include <rtc_base/thread.h>
 
 #include "../common.h"
 #include "../common.impl.h"
+#include "../p2p/base/candidate_pair_interface.h"
+#include "../p2p/client/basic_port_allocator.h"
 #include "../pc/connection_context.h"
+#include "../rtc_base/rtc_certificate.h"
 #include "../rtc_base/ssl_certificate.h"
 #include "../rtc_base/ssl_identity.h"
 #include "../rtc_base/thread.h"
 #include "../std.h"
+#include "../std.impl.h"
 #include "api/rtp_sender_interface.h"
+#include "async_dns_resolver.h"
 #include "audio/audio_device.h"
 #include "audio/audio_processing.h"
 #include "audio_codecs/audio_decoder_factory.h"
 #include "audio_codecs/audio_encoder_factory.h"
+#include "candidate.h"
+#include "crypto/crypto_options.h"
 #include "data_channel_interface.h"
 #include "dtls_transport_interface.h"
 #include "jsep.h"
 #include "media_stream_interface.h"
+#include "packet_socket_factory.h"
 #include "rtc_base/ssl_certificate.h"
 #include "rtc_error.h"
 #include "rtc_event_log.h"
@@ -103,13 +121,60 @@ class PeerConnectionObserverImpl : public webrtc::PeerConnectionObserver {
     assert(observer->OnTrack != nullptr);
     assert(observer->OnRemoveTrack != nullptr);
     assert(observer->OnDestroy != nullptr);
+    assert(observer->OnSignalingChange != nullptr);
+    assert(observer->OnAddStream != nullptr);
+    assert(observer->OnRemoveStream != nullptr);
+    assert(observer->OnNegotiationNeededEvent != nullptr);
+    assert(observer->OnIceConnectionReceivingChange != nullptr);
+    assert(observer->OnIceCandidatesRemoved != nullptr);
+    assert(observer->OnIceSelectedCandidatePairChanged != nullptr);
     observer_ = *observer;
   }
 
   ~PeerConnectionObserverImpl() override { observer_.OnDestroy(user_data_); }
 
   void OnSignalingChange(
-      webrtc::PeerConnectionInterface::SignalingState new_state) override {}
+      webrtc::PeerConnectionInterface::SignalingState new_state) override {
+    observer_.OnSignalingChange(
+        static_cast<webrtc_PeerConnectionInterface_SignalingState>(new_state),
+        user_data_);
+  }
+  void OnAddStream(
+      webrtc::scoped_refptr<webrtc::MediaStreamInterface> stream) override {
+    webrtc::scoped_refptr<webrtc::MediaStreamInterface> stream_ref(stream);
+    observer_.OnAddStream(
+        reinterpret_cast<struct webrtc_MediaStreamInterface_refcounted*>(
+            stream_ref.release()),
+        user_data_);
+  }
+  void OnRemoveStream(
+      webrtc::scoped_refptr<webrtc::MediaStreamInterface> stream) override {
+    webrtc::scoped_refptr<webrtc::MediaStreamInterface> stream_ref(stream);
+    observer_.OnRemoveStream(
+        reinterpret_cast<struct webrtc_MediaStreamInterface_refcounted*>(
+            stream_ref.release()),
+        user_data_);
+  }
+  void OnNegotiationNeededEvent(uint32_t event_id) override {
+    observer_.OnNegotiationNeededEvent(event_id, user_data_);
+  }
+  void OnIceConnectionReceivingChange(bool receiving) override {
+    observer_.OnIceConnectionReceivingChange(receiving ? 1 : 0, user_data_);
+  }
+  void OnIceCandidatesRemoved(
+      const std::vector<webrtc::Candidate>& candidates) override {
+    // C API の vector 関数は非 const を受け取るため const を外して渡す。
+    // コールバック側で変更してはならない。
+    auto vec = const_cast<std::vector<webrtc::Candidate>*>(&candidates);
+    observer_.OnIceCandidatesRemoved(
+        reinterpret_cast<struct webrtc_Candidate_vector*>(vec), user_data_);
+  }
+  void OnIceSelectedCandidatePairChanged(
+      const webrtc::CandidatePairChangeEvent& event) override {
+    observer_.OnIceSelectedCandidatePairChanged(
+        reinterpret_cast<const struct webrtc_CandidatePairChangeEvent*>(&event),
+        user_data_);
+  }
   void OnDataChannel(webrtc::scoped_refptr<webrtc::DataChannelInterface>
                          data_channel) override {
     webrtc::scoped_refptr<webrtc::DataChannelInterface> data_channel_ref(
@@ -246,6 +311,25 @@ WEBRTC_EXPORT void webrtc_PeerConnectionObserver_delete(
 // webrtc::PeerConnectionInterface
 // -------------------------
 
+// description は signaling thread 上で差し替え・破棄されるため、
+// 取得と複製は signaling thread 上で行う。
+static struct webrtc_SessionDescriptionInterface_unique*
+CloneSessionDescription(
+    webrtc::PeerConnectionInterface* pc,
+    const webrtc::SessionDescriptionInterface* (
+        webrtc::PeerConnectionInterface::*getter)() const) {
+  auto desc = pc->signaling_thread()->BlockingCall(
+      [pc, getter]() -> std::unique_ptr<webrtc::SessionDescriptionInterface> {
+        auto desc = (pc->*getter)();
+        if (desc == nullptr) {
+          return nullptr;
+        }
+        return desc->Clone();
+      });
+  return reinterpret_cast<struct webrtc_SessionDescriptionInterface_unique*>(
+      desc.release());
+}
+
 extern "C" {
 WEBRTC_DEFINE_REFCOUNTED(webrtc_PeerConnectionInterface,
                          webrtc::PeerConnectionInterface);
@@ -343,9 +427,26 @@ webrtc_PeerConnectionInterface_RTCConfiguration_get_servers(
       struct webrtc_PeerConnectionInterface_IceServer_vector*>(
       &config->servers);
 }
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_IceTransportsType_kNone = static_cast<int>(
+        webrtc::PeerConnectionInterface::IceTransportsType::kNone);
 WEBRTC_EXPORT extern const int
     webrtc_PeerConnectionInterface_IceTransportsType_kRelay = static_cast<int>(
         webrtc::PeerConnectionInterface::IceTransportsType::kRelay);
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_IceTransportsType_kNoHost = static_cast<int>(
+        webrtc::PeerConnectionInterface::IceTransportsType::kNoHost);
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_IceTransportsType_kAll = static_cast<int>(
+        webrtc::PeerConnectionInterface::IceTransportsType::kAll);
+WEBRTC_EXPORT webrtc_PeerConnectionInterface_IceTransportsType
+webrtc_PeerConnectionInterface_RTCConfiguration_get_type(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return static_cast<int>(config->type);
+}
 WEBRTC_EXPORT void webrtc_PeerConnectionInterface_RTCConfiguration_set_type(
     struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
     webrtc_PeerConnectionInterface_IceTransportsType type) {
@@ -386,6 +487,349 @@ webrtc_PeerConnectionInterface_RTCConfiguration_set_always_negotiate_data_channe
           self);
   config->always_negotiate_data_channels = always_negotiate_data_channels != 0;
 }
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_RTCConfiguration_enable_implicit_rollback(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return config->enable_implicit_rollback ? 1 : 0;
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_enable_implicit_rollback(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int enable) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  config->enable_implicit_rollback = enable != 0;
+}
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_RTCConfiguration_stats_timestamp_with_environment_clock(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return config->stats_timestamp_with_environment_clock ? 1 : 0;
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_stats_timestamp_with_environment_clock(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int enable) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  config->stats_timestamp_with_environment_clock = enable != 0;
+}
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_BundlePolicy_kBundlePolicyBalanced =
+        static_cast<int>(webrtc::PeerConnectionInterface::BundlePolicy::
+                             kBundlePolicyBalanced);
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_BundlePolicy_kBundlePolicyMaxBundle =
+        static_cast<int>(webrtc::PeerConnectionInterface::BundlePolicy::
+                             kBundlePolicyMaxBundle);
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_BundlePolicy_kBundlePolicyMaxCompat =
+        static_cast<int>(webrtc::PeerConnectionInterface::BundlePolicy::
+                             kBundlePolicyMaxCompat);
+WEBRTC_EXPORT webrtc_PeerConnectionInterface_BundlePolicy
+webrtc_PeerConnectionInterface_RTCConfiguration_get_bundle_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return static_cast<int>(config->bundle_policy);
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_bundle_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    webrtc_PeerConnectionInterface_BundlePolicy bundle_policy) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  config->bundle_policy =
+      static_cast<webrtc::PeerConnectionInterface::BundlePolicy>(bundle_policy);
+}
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_RtcpMuxPolicy_kRtcpMuxPolicyNegotiate =
+        static_cast<int>(webrtc::PeerConnectionInterface::RtcpMuxPolicy::
+                             kRtcpMuxPolicyNegotiate);
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_RtcpMuxPolicy_kRtcpMuxPolicyRequire =
+        static_cast<int>(webrtc::PeerConnectionInterface::RtcpMuxPolicy::
+                             kRtcpMuxPolicyRequire);
+WEBRTC_EXPORT webrtc_PeerConnectionInterface_RtcpMuxPolicy
+webrtc_PeerConnectionInterface_RTCConfiguration_get_rtcp_mux_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return static_cast<int>(config->rtcp_mux_policy);
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_rtcp_mux_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    webrtc_PeerConnectionInterface_RtcpMuxPolicy rtcp_mux_policy) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  config->rtcp_mux_policy =
+      static_cast<webrtc::PeerConnectionInterface::RtcpMuxPolicy>(
+          rtcp_mux_policy);
+}
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_TcpCandidatePolicy_kTcpCandidatePolicyEnabled =
+        static_cast<int>(webrtc::PeerConnectionInterface::TcpCandidatePolicy::
+                             kTcpCandidatePolicyEnabled);
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_TcpCandidatePolicy_kTcpCandidatePolicyDisabled =
+        static_cast<int>(webrtc::PeerConnectionInterface::TcpCandidatePolicy::
+                             kTcpCandidatePolicyDisabled);
+WEBRTC_EXPORT webrtc_PeerConnectionInterface_TcpCandidatePolicy
+webrtc_PeerConnectionInterface_RTCConfiguration_get_tcp_candidate_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return static_cast<int>(config->tcp_candidate_policy);
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_tcp_candidate_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    webrtc_PeerConnectionInterface_TcpCandidatePolicy tcp_candidate_policy) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  config->tcp_candidate_policy =
+      static_cast<webrtc::PeerConnectionInterface::TcpCandidatePolicy>(
+          tcp_candidate_policy);
+}
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_CandidateNetworkPolicy_kCandidateNetworkPolicyAll =
+        static_cast<int>(
+            webrtc::PeerConnectionInterface::CandidateNetworkPolicy::kCandidateNetworkPolicyAll);
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_CandidateNetworkPolicy_kCandidateNetworkPolicyLowCost =
+        static_cast<int>(
+            webrtc::PeerConnectionInterface::CandidateNetworkPolicy::kCandidateNetworkPolicyLowCost);
+WEBRTC_EXPORT webrtc_PeerConnectionInterface_CandidateNetworkPolicy
+webrtc_PeerConnectionInterface_RTCConfiguration_get_candidate_network_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return static_cast<int>(config->candidate_network_policy);
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_candidate_network_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    webrtc_PeerConnectionInterface_CandidateNetworkPolicy
+        candidate_network_policy) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  config->candidate_network_policy =
+      static_cast<webrtc::PeerConnectionInterface::CandidateNetworkPolicy>(
+          candidate_network_policy);
+}
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_ContinualGatheringPolicy_GATHER_ONCE =
+        static_cast<int>(
+            webrtc::PeerConnectionInterface::ContinualGatheringPolicy::GATHER_ONCE);
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_ContinualGatheringPolicy_GATHER_CONTINUALLY =
+        static_cast<int>(
+            webrtc::PeerConnectionInterface::ContinualGatheringPolicy::GATHER_CONTINUALLY);
+WEBRTC_EXPORT webrtc_PeerConnectionInterface_ContinualGatheringPolicy
+webrtc_PeerConnectionInterface_RTCConfiguration_get_continual_gathering_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return static_cast<int>(config->continual_gathering_policy);
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_continual_gathering_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    webrtc_PeerConnectionInterface_ContinualGatheringPolicy
+        continual_gathering_policy) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  config->continual_gathering_policy =
+      static_cast<webrtc::PeerConnectionInterface::ContinualGatheringPolicy>(
+          continual_gathering_policy);
+}
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_RTCConfiguration_get_ice_candidate_pool_size(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return config->ice_candidate_pool_size;
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_ice_candidate_pool_size(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int ice_candidate_pool_size) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  config->ice_candidate_pool_size = ice_candidate_pool_size;
+}
+WEBRTC_EXPORT struct webrtc_PeerConnectionInterface_PortAllocatorConfig*
+webrtc_PeerConnectionInterface_RTCConfiguration_get_port_allocator_config(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return reinterpret_cast<
+      struct webrtc_PeerConnectionInterface_PortAllocatorConfig*>(
+      &config->port_allocator_config);
+}
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_PortAllocatorConfig_get_min_port(
+    struct webrtc_PeerConnectionInterface_PortAllocatorConfig* self) {
+  auto port_allocator_config = reinterpret_cast<
+      webrtc::PeerConnectionInterface::PortAllocatorConfig*>(self);
+  return port_allocator_config->min_port;
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_PortAllocatorConfig_set_min_port(
+    struct webrtc_PeerConnectionInterface_PortAllocatorConfig* self,
+    int min_port) {
+  auto port_allocator_config = reinterpret_cast<
+      webrtc::PeerConnectionInterface::PortAllocatorConfig*>(self);
+  port_allocator_config->min_port = min_port;
+}
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_PortAllocatorConfig_get_max_port(
+    struct webrtc_PeerConnectionInterface_PortAllocatorConfig* self) {
+  auto port_allocator_config = reinterpret_cast<
+      webrtc::PeerConnectionInterface::PortAllocatorConfig*>(self);
+  return port_allocator_config->max_port;
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_PortAllocatorConfig_set_max_port(
+    struct webrtc_PeerConnectionInterface_PortAllocatorConfig* self,
+    int max_port) {
+  auto port_allocator_config = reinterpret_cast<
+      webrtc::PeerConnectionInterface::PortAllocatorConfig*>(self);
+  port_allocator_config->max_port = max_port;
+}
+WEBRTC_EXPORT struct webrtc_RTCCertificate_refcounted_vector*
+webrtc_PeerConnectionInterface_RTCConfiguration_get_certificates(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return reinterpret_cast<struct webrtc_RTCCertificate_refcounted_vector*>(
+      &config->certificates);
+}
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_RTCConfiguration_dscp(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return config->dscp() ? 1 : 0;
+}
+WEBRTC_EXPORT void webrtc_PeerConnectionInterface_RTCConfiguration_set_dscp(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int enable) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  config->set_dscp(enable != 0);
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_get_ice_connection_receiving_timeout(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int* out_has,
+    int* out_value) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  webrtc_c::OptionalGet(config->ice_connection_receiving_timeout, out_has,
+                        out_value);
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_ice_connection_receiving_timeout(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int has,
+    const int* value) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  webrtc_c::OptionalSet(config->ice_connection_receiving_timeout, has, value);
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_get_ice_check_min_interval(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int* out_has,
+    int* out_value) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  webrtc_c::OptionalGet(config->ice_check_min_interval, out_has, out_value);
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_ice_check_min_interval(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int has,
+    const int* value) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  webrtc_c::OptionalSet(config->ice_check_min_interval, has, value);
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_get_stun_candidate_keepalive_interval(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int* out_has,
+    int* out_value) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  webrtc_c::OptionalGet(config->stun_candidate_keepalive_interval, out_has,
+                        out_value);
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_stun_candidate_keepalive_interval(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int has,
+    const int* value) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  webrtc_c::OptionalSet(config->stun_candidate_keepalive_interval, has, value);
+}
+WEBRTC_EXPORT struct webrtc_CryptoOptions*
+webrtc_PeerConnectionInterface_RTCConfiguration_get_crypto_options(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return reinterpret_cast<struct webrtc_CryptoOptions*>(
+      &config->crypto_options);
+}
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_RTCConfiguration_get_offer_extmap_allow_mixed(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  return config->offer_extmap_allow_mixed ? 1 : 0;
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_offer_extmap_allow_mixed(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int offer_extmap_allow_mixed) {
+  auto config =
+      reinterpret_cast<webrtc::PeerConnectionInterface::RTCConfiguration*>(
+          self);
+  config->offer_extmap_allow_mixed = offer_extmap_allow_mixed != 0;
+}
 WEBRTC_EXPORT struct webrtc_PeerConnectionDependencies*
 webrtc_PeerConnectionDependencies_new(
     struct webrtc_PeerConnectionObserver* observer) {
@@ -446,6 +890,25 @@ WEBRTC_EXPORT void webrtc_PeerConnectionDependencies_set_proxy(
   deps->allocator->set_proxy(agent, pi);
 }
 
+WEBRTC_EXPORT void webrtc_PeerConnectionDependencies_set_allocator(
+    struct webrtc_PeerConnectionDependencies* self,
+    struct webrtc_PortAllocator_unique* allocator) {
+  auto deps = reinterpret_cast<webrtc::PeerConnectionDependencies*>(self);
+  deps->allocator = std::unique_ptr<webrtc::PortAllocator>(
+      reinterpret_cast<webrtc::PortAllocator*>(allocator));
+}
+
+WEBRTC_EXPORT void
+webrtc_PeerConnectionDependencies_set_async_dns_resolver_factory(
+    struct webrtc_PeerConnectionDependencies* self,
+    struct webrtc_AsyncDnsResolverFactory_unique* async_dns_resolver_factory) {
+  auto deps = reinterpret_cast<webrtc::PeerConnectionDependencies*>(self);
+  deps->async_dns_resolver_factory =
+      std::unique_ptr<webrtc::AsyncDnsResolverFactoryInterface>(
+          reinterpret_cast<webrtc::AsyncDnsResolverFactoryInterface*>(
+              async_dns_resolver_factory));
+}
+
 WEBRTC_EXPORT void webrtc_PeerConnectionDependencies_set_tls_cert_verifier(
     struct webrtc_PeerConnectionDependencies* self,
     struct webrtc_SSLCertificateVerifier_unique* tls_cert_verifier) {
@@ -637,6 +1100,18 @@ WEBRTC_EXPORT void webrtc_PeerConnectionInterface_SetLocalDescription(
   }
   pc->SetLocalDescription(std::move(cpp_desc), obs_ref);
 }
+WEBRTC_EXPORT void webrtc_PeerConnectionInterface_SetLocalDescriptionImplicit(
+    struct webrtc_PeerConnectionInterface* self,
+    struct webrtc_SetLocalDescriptionObserverInterface_refcounted* observer) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  webrtc::scoped_refptr<webrtc::SetLocalDescriptionObserverInterface> obs_ref;
+  if (observer != nullptr) {
+    auto obs = reinterpret_cast<webrtc::SetLocalDescriptionObserverInterface*>(
+        webrtc_SetLocalDescriptionObserverInterface_refcounted_get(observer));
+    obs_ref = obs;
+  }
+  pc->SetLocalDescription(obs_ref);
+}
 WEBRTC_EXPORT void webrtc_PeerConnectionInterface_SetRemoteDescription(
     struct webrtc_PeerConnectionInterface* self,
     struct webrtc_SessionDescriptionInterface_unique* desc,
@@ -663,6 +1138,14 @@ WEBRTC_EXPORT int webrtc_PeerConnectionInterface_AddIceCandidate(
   auto ice = reinterpret_cast<const webrtc::IceCandidate*>(candidate);
   return pc->AddIceCandidate(ice) ? 1 : 0;
 }
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_RemoveIceCandidates(
+    struct webrtc_PeerConnectionInterface* self,
+    struct webrtc_Candidate_vector* candidates) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  assert(candidates != nullptr);
+  auto vec = reinterpret_cast<std::vector<webrtc::Candidate>*>(candidates);
+  return pc->RemoveIceCandidates(*vec) ? 1 : 0;
+}
 WEBRTC_EXPORT void webrtc_PeerConnectionInterface_SetConfiguration(
     struct webrtc_PeerConnectionInterface* self,
     struct webrtc_PeerConnectionInterface_RTCConfiguration* config,
@@ -681,6 +1164,15 @@ WEBRTC_EXPORT void webrtc_PeerConnectionInterface_SetConfiguration(
         reinterpret_cast<struct webrtc_RTCError_unique*>(error.release());
   }
 }
+WEBRTC_EXPORT struct webrtc_PeerConnectionInterface_RTCConfiguration*
+webrtc_PeerConnectionInterface_GetConfiguration(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  auto config = new webrtc::PeerConnectionInterface::RTCConfiguration(
+      pc->GetConfiguration());
+  return reinterpret_cast<
+      struct webrtc_PeerConnectionInterface_RTCConfiguration*>(config);
+}
 
 WEBRTC_EXPORT struct webrtc_DtlsTransportInterface_refcounted*
 webrtc_PeerConnectionInterface_LookupDtlsTransportByMid(
@@ -709,6 +1201,166 @@ WEBRTC_EXPORT void webrtc_PeerConnectionInterface_GetStats(
       webrtc::make_ref_counted<RTCStatsCollectorCallbackImpl>(cbs, user_data);
   pc->GetStats(callback.get());
 }
+WEBRTC_EXPORT void webrtc_PeerConnectionInterface_GetStatsWithSender(
+    struct webrtc_PeerConnectionInterface* self,
+    struct webrtc_RtpSenderInterface_refcounted* selector,
+    struct webrtc_RTCStatsCollectorCallback_cbs* cbs,
+    void* user_data) {
+  assert(self != nullptr);
+  assert(cbs != nullptr);
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  auto raw_sender = webrtc_RtpSenderInterface_refcounted_get(selector);
+  auto rtp_sender = reinterpret_cast<webrtc::RtpSenderInterface*>(raw_sender);
+  webrtc::scoped_refptr<webrtc::RtpSenderInterface> sender_ref(rtp_sender);
+  auto callback =
+      webrtc::make_ref_counted<RTCStatsCollectorCallbackImpl>(cbs, user_data);
+  pc->GetStats(sender_ref, callback);
+}
+WEBRTC_EXPORT void webrtc_PeerConnectionInterface_GetStatsWithReceiver(
+    struct webrtc_PeerConnectionInterface* self,
+    struct webrtc_RtpReceiverInterface_refcounted* selector,
+    struct webrtc_RTCStatsCollectorCallback_cbs* cbs,
+    void* user_data) {
+  assert(self != nullptr);
+  assert(cbs != nullptr);
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  auto raw_receiver = webrtc_RtpReceiverInterface_refcounted_get(selector);
+  auto rtp_receiver =
+      reinterpret_cast<webrtc::RtpReceiverInterface*>(raw_receiver);
+  webrtc::scoped_refptr<webrtc::RtpReceiverInterface> receiver_ref(
+      rtp_receiver);
+  auto callback =
+      webrtc::make_ref_counted<RTCStatsCollectorCallbackImpl>(cbs, user_data);
+  pc->GetStats(receiver_ref, callback);
+}
+
+WEBRTC_EXPORT struct webrtc_RtpSenderInterface_refcounted_vector*
+webrtc_PeerConnectionInterface_GetSenders(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  auto senders =
+      new std::vector<webrtc::scoped_refptr<webrtc::RtpSenderInterface>>(
+          pc->GetSenders());
+  return reinterpret_cast<struct webrtc_RtpSenderInterface_refcounted_vector*>(
+      senders);
+}
+WEBRTC_EXPORT struct webrtc_RtpReceiverInterface_refcounted_vector*
+webrtc_PeerConnectionInterface_GetReceivers(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  auto receivers =
+      new std::vector<webrtc::scoped_refptr<webrtc::RtpReceiverInterface>>(
+          pc->GetReceivers());
+  return reinterpret_cast<
+      struct webrtc_RtpReceiverInterface_refcounted_vector*>(receivers);
+}
+WEBRTC_EXPORT struct webrtc_RtpTransceiverInterface_refcounted_vector*
+webrtc_PeerConnectionInterface_GetTransceivers(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  auto transceivers =
+      new std::vector<webrtc::scoped_refptr<webrtc::RtpTransceiverInterface>>(
+          pc->GetTransceivers());
+  return reinterpret_cast<
+      struct webrtc_RtpTransceiverInterface_refcounted_vector*>(transceivers);
+}
+
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_signaling_state(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return static_cast<int>(pc->signaling_state());
+}
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_ice_connection_state(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return static_cast<int>(pc->ice_connection_state());
+}
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_standardized_ice_connection_state(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return static_cast<int>(pc->standardized_ice_connection_state());
+}
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_peer_connection_state(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return static_cast<int>(pc->peer_connection_state());
+}
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_ice_gathering_state(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return static_cast<int>(pc->ice_gathering_state());
+}
+
+WEBRTC_EXPORT struct webrtc_SessionDescriptionInterface_unique*
+webrtc_PeerConnectionInterface_local_description(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return CloneSessionDescription(
+      pc, &webrtc::PeerConnectionInterface::local_description);
+}
+WEBRTC_EXPORT struct webrtc_SessionDescriptionInterface_unique*
+webrtc_PeerConnectionInterface_remote_description(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return CloneSessionDescription(
+      pc, &webrtc::PeerConnectionInterface::remote_description);
+}
+WEBRTC_EXPORT struct webrtc_SessionDescriptionInterface_unique*
+webrtc_PeerConnectionInterface_current_local_description(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return CloneSessionDescription(
+      pc, &webrtc::PeerConnectionInterface::current_local_description);
+}
+WEBRTC_EXPORT struct webrtc_SessionDescriptionInterface_unique*
+webrtc_PeerConnectionInterface_current_remote_description(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return CloneSessionDescription(
+      pc, &webrtc::PeerConnectionInterface::current_remote_description);
+}
+WEBRTC_EXPORT struct webrtc_SessionDescriptionInterface_unique*
+webrtc_PeerConnectionInterface_pending_local_description(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return CloneSessionDescription(
+      pc, &webrtc::PeerConnectionInterface::pending_local_description);
+}
+WEBRTC_EXPORT struct webrtc_SessionDescriptionInterface_unique*
+webrtc_PeerConnectionInterface_pending_remote_description(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return CloneSessionDescription(
+      pc, &webrtc::PeerConnectionInterface::pending_remote_description);
+}
+
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_ShouldFireNegotiationNeededEvent(
+    struct webrtc_PeerConnectionInterface* self,
+    uint32_t event_id) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return pc->ShouldFireNegotiationNeededEvent(event_id) ? 1 : 0;
+}
+
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_StartRtcEventLog(
+    struct webrtc_PeerConnectionInterface* self,
+    struct webrtc_RtcEventLogOutput_unique* output,
+    int64_t output_period_ms) {
+  assert(output != nullptr);
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  return pc->StartRtcEventLog(
+             std::unique_ptr<webrtc::RtcEventLogOutput>(
+                 reinterpret_cast<webrtc::RtcEventLogOutput*>(output)),
+             output_period_ms)
+             ? 1
+             : 0;
+}
+
+WEBRTC_EXPORT void webrtc_PeerConnectionInterface_StopRtcEventLog(
+    struct webrtc_PeerConnectionInterface* self) {
+  auto pc = reinterpret_cast<webrtc::PeerConnectionInterface*>(self);
+  pc->StopRtcEventLog();
+}
 
 WEBRTC_EXPORT void webrtc_PeerConnectionInterface_Close(
     struct webrtc_PeerConnectionInterface* self) {
@@ -925,6 +1577,22 @@ extern const int
     webrtc_PeerConnectionInterface_IceGatheringState_kIceGatheringComplete =
         (int)webrtc::PeerConnectionInterface::IceGatheringState::
             kIceGatheringComplete;
+extern const int webrtc_PeerConnectionInterface_SignalingState_kStable =
+    (int)webrtc::PeerConnectionInterface::SignalingState::kStable;
+extern const int
+    webrtc_PeerConnectionInterface_SignalingState_kHaveLocalOffer =
+        (int)webrtc::PeerConnectionInterface::SignalingState::kHaveLocalOffer;
+extern const int
+    webrtc_PeerConnectionInterface_SignalingState_kHaveLocalPrAnswer = (int)
+        webrtc::PeerConnectionInterface::SignalingState::kHaveLocalPrAnswer;
+extern const int
+    webrtc_PeerConnectionInterface_SignalingState_kHaveRemoteOffer =
+        (int)webrtc::PeerConnectionInterface::SignalingState::kHaveRemoteOffer;
+extern const int
+    webrtc_PeerConnectionInterface_SignalingState_kHaveRemotePrAnswer = (int)
+        webrtc::PeerConnectionInterface::SignalingState::kHaveRemotePrAnswer;
+extern const int webrtc_PeerConnectionInterface_SignalingState_kClosed =
+    (int)webrtc::PeerConnectionInterface::SignalingState::kClosed;
 }
 
 // -------------------------
@@ -1044,6 +1712,42 @@ webrtc_PeerConnectionFactoryDependencies_set_video_decoder_factory(
   deps->video_decoder_factory =
       std::move(std::unique_ptr<webrtc::VideoDecoderFactory>(factory));
 }
+WEBRTC_EXPORT void webrtc_PeerConnectionFactoryDependencies_set_socket_factory(
+    struct webrtc_PeerConnectionFactoryDependencies* self,
+    struct webrtc_SocketFactory* socket_factory) {
+  auto deps =
+      reinterpret_cast<webrtc::PeerConnectionFactoryDependencies*>(self);
+  deps->socket_factory =
+      reinterpret_cast<webrtc::SocketFactory*>(socket_factory);
+}
+WEBRTC_EXPORT void
+webrtc_PeerConnectionFactoryDependencies_set_network_manager(
+    struct webrtc_PeerConnectionFactoryDependencies* self,
+    struct webrtc_NetworkManager_unique* network_manager) {
+  auto deps =
+      reinterpret_cast<webrtc::PeerConnectionFactoryDependencies*>(self);
+  deps->network_manager = std::unique_ptr<webrtc::NetworkManager>(
+      reinterpret_cast<webrtc::NetworkManager*>(network_manager));
+}
+
+WEBRTC_EXPORT void
+webrtc_PeerConnectionFactoryDependencies_set_packet_socket_factory(
+    struct webrtc_PeerConnectionFactoryDependencies* self,
+    struct webrtc_PacketSocketFactory_unique* packet_socket_factory) {
+  auto deps =
+      reinterpret_cast<webrtc::PeerConnectionFactoryDependencies*>(self);
+  deps->packet_socket_factory = std::unique_ptr<webrtc::PacketSocketFactory>(
+      reinterpret_cast<webrtc::PacketSocketFactory*>(packet_socket_factory));
+}
+
+WEBRTC_EXPORT void webrtc_PeerConnectionFactoryDependencies_set_env(
+    struct webrtc_PeerConnectionFactoryDependencies* self,
+    struct webrtc_Environment* env) {
+  assert(env != nullptr);
+  auto deps =
+      reinterpret_cast<webrtc::PeerConnectionFactoryDependencies*>(self);
+  deps->env = *reinterpret_cast<webrtc::Environment*>(env);
+}
 
 WEBRTC_EXPORT void webrtc_EnableMedia(
     struct webrtc_PeerConnectionFactoryDependencies* dependencies) {
@@ -1064,20 +1768,24 @@ class PeerConnectionFactoryWithContext : public webrtc::PeerConnectionFactory {
   explicit PeerConnectionFactoryWithContext(
       webrtc::PeerConnectionFactoryDependencies dependencies)
       : PeerConnectionFactoryWithContext(
-            webrtc::ConnectionContext::Create(webrtc::CreateEnvironment(),
-                                              &dependencies),
+            webrtc::ConnectionContext::Create(*dependencies.env, &dependencies),
             &dependencies) {}
 
   PeerConnectionFactoryWithContext(
       webrtc::scoped_refptr<webrtc::ConnectionContext> context,
       webrtc::PeerConnectionFactoryDependencies* dependencies)
       : conn_context_(context),
-        webrtc::PeerConnectionFactory(webrtc::CreateEnvironment(),
+        webrtc::PeerConnectionFactory(*dependencies->env,
                                       context,
                                       dependencies) {}
 
   static webrtc::scoped_refptr<PeerConnectionFactoryWithContext> Create(
       webrtc::PeerConnectionFactoryDependencies dependencies) {
+    // CreateModularPeerConnectionFactory と同様に、env が設定されていれば
+    // それを利用する。
+    if (!dependencies.env.has_value()) {
+      dependencies.env = webrtc::CreateEnvironment();
+    }
     return webrtc::make_ref_counted<PeerConnectionFactoryWithContext>(
         std::move(dependencies));
   }
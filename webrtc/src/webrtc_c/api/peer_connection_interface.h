@@ -1,17 +1,26 @@
 #pragma once
 
 #include <stddef.h>
+#include <stdint.h>
 
 #include "../common.h"
+#include "../p2p/base/candidate_pair_interface.h"
+#include "../p2p/client/basic_port_allocator.h"
 #include "../pc/connection_context.h"
+#include "../rtc_base/network.h"
+#include "../rtc_base/rtc_certificate.h"
 #include "../rtc_base/ssl_certificate.h"
 #include "../rtc_base/ssl_identity.h"
 #include "../rtc_base/thread.h"
 #include "../std.h"
+#include "async_dns_resolver.h"
+#include "candidate.h"
+#include "crypto/crypto_options.h"
 #include "data_channel_interface.h"
 #include "dtls_transport_interface.h"
 #include "jsep.h"
 #include "media_stream_interface.h"
+#include "packet_socket_factory.h"
 #include "rtc_error.h"
 #include "rtc_event_log.h"
 #include "rtp_parameters.h"
@@ -77,8 +86,17 @@ WEBRTC_EXPORT struct webrtc_PeerConnectionInterface_IceServer_vector*
 webrtc_PeerConnectionInterface_RTCConfiguration_get_servers(
     struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
 typedef int webrtc_PeerConnectionInterface_IceTransportsType;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_IceTransportsType_kNone;
 WEBRTC_EXPORT extern const int
     webrtc_PeerConnectionInterface_IceTransportsType_kRelay;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_IceTransportsType_kNoHost;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_IceTransportsType_kAll;
+WEBRTC_EXPORT webrtc_PeerConnectionInterface_IceTransportsType
+webrtc_PeerConnectionInterface_RTCConfiguration_get_type(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
 WEBRTC_EXPORT void webrtc_PeerConnectionInterface_RTCConfiguration_set_type(
     struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
     webrtc_PeerConnectionInterface_IceTransportsType type);
@@ -97,6 +115,157 @@ WEBRTC_EXPORT void
 webrtc_PeerConnectionInterface_RTCConfiguration_set_always_negotiate_data_channels(
     struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
     int always_negotiate_data_channels);
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_RTCConfiguration_enable_implicit_rollback(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_enable_implicit_rollback(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int enable);
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_RTCConfiguration_stats_timestamp_with_environment_clock(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_stats_timestamp_with_environment_clock(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int enable);
+typedef int webrtc_PeerConnectionInterface_BundlePolicy;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_BundlePolicy_kBundlePolicyBalanced;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_BundlePolicy_kBundlePolicyMaxBundle;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_BundlePolicy_kBundlePolicyMaxCompat;
+WEBRTC_EXPORT webrtc_PeerConnectionInterface_BundlePolicy
+webrtc_PeerConnectionInterface_RTCConfiguration_get_bundle_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_bundle_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    webrtc_PeerConnectionInterface_BundlePolicy bundle_policy);
+typedef int webrtc_PeerConnectionInterface_RtcpMuxPolicy;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_RtcpMuxPolicy_kRtcpMuxPolicyNegotiate;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_RtcpMuxPolicy_kRtcpMuxPolicyRequire;
+WEBRTC_EXPORT webrtc_PeerConnectionInterface_RtcpMuxPolicy
+webrtc_PeerConnectionInterface_RTCConfiguration_get_rtcp_mux_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_rtcp_mux_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    webrtc_PeerConnectionInterface_RtcpMuxPolicy rtcp_mux_policy);
+typedef int webrtc_PeerConnectionInterface_TcpCandidatePolicy;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_TcpCandidatePolicy_kTcpCandidatePolicyEnabled;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_TcpCandidatePolicy_kTcpCandidatePolicyDisabled;
+WEBRTC_EXPORT webrtc_PeerConnectionInterface_TcpCandidatePolicy
+webrtc_PeerConnectionInterface_RTCConfiguration_get_tcp_candidate_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_tcp_candidate_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    webrtc_PeerConnectionInterface_TcpCandidatePolicy tcp_candidate_policy);
+typedef int webrtc_PeerConnectionInterface_CandidateNetworkPolicy;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_CandidateNetworkPolicy_kCandidateNetworkPolicyAll;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_CandidateNetworkPolicy_kCandidateNetworkPolicyLowCost;
+WEBRTC_EXPORT webrtc_PeerConnectionInterface_CandidateNetworkPolicy
+webrtc_PeerConnectionInterface_RTCConfiguration_get_candidate_network_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_candidate_network_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    webrtc_PeerConnectionInterface_CandidateNetworkPolicy
+        candidate_network_policy);
+typedef int webrtc_PeerConnectionInterface_ContinualGatheringPolicy;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_ContinualGatheringPolicy_GATHER_ONCE;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_ContinualGatheringPolicy_GATHER_CONTINUALLY;
+WEBRTC_EXPORT webrtc_PeerConnectionInterface_ContinualGatheringPolicy
+webrtc_PeerConnectionInterface_RTCConfiguration_get_continual_gathering_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_continual_gathering_policy(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    webrtc_PeerConnectionInterface_ContinualGatheringPolicy
+        continual_gathering_policy);
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_RTCConfiguration_get_ice_candidate_pool_size(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_ice_candidate_pool_size(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int ice_candidate_pool_size);
+struct webrtc_PeerConnectionInterface_PortAllocatorConfig;
+WEBRTC_EXPORT struct webrtc_PeerConnectionInterface_PortAllocatorConfig*
+webrtc_PeerConnectionInterface_RTCConfiguration_get_port_allocator_config(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_PortAllocatorConfig_get_min_port(
+    struct webrtc_PeerConnectionInterface_PortAllocatorConfig* self);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_PortAllocatorConfig_set_min_port(
+    struct webrtc_PeerConnectionInterface_PortAllocatorConfig* self,
+    int min_port);
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_PortAllocatorConfig_get_max_port(
+    struct webrtc_PeerConnectionInterface_PortAllocatorConfig* self);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_PortAllocatorConfig_set_max_port(
+    struct webrtc_PeerConnectionInterface_PortAllocatorConfig* self,
+    int max_port);
+WEBRTC_EXPORT struct webrtc_RTCCertificate_refcounted_vector*
+webrtc_PeerConnectionInterface_RTCConfiguration_get_certificates(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_RTCConfiguration_dscp(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT void webrtc_PeerConnectionInterface_RTCConfiguration_set_dscp(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int enable);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_get_ice_connection_receiving_timeout(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int* out_has,
+    int* out_value);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_ice_connection_receiving_timeout(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int has,
+    const int* value);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_get_ice_check_min_interval(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int* out_has,
+    int* out_value);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_ice_check_min_interval(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int has,
+    const int* value);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_get_stun_candidate_keepalive_interval(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int* out_has,
+    int* out_value);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_stun_candidate_keepalive_interval(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int has,
+    const int* value);
+WEBRTC_EXPORT struct webrtc_CryptoOptions*
+webrtc_PeerConnectionInterface_RTCConfiguration_get_crypto_options(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_RTCConfiguration_get_offer_extmap_allow_mixed(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionInterface_RTCConfiguration_set_offer_extmap_allow_mixed(
+    struct webrtc_PeerConnectionInterface_RTCConfiguration* self,
+    int offer_extmap_allow_mixed);
 struct webrtc_PeerConnectionDependencies;
 WEBRTC_EXPORT struct webrtc_PeerConnectionDependencies*
 webrtc_PeerConnectionDependencies_new(
@@ -116,6 +285,15 @@ WEBRTC_EXPORT void webrtc_PeerConnectionDependencies_set_proxy(
     size_t proxy_password_len,
     const char* proxy_agent,
     size_t proxy_agent_len);
+// allocator の所有権は self へ移る。
+WEBRTC_EXPORT void webrtc_PeerConnectionDependencies_set_allocator(
+    struct webrtc_PeerConnectionDependencies* self,
+    struct webrtc_PortAllocator_unique* allocator);
+// リモートの mDNS (.local) candidate の名前解決に使う。
+WEBRTC_EXPORT void
+webrtc_PeerConnectionDependencies_set_async_dns_resolver_factory(
+    struct webrtc_PeerConnectionDependencies* self,
+    struct webrtc_AsyncDnsResolverFactory_unique* async_dns_resolver_factory);
 WEBRTC_EXPORT void webrtc_PeerConnectionDependencies_set_tls_cert_verifier(
     struct webrtc_PeerConnectionDependencies* self,
     struct webrtc_SSLCertificateVerifier_unique* tls_cert_verifier);
@@ -160,6 +338,11 @@ WEBRTC_EXPORT void webrtc_PeerConnectionInterface_SetLocalDescription(
     struct webrtc_PeerConnectionInterface* self,
     struct webrtc_SessionDescriptionInterface_unique* desc,
     struct webrtc_SetLocalDescriptionObserverInterface_refcounted* observer);
+// 引数なしの SetLocalDescription(observer) の移植。
+// signaling 状態に応じて offer または answer を生成して適用する。
+WEBRTC_EXPORT void webrtc_PeerConnectionInterface_SetLocalDescriptionImplicit(
+    struct webrtc_PeerConnectionInterface* self,
+    struct webrtc_SetLocalDescriptionObserverInterface_refcounted* observer);
 WEBRTC_EXPORT void webrtc_PeerConnectionInterface_SetRemoteDescription(
     struct webrtc_PeerConnectionInterface* self,
     struct webrtc_SessionDescriptionInterface_unique* desc,
@@ -167,10 +350,17 @@ WEBRTC_EXPORT void webrtc_PeerConnectionInterface_SetRemoteDescription(
 WEBRTC_EXPORT int webrtc_PeerConnectionInterface_AddIceCandidate(
     struct webrtc_PeerConnectionInterface* self,
     const struct webrtc_IceCandidate* candidate);
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_RemoveIceCandidates(
+    struct webrtc_PeerConnectionInterface* self,
+    struct webrtc_Candidate_vector* candidates);
 WEBRTC_EXPORT void webrtc_PeerConnectionInterface_SetConfiguration(
     struct webrtc_PeerConnectionInterface* self,
     struct webrtc_PeerConnectionInterface_RTCConfiguration* config,
     struct webrtc_RTCError_unique** out_rtc_error);
+// 現在の設定のコピーを返す。利用後は RTCConfiguration_delete で解放すること
+WEBRTC_EXPORT struct webrtc_PeerConnectionInterface_RTCConfiguration*
+webrtc_PeerConnectionInterface_GetConfiguration(
+    struct webrtc_PeerConnectionInterface* self);
 WEBRTC_EXPORT struct webrtc_DtlsTransportInterface_refcounted*
 webrtc_PeerConnectionInterface_LookupDtlsTransportByMid(
     struct webrtc_PeerConnectionInterface* self,
@@ -181,6 +371,74 @@ WEBRTC_EXPORT void webrtc_PeerConnectionInterface_GetStats(
     struct webrtc_PeerConnectionInterface* self,
     struct webrtc_RTCStatsCollectorCallback_cbs* cbs,
     void* user_data);
+WEBRTC_EXPORT void webrtc_PeerConnectionInterface_GetStatsWithSender(
+    struct webrtc_PeerConnectionInterface* self,
+    struct webrtc_RtpSenderInterface_refcounted* selector,
+    struct webrtc_RTCStatsCollectorCallback_cbs* cbs,
+    void* user_data);
+WEBRTC_EXPORT void webrtc_PeerConnectionInterface_GetStatsWithReceiver(
+    struct webrtc_PeerConnectionInterface* self,
+    struct webrtc_RtpReceiverInterface_refcounted* selector,
+    struct webrtc_RTCStatsCollectorCallback_cbs* cbs,
+    void* user_data);
+
+WEBRTC_EXPORT struct webrtc_RtpSenderInterface_refcounted_vector*
+webrtc_PeerConnectionInterface_GetSenders(
+    struct webrtc_PeerConnectionInterface* self);
+WEBRTC_EXPORT struct webrtc_RtpReceiverInterface_refcounted_vector*
+webrtc_PeerConnectionInterface_GetReceivers(
+    struct webrtc_PeerConnectionInterface* self);
+WEBRTC_EXPORT struct webrtc_RtpTransceiverInterface_refcounted_vector*
+webrtc_PeerConnectionInterface_GetTransceivers(
+    struct webrtc_PeerConnectionInterface* self);
+
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_signaling_state(
+    struct webrtc_PeerConnectionInterface* self);
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_ice_connection_state(
+    struct webrtc_PeerConnectionInterface* self);
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_standardized_ice_connection_state(
+    struct webrtc_PeerConnectionInterface* self);
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_peer_connection_state(
+    struct webrtc_PeerConnectionInterface* self);
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_ice_gathering_state(
+    struct webrtc_PeerConnectionInterface* self);
+
+// 以下の description 系関数は、PeerConnection が保持している
+// SessionDescriptionInterface を Clone() したものを返す。
+// description が設定されていない場合は nullptr を返す。
+WEBRTC_EXPORT struct webrtc_SessionDescriptionInterface_unique*
+webrtc_PeerConnectionInterface_local_description(
+    struct webrtc_PeerConnectionInterface* self);
+WEBRTC_EXPORT struct webrtc_SessionDescriptionInterface_unique*
+webrtc_PeerConnectionInterface_remote_description(
+    struct webrtc_PeerConnectionInterface* self);
+WEBRTC_EXPORT struct webrtc_SessionDescriptionInterface_unique*
+webrtc_PeerConnectionInterface_current_local_description(
+    struct webrtc_PeerConnectionInterface* self);
+WEBRTC_EXPORT struct webrtc_SessionDescriptionInterface_unique*
+webrtc_PeerConnectionInterface_current_remote_description(
+    struct webrtc_PeerConnectionInterface* self);
+WEBRTC_EXPORT struct webrtc_SessionDescriptionInterface_unique*
+webrtc_PeerConnectionInterface_pending_local_description(
+    struct webrtc_PeerConnectionInterface* self);
+WEBRTC_EXPORT struct webrtc_SessionDescriptionInterface_unique*
+webrtc_PeerConnectionInterface_pending_remote_description(
+    struct webrtc_PeerConnectionInterface* self);
+
+WEBRTC_EXPORT int
+webrtc_PeerConnectionInterface_ShouldFireNegotiationNeededEvent(
+    struct webrtc_PeerConnectionInterface* self,
+    uint32_t event_id);
+
+// output_period_ms が 0 の場合はイベントごとに即時に書き出す。
+// 開始できた場合は 1 を返す。開始できなかった場合も output は破棄される。
+WEBRTC_EXPORT int webrtc_PeerConnectionInterface_StartRtcEventLog(
+    struct webrtc_PeerConnectionInterface* self,
+    struct webrtc_RtcEventLogOutput_unique* output,
+    int64_t output_period_ms);
+WEBRTC_EXPORT void webrtc_PeerConnectionInterface_StopRtcEventLog(
+    struct webrtc_PeerConnectionInterface* self);
 
 WEBRTC_EXPORT void webrtc_PeerConnectionInterface_Close(
     struct webrtc_PeerConnectionInterface* self);
@@ -222,6 +480,19 @@ WEBRTC_EXPORT extern const int
     webrtc_PeerConnectionInterface_IceGatheringState_kIceGatheringGathering;
 WEBRTC_EXPORT extern const int
     webrtc_PeerConnectionInterface_IceGatheringState_kIceGatheringComplete;
+typedef int webrtc_PeerConnectionInterface_SignalingState;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_SignalingState_kStable;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_SignalingState_kHaveLocalOffer;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_SignalingState_kHaveLocalPrAnswer;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_SignalingState_kHaveRemoteOffer;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_SignalingState_kHaveRemotePrAnswer;
+WEBRTC_EXPORT extern const int
+    webrtc_PeerConnectionInterface_SignalingState_kClosed;
 
 WEBRTC_EXPORT struct webrtc_PeerConnectionInterface_RTCOfferAnswerOptions*
 webrtc_PeerConnectionInterface_RTCOfferAnswerOptions_new();
@@ -319,6 +590,22 @@ struct webrtc_PeerConnectionObserver_cbs {
   void (*OnIceGatheringChange)(
       webrtc_PeerConnectionInterface_IceGatheringState new_state,
       void* user_data);
+  void (*OnSignalingChange)(
+      webrtc_PeerConnectionInterface_SignalingState new_state,
+      void* user_data);
+  void (*OnAddStream)(struct webrtc_MediaStreamInterface_refcounted* stream,
+                      void* user_data);
+  void (*OnRemoveStream)(struct webrtc_MediaStreamInterface_refcounted* stream,
+                         void* user_data);
+  void (*OnNegotiationNeededEvent)(uint32_t event_id, void* user_data);
+  void (*OnIceConnectionReceivingChange)(int receiving, void* user_data);
+  // candidates はコールバック中のみ有効。
+  void (*OnIceCandidatesRemoved)(struct webrtc_Candidate_vector* candidates,
+                                 void* user_data);
+  // event はコールバック中のみ有効。
+  void (*OnIceSelectedCandidatePairChanged)(
+      const struct webrtc_CandidatePairChangeEvent* event,
+      void* user_data);
 };
 WEBRTC_EXPORT struct webrtc_PeerConnectionObserver*
 webrtc_PeerConnectionObserver_new(
@@ -374,6 +661,25 @@ WEBRTC_EXPORT void
 webrtc_PeerConnectionFactoryDependencies_set_video_decoder_factory(
     struct webrtc_PeerConnectionFactoryDependencies* self,
     struct webrtc_VideoDecoderFactory_unique* video_decoder_factory);
+struct webrtc_SocketFactory;
+// socket_factory の寿命は呼び出し側で管理する。
+WEBRTC_EXPORT void webrtc_PeerConnectionFactoryDependencies_set_socket_factory(
+    struct webrtc_PeerConnectionFactoryDependencies* self,
+    struct webrtc_SocketFactory* socket_factory);
+WEBRTC_EXPORT void
+webrtc_PeerConnectionFactoryDependencies_set_network_manager(
+    struct webrtc_PeerConnectionFactoryDependencies* self,
+    struct webrtc_NetworkManager_unique* network_manager);
+
+WEBRTC_EXPORT void
+webrtc_PeerConnectionFactoryDependencies_set_packet_socket_factory(
+    struct webrtc_PeerConnectionFactoryDependencies* self,
+    struct webrtc_PacketSocketFactory_unique* packet_socket_factory);
+struct webrtc_Environment;
+// env はコピーされる。factory や PeerConnection はこの Environment を利用する。
+WEBRTC_EXPORT void webrtc_PeerConnectionFactoryDependencies_set_env(
+    struct webrtc_PeerConnectionFactoryDependencies* self,
+    struct webrtc_Environment* env);
 
 WEBRTC_EXPORT void webrtc_EnableMedia(
     struct webrtc_PeerConnectionFactoryDependencies* dependencies);
@@ -1,11 +1,16 @@
 #include "rtc_event_log.h"
 
+#include <assert.h>
 #include <stdarg.h>
 #include <stddef.h>
 #include <memory>
+#include <string>
 
 // WebRTC
+#include <absl/strings/string_view.h>
 #include <api/rtc_event_log/rtc_event_log_factory.h>
+#include <api/rtc_event_log_output.h>
+#include <api/rtc_event_log_output_file.h>
 
 #include "../common.h"
 #include "../common.impl.h"
@@ -23,3 +28,70 @@ webrtc_RtcEventLogFactory_Create() {
       factory.release());
 }
 }
+
+// -------------------------
+// webrtc::RtcEventLogOutput
+// -------------------------
+
+namespace {
+
+class RtcEventLogOutputImpl : public webrtc::RtcEventLogOutput {
+ public:
+  RtcEventLogOutputImpl(const struct webrtc_RtcEventLogOutput_cbs* cbs,
+                        void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->IsActive != nullptr);
+    assert(cbs->Write != nullptr);
+    assert(cbs->Flush != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~RtcEventLogOutputImpl() override { cbs_.OnDestroy(user_data_); }
+
+  bool IsActive() const override { return cbs_.IsActive(user_data_) != 0; }
+
+  bool Write(absl::string_view output) override {
+    return cbs_.Write(output.data(), output.size(), user_data_) != 0;
+  }
+
+  void Flush() override { cbs_.Flush(user_data_); }
+
+ private:
+  webrtc_RtcEventLogOutput_cbs cbs_{};
+  void* user_data_;
+};
+
+}  // namespace
+
+extern "C" {
+WEBRTC_DEFINE_UNIQUE(webrtc_RtcEventLogOutput, webrtc::RtcEventLogOutput);
+
+WEBRTC_EXPORT struct webrtc_RtcEventLogOutput_unique*
+webrtc_RtcEventLogOutput_new(const struct webrtc_RtcEventLogOutput_cbs* cbs,
+                             void* user_data) {
+  auto output = std::make_unique<RtcEventLogOutputImpl>(cbs, user_data);
+  return reinterpret_cast<struct webrtc_RtcEventLogOutput_unique*>(
+      static_cast<webrtc::RtcEventLogOutput*>(output.release()));
+}
+
+WEBRTC_EXPORT struct webrtc_RtcEventLogOutput_unique*
+webrtc_RtcEventLogOutputFile_Create(const char* file_name,
+                                    size_t file_name_len,
+                                    size_t max_size_bytes) {
+  assert(file_name != nullptr);
+  // 上限を超える値は RtcEventLogOutputFile 内の RTC_CHECK で落ちるため弾く。
+  if (max_size_bytes >
+      webrtc::RtcEventLogOutputFile::kMaxReasonableFileSize) {
+    return nullptr;
+  }
+  auto output = std::make_unique<webrtc::RtcEventLogOutputFile>(
+      std::string(file_name, file_name_len), max_size_bytes);
+  if (!output->IsActive()) {
+    return nullptr;
+  }
+  return reinterpret_cast<struct webrtc_RtcEventLogOutput_unique*>(
+      static_cast<webrtc::RtcEventLogOutput*>(output.release()));
+}
+}
@@ -1,5 +1,7 @@
 #pragma once
 
+#include <stddef.h>
+
 #include "../common.h"
 
 #if defined(__cplusplus)
@@ -14,6 +16,37 @@ WEBRTC_DECLARE_UNIQUE(webrtc_RtcEventLogFactory);
 WEBRTC_EXPORT struct webrtc_RtcEventLogFactory_unique*
 webrtc_RtcEventLogFactory_Create();
 
+// -------------------------
+// webrtc::RtcEventLogOutput
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_RtcEventLogOutput);
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// コールバックは RtcEventLog のタスクキューから呼ばれる。
+struct webrtc_RtcEventLogOutput_cbs {
+  // 書き込みを受け付ける場合は 1 を返す。
+  int (*IsActive)(void* user_data);
+  // 書き込みに成功した場合は 1 を返す。0 を返すとログの出力を停止する。
+  int (*Write)(const char* data, size_t len, void* user_data);
+  void (*Flush)(void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_RtcEventLogOutput_unique*
+webrtc_RtcEventLogOutput_new(const struct webrtc_RtcEventLogOutput_cbs* cbs,
+                             void* user_data);
+
+// webrtc::RtcEventLogOutputFile を生成する。
+// max_size_bytes が 0 の場合はサイズの上限を設けない。
+// max_size_bytes が RtcEventLogOutputFile::kMaxReasonableFileSize を超える場合と
+// ファイルを開けなかった場合は null を返す。
+WEBRTC_EXPORT struct webrtc_RtcEventLogOutput_unique*
+webrtc_RtcEventLogOutputFile_Create(const char* file_name,
+                                    size_t file_name_len,
+                                    size_t max_size_bytes);
+
 #if defined(__cplusplus)
 }
 #endif
@@ -11,6 +11,7 @@
 #include <api/media_types.h>
 #include <api/priority.h>
 #include <api/rtp_parameters.h>
+#include <api/rtp_transceiver_direction.h>
 #include <api/video/resolution.h>
 
 #include "../common.h"
@@ -100,6 +101,68 @@ WEBRTC_EXPORT void webrtc_RtpCodecCapability_delete(
   delete cap;
 }
 
+// -------------------------
+// webrtc::RtpHeaderExtensionCapability
+// -------------------------
+
+WEBRTC_DEFINE_VECTOR(webrtc_RtpHeaderExtensionCapability,
+                     webrtc::RtpHeaderExtensionCapability);
+
+WEBRTC_EXPORT void webrtc_RtpHeaderExtensionCapability_get_uri(
+    struct webrtc_RtpHeaderExtensionCapability* self,
+    const char** out_uri,
+    size_t* out_uri_len) {
+  assert(out_uri != nullptr);
+  assert(out_uri_len != nullptr);
+  auto cap = reinterpret_cast<webrtc::RtpHeaderExtensionCapability*>(self);
+  *out_uri = cap->uri.data();
+  *out_uri_len = cap->uri.size();
+}
+WEBRTC_EXPORT void webrtc_RtpHeaderExtensionCapability_set_uri(
+    struct webrtc_RtpHeaderExtensionCapability* self,
+    const char* uri,
+    size_t uri_len) {
+  assert(uri != nullptr || uri_len == 0);
+  auto cap = reinterpret_cast<webrtc::RtpHeaderExtensionCapability*>(self);
+  cap->uri = std::string(uri, uri_len);
+}
+WEBRTC_EXPORT void webrtc_RtpHeaderExtensionCapability_get_preferred_id(
+    struct webrtc_RtpHeaderExtensionCapability* self,
+    int* out_has,
+    int* out_value) {
+  auto cap = reinterpret_cast<webrtc::RtpHeaderExtensionCapability*>(self);
+  webrtc_c::OptionalGet(cap->preferred_id, out_has, out_value);
+}
+WEBRTC_EXPORT void webrtc_RtpHeaderExtensionCapability_set_preferred_id(
+    struct webrtc_RtpHeaderExtensionCapability* self,
+    int has,
+    const int* value) {
+  auto cap = reinterpret_cast<webrtc::RtpHeaderExtensionCapability*>(self);
+  webrtc_c::OptionalSet(cap->preferred_id, has, value);
+}
+WEBRTC_EXPORT int webrtc_RtpHeaderExtensionCapability_get_preferred_encrypt(
+    struct webrtc_RtpHeaderExtensionCapability* self) {
+  auto cap = reinterpret_cast<webrtc::RtpHeaderExtensionCapability*>(self);
+  return cap->preferred_encrypt ? 1 : 0;
+}
+WEBRTC_EXPORT void webrtc_RtpHeaderExtensionCapability_set_preferred_encrypt(
+    struct webrtc_RtpHeaderExtensionCapability* self,
+    int value) {
+  auto cap = reinterpret_cast<webrtc::RtpHeaderExtensionCapability*>(self);
+  cap->preferred_encrypt = value != 0;
+}
+WEBRTC_EXPORT int webrtc_RtpHeaderExtensionCapability_get_direction(
+    struct webrtc_RtpHeaderExtensionCapability* self) {
+  auto cap = reinterpret_cast<webrtc::RtpHeaderExtensionCapability*>(self);
+  return static_cast<int>(cap->direction);
+}
+WEBRTC_EXPORT void webrtc_RtpHeaderExtensionCapability_set_direction(
+    struct webrtc_RtpHeaderExtensionCapability* self,
+    int direction) {
+  auto cap = reinterpret_cast<webrtc::RtpHeaderExtensionCapability*>(self);
+  cap->direction = static_cast<webrtc::RtpTransceiverDirection>(direction);
+}
+
 // -------------------------
 // webrtc::RtpCapabilities
 // -------------------------
@@ -115,6 +178,38 @@ webrtc_RtpCapabilities_get_codecs(struct webrtc_RtpCapabilities* self) {
   return reinterpret_cast<struct webrtc_RtpCodecCapability_vector*>(
       &caps->codecs);
 }
+WEBRTC_EXPORT struct webrtc_RtpHeaderExtensionCapability_vector*
+webrtc_RtpCapabilities_get_header_extensions(
+    struct webrtc_RtpCapabilities* self) {
+  auto caps = reinterpret_cast<webrtc::RtpCapabilities*>(self);
+  return reinterpret_cast<struct webrtc_RtpHeaderExtensionCapability_vector*>(
+      &caps->header_extensions);
+}
+
+// -------------------------
+// webrtc::RtpExtension
+// -------------------------
+
+WEBRTC_EXPORT void webrtc_RtpExtension_get_uri(
+    const struct webrtc_RtpExtension* self,
+    const char** out_uri,
+    size_t* out_uri_len) {
+  assert(out_uri != nullptr);
+  assert(out_uri_len != nullptr);
+  auto extension = reinterpret_cast<const webrtc::RtpExtension*>(self);
+  *out_uri = extension->uri.data();
+  *out_uri_len = extension->uri.size();
+}
+WEBRTC_EXPORT int webrtc_RtpExtension_get_id(
+    const struct webrtc_RtpExtension* self) {
+  auto extension = reinterpret_cast<const webrtc::RtpExtension*>(self);
+  return extension->id;
+}
+WEBRTC_EXPORT int webrtc_RtpExtension_get_encrypt(
+    const struct webrtc_RtpExtension* self) {
+  auto extension = reinterpret_cast<const webrtc::RtpExtension*>(self);
+  return extension->encrypt ? 1 : 0;
+}
 
 // -------------------------
 // webrtc::Resolution
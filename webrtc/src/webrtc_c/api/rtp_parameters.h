@@ -51,6 +51,39 @@ WEBRTC_EXPORT struct webrtc_RtpCodecCapability* webrtc_RtpCodecCapability_new();
 WEBRTC_EXPORT void webrtc_RtpCodecCapability_delete(
     struct webrtc_RtpCodecCapability* self);
 
+// -------------------------
+// webrtc::RtpHeaderExtensionCapability
+// -------------------------
+
+WEBRTC_DECLARE_VECTOR(webrtc_RtpHeaderExtensionCapability);
+WEBRTC_EXPORT void webrtc_RtpHeaderExtensionCapability_get_uri(
+    struct webrtc_RtpHeaderExtensionCapability* self,
+    const char** out_uri,
+    size_t* out_uri_len);
+WEBRTC_EXPORT void webrtc_RtpHeaderExtensionCapability_set_uri(
+    struct webrtc_RtpHeaderExtensionCapability* self,
+    const char* uri,
+    size_t uri_len);
+WEBRTC_EXPORT void webrtc_RtpHeaderExtensionCapability_get_preferred_id(
+    struct webrtc_RtpHeaderExtensionCapability* self,
+    int* out_has,
+    int* out_value);
+WEBRTC_EXPORT void webrtc_RtpHeaderExtensionCapability_set_preferred_id(
+    struct webrtc_RtpHeaderExtensionCapability* self,
+    int has,
+    const int* value);
+WEBRTC_EXPORT int webrtc_RtpHeaderExtensionCapability_get_preferred_encrypt(
+    struct webrtc_RtpHeaderExtensionCapability* self);
+WEBRTC_EXPORT void webrtc_RtpHeaderExtensionCapability_set_preferred_encrypt(
+    struct webrtc_RtpHeaderExtensionCapability* self,
+    int value);
+// direction は webrtc_RtpTransceiverDirection_* の値。
+WEBRTC_EXPORT int webrtc_RtpHeaderExtensionCapability_get_direction(
+    struct webrtc_RtpHeaderExtensionCapability* self);
+WEBRTC_EXPORT void webrtc_RtpHeaderExtensionCapability_set_direction(
+    struct webrtc_RtpHeaderExtensionCapability* self,
+    int direction);
+
 // -------------------------
 // webrtc::RtpCapabilities
 // -------------------------
@@ -60,6 +93,23 @@ WEBRTC_EXPORT void webrtc_RtpCapabilities_delete(
     struct webrtc_RtpCapabilities* self);
 WEBRTC_EXPORT struct webrtc_RtpCodecCapability_vector*
 webrtc_RtpCapabilities_get_codecs(struct webrtc_RtpCapabilities* self);
+WEBRTC_EXPORT struct webrtc_RtpHeaderExtensionCapability_vector*
+webrtc_RtpCapabilities_get_header_extensions(
+    struct webrtc_RtpCapabilities* self);
+
+// -------------------------
+// webrtc::RtpExtension
+// -------------------------
+
+struct webrtc_RtpExtension;
+WEBRTC_EXPORT void webrtc_RtpExtension_get_uri(
+    const struct webrtc_RtpExtension* self,
+    const char** out_uri,
+    size_t* out_uri_len);
+WEBRTC_EXPORT int webrtc_RtpExtension_get_id(
+    const struct webrtc_RtpExtension* self);
+WEBRTC_EXPORT int webrtc_RtpExtension_get_encrypt(
+    const struct webrtc_RtpExtension* self);
 
 // -------------------------
 // webrtc::Resolution
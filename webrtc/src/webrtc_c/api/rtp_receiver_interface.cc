@@ -1,13 +1,81 @@
 #include "rtp_receiver_interface.h"
 
+#include <assert.h>
+#include <memory>
+#include <optional>
+#include <string>
+#include <vector>
+
+#include <api/crypto/frame_decryptor_interface.h>
+#include <api/dtls_transport_interface.h>
+#include <api/frame_transformer_interface.h>
+#include <api/media_types.h>
+#include <api/rtp_parameters.h>
 #include <api/rtp_receiver_interface.h>
 #include <api/scoped_refptr.h>
+#include <api/transport/rtp/rtp_source.h>
 
 #include "../common.h"
 #include "../common.impl.h"
+#include "../std.h"
+#include "../std.impl.h"
+#include "crypto/frame_decryptor_interface.h"
+#include "dtls_transport_interface.h"
+#include "frame_transformer_interface.h"
 #include "media_stream_interface.h"
+#include "rtp_parameters.h"
+#include "transport/rtp/rtp_source.h"
+
+// -------------------------
+// webrtc::RtpReceiverObserverInterface
+// -------------------------
+
+namespace {
+
+class RtpReceiverObserverInterfaceImpl
+    : public webrtc::RtpReceiverObserverInterface {
+ public:
+  RtpReceiverObserverInterfaceImpl(
+      const struct webrtc_RtpReceiverObserverInterface_cbs* cbs,
+      void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->OnFirstPacketReceived != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~RtpReceiverObserverInterfaceImpl() override { cbs_.OnDestroy(user_data_); }
+
+  void OnFirstPacketReceived(webrtc::MediaType media_type) override {
+    cbs_.OnFirstPacketReceived(static_cast<int>(media_type), user_data_);
+  }
+
+ private:
+  webrtc_RtpReceiverObserverInterface_cbs cbs_{};
+  void* user_data_;
+};
+
+}  // namespace
 
 extern "C" {
+WEBRTC_EXPORT struct webrtc_RtpReceiverObserverInterface*
+webrtc_RtpReceiverObserverInterface_new(
+    const struct webrtc_RtpReceiverObserverInterface_cbs* cbs,
+    void* user_data) {
+  auto impl = new RtpReceiverObserverInterfaceImpl(cbs, user_data);
+  return reinterpret_cast<struct webrtc_RtpReceiverObserverInterface*>(impl);
+}
+WEBRTC_EXPORT void webrtc_RtpReceiverObserverInterface_delete(
+    struct webrtc_RtpReceiverObserverInterface* self) {
+  auto impl = reinterpret_cast<RtpReceiverObserverInterfaceImpl*>(self);
+  delete impl;
+}
+
+// -------------------------
+// webrtc::RtpReceiverInterface
+// -------------------------
+
 WEBRTC_DEFINE_REFCOUNTED(webrtc_RtpReceiverInterface,
                          webrtc::RtpReceiverInterface);
 
@@ -18,4 +86,87 @@ webrtc_RtpReceiverInterface_track(struct webrtc_RtpReceiverInterface* self) {
   return reinterpret_cast<struct webrtc_MediaStreamTrackInterface_refcounted*>(
       track.release());
 }
+WEBRTC_EXPORT struct webrtc_DtlsTransportInterface_refcounted*
+webrtc_RtpReceiverInterface_dtls_transport(
+    struct webrtc_RtpReceiverInterface* self) {
+  auto receiver = reinterpret_cast<webrtc::RtpReceiverInterface*>(self);
+  auto transport = receiver->dtls_transport();
+  return reinterpret_cast<struct webrtc_DtlsTransportInterface_refcounted*>(
+      transport.release());
+}
+WEBRTC_EXPORT struct std_string_vector* webrtc_RtpReceiverInterface_stream_ids(
+    struct webrtc_RtpReceiverInterface* self) {
+  auto receiver = reinterpret_cast<webrtc::RtpReceiverInterface*>(self);
+  auto stream_ids = new std::vector<std::string>(receiver->stream_ids());
+  return reinterpret_cast<struct std_string_vector*>(stream_ids);
+}
+WEBRTC_EXPORT int webrtc_RtpReceiverInterface_media_type(
+    struct webrtc_RtpReceiverInterface* self) {
+  auto receiver = reinterpret_cast<webrtc::RtpReceiverInterface*>(self);
+  return static_cast<int>(receiver->media_type());
+}
+WEBRTC_EXPORT struct std_string_unique* webrtc_RtpReceiverInterface_id(
+    struct webrtc_RtpReceiverInterface* self) {
+  auto receiver = reinterpret_cast<webrtc::RtpReceiverInterface*>(self);
+  auto id = std::make_unique<std::string>(receiver->id());
+  return reinterpret_cast<struct std_string_unique*>(id.release());
+}
+WEBRTC_EXPORT struct webrtc_RtpParameters*
+webrtc_RtpReceiverInterface_GetParameters(
+    struct webrtc_RtpReceiverInterface* self) {
+  auto receiver = reinterpret_cast<webrtc::RtpReceiverInterface*>(self);
+  auto parameters = new webrtc::RtpParameters(receiver->GetParameters());
+  return reinterpret_cast<struct webrtc_RtpParameters*>(parameters);
+}
+WEBRTC_EXPORT void webrtc_RtpReceiverInterface_SetObserver(
+    struct webrtc_RtpReceiverInterface* self,
+    struct webrtc_RtpReceiverObserverInterface* observer) {
+  auto receiver = reinterpret_cast<webrtc::RtpReceiverInterface*>(self);
+  auto obs = reinterpret_cast<RtpReceiverObserverInterfaceImpl*>(observer);
+  receiver->SetObserver(obs);
+}
+WEBRTC_EXPORT void webrtc_RtpReceiverInterface_SetJitterBufferMinimumDelay(
+    struct webrtc_RtpReceiverInterface* self,
+    int has,
+    const double* delay_seconds) {
+  auto receiver = reinterpret_cast<webrtc::RtpReceiverInterface*>(self);
+  std::optional<double> delay;
+  webrtc_c::OptionalSet(delay, has, delay_seconds);
+  receiver->SetJitterBufferMinimumDelay(delay);
+}
+WEBRTC_EXPORT struct webrtc_RtpSource_vector*
+webrtc_RtpReceiverInterface_GetSources(
+    struct webrtc_RtpReceiverInterface* self) {
+  auto receiver = reinterpret_cast<webrtc::RtpReceiverInterface*>(self);
+  auto sources = new std::vector<webrtc::RtpSource>(receiver->GetSources());
+  return reinterpret_cast<struct webrtc_RtpSource_vector*>(sources);
+}
+WEBRTC_EXPORT void webrtc_RtpReceiverInterface_SetFrameTransformer(
+    struct webrtc_RtpReceiverInterface* self,
+    struct webrtc_FrameTransformerInterface_refcounted* frame_transformer) {
+  assert(frame_transformer != nullptr);
+  auto receiver = reinterpret_cast<webrtc::RtpReceiverInterface*>(self);
+  webrtc::scoped_refptr<webrtc::FrameTransformerInterface> transformer(
+      reinterpret_cast<webrtc::FrameTransformerInterface*>(
+          webrtc_FrameTransformerInterface_refcounted_get(frame_transformer)));
+  receiver->SetFrameTransformer(transformer);
+}
+WEBRTC_EXPORT void webrtc_RtpReceiverInterface_SetFrameDecryptor(
+    struct webrtc_RtpReceiverInterface* self,
+    struct webrtc_FrameDecryptorInterface_refcounted* frame_decryptor) {
+  auto receiver = reinterpret_cast<webrtc::RtpReceiverInterface*>(self);
+  webrtc::scoped_refptr<webrtc::FrameDecryptorInterface> decryptor;
+  if (frame_decryptor != nullptr) {
+    decryptor = reinterpret_cast<webrtc::FrameDecryptorInterface*>(
+        webrtc_FrameDecryptorInterface_refcounted_get(frame_decryptor));
+  }
+  receiver->SetFrameDecryptor(decryptor);
+}
+
+// -------------------------
+// std::vector<scoped_refptr<webrtc::RtpReceiverInterface>>
+// -------------------------
+
+WEBRTC_DEFINE_REFCOUNTED_VECTOR(webrtc_RtpReceiverInterface,
+                                webrtc::RtpReceiverInterface);
 }
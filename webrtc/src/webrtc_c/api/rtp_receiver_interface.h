@@ -1,12 +1,39 @@
 #pragma once
 
 #include "../common.h"
+#include "../std.h"
+#include "crypto/frame_decryptor_interface.h"
+#include "dtls_transport_interface.h"
+#include "frame_transformer_interface.h"
 #include "media_stream_interface.h"
+#include "rtp_parameters.h"
+#include "transport/rtp/rtp_source.h"
 
 #if defined(__cplusplus)
 extern "C" {
 #endif
 
+// -------------------------
+// webrtc::RtpReceiverObserverInterface
+// -------------------------
+
+struct webrtc_RtpReceiverObserverInterface;
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// media_type は webrtc_MediaType_* の値。
+struct webrtc_RtpReceiverObserverInterface_cbs {
+  void (*OnFirstPacketReceived)(int media_type, void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_RtpReceiverObserverInterface*
+webrtc_RtpReceiverObserverInterface_new(
+    const struct webrtc_RtpReceiverObserverInterface_cbs* cbs,
+    void* user_data);
+WEBRTC_EXPORT void webrtc_RtpReceiverObserverInterface_delete(
+    struct webrtc_RtpReceiverObserverInterface* self);
+
 // -------------------------
 // webrtc::RtpReceiverInterface
 // -------------------------
@@ -15,6 +42,45 @@ WEBRTC_DECLARE_REFCOUNTED(webrtc_RtpReceiverInterface);
 
 WEBRTC_EXPORT struct webrtc_MediaStreamTrackInterface_refcounted*
 webrtc_RtpReceiverInterface_track(struct webrtc_RtpReceiverInterface* self);
+// DTLS トランスポートが未確定の場合は null を返す。
+WEBRTC_EXPORT struct webrtc_DtlsTransportInterface_refcounted*
+webrtc_RtpReceiverInterface_dtls_transport(
+    struct webrtc_RtpReceiverInterface* self);
+WEBRTC_EXPORT struct std_string_vector* webrtc_RtpReceiverInterface_stream_ids(
+    struct webrtc_RtpReceiverInterface* self);
+WEBRTC_EXPORT int webrtc_RtpReceiverInterface_media_type(
+    struct webrtc_RtpReceiverInterface* self);
+WEBRTC_EXPORT struct std_string_unique* webrtc_RtpReceiverInterface_id(
+    struct webrtc_RtpReceiverInterface* self);
+WEBRTC_EXPORT struct webrtc_RtpParameters*
+webrtc_RtpReceiverInterface_GetParameters(
+    struct webrtc_RtpReceiverInterface* self);
+// observer が null の場合は解除する。observer は解除するまで破棄しないこと。
+WEBRTC_EXPORT void webrtc_RtpReceiverInterface_SetObserver(
+    struct webrtc_RtpReceiverInterface* self,
+    struct webrtc_RtpReceiverObserverInterface* observer);
+// has が 0 の場合は既定値に戻す。
+WEBRTC_EXPORT void webrtc_RtpReceiverInterface_SetJitterBufferMinimumDelay(
+    struct webrtc_RtpReceiverInterface* self,
+    int has,
+    const double* delay_seconds);
+WEBRTC_EXPORT struct webrtc_RtpSource_vector*
+webrtc_RtpReceiverInterface_GetSources(
+    struct webrtc_RtpReceiverInterface* self);
+// 設定済みの frame_transformer は解除できない。
+WEBRTC_EXPORT void webrtc_RtpReceiverInterface_SetFrameTransformer(
+    struct webrtc_RtpReceiverInterface* self,
+    struct webrtc_FrameTransformerInterface_refcounted* frame_transformer);
+// frame_decryptor が null の場合は復号を解除する。
+WEBRTC_EXPORT void webrtc_RtpReceiverInterface_SetFrameDecryptor(
+    struct webrtc_RtpReceiverInterface* self,
+    struct webrtc_FrameDecryptorInterface_refcounted* frame_decryptor);
+
+// -------------------------
+// std::vector<scoped_refptr<webrtc::RtpReceiverInterface>>
+// -------------------------
+
+WEBRTC_DECLARE_REFCOUNTED_VECTOR(webrtc_RtpReceiverInterface);
 
 #if defined(__cplusplus)
 }
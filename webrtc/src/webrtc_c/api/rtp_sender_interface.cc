@@ -1,21 +1,99 @@
 #include "rtp_sender_interface.h"
 
 #include <assert.h>
+#include <stdint.h>
 #include <memory>
+#include <string>
+#include <vector>
 
+#include <api/crypto/frame_encryptor_interface.h>
+#include <api/dtls_transport_interface.h>
+#include <api/dtmf_sender_interface.h>
+#include <api/frame_transformer_interface.h>
+#include <api/media_stream_interface.h>
+#include <api/media_types.h>
 #include <api/rtc_error.h>
 #include <api/rtp_parameters.h>
 #include <api/rtp_sender_interface.h>
+#include <api/scoped_refptr.h>
 
 #include "../common.h"
 #include "../common.impl.h"
+#include "../std.h"
 #include "api/media_stream_interface.h"
+#include "crypto/frame_encryptor_interface.h"
+#include "dtls_transport_interface.h"
+#include "dtmf_sender_interface.h"
+#include "frame_transformer_interface.h"
 #include "rtc_error.h"
 #include "rtp_parameters.h"
 
 extern "C" {
 WEBRTC_DEFINE_REFCOUNTED(webrtc_RtpSenderInterface, webrtc::RtpSenderInterface);
 
+WEBRTC_EXPORT struct webrtc_MediaStreamTrackInterface_refcounted*
+webrtc_RtpSenderInterface_track(struct webrtc_RtpSenderInterface* self) {
+  auto sender = reinterpret_cast<webrtc::RtpSenderInterface*>(self);
+  auto track = sender->track();
+  return reinterpret_cast<struct webrtc_MediaStreamTrackInterface_refcounted*>(
+      track.release());
+}
+WEBRTC_EXPORT uint32_t
+webrtc_RtpSenderInterface_ssrc(struct webrtc_RtpSenderInterface* self) {
+  auto sender = reinterpret_cast<webrtc::RtpSenderInterface*>(self);
+  return sender->ssrc();
+}
+WEBRTC_EXPORT int webrtc_RtpSenderInterface_media_type(
+    struct webrtc_RtpSenderInterface* self) {
+  auto sender = reinterpret_cast<webrtc::RtpSenderInterface*>(self);
+  return static_cast<int>(sender->media_type());
+}
+WEBRTC_EXPORT struct std_string_unique* webrtc_RtpSenderInterface_id(
+    struct webrtc_RtpSenderInterface* self) {
+  auto sender = reinterpret_cast<webrtc::RtpSenderInterface*>(self);
+  auto id = std::make_unique<std::string>(sender->id());
+  return reinterpret_cast<struct std_string_unique*>(id.release());
+}
+WEBRTC_EXPORT struct std_string_vector* webrtc_RtpSenderInterface_stream_ids(
+    struct webrtc_RtpSenderInterface* self) {
+  auto sender = reinterpret_cast<webrtc::RtpSenderInterface*>(self);
+  auto stream_ids = new std::vector<std::string>(sender->stream_ids());
+  return reinterpret_cast<struct std_string_vector*>(stream_ids);
+}
+WEBRTC_EXPORT void webrtc_RtpSenderInterface_SetStreams(
+    struct webrtc_RtpSenderInterface* self,
+    struct std_string_vector* stream_ids) {
+  assert(stream_ids != nullptr);
+  auto sender = reinterpret_cast<webrtc::RtpSenderInterface*>(self);
+  auto ids = reinterpret_cast<std::vector<std::string>*>(stream_ids);
+  sender->SetStreams(*ids);
+}
+WEBRTC_EXPORT struct webrtc_DtlsTransportInterface_refcounted*
+webrtc_RtpSenderInterface_dtls_transport(
+    struct webrtc_RtpSenderInterface* self) {
+  auto sender = reinterpret_cast<webrtc::RtpSenderInterface*>(self);
+  auto transport = sender->dtls_transport();
+  return reinterpret_cast<struct webrtc_DtlsTransportInterface_refcounted*>(
+      transport.release());
+}
+WEBRTC_EXPORT struct webrtc_RtpEncodingParameters_vector*
+webrtc_RtpSenderInterface_init_send_encodings(
+    struct webrtc_RtpSenderInterface* self) {
+  auto sender = reinterpret_cast<webrtc::RtpSenderInterface*>(self);
+  auto encodings = new std::vector<webrtc::RtpEncodingParameters>(
+      sender->init_send_encodings());
+  return reinterpret_cast<struct webrtc_RtpEncodingParameters_vector*>(
+      encodings);
+}
+WEBRTC_EXPORT struct webrtc_DtmfSenderInterface_refcounted*
+webrtc_RtpSenderInterface_GetDtmfSender(
+    struct webrtc_RtpSenderInterface* self) {
+  auto sender = reinterpret_cast<webrtc::RtpSenderInterface*>(self);
+  auto dtmf_sender = sender->GetDtmfSender();
+  return reinterpret_cast<struct webrtc_DtmfSenderInterface_refcounted*>(
+      dtmf_sender.release());
+}
+
 WEBRTC_EXPORT struct webrtc_RtpParameters*
 webrtc_RtpSenderInterface_GetParameters(
     struct webrtc_RtpSenderInterface* self) {
@@ -50,4 +128,32 @@ WEBRTC_EXPORT int webrtc_RtpSenderInterface_SetTrack(
       reinterpret_cast<webrtc::MediaStreamTrackInterface*>(track);
   return sender->SetTrack(media_track) ? 1 : 0;
 }
+WEBRTC_EXPORT void webrtc_RtpSenderInterface_SetFrameTransformer(
+    struct webrtc_RtpSenderInterface* self,
+    struct webrtc_FrameTransformerInterface_refcounted* frame_transformer) {
+  assert(frame_transformer != nullptr);
+  auto sender = reinterpret_cast<webrtc::RtpSenderInterface*>(self);
+  webrtc::scoped_refptr<webrtc::FrameTransformerInterface> transformer(
+      reinterpret_cast<webrtc::FrameTransformerInterface*>(
+          webrtc_FrameTransformerInterface_refcounted_get(frame_transformer)));
+  sender->SetFrameTransformer(transformer);
+}
+WEBRTC_EXPORT void webrtc_RtpSenderInterface_SetFrameEncryptor(
+    struct webrtc_RtpSenderInterface* self,
+    struct webrtc_FrameEncryptorInterface_refcounted* frame_encryptor) {
+  auto sender = reinterpret_cast<webrtc::RtpSenderInterface*>(self);
+  webrtc::scoped_refptr<webrtc::FrameEncryptorInterface> encryptor;
+  if (frame_encryptor != nullptr) {
+    encryptor = reinterpret_cast<webrtc::FrameEncryptorInterface*>(
+        webrtc_FrameEncryptorInterface_refcounted_get(frame_encryptor));
+  }
+  sender->SetFrameEncryptor(encryptor);
+}
+
+// -------------------------
+// std::vector<scoped_refptr<webrtc::RtpSenderInterface>>
+// -------------------------
+
+WEBRTC_DEFINE_REFCOUNTED_VECTOR(webrtc_RtpSenderInterface,
+                                webrtc::RtpSenderInterface);
 }
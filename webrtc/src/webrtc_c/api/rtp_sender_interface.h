@@ -1,6 +1,14 @@
 #pragma once
 
+#include <stdint.h>
+
 #include "../common.h"
+#include "../std.h"
+#include "crypto/frame_encryptor_interface.h"
+#include "dtls_transport_interface.h"
+#include "dtmf_sender_interface.h"
+#include "frame_transformer_interface.h"
+#include "media_stream_interface.h"
 #include "rtc_error.h"
 #include "rtp_parameters.h"
 
@@ -14,6 +22,32 @@ extern "C" {
 
 WEBRTC_DECLARE_REFCOUNTED(webrtc_RtpSenderInterface);
 
+// トラックが未設定の場合は null を返す。
+WEBRTC_EXPORT struct webrtc_MediaStreamTrackInterface_refcounted*
+webrtc_RtpSenderInterface_track(struct webrtc_RtpSenderInterface* self);
+// 送信に使う SSRC。未確定の場合は 0 を返す。
+WEBRTC_EXPORT uint32_t
+webrtc_RtpSenderInterface_ssrc(struct webrtc_RtpSenderInterface* self);
+WEBRTC_EXPORT int webrtc_RtpSenderInterface_media_type(
+    struct webrtc_RtpSenderInterface* self);
+WEBRTC_EXPORT struct std_string_unique* webrtc_RtpSenderInterface_id(
+    struct webrtc_RtpSenderInterface* self);
+WEBRTC_EXPORT struct std_string_vector* webrtc_RtpSenderInterface_stream_ids(
+    struct webrtc_RtpSenderInterface* self);
+WEBRTC_EXPORT void webrtc_RtpSenderInterface_SetStreams(
+    struct webrtc_RtpSenderInterface* self,
+    struct std_string_vector* stream_ids);
+// DTLS トランスポートが未確定の場合は null を返す。
+WEBRTC_EXPORT struct webrtc_DtlsTransportInterface_refcounted*
+webrtc_RtpSenderInterface_dtls_transport(
+    struct webrtc_RtpSenderInterface* self);
+WEBRTC_EXPORT struct webrtc_RtpEncodingParameters_vector*
+webrtc_RtpSenderInterface_init_send_encodings(
+    struct webrtc_RtpSenderInterface* self);
+// 音声以外の送信者では null を返す。
+WEBRTC_EXPORT struct webrtc_DtmfSenderInterface_refcounted*
+webrtc_RtpSenderInterface_GetDtmfSender(
+    struct webrtc_RtpSenderInterface* self);
 WEBRTC_EXPORT struct webrtc_RtpParameters*
 webrtc_RtpSenderInterface_GetParameters(struct webrtc_RtpSenderInterface* self);
 WEBRTC_EXPORT void webrtc_RtpSenderInterface_SetParameters(
@@ -23,6 +57,20 @@ WEBRTC_EXPORT void webrtc_RtpSenderInterface_SetParameters(
 WEBRTC_EXPORT int webrtc_RtpSenderInterface_SetTrack(
     struct webrtc_RtpSenderInterface* self,
     struct webrtc_MediaStreamTrackInterface* track);
+// 設定済みの frame_transformer は解除できない。
+WEBRTC_EXPORT void webrtc_RtpSenderInterface_SetFrameTransformer(
+    struct webrtc_RtpSenderInterface* self,
+    struct webrtc_FrameTransformerInterface_refcounted* frame_transformer);
+// frame_encryptor が null の場合は暗号化を解除する。
+WEBRTC_EXPORT void webrtc_RtpSenderInterface_SetFrameEncryptor(
+    struct webrtc_RtpSenderInterface* self,
+    struct webrtc_FrameEncryptorInterface_refcounted* frame_encryptor);
+
+// -------------------------
+// std::vector<scoped_refptr<webrtc::RtpSenderInterface>>
+// -------------------------
+
+WEBRTC_DECLARE_REFCOUNTED_VECTOR(webrtc_RtpSenderInterface);
 
 #if defined(__cplusplus)
 }
@@ -19,4 +19,8 @@ WEBRTC_EXPORT extern const int webrtc_RtpTransceiverDirection_kSendOnly =
     static_cast<int>(webrtc::RtpTransceiverDirection::kSendOnly);
 WEBRTC_EXPORT extern const int webrtc_RtpTransceiverDirection_kRecvOnly =
     static_cast<int>(webrtc::RtpTransceiverDirection::kRecvOnly);
+WEBRTC_EXPORT extern const int webrtc_RtpTransceiverDirection_kInactive =
+    static_cast<int>(webrtc::RtpTransceiverDirection::kInactive);
+WEBRTC_EXPORT extern const int webrtc_RtpTransceiverDirection_kStopped =
+    static_cast<int>(webrtc::RtpTransceiverDirection::kStopped);
 }
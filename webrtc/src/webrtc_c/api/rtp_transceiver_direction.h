@@ -13,6 +13,8 @@ extern "C" {
 WEBRTC_EXPORT extern const int webrtc_RtpTransceiverDirection_kSendRecv;
 WEBRTC_EXPORT extern const int webrtc_RtpTransceiverDirection_kSendOnly;
 WEBRTC_EXPORT extern const int webrtc_RtpTransceiverDirection_kRecvOnly;
+WEBRTC_EXPORT extern const int webrtc_RtpTransceiverDirection_kInactive;
+WEBRTC_EXPORT extern const int webrtc_RtpTransceiverDirection_kStopped;
 
 #if defined(__cplusplus)
 }
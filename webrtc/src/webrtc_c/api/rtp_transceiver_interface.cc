@@ -4,9 +4,11 @@
 #include <stdarg.h>
 #include <stddef.h>
 #include <memory>
+#include <string>
 #include <vector>
 
 // WebRTC
+#include <api/media_types.h>
 #include <api/rtc_error.h>
 #include <api/rtp_parameters.h>
 #include <api/rtp_transceiver_direction.h>
@@ -15,8 +17,11 @@
 #include "../common.h"
 #include "../common.impl.h"
 #include "../std.h"
+#include "../std.impl.h"
+#include "rtc_error.h"
 #include "rtp_parameters.h"
 #include "rtp_receiver_interface.h"
+#include "rtp_sender_interface.h"
 
 // -------------------------
 // webrtc::RtpTransceiverInit
@@ -87,3 +92,142 @@ webrtc_RtpTransceiverInterface_receiver(
   return reinterpret_cast<struct webrtc_RtpReceiverInterface_refcounted*>(
       receiver.release());
 }
+WEBRTC_EXPORT struct webrtc_RtpSenderInterface_refcounted*
+webrtc_RtpTransceiverInterface_sender(
+    struct webrtc_RtpTransceiverInterface* self) {
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  auto sender = transceiver->sender();
+  return reinterpret_cast<struct webrtc_RtpSenderInterface_refcounted*>(
+      sender.release());
+}
+WEBRTC_EXPORT int webrtc_RtpTransceiverInterface_media_type(
+    struct webrtc_RtpTransceiverInterface* self) {
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  return static_cast<int>(transceiver->media_type());
+}
+WEBRTC_EXPORT struct std_string_unique* webrtc_RtpTransceiverInterface_mid(
+    struct webrtc_RtpTransceiverInterface* self) {
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  auto mid = transceiver->mid();
+  if (!mid.has_value()) {
+    return nullptr;
+  }
+  auto str = std::make_unique<std::string>(*mid);
+  return reinterpret_cast<struct std_string_unique*>(str.release());
+}
+WEBRTC_EXPORT int webrtc_RtpTransceiverInterface_stopped(
+    struct webrtc_RtpTransceiverInterface* self) {
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  return transceiver->stopped() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_RtpTransceiverInterface_stopping(
+    struct webrtc_RtpTransceiverInterface* self) {
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  return transceiver->stopping() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_RtpTransceiverInterface_direction(
+    struct webrtc_RtpTransceiverInterface* self) {
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  return static_cast<int>(transceiver->direction());
+}
+WEBRTC_EXPORT void webrtc_RtpTransceiverInterface_SetDirectionWithError(
+    struct webrtc_RtpTransceiverInterface* self,
+    int new_direction,
+    struct webrtc_RTCError_unique** out_rtc_error) {
+  assert(out_rtc_error != nullptr);
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  auto result = transceiver->SetDirectionWithError(
+      static_cast<webrtc::RtpTransceiverDirection>(new_direction));
+  if (result.ok()) {
+    *out_rtc_error = nullptr;
+  } else {
+    auto error = std::make_unique<webrtc::RTCError>(result);
+    *out_rtc_error =
+        reinterpret_cast<struct webrtc_RTCError_unique*>(error.release());
+  }
+}
+WEBRTC_EXPORT void webrtc_RtpTransceiverInterface_current_direction(
+    struct webrtc_RtpTransceiverInterface* self,
+    int* out_has,
+    int* out_value) {
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  auto direction = transceiver->current_direction();
+  webrtc_c::OptionalGetAs(direction, out_has, out_value,
+                          [&]() { return static_cast<int>(*direction); });
+}
+WEBRTC_EXPORT void webrtc_RtpTransceiverInterface_fired_direction(
+    struct webrtc_RtpTransceiverInterface* self,
+    int* out_has,
+    int* out_value) {
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  auto direction = transceiver->fired_direction();
+  webrtc_c::OptionalGetAs(direction, out_has, out_value,
+                          [&]() { return static_cast<int>(*direction); });
+}
+WEBRTC_EXPORT void webrtc_RtpTransceiverInterface_StopStandard(
+    struct webrtc_RtpTransceiverInterface* self,
+    struct webrtc_RTCError_unique** out_rtc_error) {
+  assert(out_rtc_error != nullptr);
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  auto result = transceiver->StopStandard();
+  if (result.ok()) {
+    *out_rtc_error = nullptr;
+  } else {
+    auto error = std::make_unique<webrtc::RTCError>(result);
+    *out_rtc_error =
+        reinterpret_cast<struct webrtc_RTCError_unique*>(error.release());
+  }
+}
+WEBRTC_EXPORT struct webrtc_RtpCodecCapability_vector*
+webrtc_RtpTransceiverInterface_codec_preferences(
+    struct webrtc_RtpTransceiverInterface* self) {
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  auto codecs = new std::vector<webrtc::RtpCodecCapability>(
+      transceiver->codec_preferences());
+  return reinterpret_cast<struct webrtc_RtpCodecCapability_vector*>(codecs);
+}
+WEBRTC_EXPORT struct webrtc_RtpHeaderExtensionCapability_vector*
+webrtc_RtpTransceiverInterface_GetHeaderExtensionsToNegotiate(
+    struct webrtc_RtpTransceiverInterface* self) {
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  auto extensions = new std::vector<webrtc::RtpHeaderExtensionCapability>(
+      transceiver->GetHeaderExtensionsToNegotiate());
+  return reinterpret_cast<
+      struct webrtc_RtpHeaderExtensionCapability_vector*>(extensions);
+}
+WEBRTC_EXPORT struct webrtc_RtpHeaderExtensionCapability_vector*
+webrtc_RtpTransceiverInterface_GetNegotiatedHeaderExtensions(
+    struct webrtc_RtpTransceiverInterface* self) {
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  auto extensions = new std::vector<webrtc::RtpHeaderExtensionCapability>(
+      transceiver->GetNegotiatedHeaderExtensions());
+  return reinterpret_cast<
+      struct webrtc_RtpHeaderExtensionCapability_vector*>(extensions);
+}
+WEBRTC_EXPORT void
+webrtc_RtpTransceiverInterface_SetHeaderExtensionsToNegotiate(
+    struct webrtc_RtpTransceiverInterface* self,
+    struct webrtc_RtpHeaderExtensionCapability_vector* extensions,
+    struct webrtc_RTCError_unique** out_rtc_error) {
+  assert(extensions != nullptr);
+  assert(out_rtc_error != nullptr);
+  auto transceiver = reinterpret_cast<webrtc::RtpTransceiverInterface*>(self);
+  auto vec =
+      reinterpret_cast<std::vector<webrtc::RtpHeaderExtensionCapability>*>(
+          extensions);
+  auto result = transceiver->SetHeaderExtensionsToNegotiate(*vec);
+  if (result.ok()) {
+    *out_rtc_error = nullptr;
+  } else {
+    auto error = std::make_unique<webrtc::RTCError>(result);
+    *out_rtc_error =
+        reinterpret_cast<struct webrtc_RTCError_unique*>(error.release());
+  }
+}
+
+// -------------------------
+// std::vector<scoped_refptr<webrtc::RtpTransceiverInterface>>
+// -------------------------
+
+WEBRTC_DEFINE_REFCOUNTED_VECTOR(webrtc_RtpTransceiverInterface,
+                                webrtc::RtpTransceiverInterface);
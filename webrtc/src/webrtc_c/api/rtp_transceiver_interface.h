@@ -5,6 +5,7 @@
 #include "rtc_error.h"
 #include "rtp_parameters.h"
 #include "rtp_receiver_interface.h"
+#include "rtp_sender_interface.h"
 
 #if defined(__cplusplus)
 extern "C" {
@@ -41,6 +42,57 @@ WEBRTC_EXPORT void webrtc_RtpTransceiverInterface_SetCodecPreferences(
 WEBRTC_EXPORT struct webrtc_RtpReceiverInterface_refcounted*
 webrtc_RtpTransceiverInterface_receiver(
     struct webrtc_RtpTransceiverInterface* self);
+WEBRTC_EXPORT struct webrtc_RtpSenderInterface_refcounted*
+webrtc_RtpTransceiverInterface_sender(
+    struct webrtc_RtpTransceiverInterface* self);
+WEBRTC_EXPORT int webrtc_RtpTransceiverInterface_media_type(
+    struct webrtc_RtpTransceiverInterface* self);
+// mid が未確定の場合は null を返す。
+WEBRTC_EXPORT struct std_string_unique* webrtc_RtpTransceiverInterface_mid(
+    struct webrtc_RtpTransceiverInterface* self);
+WEBRTC_EXPORT int webrtc_RtpTransceiverInterface_stopped(
+    struct webrtc_RtpTransceiverInterface* self);
+WEBRTC_EXPORT int webrtc_RtpTransceiverInterface_stopping(
+    struct webrtc_RtpTransceiverInterface* self);
+WEBRTC_EXPORT int webrtc_RtpTransceiverInterface_direction(
+    struct webrtc_RtpTransceiverInterface* self);
+WEBRTC_EXPORT void webrtc_RtpTransceiverInterface_SetDirectionWithError(
+    struct webrtc_RtpTransceiverInterface* self,
+    int new_direction,
+    struct webrtc_RTCError_unique** out_rtc_error);
+WEBRTC_EXPORT void webrtc_RtpTransceiverInterface_current_direction(
+    struct webrtc_RtpTransceiverInterface* self,
+    int* out_has,
+    int* out_value);
+WEBRTC_EXPORT void webrtc_RtpTransceiverInterface_fired_direction(
+    struct webrtc_RtpTransceiverInterface* self,
+    int* out_has,
+    int* out_value);
+WEBRTC_EXPORT void webrtc_RtpTransceiverInterface_StopStandard(
+    struct webrtc_RtpTransceiverInterface* self,
+    struct webrtc_RTCError_unique** out_rtc_error);
+WEBRTC_EXPORT struct webrtc_RtpCodecCapability_vector*
+webrtc_RtpTransceiverInterface_codec_preferences(
+    struct webrtc_RtpTransceiverInterface* self);
+WEBRTC_EXPORT struct webrtc_RtpHeaderExtensionCapability_vector*
+webrtc_RtpTransceiverInterface_GetHeaderExtensionsToNegotiate(
+    struct webrtc_RtpTransceiverInterface* self);
+// ネゴシエーション前は全拡張が kStopped の状態で返る。
+WEBRTC_EXPORT struct webrtc_RtpHeaderExtensionCapability_vector*
+webrtc_RtpTransceiverInterface_GetNegotiatedHeaderExtensions(
+    struct webrtc_RtpTransceiverInterface* self);
+WEBRTC_EXPORT void
+webrtc_RtpTransceiverInterface_SetHeaderExtensionsToNegotiate(
+    struct webrtc_RtpTransceiverInterface* self,
+    struct webrtc_RtpHeaderExtensionCapability_vector* extensions,
+    struct webrtc_RTCError_unique** out_rtc_error);
+
+// -------------------------
+// std::vector<scoped_refptr<webrtc::RtpTransceiverInterface>>
+// -------------------------
+
+WEBRTC_DECLARE_REFCOUNTED_VECTOR(webrtc_RtpTransceiverInterface);
+
 #if defined(__cplusplus)
 }
 #endif
@@ -0,0 +1,116 @@
+#include "attribute.h"
+
+#include <stdint.h>
+#include <cassert>
+#include <memory>
+#include <string>
+#include <vector>
+
+// WebRTC
+#include <api/stats/attribute.h>
+
+#include "../../common.h"
+#include "../../common.impl.h"
+#include "../../std.h"
+
+// -------------------------
+// webrtc::Attribute
+// -------------------------
+
+extern "C" {
+WEBRTC_DEFINE_VECTOR_NO_DEFAULT_CTOR(webrtc_Attribute, webrtc::Attribute);
+
+WEBRTC_EXPORT const char* webrtc_Attribute_name(struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->name();
+}
+WEBRTC_EXPORT int webrtc_Attribute_has_value(struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->has_value() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_Attribute_is_sequence(struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->is_sequence() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_Attribute_is_string(struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->is_string() ? 1 : 0;
+}
+WEBRTC_EXPORT struct std_string_unique* webrtc_Attribute_ToString(
+    struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  auto str = std::make_unique<std::string>(attr->ToString());
+  return reinterpret_cast<struct std_string_unique*>(str.release());
+}
+
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_bool(
+    struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->holds_alternative<bool>() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_int32(
+    struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->holds_alternative<int32_t>() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_uint32(
+    struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->holds_alternative<uint32_t>() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_int64(
+    struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->holds_alternative<int64_t>() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_uint64(
+    struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->holds_alternative<uint64_t>() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_double(
+    struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->holds_alternative<double>() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_string(
+    struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->holds_alternative<std::string>() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_Attribute_get_bool(struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->get<bool>() ? 1 : 0;
+}
+WEBRTC_EXPORT int32_t
+webrtc_Attribute_get_int32(struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->get<int32_t>();
+}
+WEBRTC_EXPORT uint32_t
+webrtc_Attribute_get_uint32(struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->get<uint32_t>();
+}
+WEBRTC_EXPORT int64_t
+webrtc_Attribute_get_int64(struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->get<int64_t>();
+}
+WEBRTC_EXPORT uint64_t
+webrtc_Attribute_get_uint64(struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->get<uint64_t>();
+}
+WEBRTC_EXPORT double webrtc_Attribute_get_double(
+    struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  return attr->get<double>();
+}
+WEBRTC_EXPORT struct std_string_unique* webrtc_Attribute_get_string(
+    struct webrtc_Attribute* self) {
+  auto attr = reinterpret_cast<webrtc::Attribute*>(self);
+  auto str = std::make_unique<std::string>(attr->get<std::string>());
+  return reinterpret_cast<struct std_string_unique*>(str.release());
+}
+}
@@ -0,0 +1,54 @@
+#pragma once
+
+#include <stdint.h>
+
+#include "../../common.h"
+#include "../../std.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::Attribute
+// -------------------------
+
+WEBRTC_DECLARE_VECTOR_NO_DEFAULT_CTOR(webrtc_Attribute);
+
+WEBRTC_EXPORT const char* webrtc_Attribute_name(struct webrtc_Attribute* self);
+WEBRTC_EXPORT int webrtc_Attribute_has_value(struct webrtc_Attribute* self);
+WEBRTC_EXPORT int webrtc_Attribute_is_sequence(struct webrtc_Attribute* self);
+WEBRTC_EXPORT int webrtc_Attribute_is_string(struct webrtc_Attribute* self);
+WEBRTC_EXPORT struct std_string_unique* webrtc_Attribute_ToString(
+    struct webrtc_Attribute* self);
+
+// Attribute::holds_alternative<T>() / Attribute::get<T>() の移植。
+// get 系は has_value() かつ holds_alternative が真の場合のみ呼び出せる。
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_bool(
+    struct webrtc_Attribute* self);
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_int32(
+    struct webrtc_Attribute* self);
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_uint32(
+    struct webrtc_Attribute* self);
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_int64(
+    struct webrtc_Attribute* self);
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_uint64(
+    struct webrtc_Attribute* self);
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_double(
+    struct webrtc_Attribute* self);
+WEBRTC_EXPORT int webrtc_Attribute_holds_alternative_string(
+    struct webrtc_Attribute* self);
+WEBRTC_EXPORT int webrtc_Attribute_get_bool(struct webrtc_Attribute* self);
+WEBRTC_EXPORT int32_t webrtc_Attribute_get_int32(struct webrtc_Attribute* self);
+WEBRTC_EXPORT uint32_t
+webrtc_Attribute_get_uint32(struct webrtc_Attribute* self);
+WEBRTC_EXPORT int64_t webrtc_Attribute_get_int64(struct webrtc_Attribute* self);
+WEBRTC_EXPORT uint64_t
+webrtc_Attribute_get_uint64(struct webrtc_Attribute* self);
+WEBRTC_EXPORT double webrtc_Attribute_get_double(struct webrtc_Attribute* self);
+WEBRTC_EXPORT struct std_string_unique* webrtc_Attribute_get_string(
+    struct webrtc_Attribute* self);
+
+#if defined(__cplusplus)
+}
+#endif
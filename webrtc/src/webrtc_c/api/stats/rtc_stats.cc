@@ -0,0 +1,50 @@
+#include "rtc_stats.h"
+
+#include <stdint.h>
+#include <memory>
+#include <string>
+#include <vector>
+
+// WebRTC
+#include <api/stats/attribute.h>
+#include <api/stats/rtc_stats.h>
+
+#include "../../common.h"
+#include "../../common.impl.h"
+#include "../../std.h"
+
+// -------------------------
+// webrtc::RTCStats
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT struct std_string_unique* webrtc_RTCStats_id(
+    struct webrtc_RTCStats* self) {
+  auto stats = reinterpret_cast<webrtc::RTCStats*>(self);
+  auto str = std::make_unique<std::string>(stats->id());
+  return reinterpret_cast<struct std_string_unique*>(str.release());
+}
+WEBRTC_EXPORT const char* webrtc_RTCStats_type(struct webrtc_RTCStats* self) {
+  auto stats = reinterpret_cast<webrtc::RTCStats*>(self);
+  return stats->type();
+}
+WEBRTC_EXPORT int64_t
+webrtc_RTCStats_timestamp_us(struct webrtc_RTCStats* self) {
+  auto stats = reinterpret_cast<webrtc::RTCStats*>(self);
+  return stats->timestamp().us();
+}
+WEBRTC_EXPORT struct webrtc_Attribute_vector* webrtc_RTCStats_Attributes(
+    struct webrtc_RTCStats* self) {
+  auto stats = reinterpret_cast<webrtc::RTCStats*>(self);
+  auto attributes =
+      std::make_unique<std::vector<webrtc::Attribute>>(stats->Attributes());
+  return reinterpret_cast<struct webrtc_Attribute_vector*>(
+      attributes.release());
+}
+WEBRTC_EXPORT struct std_string_unique* webrtc_RTCStats_ToJson(
+    struct webrtc_RTCStats* self) {
+  auto stats = reinterpret_cast<webrtc::RTCStats*>(self);
+  auto str = std::make_unique<std::string>(stats->ToJson());
+  return reinterpret_cast<struct std_string_unique*>(str.release());
+}
+}
@@ -0,0 +1,31 @@
+#pragma once
+
+#include <stdint.h>
+
+#include "../../common.h"
+#include "../../std.h"
+#include "attribute.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::RTCStats
+// -------------------------
+
+// RTCStatsReport が所有するため、利用者が解放してはならない。
+struct webrtc_RTCStats;
+
+WEBRTC_EXPORT struct std_string_unique* webrtc_RTCStats_id(
+    struct webrtc_RTCStats* self);
+WEBRTC_EXPORT const char* webrtc_RTCStats_type(struct webrtc_RTCStats* self);
+WEBRTC_EXPORT int64_t webrtc_RTCStats_timestamp_us(struct webrtc_RTCStats* self);
+WEBRTC_EXPORT struct webrtc_Attribute_vector* webrtc_RTCStats_Attributes(
+    struct webrtc_RTCStats* self);
+WEBRTC_EXPORT struct std_string_unique* webrtc_RTCStats_ToJson(
+    struct webrtc_RTCStats* self);
+
+#if defined(__cplusplus)
+}
+#endif
@@ -1,11 +1,14 @@
 #include "rtc_stats_report.h"
 
 #include <stddef.h>
+#include <stdint.h>
+#include <cassert>
 #include <memory>
 #include <string>
 #include <utility>
 
 // WebRTC
+#include <api/stats/rtc_stats.h>
 #include <api/stats/rtc_stats_report.h>
 
 #include "../../common.h"
@@ -29,4 +32,75 @@ WEBRTC_EXPORT struct std_string_unique* webrtc_RTCStatsReport_ToJson(
   auto str = std::make_unique<std::string>(std::move(json));
   return reinterpret_cast<struct std_string_unique*>(str.release());
 }
+WEBRTC_EXPORT int64_t
+webrtc_RTCStatsReport_timestamp_us(struct webrtc_RTCStatsReport* self) {
+  auto report = reinterpret_cast<webrtc::RTCStatsReport*>(self);
+  return report->timestamp().us();
+}
+WEBRTC_EXPORT size_t
+webrtc_RTCStatsReport_size(struct webrtc_RTCStatsReport* self) {
+  auto report = reinterpret_cast<webrtc::RTCStatsReport*>(self);
+  return report->size();
+}
+WEBRTC_EXPORT struct webrtc_RTCStats* webrtc_RTCStatsReport_Get(
+    struct webrtc_RTCStatsReport* self,
+    const char* id,
+    size_t id_len) {
+  auto report = reinterpret_cast<webrtc::RTCStatsReport*>(self);
+  auto stats = report->Get(std::string(id, id_len));
+  return reinterpret_cast<struct webrtc_RTCStats*>(
+      const_cast<webrtc::RTCStats*>(stats));
+}
+}
+
+// -------------------------
+// webrtc::RTCStatsReport::ConstIterator
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT struct webrtc_RTCStatsReport_ConstIterator*
+webrtc_RTCStatsReport_begin(struct webrtc_RTCStatsReport* self) {
+  auto report = reinterpret_cast<webrtc::RTCStatsReport*>(self);
+  auto it =
+      std::make_unique<webrtc::RTCStatsReport::ConstIterator>(report->begin());
+  return reinterpret_cast<struct webrtc_RTCStatsReport_ConstIterator*>(
+      it.release());
+}
+WEBRTC_EXPORT struct webrtc_RTCStatsReport_ConstIterator*
+webrtc_RTCStatsReport_end(struct webrtc_RTCStatsReport* self) {
+  auto report = reinterpret_cast<webrtc::RTCStatsReport*>(self);
+  auto it =
+      std::make_unique<webrtc::RTCStatsReport::ConstIterator>(report->end());
+  return reinterpret_cast<struct webrtc_RTCStatsReport_ConstIterator*>(
+      it.release());
+}
+WEBRTC_EXPORT void webrtc_RTCStatsReport_ConstIterator_delete(
+    struct webrtc_RTCStatsReport_ConstIterator* self) {
+  auto it = reinterpret_cast<webrtc::RTCStatsReport::ConstIterator*>(self);
+  delete it;
+}
+WEBRTC_EXPORT void webrtc_RTCStatsReport_ConstIterator_increment(
+    struct webrtc_RTCStatsReport_ConstIterator* self) {
+  auto it = reinterpret_cast<webrtc::RTCStatsReport::ConstIterator*>(self);
+  assert(it != nullptr);
+  ++(*it);
+}
+WEBRTC_EXPORT struct webrtc_RTCStats* webrtc_RTCStatsReport_ConstIterator_get(
+    struct webrtc_RTCStatsReport_ConstIterator* self) {
+  auto it = reinterpret_cast<webrtc::RTCStatsReport::ConstIterator*>(self);
+  assert(it != nullptr);
+  const webrtc::RTCStats& stats = **it;
+  return reinterpret_cast<struct webrtc_RTCStats*>(
+      const_cast<webrtc::RTCStats*>(&stats));
+}
+WEBRTC_EXPORT int webrtc_RTCStatsReport_ConstIterator_equals(
+    struct webrtc_RTCStatsReport_ConstIterator* self,
+    struct webrtc_RTCStatsReport_ConstIterator* other) {
+  auto it = reinterpret_cast<webrtc::RTCStatsReport::ConstIterator*>(self);
+  auto other_it =
+      reinterpret_cast<webrtc::RTCStatsReport::ConstIterator*>(other);
+  assert(it != nullptr);
+  assert(other_it != nullptr);
+  return *it == *other_it ? 1 : 0;
+}
 }
@@ -1,7 +1,11 @@
 #pragma once
 
+#include <stddef.h>
+#include <stdint.h>
+
 #include "../../common.h"
 #include "../../std.h"
+#include "rtc_stats.h"
 
 #if defined(__cplusplus)
 extern "C" {
@@ -15,6 +19,37 @@ WEBRTC_DECLARE_REFCOUNTED(webrtc_RTCStatsReport);
 
 WEBRTC_EXPORT struct std_string_unique* webrtc_RTCStatsReport_ToJson(
     struct webrtc_RTCStatsReport* report);
+WEBRTC_EXPORT int64_t
+webrtc_RTCStatsReport_timestamp_us(struct webrtc_RTCStatsReport* self);
+WEBRTC_EXPORT size_t
+webrtc_RTCStatsReport_size(struct webrtc_RTCStatsReport* self);
+// 見つからない場合は null を返す。
+WEBRTC_EXPORT struct webrtc_RTCStats* webrtc_RTCStatsReport_Get(
+    struct webrtc_RTCStatsReport* self,
+    const char* id,
+    size_t id_len);
+
+// -------------------------
+// webrtc::RTCStatsReport::ConstIterator
+// -------------------------
+
+struct webrtc_RTCStatsReport_ConstIterator;
+WEBRTC_EXPORT struct webrtc_RTCStatsReport_ConstIterator*
+webrtc_RTCStatsReport_begin(struct webrtc_RTCStatsReport* self);
+WEBRTC_EXPORT struct webrtc_RTCStatsReport_ConstIterator*
+webrtc_RTCStatsReport_end(struct webrtc_RTCStatsReport* self);
+WEBRTC_EXPORT void webrtc_RTCStatsReport_ConstIterator_delete(
+    struct webrtc_RTCStatsReport_ConstIterator* self);
+// operator++ の移植。
+WEBRTC_EXPORT void webrtc_RTCStatsReport_ConstIterator_increment(
+    struct webrtc_RTCStatsReport_ConstIterator* self);
+// operator* の移植。
+WEBRTC_EXPORT struct webrtc_RTCStats* webrtc_RTCStatsReport_ConstIterator_get(
+    struct webrtc_RTCStatsReport_ConstIterator* self);
+// operator== の移植。
+WEBRTC_EXPORT int webrtc_RTCStatsReport_ConstIterator_equals(
+    struct webrtc_RTCStatsReport_ConstIterator* self,
+    struct webrtc_RTCStatsReport_ConstIterator* other);
 
 #if defined(__cplusplus)
 }
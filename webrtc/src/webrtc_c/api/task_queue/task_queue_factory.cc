@@ -0,0 +1,216 @@
+#include "task_queue_factory.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <memory>
+#include <utility>
+
+// WebRTC
+#include <absl/functional/any_invocable.h>
+#include <absl/strings/string_view.h>
+#include <api/location.h>
+#include <api/task_queue/task_queue_base.h>
+#include <api/task_queue/task_queue_factory.h>
+#include <api/units/time_delta.h>
+
+#include "../../common.h"
+#include "../../common.impl.h"
+
+// -------------------------
+// webrtc::TaskQueueBase
+// -------------------------
+
+namespace {
+
+class TaskQueueImpl;
+
+struct TaskQueueTask {
+  TaskQueueImpl* queue;
+  absl::AnyInvocable<void() &&> task;
+};
+
+class TaskQueueImpl : public webrtc::TaskQueueBase {
+ public:
+  TaskQueueImpl(const struct webrtc_TaskQueueBase_cbs* cbs, void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->PostTask != nullptr);
+    assert(cbs->PostDelayedTask != nullptr);
+    assert(cbs->Delete != nullptr);
+    cbs_ = *cbs;
+  }
+
+  void Delete() override {
+    cbs_.Delete(user_data_);
+    delete this;
+  }
+
+  void RunTask(absl::AnyInvocable<void() &&> task) {
+    CurrentTaskQueueSetter setter(this);
+    std::move(task)();
+  }
+
+ protected:
+  void PostTaskImpl(absl::AnyInvocable<void() &&> task,
+                    const PostTaskTraits& traits,
+                    const webrtc::Location& location) override {
+    cbs_.PostTask(WrapTask(std::move(task)), user_data_);
+  }
+
+  void PostDelayedTaskImpl(absl::AnyInvocable<void() &&> task,
+                           webrtc::TimeDelta delay,
+                           const PostDelayedTaskTraits& traits,
+                           const webrtc::Location& location) override {
+    cbs_.PostDelayedTask(WrapTask(std::move(task)), delay.us(),
+                         traits.high_precision ? 1 : 0, user_data_);
+  }
+
+ private:
+  ~TaskQueueImpl() override = default;
+
+  struct webrtc_TaskQueueTask_unique* WrapTask(
+      absl::AnyInvocable<void() &&> task) {
+    auto wrapped = new TaskQueueTask{this, std::move(task)};
+    return reinterpret_cast<struct webrtc_TaskQueueTask_unique*>(wrapped);
+  }
+
+  webrtc_TaskQueueBase_cbs cbs_{};
+  void* user_data_;
+};
+
+class TaskImpl {
+ public:
+  TaskImpl(const struct webrtc_TaskQueueBase_Task_cbs* cbs, void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->Run != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~TaskImpl() { cbs_.OnDestroy(user_data_); }
+
+  void Run() { cbs_.Run(user_data_); }
+
+ private:
+  webrtc_TaskQueueBase_Task_cbs cbs_{};
+  void* user_data_;
+};
+
+}  // namespace
+
+extern "C" {
+
+WEBRTC_DEFINE_UNIQUE(webrtc_TaskQueueTask, TaskQueueTask);
+
+WEBRTC_EXPORT void webrtc_TaskQueueTask_Run(
+    struct webrtc_TaskQueueTask_unique* task) {
+  assert(task != nullptr);
+  std::unique_ptr<TaskQueueTask> t(reinterpret_cast<TaskQueueTask*>(task));
+  t->queue->RunTask(std::move(t->task));
+}
+
+WEBRTC_EXPORT struct webrtc_TaskQueueBase* webrtc_TaskQueueBase_new(
+    const struct webrtc_TaskQueueBase_cbs* cbs,
+    void* user_data) {
+  auto queue = new TaskQueueImpl(cbs, user_data);
+  return reinterpret_cast<struct webrtc_TaskQueueBase*>(
+      static_cast<webrtc::TaskQueueBase*>(queue));
+}
+
+WEBRTC_EXPORT void webrtc_TaskQueueBase_Delete(
+    struct webrtc_TaskQueueBase* self) {
+  auto queue = reinterpret_cast<webrtc::TaskQueueBase*>(self);
+  queue->Delete();
+}
+
+WEBRTC_EXPORT void webrtc_TaskQueueBase_PostTask(
+    struct webrtc_TaskQueueBase* self,
+    const struct webrtc_TaskQueueBase_Task_cbs* cbs,
+    void* user_data) {
+  auto queue = reinterpret_cast<webrtc::TaskQueueBase*>(self);
+  queue->PostTask(
+      [task = std::make_unique<TaskImpl>(cbs, user_data)]() { task->Run(); });
+}
+
+WEBRTC_EXPORT void webrtc_TaskQueueBase_PostDelayedTask(
+    struct webrtc_TaskQueueBase* self,
+    const struct webrtc_TaskQueueBase_Task_cbs* cbs,
+    void* user_data,
+    int64_t delay_us) {
+  auto queue = reinterpret_cast<webrtc::TaskQueueBase*>(self);
+  queue->PostDelayedTask(
+      [task = std::make_unique<TaskImpl>(cbs, user_data)]() { task->Run(); },
+      webrtc::TimeDelta::Micros(delay_us));
+}
+}
+
+// -------------------------
+// webrtc::TaskQueueFactory
+// -------------------------
+
+namespace {
+
+class TaskQueueFactoryImpl : public webrtc::TaskQueueFactory {
+ public:
+  TaskQueueFactoryImpl(const struct webrtc_TaskQueueFactory_cbs* cbs,
+                       void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->CreateTaskQueue != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~TaskQueueFactoryImpl() override { cbs_.OnDestroy(user_data_); }
+
+  std::unique_ptr<webrtc::TaskQueueBase, webrtc::TaskQueueDeleter>
+  CreateTaskQueue(absl::string_view name, Priority priority) const override {
+    auto queue = reinterpret_cast<webrtc::TaskQueueBase*>(
+        cbs_.CreateTaskQueue(name.data(), name.size(),
+                             static_cast<int>(priority), user_data_));
+    assert(queue != nullptr);
+    return std::unique_ptr<webrtc::TaskQueueBase, webrtc::TaskQueueDeleter>(
+        queue);
+  }
+
+ private:
+  webrtc_TaskQueueFactory_cbs cbs_{};
+  void* user_data_;
+};
+
+}  // namespace
+
+extern "C" {
+
+WEBRTC_EXPORT extern const int webrtc_TaskQueueFactory_Priority_kNormal =
+    static_cast<int>(webrtc::TaskQueueFactory::Priority::NORMAL);
+WEBRTC_EXPORT extern const int webrtc_TaskQueueFactory_Priority_kHigh =
+    static_cast<int>(webrtc::TaskQueueFactory::Priority::HIGH);
+WEBRTC_EXPORT extern const int webrtc_TaskQueueFactory_Priority_kLow =
+    static_cast<int>(webrtc::TaskQueueFactory::Priority::LOW);
+
+WEBRTC_DEFINE_UNIQUE(webrtc_TaskQueueFactory, webrtc::TaskQueueFactory);
+
+WEBRTC_EXPORT struct webrtc_TaskQueueFactory_unique*
+webrtc_TaskQueueFactory_new(const struct webrtc_TaskQueueFactory_cbs* cbs,
+                            void* user_data) {
+  auto factory = std::make_unique<TaskQueueFactoryImpl>(cbs, user_data);
+  return reinterpret_cast<struct webrtc_TaskQueueFactory_unique*>(
+      static_cast<webrtc::TaskQueueFactory*>(factory.release()));
+}
+
+WEBRTC_EXPORT struct webrtc_TaskQueueBase*
+webrtc_TaskQueueFactory_CreateTaskQueue(
+    struct webrtc_TaskQueueFactory* self,
+    const char* name,
+    size_t name_len,
+    webrtc_TaskQueueFactory_Priority priority) {
+  auto factory = reinterpret_cast<webrtc::TaskQueueFactory*>(self);
+  auto queue = factory->CreateTaskQueue(
+      absl::string_view(name, name_len),
+      static_cast<webrtc::TaskQueueFactory::Priority>(priority));
+  return reinterpret_cast<struct webrtc_TaskQueueBase*>(queue.release());
+}
+}
@@ -0,0 +1,104 @@
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#include "../../common.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::TaskQueueBase
+// -------------------------
+
+struct webrtc_TaskQueueBase;
+
+// TaskQueueBase に投入された 1 つのタスク。
+WEBRTC_DECLARE_UNIQUE(webrtc_TaskQueueTask);
+// タスクを実行して破棄する。実行中は TaskQueueBase::Current() が
+// 投入先の TaskQueueBase を返す。
+// 同じ TaskQueueBase のタスクを同時に実行してはならない。
+WEBRTC_EXPORT void webrtc_TaskQueueTask_Run(
+    struct webrtc_TaskQueueTask_unique* task);
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// PostTask / PostDelayedTask は任意のスレッドから同時に呼ばれうる。
+struct webrtc_TaskQueueBase_cbs {
+  void (*PostTask)(struct webrtc_TaskQueueTask_unique* task, void* user_data);
+  void (*PostDelayedTask)(struct webrtc_TaskQueueTask_unique* task,
+                          int64_t delay_us,
+                          int high_precision,
+                          void* user_data);
+  // 実行中のタスクの完了を待ち、以後タスクを実行しないようにしてから
+  // user_data を解放する。キュー上のタスクから呼ばれた場合は待たずに戻る。
+  void (*Delete)(void* user_data);
+};
+
+// 戻り値は TaskQueueFactory の CreateTaskQueue から返す。
+WEBRTC_EXPORT struct webrtc_TaskQueueBase* webrtc_TaskQueueBase_new(
+    const struct webrtc_TaskQueueBase_cbs* cbs,
+    void* user_data);
+
+// webrtc::TaskQueueDeleter と同じく Delete を呼んでキューを破棄する。
+WEBRTC_EXPORT void webrtc_TaskQueueBase_Delete(
+    struct webrtc_TaskQueueBase* self);
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// Run はタスクが実行された場合のみ呼ばれ、その後必ず OnDestroy が呼ばれる。
+struct webrtc_TaskQueueBase_Task_cbs {
+  void (*Run)(void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT void webrtc_TaskQueueBase_PostTask(
+    struct webrtc_TaskQueueBase* self,
+    const struct webrtc_TaskQueueBase_Task_cbs* cbs,
+    void* user_data);
+WEBRTC_EXPORT void webrtc_TaskQueueBase_PostDelayedTask(
+    struct webrtc_TaskQueueBase* self,
+    const struct webrtc_TaskQueueBase_Task_cbs* cbs,
+    void* user_data,
+    int64_t delay_us);
+
+// -------------------------
+// webrtc::TaskQueueFactory
+// -------------------------
+
+typedef int webrtc_TaskQueueFactory_Priority;
+WEBRTC_EXPORT extern const int webrtc_TaskQueueFactory_Priority_kNormal;
+WEBRTC_EXPORT extern const int webrtc_TaskQueueFactory_Priority_kHigh;
+WEBRTC_EXPORT extern const int webrtc_TaskQueueFactory_Priority_kLow;
+
+WEBRTC_DECLARE_UNIQUE(webrtc_TaskQueueFactory);
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// CreateTaskQueue は任意のスレッドから同時に呼ばれうる。
+struct webrtc_TaskQueueFactory_cbs {
+  // webrtc_TaskQueueBase_new で生成したキューを返す。null は返さないこと。
+  struct webrtc_TaskQueueBase* (*CreateTaskQueue)(
+      const char* name,
+      size_t name_len,
+      webrtc_TaskQueueFactory_Priority priority,
+      void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_TaskQueueFactory_unique*
+webrtc_TaskQueueFactory_new(const struct webrtc_TaskQueueFactory_cbs* cbs,
+                            void* user_data);
+// 戻り値は webrtc_TaskQueueBase_Delete で破棄する。
+WEBRTC_EXPORT struct webrtc_TaskQueueBase*
+webrtc_TaskQueueFactory_CreateTaskQueue(
+    struct webrtc_TaskQueueFactory* self,
+    const char* name,
+    size_t name_len,
+    webrtc_TaskQueueFactory_Priority priority);
+
+#if defined(__cplusplus)
+}
+#endif
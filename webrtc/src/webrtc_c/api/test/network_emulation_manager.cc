@@ -0,0 +1,264 @@
+#include "network_emulation_manager.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <memory>
+#include <utility>
+#include <vector>
+
+// WebRTC
+#include <api/test/create_network_emulation_manager.h>
+#include <api/test/network_emulation_manager.h>
+#include <api/test/simulated_network.h>
+#include <api/units/data_rate.h>
+#include <rtc_base/network.h>
+#include <rtc_base/socket_factory.h>
+#include <rtc_base/thread.h>
+
+#include "../../common.h"
+#include "../../common.impl.h"
+
+extern "C" {
+
+// -------------------------
+// webrtc::BuiltInNetworkBehaviorConfig
+// -------------------------
+
+WEBRTC_EXPORT struct webrtc_BuiltInNetworkBehaviorConfig*
+webrtc_BuiltInNetworkBehaviorConfig_new() {
+  auto config = new webrtc::BuiltInNetworkBehaviorConfig();
+  return reinterpret_cast<struct webrtc_BuiltInNetworkBehaviorConfig*>(config);
+}
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_delete(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  delete config;
+}
+WEBRTC_EXPORT size_t
+webrtc_BuiltInNetworkBehaviorConfig_get_queue_length_packets(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  return config->queue_length_packets;
+}
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_set_queue_length_packets(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    size_t queue_length_packets) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  config->queue_length_packets = queue_length_packets;
+}
+WEBRTC_EXPORT int webrtc_BuiltInNetworkBehaviorConfig_get_queue_delay_ms(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  return config->queue_delay_ms;
+}
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_set_queue_delay_ms(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int queue_delay_ms) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  config->queue_delay_ms = queue_delay_ms;
+}
+WEBRTC_EXPORT int
+webrtc_BuiltInNetworkBehaviorConfig_get_delay_standard_deviation_ms(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  return config->delay_standard_deviation_ms;
+}
+WEBRTC_EXPORT void
+webrtc_BuiltInNetworkBehaviorConfig_set_delay_standard_deviation_ms(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int delay_standard_deviation_ms) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  config->delay_standard_deviation_ms = delay_standard_deviation_ms;
+}
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_get_link_capacity_kbps(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int* out_has,
+    int64_t* out_value) {
+  assert(out_has != nullptr);
+  assert(out_value != nullptr);
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  if (config->link_capacity.IsPlusInfinity()) {
+    *out_has = 0;
+    return;
+  }
+  *out_has = 1;
+  *out_value = config->link_capacity.kbps();
+}
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_set_link_capacity_kbps(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int has,
+    const int64_t* value) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  if (has == 0) {
+    config->link_capacity = webrtc::DataRate::Infinity();
+    return;
+  }
+  assert(value != nullptr);
+  config->link_capacity = webrtc::DataRate::KilobitsPerSec(*value);
+}
+WEBRTC_EXPORT int webrtc_BuiltInNetworkBehaviorConfig_get_loss_percent(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  return config->loss_percent;
+}
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_set_loss_percent(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int loss_percent) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  config->loss_percent = loss_percent;
+}
+WEBRTC_EXPORT int webrtc_BuiltInNetworkBehaviorConfig_get_allow_reordering(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  return config->allow_reordering ? 1 : 0;
+}
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_set_allow_reordering(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int allow_reordering) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  config->allow_reordering = allow_reordering != 0;
+}
+WEBRTC_EXPORT int
+webrtc_BuiltInNetworkBehaviorConfig_get_avg_burst_loss_length(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  return config->avg_burst_loss_length;
+}
+WEBRTC_EXPORT void
+webrtc_BuiltInNetworkBehaviorConfig_set_avg_burst_loss_length(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int avg_burst_loss_length) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  config->avg_burst_loss_length = avg_burst_loss_length;
+}
+WEBRTC_EXPORT int webrtc_BuiltInNetworkBehaviorConfig_get_packet_overhead(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  return config->packet_overhead;
+}
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_set_packet_overhead(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int packet_overhead) {
+  auto config = reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(self);
+  config->packet_overhead = packet_overhead;
+}
+
+// -------------------------
+// webrtc::EmulatedNetworkManagerInterface
+// -------------------------
+
+WEBRTC_EXPORT struct webrtc_Thread*
+webrtc_EmulatedNetworkManagerInterface_network_thread(
+    struct webrtc_EmulatedNetworkManagerInterface* self) {
+  auto network =
+      reinterpret_cast<webrtc::EmulatedNetworkManagerInterface*>(self);
+  return reinterpret_cast<struct webrtc_Thread*>(network->network_thread());
+}
+WEBRTC_EXPORT struct webrtc_SocketFactory*
+webrtc_EmulatedNetworkManagerInterface_socket_factory(
+    struct webrtc_EmulatedNetworkManagerInterface* self) {
+  auto network =
+      reinterpret_cast<webrtc::EmulatedNetworkManagerInterface*>(self);
+  return reinterpret_cast<struct webrtc_SocketFactory*>(
+      network->socket_factory());
+}
+WEBRTC_EXPORT struct webrtc_NetworkManager_unique*
+webrtc_EmulatedNetworkManagerInterface_ReleaseNetworkManager(
+    struct webrtc_EmulatedNetworkManagerInterface* self) {
+  auto network =
+      reinterpret_cast<webrtc::EmulatedNetworkManagerInterface*>(self);
+  auto network_manager = network->ReleaseNetworkManager();
+  return reinterpret_cast<struct webrtc_NetworkManager_unique*>(
+      network_manager.release());
+}
+
+// -------------------------
+// webrtc::NetworkEmulationManager
+// -------------------------
+
+WEBRTC_DEFINE_UNIQUE(webrtc_NetworkEmulationManager,
+                     webrtc::NetworkEmulationManager);
+
+WEBRTC_EXPORT struct webrtc_NetworkEmulationManager_unique*
+webrtc_CreateNetworkEmulationManager() {
+  auto manager = webrtc::CreateNetworkEmulationManager();
+  return reinterpret_cast<struct webrtc_NetworkEmulationManager_unique*>(
+      manager.release());
+}
+
+WEBRTC_EXPORT struct webrtc_EmulatedNetworkNode*
+webrtc_NetworkEmulationManager_CreateEmulatedNode(
+    struct webrtc_NetworkEmulationManager* self,
+    struct webrtc_BuiltInNetworkBehaviorConfig* config,
+    uint64_t random_seed) {
+  assert(config != nullptr);
+  auto manager = reinterpret_cast<webrtc::NetworkEmulationManager*>(self);
+  auto node = manager->CreateEmulatedNode(
+      *reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(config),
+      random_seed);
+  return reinterpret_cast<struct webrtc_EmulatedNetworkNode*>(node);
+}
+WEBRTC_EXPORT struct webrtc_EmulatedEndpoint*
+webrtc_NetworkEmulationManager_CreateEndpoint(
+    struct webrtc_NetworkEmulationManager* self) {
+  auto manager = reinterpret_cast<webrtc::NetworkEmulationManager*>(self);
+  auto endpoint = manager->CreateEndpoint(webrtc::EmulatedEndpointConfig());
+  return reinterpret_cast<struct webrtc_EmulatedEndpoint*>(endpoint);
+}
+WEBRTC_EXPORT struct webrtc_EmulatedRoute*
+webrtc_NetworkEmulationManager_CreateRoute(
+    struct webrtc_NetworkEmulationManager* self,
+    struct webrtc_EmulatedEndpoint* from,
+    struct webrtc_EmulatedNetworkNode** via_nodes,
+    size_t via_nodes_len,
+    struct webrtc_EmulatedEndpoint* to) {
+  assert(from != nullptr);
+  assert(to != nullptr);
+  assert(via_nodes != nullptr || via_nodes_len == 0);
+  auto manager = reinterpret_cast<webrtc::NetworkEmulationManager*>(self);
+  std::vector<webrtc::EmulatedNetworkNode*> nodes;
+  for (size_t i = 0; i < via_nodes_len; ++i) {
+    nodes.push_back(
+        reinterpret_cast<webrtc::EmulatedNetworkNode*>(via_nodes[i]));
+  }
+  auto route = manager->CreateRoute(
+      reinterpret_cast<webrtc::EmulatedEndpoint*>(from), nodes,
+      reinterpret_cast<webrtc::EmulatedEndpoint*>(to));
+  return reinterpret_cast<struct webrtc_EmulatedRoute*>(route);
+}
+WEBRTC_EXPORT struct webrtc_EmulatedNetworkManagerInterface*
+webrtc_NetworkEmulationManager_CreateEmulatedNetworkManagerInterface(
+    struct webrtc_NetworkEmulationManager* self,
+    struct webrtc_EmulatedEndpoint** endpoints,
+    size_t endpoints_len) {
+  assert(endpoints != nullptr || endpoints_len == 0);
+  auto manager = reinterpret_cast<webrtc::NetworkEmulationManager*>(self);
+  std::vector<webrtc::EmulatedEndpoint*> v;
+  for (size_t i = 0; i < endpoints_len; ++i) {
+    v.push_back(reinterpret_cast<webrtc::EmulatedEndpoint*>(endpoints[i]));
+  }
+  auto network = manager->CreateEmulatedNetworkManagerInterface(v);
+  return reinterpret_cast<struct webrtc_EmulatedNetworkManagerInterface*>(
+      network);
+}
+WEBRTC_EXPORT void
+webrtc_NetworkEmulationManager_CreateEndpointPairWithTwoWayRoutes(
+    struct webrtc_NetworkEmulationManager* self,
+    struct webrtc_BuiltInNetworkBehaviorConfig* config,
+    struct webrtc_EmulatedNetworkManagerInterface** out_first,
+    struct webrtc_EmulatedNetworkManagerInterface** out_second) {
+  assert(config != nullptr);
+  assert(out_first != nullptr);
+  assert(out_second != nullptr);
+  auto manager = reinterpret_cast<webrtc::NetworkEmulationManager*>(self);
+  auto pair = manager->CreateEndpointPairWithTwoWayRoutes(
+      *reinterpret_cast<webrtc::BuiltInNetworkBehaviorConfig*>(config));
+  *out_first =
+      reinterpret_cast<struct webrtc_EmulatedNetworkManagerInterface*>(
+          pair.first);
+  *out_second =
+      reinterpret_cast<struct webrtc_EmulatedNetworkManagerInterface*>(
+          pair.second);
+}
+}
@@ -0,0 +1,137 @@
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#include "../../common.h"
+#include "../../rtc_base/network.h"
+#include "../../rtc_base/thread.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::BuiltInNetworkBehaviorConfig
+// -------------------------
+
+struct webrtc_BuiltInNetworkBehaviorConfig;
+WEBRTC_EXPORT struct webrtc_BuiltInNetworkBehaviorConfig*
+webrtc_BuiltInNetworkBehaviorConfig_new();
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_delete(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self);
+WEBRTC_EXPORT size_t
+webrtc_BuiltInNetworkBehaviorConfig_get_queue_length_packets(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self);
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_set_queue_length_packets(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    size_t queue_length_packets);
+WEBRTC_EXPORT int webrtc_BuiltInNetworkBehaviorConfig_get_queue_delay_ms(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self);
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_set_queue_delay_ms(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int queue_delay_ms);
+WEBRTC_EXPORT int
+webrtc_BuiltInNetworkBehaviorConfig_get_delay_standard_deviation_ms(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self);
+WEBRTC_EXPORT void
+webrtc_BuiltInNetworkBehaviorConfig_set_delay_standard_deviation_ms(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int delay_standard_deviation_ms);
+// 帯域が無制限の場合は out_has に 0 を設定する。
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_get_link_capacity_kbps(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int* out_has,
+    int64_t* out_value);
+// has が 0 の場合は帯域を無制限にする。
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_set_link_capacity_kbps(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int has,
+    const int64_t* value);
+WEBRTC_EXPORT int webrtc_BuiltInNetworkBehaviorConfig_get_loss_percent(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self);
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_set_loss_percent(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int loss_percent);
+WEBRTC_EXPORT int webrtc_BuiltInNetworkBehaviorConfig_get_allow_reordering(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self);
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_set_allow_reordering(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int allow_reordering);
+WEBRTC_EXPORT int
+webrtc_BuiltInNetworkBehaviorConfig_get_avg_burst_loss_length(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self);
+WEBRTC_EXPORT void
+webrtc_BuiltInNetworkBehaviorConfig_set_avg_burst_loss_length(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int avg_burst_loss_length);
+WEBRTC_EXPORT int webrtc_BuiltInNetworkBehaviorConfig_get_packet_overhead(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self);
+WEBRTC_EXPORT void webrtc_BuiltInNetworkBehaviorConfig_set_packet_overhead(
+    struct webrtc_BuiltInNetworkBehaviorConfig* self,
+    int packet_overhead);
+
+// -------------------------
+// webrtc::EmulatedNetworkManagerInterface
+// -------------------------
+
+struct webrtc_EmulatedNetworkNode;
+struct webrtc_EmulatedEndpoint;
+struct webrtc_EmulatedRoute;
+struct webrtc_EmulatedNetworkManagerInterface;
+struct webrtc_SocketFactory;
+
+// 戻り値は NetworkEmulationManager が所有する。
+WEBRTC_EXPORT struct webrtc_Thread*
+webrtc_EmulatedNetworkManagerInterface_network_thread(
+    struct webrtc_EmulatedNetworkManagerInterface* self);
+// 戻り値は NetworkEmulationManager が所有する。
+WEBRTC_EXPORT struct webrtc_SocketFactory*
+webrtc_EmulatedNetworkManagerInterface_socket_factory(
+    struct webrtc_EmulatedNetworkManagerInterface* self);
+// 1 つの EmulatedNetworkManagerInterface につき 1 回だけ呼び出せる。
+WEBRTC_EXPORT struct webrtc_NetworkManager_unique*
+webrtc_EmulatedNetworkManagerInterface_ReleaseNetworkManager(
+    struct webrtc_EmulatedNetworkManagerInterface* self);
+
+// -------------------------
+// webrtc::NetworkEmulationManager
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_NetworkEmulationManager);
+
+// 実時間で動作する NetworkEmulationManager を生成する。
+WEBRTC_EXPORT struct webrtc_NetworkEmulationManager_unique*
+webrtc_CreateNetworkEmulationManager();
+
+// 以下で生成したノードやエンドポイントは NetworkEmulationManager が所有する。
+WEBRTC_EXPORT struct webrtc_EmulatedNetworkNode*
+webrtc_NetworkEmulationManager_CreateEmulatedNode(
+    struct webrtc_NetworkEmulationManager* self,
+    struct webrtc_BuiltInNetworkBehaviorConfig* config,
+    uint64_t random_seed);
+WEBRTC_EXPORT struct webrtc_EmulatedEndpoint*
+webrtc_NetworkEmulationManager_CreateEndpoint(
+    struct webrtc_NetworkEmulationManager* self);
+WEBRTC_EXPORT struct webrtc_EmulatedRoute*
+webrtc_NetworkEmulationManager_CreateRoute(
+    struct webrtc_NetworkEmulationManager* self,
+    struct webrtc_EmulatedEndpoint* from,
+    struct webrtc_EmulatedNetworkNode** via_nodes,
+    size_t via_nodes_len,
+    struct webrtc_EmulatedEndpoint* to);
+WEBRTC_EXPORT struct webrtc_EmulatedNetworkManagerInterface*
+webrtc_NetworkEmulationManager_CreateEmulatedNetworkManagerInterface(
+    struct webrtc_NetworkEmulationManager* self,
+    struct webrtc_EmulatedEndpoint** endpoints,
+    size_t endpoints_len);
+WEBRTC_EXPORT void
+webrtc_NetworkEmulationManager_CreateEndpointPairWithTwoWayRoutes(
+    struct webrtc_NetworkEmulationManager* self,
+    struct webrtc_BuiltInNetworkBehaviorConfig* config,
+    struct webrtc_EmulatedNetworkManagerInterface** out_first,
+    struct webrtc_EmulatedNetworkManagerInterface** out_second);
+
+#if defined(__cplusplus)
+}
+#endif
@@ -0,0 +1,62 @@
+#include "rtp_source.h"
+
+#include <assert.h>
+#include <stdint.h>
+#include <vector>
+
+// WebRTC
+#include <api/transport/rtp/rtp_source.h>
+
+#include "../../../common.h"
+#include "../../../common.impl.h"
+
+extern "C" {
+
+// -------------------------
+// webrtc::RtpSourceType
+// -------------------------
+
+WEBRTC_EXPORT extern const int webrtc_RtpSourceType_SSRC =
+    static_cast<int>(webrtc::RtpSourceType::SSRC);
+WEBRTC_EXPORT extern const int webrtc_RtpSourceType_CSRC =
+    static_cast<int>(webrtc::RtpSourceType::CSRC);
+
+// -------------------------
+// webrtc::RtpSource
+// -------------------------
+
+WEBRTC_DEFINE_VECTOR_NO_DEFAULT_CTOR(webrtc_RtpSource, webrtc::RtpSource);
+
+WEBRTC_EXPORT int64_t
+webrtc_RtpSource_timestamp_ms(struct webrtc_RtpSource* self) {
+  auto source = reinterpret_cast<webrtc::RtpSource*>(self);
+  return source->timestamp().ms();
+}
+WEBRTC_EXPORT uint32_t
+webrtc_RtpSource_source_id(struct webrtc_RtpSource* self) {
+  auto source = reinterpret_cast<webrtc::RtpSource*>(self);
+  return source->source_id();
+}
+WEBRTC_EXPORT webrtc_RtpSourceType
+webrtc_RtpSource_source_type(struct webrtc_RtpSource* self) {
+  auto source = reinterpret_cast<webrtc::RtpSource*>(self);
+  return static_cast<int>(source->source_type());
+}
+WEBRTC_EXPORT void webrtc_RtpSource_audio_level(struct webrtc_RtpSource* self,
+                                                int* out_has,
+                                                int* out_value) {
+  assert(out_has != nullptr);
+  assert(out_value != nullptr);
+  auto source = reinterpret_cast<webrtc::RtpSource*>(self);
+  auto audio_level = source->audio_level();
+  *out_has = audio_level.has_value() ? 1 : 0;
+  if (audio_level.has_value()) {
+    *out_value = *audio_level;
+  }
+}
+WEBRTC_EXPORT uint32_t
+webrtc_RtpSource_rtp_timestamp(struct webrtc_RtpSource* self) {
+  auto source = reinterpret_cast<webrtc::RtpSource*>(self);
+  return source->rtp_timestamp();
+}
+}
@@ -0,0 +1,39 @@
+#pragma once
+
+#include <stdint.h>
+
+#include "../../../common.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::RtpSourceType
+// -------------------------
+
+typedef int webrtc_RtpSourceType;
+WEBRTC_EXPORT extern const int webrtc_RtpSourceType_SSRC;
+WEBRTC_EXPORT extern const int webrtc_RtpSourceType_CSRC;
+
+// -------------------------
+// webrtc::RtpSource
+// -------------------------
+
+WEBRTC_DECLARE_VECTOR_NO_DEFAULT_CTOR(webrtc_RtpSource);
+
+WEBRTC_EXPORT int64_t
+webrtc_RtpSource_timestamp_ms(struct webrtc_RtpSource* self);
+WEBRTC_EXPORT uint32_t
+webrtc_RtpSource_source_id(struct webrtc_RtpSource* self);
+WEBRTC_EXPORT webrtc_RtpSourceType
+webrtc_RtpSource_source_type(struct webrtc_RtpSource* self);
+WEBRTC_EXPORT void webrtc_RtpSource_audio_level(struct webrtc_RtpSource* self,
+                                                int* out_has,
+                                                int* out_value);
+WEBRTC_EXPORT uint32_t
+webrtc_RtpSource_rtp_timestamp(struct webrtc_RtpSource* self);
+
+#if defined(__cplusplus)
+}
+#endif
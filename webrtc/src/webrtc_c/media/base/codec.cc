@@ -0,0 +1,46 @@
+#include "codec.h"
+
+#include <stddef.h>
+#include <cassert>
+#include <map>
+#include <string>
+
+// WebRTC
+#include <media/base/codec.h>
+
+#include "../../common.h"
+#include "../../std.h"
+
+// -------------------------
+// webrtc::Codec
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT int webrtc_Codec_get_id(const struct webrtc_Codec* self) {
+  auto codec = reinterpret_cast<const webrtc::Codec*>(self);
+  return codec->id;
+}
+WEBRTC_EXPORT void webrtc_Codec_get_name(const struct webrtc_Codec* self,
+                                         const char** out_name,
+                                         size_t* out_name_len) {
+  assert(out_name != nullptr);
+  assert(out_name_len != nullptr);
+  auto codec = reinterpret_cast<const webrtc::Codec*>(self);
+  *out_name = codec->name.data();
+  *out_name_len = codec->name.size();
+}
+WEBRTC_EXPORT int webrtc_Codec_get_clockrate(const struct webrtc_Codec* self) {
+  auto codec = reinterpret_cast<const webrtc::Codec*>(self);
+  return codec->clockrate;
+}
+WEBRTC_EXPORT size_t webrtc_Codec_get_channels(
+    const struct webrtc_Codec* self) {
+  auto codec = reinterpret_cast<const webrtc::Codec*>(self);
+  return codec->channels;
+}
+WEBRTC_EXPORT const struct std_map_string_string* webrtc_Codec_get_params(
+    const struct webrtc_Codec* self) {
+  auto codec = reinterpret_cast<const webrtc::Codec*>(self);
+  return reinterpret_cast<const struct std_map_string_string*>(&codec->params);
+}
+}
@@ -0,0 +1,29 @@
+#pragma once
+
+#include <stddef.h>
+
+#include "../../common.h"
+#include "../../std.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::Codec
+// -------------------------
+
+struct webrtc_Codec;
+WEBRTC_EXPORT int webrtc_Codec_get_id(const struct webrtc_Codec* self);
+WEBRTC_EXPORT void webrtc_Codec_get_name(const struct webrtc_Codec* self,
+                                         const char** out_name,
+                                         size_t* out_name_len);
+WEBRTC_EXPORT int webrtc_Codec_get_clockrate(const struct webrtc_Codec* self);
+WEBRTC_EXPORT size_t webrtc_Codec_get_channels(const struct webrtc_Codec* self);
+// fmtp パラメータ。読み取り専用として扱うこと。
+WEBRTC_EXPORT const struct std_map_string_string* webrtc_Codec_get_params(
+    const struct webrtc_Codec* self);
+
+#if defined(__cplusplus)
+}
+#endif
@@ -0,0 +1,109 @@
+#include "stream_params.h"
+
+#include <stddef.h>
+#include <stdint.h>
+#include <algorithm>
+#include <cassert>
+#include <vector>
+
+// WebRTC
+#include <media/base/stream_params.h>
+
+#include "../../common.h"
+
+namespace {
+
+size_t CopySsrcs(const std::vector<uint32_t>& ssrcs,
+                 uint32_t* out_ssrcs,
+                 size_t out_ssrcs_len) {
+  size_t n = std::min(ssrcs.size(), out_ssrcs_len);
+  if (n == 0) {
+    return 0;
+  }
+  assert(out_ssrcs != nullptr);
+  std::copy(ssrcs.begin(), ssrcs.begin() + n, out_ssrcs);
+  return n;
+}
+
+}  // namespace
+
+// -------------------------
+// webrtc::SsrcGroup
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT void webrtc_SsrcGroup_get_semantics(
+    const struct webrtc_SsrcGroup* self,
+    const char** out_semantics,
+    size_t* out_semantics_len) {
+  assert(out_semantics != nullptr);
+  assert(out_semantics_len != nullptr);
+  auto group = reinterpret_cast<const webrtc::SsrcGroup*>(self);
+  *out_semantics = group->semantics.data();
+  *out_semantics_len = group->semantics.size();
+}
+WEBRTC_EXPORT size_t
+webrtc_SsrcGroup_get_ssrcs_size(const struct webrtc_SsrcGroup* self) {
+  auto group = reinterpret_cast<const webrtc::SsrcGroup*>(self);
+  return group->ssrcs.size();
+}
+WEBRTC_EXPORT size_t
+webrtc_SsrcGroup_copy_ssrcs(const struct webrtc_SsrcGroup* self,
+                            uint32_t* out_ssrcs,
+                            size_t out_ssrcs_len) {
+  auto group = reinterpret_cast<const webrtc::SsrcGroup*>(self);
+  return CopySsrcs(group->ssrcs, out_ssrcs, out_ssrcs_len);
+}
+}
+
+// -------------------------
+// webrtc::StreamParams
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT void webrtc_StreamParams_get_id(
+    const struct webrtc_StreamParams* self,
+    const char** out_id,
+    size_t* out_id_len) {
+  assert(out_id != nullptr);
+  assert(out_id_len != nullptr);
+  auto params = reinterpret_cast<const webrtc::StreamParams*>(self);
+  *out_id = params->id.data();
+  *out_id_len = params->id.size();
+}
+WEBRTC_EXPORT void webrtc_StreamParams_get_cname(
+    const struct webrtc_StreamParams* self,
+    const char** out_cname,
+    size_t* out_cname_len) {
+  assert(out_cname != nullptr);
+  assert(out_cname_len != nullptr);
+  auto params = reinterpret_cast<const webrtc::StreamParams*>(self);
+  *out_cname = params->cname.data();
+  *out_cname_len = params->cname.size();
+}
+WEBRTC_EXPORT size_t
+webrtc_StreamParams_get_ssrcs_size(const struct webrtc_StreamParams* self) {
+  auto params = reinterpret_cast<const webrtc::StreamParams*>(self);
+  return params->ssrcs.size();
+}
+WEBRTC_EXPORT size_t
+webrtc_StreamParams_copy_ssrcs(const struct webrtc_StreamParams* self,
+                               uint32_t* out_ssrcs,
+                               size_t out_ssrcs_len) {
+  auto params = reinterpret_cast<const webrtc::StreamParams*>(self);
+  return CopySsrcs(params->ssrcs, out_ssrcs, out_ssrcs_len);
+}
+WEBRTC_EXPORT size_t webrtc_StreamParams_get_ssrc_groups_size(
+    const struct webrtc_StreamParams* self) {
+  auto params = reinterpret_cast<const webrtc::StreamParams*>(self);
+  return params->ssrc_groups.size();
+}
+WEBRTC_EXPORT const struct webrtc_SsrcGroup*
+webrtc_StreamParams_get_ssrc_group(const struct webrtc_StreamParams* self,
+                                   size_t index) {
+  auto params = reinterpret_cast<const webrtc::StreamParams*>(self);
+  assert(index < params->ssrc_groups.size());
+  return reinterpret_cast<const struct webrtc_SsrcGroup*>(
+      &params->ssrc_groups[index]);
+}
+}
@@ -0,0 +1,59 @@
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#include "../../common.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::SsrcGroup
+// -------------------------
+
+struct webrtc_SsrcGroup;
+WEBRTC_EXPORT void webrtc_SsrcGroup_get_semantics(
+    const struct webrtc_SsrcGroup* self,
+    const char** out_semantics,
+    size_t* out_semantics_len);
+WEBRTC_EXPORT size_t
+webrtc_SsrcGroup_get_ssrcs_size(const struct webrtc_SsrcGroup* self);
+// out_ssrcs に最大 out_ssrcs_len 個コピーし、コピーした数を返す。
+// out_ssrcs_len が 0 の場合のみ out_ssrcs に null を指定できる。
+WEBRTC_EXPORT size_t
+webrtc_SsrcGroup_copy_ssrcs(const struct webrtc_SsrcGroup* self,
+                            uint32_t* out_ssrcs,
+                            size_t out_ssrcs_len);
+
+// -------------------------
+// webrtc::StreamParams
+// -------------------------
+
+struct webrtc_StreamParams;
+WEBRTC_EXPORT void webrtc_StreamParams_get_id(
+    const struct webrtc_StreamParams* self,
+    const char** out_id,
+    size_t* out_id_len);
+WEBRTC_EXPORT void webrtc_StreamParams_get_cname(
+    const struct webrtc_StreamParams* self,
+    const char** out_cname,
+    size_t* out_cname_len);
+WEBRTC_EXPORT size_t
+webrtc_StreamParams_get_ssrcs_size(const struct webrtc_StreamParams* self);
+// out_ssrcs に最大 out_ssrcs_len 個コピーし、コピーした数を返す。
+// out_ssrcs_len が 0 の場合のみ out_ssrcs に null を指定できる。
+WEBRTC_EXPORT size_t
+webrtc_StreamParams_copy_ssrcs(const struct webrtc_StreamParams* self,
+                               uint32_t* out_ssrcs,
+                               size_t out_ssrcs_len);
+WEBRTC_EXPORT size_t webrtc_StreamParams_get_ssrc_groups_size(
+    const struct webrtc_StreamParams* self);
+WEBRTC_EXPORT const struct webrtc_SsrcGroup*
+webrtc_StreamParams_get_ssrc_group(const struct webrtc_StreamParams* self,
+                                   size_t index);
+
+#if defined(__cplusplus)
+}
+#endif
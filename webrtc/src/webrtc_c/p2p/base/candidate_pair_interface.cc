@@ -0,0 +1,65 @@
+#include "candidate_pair_interface.h"
+
+#include <stddef.h>
+#include <stdint.h>
+#include <cassert>
+
+// WebRTC
+#include <api/candidate.h>
+#include <p2p/base/candidate_pair_interface.h>
+
+#include "../../api/candidate.h"
+#include "../../common.h"
+
+// -------------------------
+// webrtc::CandidatePair
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT const struct webrtc_Candidate*
+webrtc_CandidatePair_local_candidate(const struct webrtc_CandidatePair* self) {
+  auto pair = reinterpret_cast<const webrtc::CandidatePair*>(self);
+  return reinterpret_cast<const struct webrtc_Candidate*>(
+      &pair->local_candidate());
+}
+WEBRTC_EXPORT const struct webrtc_Candidate*
+webrtc_CandidatePair_remote_candidate(const struct webrtc_CandidatePair* self) {
+  auto pair = reinterpret_cast<const webrtc::CandidatePair*>(self);
+  return reinterpret_cast<const struct webrtc_Candidate*>(
+      &pair->remote_candidate());
+}
+
+// -------------------------
+// webrtc::CandidatePairChangeEvent
+// -------------------------
+
+WEBRTC_EXPORT const struct webrtc_CandidatePair*
+webrtc_CandidatePairChangeEvent_get_selected_candidate_pair(
+    const struct webrtc_CandidatePairChangeEvent* self) {
+  auto event = reinterpret_cast<const webrtc::CandidatePairChangeEvent*>(self);
+  return reinterpret_cast<const struct webrtc_CandidatePair*>(
+      &event->selected_candidate_pair);
+}
+WEBRTC_EXPORT int64_t
+webrtc_CandidatePairChangeEvent_get_last_data_received_ms(
+    const struct webrtc_CandidatePairChangeEvent* self) {
+  auto event = reinterpret_cast<const webrtc::CandidatePairChangeEvent*>(self);
+  return event->last_data_received_ms;
+}
+WEBRTC_EXPORT void webrtc_CandidatePairChangeEvent_get_reason(
+    const struct webrtc_CandidatePairChangeEvent* self,
+    const char** out_reason,
+    size_t* out_len) {
+  assert(out_reason != nullptr);
+  assert(out_len != nullptr);
+  auto event = reinterpret_cast<const webrtc::CandidatePairChangeEvent*>(self);
+  *out_reason = event->reason.c_str();
+  *out_len = event->reason.size();
+}
+WEBRTC_EXPORT int64_t
+webrtc_CandidatePairChangeEvent_get_estimated_disconnected_time_ms(
+    const struct webrtc_CandidatePairChangeEvent* self) {
+  auto event = reinterpret_cast<const webrtc::CandidatePairChangeEvent*>(self);
+  return event->estimated_disconnected_time_ms;
+}
+}
@@ -0,0 +1,44 @@
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#include "../../api/candidate.h"
+#include "../../common.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::CandidatePair
+// -------------------------
+
+struct webrtc_CandidatePair;
+WEBRTC_EXPORT const struct webrtc_Candidate*
+webrtc_CandidatePair_local_candidate(const struct webrtc_CandidatePair* self);
+WEBRTC_EXPORT const struct webrtc_Candidate*
+webrtc_CandidatePair_remote_candidate(const struct webrtc_CandidatePair* self);
+
+// -------------------------
+// webrtc::CandidatePairChangeEvent
+// -------------------------
+
+struct webrtc_CandidatePairChangeEvent;
+WEBRTC_EXPORT const struct webrtc_CandidatePair*
+webrtc_CandidatePairChangeEvent_get_selected_candidate_pair(
+    const struct webrtc_CandidatePairChangeEvent* self);
+WEBRTC_EXPORT int64_t
+webrtc_CandidatePairChangeEvent_get_last_data_received_ms(
+    const struct webrtc_CandidatePairChangeEvent* self);
+WEBRTC_EXPORT void webrtc_CandidatePairChangeEvent_get_reason(
+    const struct webrtc_CandidatePairChangeEvent* self,
+    const char** out_reason,
+    size_t* out_len);
+WEBRTC_EXPORT int64_t
+webrtc_CandidatePairChangeEvent_get_estimated_disconnected_time_ms(
+    const struct webrtc_CandidatePairChangeEvent* self);
+
+#if defined(__cplusplus)
+}
+#endif
@@ -0,0 +1,68 @@
+#include "transport_description.h"
+
+#include <stddef.h>
+#include <cassert>
+
+// WebRTC
+#include <p2p/base/transport_description.h>
+#include <rtc_base/ssl_fingerprint.h>
+
+#include "../../common.h"
+#include "../../rtc_base/ssl_fingerprint.h"
+
+// -------------------------
+// webrtc::ConnectionRole
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT extern const int webrtc_ConnectionRole_CONNECTIONROLE_NONE =
+    static_cast<int>(webrtc::CONNECTIONROLE_NONE);
+WEBRTC_EXPORT extern const int webrtc_ConnectionRole_CONNECTIONROLE_ACTIVE =
+    static_cast<int>(webrtc::CONNECTIONROLE_ACTIVE);
+WEBRTC_EXPORT extern const int webrtc_ConnectionRole_CONNECTIONROLE_PASSIVE =
+    static_cast<int>(webrtc::CONNECTIONROLE_PASSIVE);
+WEBRTC_EXPORT extern const int webrtc_ConnectionRole_CONNECTIONROLE_ACTPASS =
+    static_cast<int>(webrtc::CONNECTIONROLE_ACTPASS);
+WEBRTC_EXPORT extern const int webrtc_ConnectionRole_CONNECTIONROLE_HOLDCONN =
+    static_cast<int>(webrtc::CONNECTIONROLE_HOLDCONN);
+}
+
+// -------------------------
+// webrtc::TransportDescription
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT void webrtc_TransportDescription_get_ice_ufrag(
+    const struct webrtc_TransportDescription* self,
+    const char** out_ice_ufrag,
+    size_t* out_ice_ufrag_len) {
+  assert(out_ice_ufrag != nullptr);
+  assert(out_ice_ufrag_len != nullptr);
+  auto desc = reinterpret_cast<const webrtc::TransportDescription*>(self);
+  *out_ice_ufrag = desc->ice_ufrag.data();
+  *out_ice_ufrag_len = desc->ice_ufrag.size();
+}
+WEBRTC_EXPORT void webrtc_TransportDescription_get_ice_pwd(
+    const struct webrtc_TransportDescription* self,
+    const char** out_ice_pwd,
+    size_t* out_ice_pwd_len) {
+  assert(out_ice_pwd != nullptr);
+  assert(out_ice_pwd_len != nullptr);
+  auto desc = reinterpret_cast<const webrtc::TransportDescription*>(self);
+  *out_ice_pwd = desc->ice_pwd.data();
+  *out_ice_pwd_len = desc->ice_pwd.size();
+}
+WEBRTC_EXPORT webrtc_ConnectionRole
+webrtc_TransportDescription_get_connection_role(
+    const struct webrtc_TransportDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::TransportDescription*>(self);
+  return static_cast<webrtc_ConnectionRole>(desc->connection_role);
+}
+WEBRTC_EXPORT const struct webrtc_SSLFingerprint*
+webrtc_TransportDescription_get_identity_fingerprint(
+    const struct webrtc_TransportDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::TransportDescription*>(self);
+  return reinterpret_cast<const struct webrtc_SSLFingerprint*>(
+      desc->identity_fingerprint.get());
+}
+}
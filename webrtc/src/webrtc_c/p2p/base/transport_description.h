@@ -0,0 +1,46 @@
+#pragma once
+
+#include <stddef.h>
+
+#include "../../common.h"
+#include "../../rtc_base/ssl_fingerprint.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::ConnectionRole
+// -------------------------
+
+typedef int webrtc_ConnectionRole;
+WEBRTC_EXPORT extern const int webrtc_ConnectionRole_CONNECTIONROLE_NONE;
+WEBRTC_EXPORT extern const int webrtc_ConnectionRole_CONNECTIONROLE_ACTIVE;
+WEBRTC_EXPORT extern const int webrtc_ConnectionRole_CONNECTIONROLE_PASSIVE;
+WEBRTC_EXPORT extern const int webrtc_ConnectionRole_CONNECTIONROLE_ACTPASS;
+WEBRTC_EXPORT extern const int webrtc_ConnectionRole_CONNECTIONROLE_HOLDCONN;
+
+// -------------------------
+// webrtc::TransportDescription
+// -------------------------
+
+struct webrtc_TransportDescription;
+WEBRTC_EXPORT void webrtc_TransportDescription_get_ice_ufrag(
+    const struct webrtc_TransportDescription* self,
+    const char** out_ice_ufrag,
+    size_t* out_ice_ufrag_len);
+WEBRTC_EXPORT void webrtc_TransportDescription_get_ice_pwd(
+    const struct webrtc_TransportDescription* self,
+    const char** out_ice_pwd,
+    size_t* out_ice_pwd_len);
+WEBRTC_EXPORT webrtc_ConnectionRole
+webrtc_TransportDescription_get_connection_role(
+    const struct webrtc_TransportDescription* self);
+// a=fingerprint が無い場合は null を返す
+WEBRTC_EXPORT const struct webrtc_SSLFingerprint*
+webrtc_TransportDescription_get_identity_fingerprint(
+    const struct webrtc_TransportDescription* self);
+
+#if defined(__cplusplus)
+}
+#endif
@@ -0,0 +1,40 @@
+#include "basic_port_allocator.h"
+
+#include <assert.h>
+#include <memory>
+
+// WebRTC
+#include <api/environment/environment.h>
+#include <api/packet_socket_factory.h>
+#include <p2p/base/port_allocator.h>
+#include <p2p/client/basic_port_allocator.h>
+#include <rtc_base/network.h>
+
+#include "../../common.h"
+#include "../../common.impl.h"
+
+// -------------------------
+// webrtc::PortAllocator
+// -------------------------
+
+extern "C" {
+
+WEBRTC_DEFINE_UNIQUE(webrtc_PortAllocator, webrtc::PortAllocator);
+
+// -------------------------
+// webrtc::BasicPortAllocator
+// -------------------------
+
+WEBRTC_EXPORT struct webrtc_PortAllocator_unique* webrtc_BasicPortAllocator_new(
+    struct webrtc_Environment* env,
+    struct webrtc_NetworkManager* network_manager,
+    struct webrtc_PacketSocketFactory* socket_factory) {
+  assert(env != nullptr);
+  auto allocator = std::make_unique<webrtc::BasicPortAllocator>(
+      *reinterpret_cast<webrtc::Environment*>(env),
+      reinterpret_cast<webrtc::NetworkManager*>(network_manager),
+      reinterpret_cast<webrtc::PacketSocketFactory*>(socket_factory));
+  return reinterpret_cast<struct webrtc_PortAllocator_unique*>(
+      static_cast<webrtc::PortAllocator*>(allocator.release()));
+}
+}
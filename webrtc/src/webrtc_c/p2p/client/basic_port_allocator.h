@@ -0,0 +1,32 @@
+#pragma once
+
+#include "../../common.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::PortAllocator
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_PortAllocator);
+
+// -------------------------
+// webrtc::BasicPortAllocator
+// -------------------------
+
+struct webrtc_Environment;
+struct webrtc_NetworkManager;
+struct webrtc_PacketSocketFactory;
+
+// env はコピーされる。network_manager と socket_factory の所有権は移らないため、
+// 戻り値より長く生存させること。
+WEBRTC_EXPORT struct webrtc_PortAllocator_unique* webrtc_BasicPortAllocator_new(
+    struct webrtc_Environment* env,
+    struct webrtc_NetworkManager* network_manager,
+    struct webrtc_PacketSocketFactory* socket_factory);
+
+#if defined(__cplusplus)
+}
+#endif
@@ -0,0 +1,157 @@
+#include "session_description.h"
+
+#include <stddef.h>
+#include <cassert>
+
+// WebRTC
+#include <absl/strings/string_view.h>
+#include <api/media_types.h>
+#include <api/rtp_parameters.h>
+#include <api/rtp_transceiver_direction.h>
+#include <media/base/codec.h>
+#include <media/base/stream_params.h>
+#include <p2p/base/transport_description.h>
+#include <pc/session_description.h>
+#include <pc/simulcast_description.h>
+
+#include "../api/rtp_parameters.h"
+#include "../common.h"
+#include "../media/base/codec.h"
+#include "../media/base/stream_params.h"
+#include "../p2p/base/transport_description.h"
+#include "simulcast_description.h"
+
+// -------------------------
+// webrtc::MediaContentDescription
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT int webrtc_MediaContentDescription_type(
+    const struct webrtc_MediaContentDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::MediaContentDescription*>(self);
+  return static_cast<int>(desc->type());
+}
+WEBRTC_EXPORT int webrtc_MediaContentDescription_direction(
+    const struct webrtc_MediaContentDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::MediaContentDescription*>(self);
+  return static_cast<int>(desc->direction());
+}
+WEBRTC_EXPORT int webrtc_MediaContentDescription_rtcp_mux(
+    const struct webrtc_MediaContentDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::MediaContentDescription*>(self);
+  return desc->rtcp_mux() ? 1 : 0;
+}
+WEBRTC_EXPORT size_t webrtc_MediaContentDescription_get_codecs_size(
+    const struct webrtc_MediaContentDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::MediaContentDescription*>(self);
+  return desc->codecs().size();
+}
+WEBRTC_EXPORT const struct webrtc_Codec*
+webrtc_MediaContentDescription_get_codec(
+    const struct webrtc_MediaContentDescription* self,
+    size_t index) {
+  auto desc = reinterpret_cast<const webrtc::MediaContentDescription*>(self);
+  assert(index < desc->codecs().size());
+  return reinterpret_cast<const struct webrtc_Codec*>(&desc->codecs()[index]);
+}
+WEBRTC_EXPORT size_t
+webrtc_MediaContentDescription_get_rtp_header_extensions_size(
+    const struct webrtc_MediaContentDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::MediaContentDescription*>(self);
+  return desc->rtp_header_extensions().size();
+}
+WEBRTC_EXPORT const struct webrtc_RtpExtension*
+webrtc_MediaContentDescription_get_rtp_header_extension(
+    const struct webrtc_MediaContentDescription* self,
+    size_t index) {
+  auto desc = reinterpret_cast<const webrtc::MediaContentDescription*>(self);
+  const auto& extensions = desc->rtp_header_extensions();
+  assert(index < extensions.size());
+  return reinterpret_cast<const struct webrtc_RtpExtension*>(
+      &extensions[index]);
+}
+WEBRTC_EXPORT size_t webrtc_MediaContentDescription_get_streams_size(
+    const struct webrtc_MediaContentDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::MediaContentDescription*>(self);
+  return desc->streams().size();
+}
+WEBRTC_EXPORT const struct webrtc_StreamParams*
+webrtc_MediaContentDescription_get_stream(
+    const struct webrtc_MediaContentDescription* self,
+    size_t index) {
+  auto desc = reinterpret_cast<const webrtc::MediaContentDescription*>(self);
+  assert(index < desc->streams().size());
+  return reinterpret_cast<const struct webrtc_StreamParams*>(
+      &desc->streams()[index]);
+}
+WEBRTC_EXPORT const struct webrtc_SimulcastDescription*
+webrtc_MediaContentDescription_simulcast_description(
+    const struct webrtc_MediaContentDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::MediaContentDescription*>(self);
+  return reinterpret_cast<const struct webrtc_SimulcastDescription*>(
+      &desc->simulcast_description());
+}
+}
+
+// -------------------------
+// webrtc::ContentInfo
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT void webrtc_ContentInfo_mid(const struct webrtc_ContentInfo* self,
+                                          const char** out_mid,
+                                          size_t* out_mid_len) {
+  assert(out_mid != nullptr);
+  assert(out_mid_len != nullptr);
+  auto content = reinterpret_cast<const webrtc::ContentInfo*>(self);
+  const auto& mid = content->mid();
+  *out_mid = mid.data();
+  *out_mid_len = mid.size();
+}
+WEBRTC_EXPORT int webrtc_ContentInfo_get_rejected(
+    const struct webrtc_ContentInfo* self) {
+  auto content = reinterpret_cast<const webrtc::ContentInfo*>(self);
+  return content->rejected ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_ContentInfo_get_bundle_only(
+    const struct webrtc_ContentInfo* self) {
+  auto content = reinterpret_cast<const webrtc::ContentInfo*>(self);
+  return content->bundle_only ? 1 : 0;
+}
+WEBRTC_EXPORT const struct webrtc_MediaContentDescription*
+webrtc_ContentInfo_media_description(const struct webrtc_ContentInfo* self) {
+  auto content = reinterpret_cast<const webrtc::ContentInfo*>(self);
+  return reinterpret_cast<const struct webrtc_MediaContentDescription*>(
+      content->media_description());
+}
+}
+
+// -------------------------
+// webrtc::SessionDescription
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT size_t webrtc_SessionDescription_get_contents_size(
+    const struct webrtc_SessionDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::SessionDescription*>(self);
+  return desc->contents().size();
+}
+WEBRTC_EXPORT const struct webrtc_ContentInfo*
+webrtc_SessionDescription_get_content(
+    const struct webrtc_SessionDescription* self,
+    size_t index) {
+  auto desc = reinterpret_cast<const webrtc::SessionDescription*>(self);
+  assert(index < desc->contents().size());
+  return reinterpret_cast<const struct webrtc_ContentInfo*>(
+      &desc->contents()[index]);
+}
+WEBRTC_EXPORT const struct webrtc_TransportDescription*
+webrtc_SessionDescription_GetTransportDescriptionByName(
+    const struct webrtc_SessionDescription* self,
+    const char* name,
+    size_t name_len) {
+  auto desc = reinterpret_cast<const webrtc::SessionDescription*>(self);
+  return reinterpret_cast<const struct webrtc_TransportDescription*>(
+      desc->GetTransportDescriptionByName(absl::string_view(name, name_len)));
+}
+}
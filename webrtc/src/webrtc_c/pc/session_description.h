@@ -0,0 +1,88 @@
+#pragma once
+
+#include <stddef.h>
+
+#include "../api/media_types.h"
+#include "../api/rtp_parameters.h"
+#include "../common.h"
+#include "../media/base/codec.h"
+#include "../media/base/stream_params.h"
+#include "../p2p/base/transport_description.h"
+#include "simulcast_description.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::MediaContentDescription
+// -------------------------
+
+struct webrtc_MediaContentDescription;
+// webrtc_MediaType_* のいずれか
+WEBRTC_EXPORT int webrtc_MediaContentDescription_type(
+    const struct webrtc_MediaContentDescription* self);
+// webrtc_RtpTransceiverDirection_* のいずれか
+WEBRTC_EXPORT int webrtc_MediaContentDescription_direction(
+    const struct webrtc_MediaContentDescription* self);
+WEBRTC_EXPORT int webrtc_MediaContentDescription_rtcp_mux(
+    const struct webrtc_MediaContentDescription* self);
+WEBRTC_EXPORT size_t webrtc_MediaContentDescription_get_codecs_size(
+    const struct webrtc_MediaContentDescription* self);
+WEBRTC_EXPORT const struct webrtc_Codec*
+webrtc_MediaContentDescription_get_codec(
+    const struct webrtc_MediaContentDescription* self,
+    size_t index);
+WEBRTC_EXPORT size_t
+webrtc_MediaContentDescription_get_rtp_header_extensions_size(
+    const struct webrtc_MediaContentDescription* self);
+WEBRTC_EXPORT const struct webrtc_RtpExtension*
+webrtc_MediaContentDescription_get_rtp_header_extension(
+    const struct webrtc_MediaContentDescription* self,
+    size_t index);
+WEBRTC_EXPORT size_t webrtc_MediaContentDescription_get_streams_size(
+    const struct webrtc_MediaContentDescription* self);
+WEBRTC_EXPORT const struct webrtc_StreamParams*
+webrtc_MediaContentDescription_get_stream(
+    const struct webrtc_MediaContentDescription* self,
+    size_t index);
+WEBRTC_EXPORT const struct webrtc_SimulcastDescription*
+webrtc_MediaContentDescription_simulcast_description(
+    const struct webrtc_MediaContentDescription* self);
+
+// -------------------------
+// webrtc::ContentInfo
+// -------------------------
+
+struct webrtc_ContentInfo;
+WEBRTC_EXPORT void webrtc_ContentInfo_mid(const struct webrtc_ContentInfo* self,
+                                          const char** out_mid,
+                                          size_t* out_mid_len);
+WEBRTC_EXPORT int webrtc_ContentInfo_get_rejected(
+    const struct webrtc_ContentInfo* self);
+WEBRTC_EXPORT int webrtc_ContentInfo_get_bundle_only(
+    const struct webrtc_ContentInfo* self);
+WEBRTC_EXPORT const struct webrtc_MediaContentDescription*
+webrtc_ContentInfo_media_description(const struct webrtc_ContentInfo* self);
+
+// -------------------------
+// webrtc::SessionDescription
+// -------------------------
+
+struct webrtc_SessionDescription;
+WEBRTC_EXPORT size_t webrtc_SessionDescription_get_contents_size(
+    const struct webrtc_SessionDescription* self);
+WEBRTC_EXPORT const struct webrtc_ContentInfo*
+webrtc_SessionDescription_get_content(
+    const struct webrtc_SessionDescription* self,
+    size_t index);
+// 該当する mid が無い場合は null を返す
+WEBRTC_EXPORT const struct webrtc_TransportDescription*
+webrtc_SessionDescription_GetTransportDescriptionByName(
+    const struct webrtc_SessionDescription* self,
+    const char* name,
+    size_t name_len);
+
+#if defined(__cplusplus)
+}
+#endif
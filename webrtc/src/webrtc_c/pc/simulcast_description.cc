@@ -0,0 +1,88 @@
+#include "simulcast_description.h"
+
+#include <stddef.h>
+#include <cassert>
+
+// WebRTC
+#include <pc/simulcast_description.h>
+
+#include "../common.h"
+
+// -------------------------
+// webrtc::SimulcastLayer
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT void webrtc_SimulcastLayer_get_rid(
+    const struct webrtc_SimulcastLayer* self,
+    const char** out_rid,
+    size_t* out_rid_len) {
+  assert(out_rid != nullptr);
+  assert(out_rid_len != nullptr);
+  auto layer = reinterpret_cast<const webrtc::SimulcastLayer*>(self);
+  *out_rid = layer->rid.data();
+  *out_rid_len = layer->rid.size();
+}
+WEBRTC_EXPORT int webrtc_SimulcastLayer_get_is_paused(
+    const struct webrtc_SimulcastLayer* self) {
+  auto layer = reinterpret_cast<const webrtc::SimulcastLayer*>(self);
+  return layer->is_paused ? 1 : 0;
+}
+}
+
+// -------------------------
+// webrtc::SimulcastLayerList
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT size_t
+webrtc_SimulcastLayerList_size(const struct webrtc_SimulcastLayerList* self) {
+  auto list = reinterpret_cast<const webrtc::SimulcastLayerList*>(self);
+  return list->size();
+}
+WEBRTC_EXPORT size_t webrtc_SimulcastLayerList_get_alternatives_size(
+    const struct webrtc_SimulcastLayerList* self,
+    size_t index) {
+  auto list = reinterpret_cast<const webrtc::SimulcastLayerList*>(self);
+  assert(index < list->size());
+  return (*list)[index].size();
+}
+WEBRTC_EXPORT const struct webrtc_SimulcastLayer*
+webrtc_SimulcastLayerList_get_alternative(
+    const struct webrtc_SimulcastLayerList* self,
+    size_t index,
+    size_t alternative_index) {
+  auto list = reinterpret_cast<const webrtc::SimulcastLayerList*>(self);
+  assert(index < list->size());
+  const auto& alternatives = (*list)[index];
+  assert(alternative_index < alternatives.size());
+  return reinterpret_cast<const struct webrtc_SimulcastLayer*>(
+      &alternatives[alternative_index]);
+}
+}
+
+// -------------------------
+// webrtc::SimulcastDescription
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT const struct webrtc_SimulcastLayerList*
+webrtc_SimulcastDescription_send_layers(
+    const struct webrtc_SimulcastDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::SimulcastDescription*>(self);
+  return reinterpret_cast<const struct webrtc_SimulcastLayerList*>(
+      &desc->send_layers());
+}
+WEBRTC_EXPORT const struct webrtc_SimulcastLayerList*
+webrtc_SimulcastDescription_receive_layers(
+    const struct webrtc_SimulcastDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::SimulcastDescription*>(self);
+  return reinterpret_cast<const struct webrtc_SimulcastLayerList*>(
+      &desc->receive_layers());
+}
+WEBRTC_EXPORT int webrtc_SimulcastDescription_empty(
+    const struct webrtc_SimulcastDescription* self) {
+  auto desc = reinterpret_cast<const webrtc::SimulcastDescription*>(self);
+  return desc->empty() ? 1 : 0;
+}
+}
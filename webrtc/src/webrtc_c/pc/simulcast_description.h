@@ -0,0 +1,56 @@
+#pragma once
+
+#include <stddef.h>
+
+#include "../common.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::SimulcastLayer
+// -------------------------
+
+struct webrtc_SimulcastLayer;
+WEBRTC_EXPORT void webrtc_SimulcastLayer_get_rid(
+    const struct webrtc_SimulcastLayer* self,
+    const char** out_rid,
+    size_t* out_rid_len);
+WEBRTC_EXPORT int webrtc_SimulcastLayer_get_is_paused(
+    const struct webrtc_SimulcastLayer* self);
+
+// -------------------------
+// webrtc::SimulcastLayerList
+// -------------------------
+
+struct webrtc_SimulcastLayerList;
+WEBRTC_EXPORT size_t
+webrtc_SimulcastLayerList_size(const struct webrtc_SimulcastLayerList* self);
+// index 番目のレイヤーの代替 (a=simulcast の "," 区切り) の数を返す。
+WEBRTC_EXPORT size_t webrtc_SimulcastLayerList_get_alternatives_size(
+    const struct webrtc_SimulcastLayerList* self,
+    size_t index);
+WEBRTC_EXPORT const struct webrtc_SimulcastLayer*
+webrtc_SimulcastLayerList_get_alternative(
+    const struct webrtc_SimulcastLayerList* self,
+    size_t index,
+    size_t alternative_index);
+
+// -------------------------
+// webrtc::SimulcastDescription
+// -------------------------
+
+struct webrtc_SimulcastDescription;
+WEBRTC_EXPORT const struct webrtc_SimulcastLayerList*
+webrtc_SimulcastDescription_send_layers(
+    const struct webrtc_SimulcastDescription* self);
+WEBRTC_EXPORT const struct webrtc_SimulcastLayerList*
+webrtc_SimulcastDescription_receive_layers(
+    const struct webrtc_SimulcastDescription* self);
+WEBRTC_EXPORT int webrtc_SimulcastDescription_empty(
+    const struct webrtc_SimulcastDescription* self);
+
+#if defined(__cplusplus)
+}
+#endif
@@ -0,0 +1,214 @@
+#include "async_packet_socket.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <memory>
+#include <optional>
+
+// WebRTC
+#include <api/array_view.h>
+#include <api/units/timestamp.h>
+#include <rtc_base/async_packet_socket.h>
+#include <rtc_base/network/received_packet.h>
+#include <rtc_base/network/sent_packet.h>
+#include <rtc_base/socket.h>
+#include <rtc_base/socket_address.h>
+#include <rtc_base/time_utils.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+
+// -------------------------
+// webrtc::AsyncPacketSocket
+// -------------------------
+
+namespace {
+
+class AsyncPacketSocketImpl : public webrtc::AsyncPacketSocket {
+ public:
+  AsyncPacketSocketImpl(const struct webrtc_AsyncPacketSocket_cbs* cbs,
+                        void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->GetLocalAddress != nullptr);
+    assert(cbs->GetRemoteAddress != nullptr);
+    assert(cbs->Send != nullptr);
+    assert(cbs->SendTo != nullptr);
+    assert(cbs->Close != nullptr);
+    assert(cbs->GetState != nullptr);
+    assert(cbs->GetOption != nullptr);
+    assert(cbs->SetOption != nullptr);
+    assert(cbs->GetError != nullptr);
+    assert(cbs->SetError != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~AsyncPacketSocketImpl() override { cbs_.OnDestroy(user_data_); }
+
+  webrtc::SocketAddress GetLocalAddress() const override {
+    return TakeAddress(cbs_.GetLocalAddress(user_data_));
+  }
+
+  webrtc::SocketAddress GetRemoteAddress() const override {
+    return TakeAddress(cbs_.GetRemoteAddress(user_data_));
+  }
+
+  int Send(const void* pv,
+           size_t cb,
+           const webrtc::AsyncSocketPacketOptions& options) override {
+    int ret = cbs_.Send(static_cast<const uint8_t*>(pv), cb, options.packet_id,
+                        user_data_);
+    NotifySent(ret, cb, options);
+    return ret;
+  }
+
+  int SendTo(const void* pv,
+             size_t cb,
+             const webrtc::SocketAddress& addr,
+             const webrtc::AsyncSocketPacketOptions& options) override {
+    int ret = cbs_.SendTo(
+        static_cast<const uint8_t*>(pv), cb,
+        reinterpret_cast<const struct webrtc_SocketAddress*>(&addr),
+        options.packet_id, user_data_);
+    NotifySent(ret, cb, options);
+    return ret;
+  }
+
+  int Close() override { return cbs_.Close(user_data_); }
+
+  State GetState() const override {
+    return static_cast<State>(cbs_.GetState(user_data_));
+  }
+
+  int GetOption(webrtc::Socket::Option opt, int* value) override {
+    return cbs_.GetOption(static_cast<int>(opt), value, user_data_);
+  }
+
+  int SetOption(webrtc::Socket::Option opt, int value) override {
+    return cbs_.SetOption(static_cast<int>(opt), value, user_data_);
+  }
+
+  int GetError() const override { return cbs_.GetError(user_data_); }
+
+  void SetError(int error) override { cbs_.SetError(error, user_data_); }
+
+  void OnPacketReceived(const uint8_t* data,
+                        size_t size,
+                        const webrtc::SocketAddress& source_address,
+                        std::optional<webrtc::Timestamp> arrival_time) {
+    NotifyPacketReceived(webrtc::ReceivedIpPacket(
+        webrtc::ArrayView<const uint8_t>(data, size), source_address,
+        arrival_time));
+  }
+
+  void OnReadyToSend() { SignalReadyToSend(this); }
+
+  void OnClosed(int error) { NotifyClosed(error); }
+
+ private:
+  static webrtc::SocketAddress TakeAddress(struct webrtc_SocketAddress* p) {
+    std::unique_ptr<webrtc::SocketAddress> address(
+        reinterpret_cast<webrtc::SocketAddress*>(p));
+    return address ? *address : webrtc::SocketAddress();
+  }
+
+  void NotifySent(int ret,
+                  size_t size,
+                  const webrtc::AsyncSocketPacketOptions& options) {
+    if (ret < 0) {
+      return;
+    }
+    webrtc::SentPacketInfo sent_packet(options.packet_id, webrtc::TimeMillis(),
+                                       options.info_signaled_after_sent);
+    webrtc::CopySocketInformationToPacketInfo(size, *this, &sent_packet.info);
+    SignalSentPacket(this, sent_packet);
+  }
+
+  webrtc_AsyncPacketSocket_cbs cbs_{};
+  void* user_data_;
+};
+
+AsyncPacketSocketImpl* AsImpl(struct webrtc_AsyncPacketSocket* self) {
+  return static_cast<AsyncPacketSocketImpl*>(
+      reinterpret_cast<webrtc::AsyncPacketSocket*>(self));
+}
+
+}  // namespace
+
+extern "C" {
+
+// -------------------------
+// webrtc::Socket::Option
+// -------------------------
+
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptDontFragment =
+    static_cast<int>(webrtc::Socket::OPT_DONTFRAGMENT);
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptRcvBuf =
+    static_cast<int>(webrtc::Socket::OPT_RCVBUF);
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptSndBuf =
+    static_cast<int>(webrtc::Socket::OPT_SNDBUF);
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptNoDelay =
+    static_cast<int>(webrtc::Socket::OPT_NODELAY);
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptIpv6V6Only =
+    static_cast<int>(webrtc::Socket::OPT_IPV6_V6ONLY);
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptDscp =
+    static_cast<int>(webrtc::Socket::OPT_DSCP);
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptRtpSendtimeExtnId =
+    static_cast<int>(webrtc::Socket::OPT_RTP_SENDTIME_EXTN_ID);
+
+// -------------------------
+// webrtc::AsyncPacketSocket
+// -------------------------
+
+WEBRTC_DEFINE_UNIQUE(webrtc_AsyncPacketSocket, webrtc::AsyncPacketSocket);
+
+WEBRTC_EXPORT extern const int webrtc_AsyncPacketSocket_State_kClosed =
+    static_cast<int>(webrtc::AsyncPacketSocket::STATE_CLOSED);
+WEBRTC_EXPORT extern const int webrtc_AsyncPacketSocket_State_kBinding =
+    static_cast<int>(webrtc::AsyncPacketSocket::STATE_BINDING);
+WEBRTC_EXPORT extern const int webrtc_AsyncPacketSocket_State_kBound =
+    static_cast<int>(webrtc::AsyncPacketSocket::STATE_BOUND);
+WEBRTC_EXPORT extern const int webrtc_AsyncPacketSocket_State_kConnecting =
+    static_cast<int>(webrtc::AsyncPacketSocket::STATE_CONNECTING);
+WEBRTC_EXPORT extern const int webrtc_AsyncPacketSocket_State_kConnected =
+    static_cast<int>(webrtc::AsyncPacketSocket::STATE_CONNECTED);
+
+WEBRTC_EXPORT struct webrtc_AsyncPacketSocket_unique*
+webrtc_AsyncPacketSocket_new(const struct webrtc_AsyncPacketSocket_cbs* cbs,
+                             void* user_data) {
+  auto socket = std::make_unique<AsyncPacketSocketImpl>(cbs, user_data);
+  return reinterpret_cast<struct webrtc_AsyncPacketSocket_unique*>(
+      static_cast<webrtc::AsyncPacketSocket*>(socket.release()));
+}
+
+WEBRTC_EXPORT void webrtc_AsyncPacketSocket_NotifyPacketReceived(
+    struct webrtc_AsyncPacketSocket* self,
+    const uint8_t* data,
+    size_t size,
+    const struct webrtc_SocketAddress* source_address,
+    int has_arrival_time_us,
+    const int64_t* arrival_time_us) {
+  assert(data != nullptr || size == 0);
+  assert(source_address != nullptr);
+  std::optional<webrtc::Timestamp> arrival_time;
+  if (has_arrival_time_us != 0) {
+    assert(arrival_time_us != nullptr);
+    arrival_time = webrtc::Timestamp::Micros(*arrival_time_us);
+  }
+  AsImpl(self)->OnPacketReceived(
+      data, size,
+      *reinterpret_cast<const webrtc::SocketAddress*>(source_address),
+      arrival_time);
+}
+WEBRTC_EXPORT void webrtc_AsyncPacketSocket_NotifyReadyToSend(
+    struct webrtc_AsyncPacketSocket* self) {
+  AsImpl(self)->OnReadyToSend();
+}
+WEBRTC_EXPORT void webrtc_AsyncPacketSocket_NotifyClosed(
+    struct webrtc_AsyncPacketSocket* self,
+    int error) {
+  AsImpl(self)->OnClosed(error);
+}
+}
@@ -0,0 +1,87 @@
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#include "../common.h"
+#include "socket_address.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::Socket::Option
+// -------------------------
+
+typedef int webrtc_Socket_Option;
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptDontFragment;
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptRcvBuf;
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptSndBuf;
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptNoDelay;
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptIpv6V6Only;
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptDscp;
+WEBRTC_EXPORT extern const int webrtc_Socket_Option_kOptRtpSendtimeExtnId;
+
+// -------------------------
+// webrtc::AsyncPacketSocket
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_AsyncPacketSocket);
+
+typedef int webrtc_AsyncPacketSocket_State;
+WEBRTC_EXPORT extern const int webrtc_AsyncPacketSocket_State_kClosed;
+WEBRTC_EXPORT extern const int webrtc_AsyncPacketSocket_State_kBinding;
+WEBRTC_EXPORT extern const int webrtc_AsyncPacketSocket_State_kBound;
+WEBRTC_EXPORT extern const int webrtc_AsyncPacketSocket_State_kConnecting;
+WEBRTC_EXPORT extern const int webrtc_AsyncPacketSocket_State_kConnected;
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// コールバックは network thread から呼ばれる。
+// Send / SendTo が 0 以上を返した場合は SignalSentPacket を通知する。
+struct webrtc_AsyncPacketSocket_cbs {
+  // 戻り値の所有権は呼び出し側へ移る。null は返さないこと。
+  struct webrtc_SocketAddress* (*GetLocalAddress)(void* user_data);
+  // 戻り値の所有権は呼び出し側へ移る。未接続の場合は null を返す。
+  struct webrtc_SocketAddress* (*GetRemoteAddress)(void* user_data);
+  int (*Send)(const uint8_t* data,
+              size_t size,
+              int64_t packet_id,
+              void* user_data);
+  int (*SendTo)(const uint8_t* data,
+                size_t size,
+                const struct webrtc_SocketAddress* address,
+                int64_t packet_id,
+                void* user_data);
+  int (*Close)(void* user_data);
+  webrtc_AsyncPacketSocket_State (*GetState)(void* user_data);
+  int (*GetOption)(webrtc_Socket_Option opt, int* value, void* user_data);
+  int (*SetOption)(webrtc_Socket_Option opt, int value, void* user_data);
+  int (*GetError)(void* user_data);
+  void (*SetError)(int error, void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_AsyncPacketSocket_unique*
+webrtc_AsyncPacketSocket_new(const struct webrtc_AsyncPacketSocket_cbs* cbs,
+                             void* user_data);
+
+// 以下は webrtc_AsyncPacketSocket_new で生成したソケットに対して
+// network thread から呼び出すこと。
+WEBRTC_EXPORT void webrtc_AsyncPacketSocket_NotifyPacketReceived(
+    struct webrtc_AsyncPacketSocket* self,
+    const uint8_t* data,
+    size_t size,
+    const struct webrtc_SocketAddress* source_address,
+    int has_arrival_time_us,
+    const int64_t* arrival_time_us);
+WEBRTC_EXPORT void webrtc_AsyncPacketSocket_NotifyReadyToSend(
+    struct webrtc_AsyncPacketSocket* self);
+WEBRTC_EXPORT void webrtc_AsyncPacketSocket_NotifyClosed(
+    struct webrtc_AsyncPacketSocket* self,
+    int error);
+
+#if defined(__cplusplus)
+}
+#endif
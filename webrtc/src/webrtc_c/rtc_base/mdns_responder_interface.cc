@@ -0,0 +1,87 @@
+#include "mdns_responder_interface.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <memory>
+#include <string>
+#include <utility>
+
+// WebRTC
+#include <api/task_queue/pending_task_safety_flag.h>
+#include <api/task_queue/task_queue_base.h>
+#include <rtc_base/ip_address.h>
+#include <rtc_base/mdns_responder_interface.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+#include "../std.h"
+
+// -------------------------
+// webrtc::MdnsResponderInterface
+// -------------------------
+
+namespace {
+
+class MdnsResponderImpl : public webrtc::MdnsResponderInterface {
+ public:
+  MdnsResponderImpl(const struct webrtc_MdnsResponderInterface_cbs* cbs,
+                    void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->CreateNameForAddress != nullptr);
+    assert(cbs->RemoveNameForAddress != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~MdnsResponderImpl() override { cbs_.OnDestroy(user_data_); }
+
+  // 実際の mDNS レスポンダと同様に、結果は非同期で通知する。
+  void CreateNameForAddress(const webrtc::IPAddress& addr,
+                            NameCreatedCallback callback) override {
+    const std::string address = addr.ToString();
+    std::unique_ptr<std::string> name(reinterpret_cast<std::string*>(
+        cbs_.CreateNameForAddress(address.data(), address.size(),
+                                  user_data_)));
+    assert(name != nullptr);
+    webrtc::TaskQueueBase::Current()->PostTask(webrtc::SafeTask(
+        safety_.flag(), [addr, name = std::move(*name),
+                         callback = std::move(callback)]() mutable {
+          callback(addr, name);
+        }));
+  }
+
+  void RemoveNameForAddress(const webrtc::IPAddress& addr,
+                            NameRemovedCallback callback) override {
+    const std::string address = addr.ToString();
+    bool removed = cbs_.RemoveNameForAddress(address.data(), address.size(),
+                                             user_data_) != 0;
+    webrtc::TaskQueueBase::Current()->PostTask(webrtc::SafeTask(
+        safety_.flag(),
+        [removed, callback = std::move(callback)]() mutable {
+          callback(removed);
+        }));
+  }
+
+ private:
+  webrtc_MdnsResponderInterface_cbs cbs_{};
+  void* user_data_;
+  webrtc::ScopedTaskSafety safety_;
+};
+
+}  // namespace
+
+extern "C" {
+
+WEBRTC_DEFINE_UNIQUE(webrtc_MdnsResponderInterface,
+                     webrtc::MdnsResponderInterface);
+
+WEBRTC_EXPORT struct webrtc_MdnsResponderInterface_unique*
+webrtc_MdnsResponderInterface_new(
+    const struct webrtc_MdnsResponderInterface_cbs* cbs,
+    void* user_data) {
+  auto responder = std::make_unique<MdnsResponderImpl>(cbs, user_data);
+  return reinterpret_cast<struct webrtc_MdnsResponderInterface_unique*>(
+      static_cast<webrtc::MdnsResponderInterface*>(responder.release()));
+}
+}
@@ -0,0 +1,40 @@
+#pragma once
+
+#include <stddef.h>
+
+#include "../common.h"
+#include "../std.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::MdnsResponderInterface
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_MdnsResponderInterface);
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// コールバックは network thread から呼ばれる。address は IP アドレス文字列。
+struct webrtc_MdnsResponderInterface_cbs {
+  // address に割り当てる ".local" で終わる名前を返す。null は返さないこと。
+  struct std_string_unique* (*CreateNameForAddress)(const char* address,
+                                                    size_t address_len,
+                                                    void* user_data);
+  // 名前を削除できた場合は 1 を返す。
+  int (*RemoveNameForAddress)(const char* address,
+                              size_t address_len,
+                              void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_MdnsResponderInterface_unique*
+webrtc_MdnsResponderInterface_new(
+    const struct webrtc_MdnsResponderInterface_cbs* cbs,
+    void* user_data);
+
+#if defined(__cplusplus)
+}
+#endif
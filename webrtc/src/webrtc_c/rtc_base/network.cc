@@ -0,0 +1,215 @@
+#include "network.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <memory>
+#include <string>
+#include <utility>
+#include <vector>
+
+// WebRTC
+#include <absl/strings/string_view.h>
+#include <api/environment/environment.h>
+#include <api/task_queue/pending_task_safety_flag.h>
+#include <rtc_base/ip_address.h>
+#include <rtc_base/mdns_responder_interface.h>
+#include <rtc_base/network.h>
+#include <rtc_base/network_constants.h>
+#include <rtc_base/socket_factory.h>
+#include <rtc_base/thread.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+
+// -------------------------
+// webrtc::NetworkManager
+// -------------------------
+
+namespace {
+
+class NetworkManagerImpl : public webrtc::NetworkManagerBase {
+ public:
+  NetworkManagerImpl(const struct webrtc_NetworkManager_cbs* cbs,
+                     void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->StartUpdating != nullptr);
+    assert(cbs->StopUpdating != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~NetworkManagerImpl() override { cbs_.OnDestroy(user_data_); }
+
+  void StartUpdating() override {
+    cbs_.StartUpdating(reinterpret_cast<struct webrtc_NetworkManager*>(
+                           static_cast<webrtc::NetworkManager*>(this)),
+                       user_data_);
+    // BasicNetworkManager と同様に、StartUpdating の呼び出し元へ再入しないよう
+    // 非同期で通知する。
+    webrtc::Thread::Current()->PostTask(webrtc::SafeTask(
+        safety_.flag(), [this]() { SignalNetworksChanged(); }));
+  }
+
+  void StopUpdating() override { cbs_.StopUpdating(user_data_); }
+
+  webrtc::MdnsResponderInterface* GetMdnsResponder() const override {
+    return mdns_responder_.get();
+  }
+
+  void set_mdns_responder(
+      std::unique_ptr<webrtc::MdnsResponderInterface> mdns_responder) {
+    mdns_responder_ = std::move(mdns_responder);
+  }
+
+  void UpdateNetworks(std::vector<std::unique_ptr<webrtc::Network>> networks) {
+    bool changed = false;
+    MergeNetworkList(std::move(networks), &changed);
+  }
+
+ private:
+  webrtc_NetworkManager_cbs cbs_{};
+  void* user_data_;
+  std::unique_ptr<webrtc::MdnsResponderInterface> mdns_responder_;
+  webrtc::ScopedTaskSafety safety_;
+};
+
+class BasicNetworkManagerImpl : public webrtc::BasicNetworkManager {
+ public:
+  BasicNetworkManagerImpl(
+      const webrtc::Environment& env,
+      webrtc::SocketFactory* socket_factory,
+      std::unique_ptr<webrtc::MdnsResponderInterface> mdns_responder)
+      : webrtc::BasicNetworkManager(env, socket_factory),
+        mdns_responder_(std::move(mdns_responder)) {}
+
+  webrtc::MdnsResponderInterface* GetMdnsResponder() const override {
+    return mdns_responder_.get();
+  }
+
+ private:
+  std::unique_ptr<webrtc::MdnsResponderInterface> mdns_responder_;
+};
+
+}  // namespace
+
+extern "C" {
+
+// -------------------------
+// webrtc::AdapterType
+// -------------------------
+
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kUnknown =
+    static_cast<int>(webrtc::ADAPTER_TYPE_UNKNOWN);
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kEthernet =
+    static_cast<int>(webrtc::ADAPTER_TYPE_ETHERNET);
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kWifi =
+    static_cast<int>(webrtc::ADAPTER_TYPE_WIFI);
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kCellular =
+    static_cast<int>(webrtc::ADAPTER_TYPE_CELLULAR);
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kVpn =
+    static_cast<int>(webrtc::ADAPTER_TYPE_VPN);
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kLoopback =
+    static_cast<int>(webrtc::ADAPTER_TYPE_LOOPBACK);
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kAny =
+    static_cast<int>(webrtc::ADAPTER_TYPE_ANY);
+
+// -------------------------
+// webrtc::Network
+// -------------------------
+
+WEBRTC_DEFINE_UNIQUE(webrtc_Network, webrtc::Network);
+
+WEBRTC_EXPORT struct webrtc_Network_unique* webrtc_Network_new(
+    const char* name,
+    size_t name_len,
+    const char* description,
+    size_t description_len,
+    const char* prefix,
+    size_t prefix_len,
+    int prefix_length,
+    webrtc_AdapterType type) {
+  assert(name != nullptr || name_len == 0);
+  assert(description != nullptr || description_len == 0);
+  assert(prefix != nullptr);
+  webrtc::IPAddress ip;
+  if (!webrtc::IPFromString(absl::string_view(prefix, prefix_len), &ip)) {
+    return nullptr;
+  }
+  auto network = std::make_unique<webrtc::Network>(
+      absl::string_view(name, name_len),
+      absl::string_view(description, description_len), ip, prefix_length,
+      static_cast<webrtc::AdapterType>(type));
+  return reinterpret_cast<struct webrtc_Network_unique*>(network.release());
+}
+WEBRTC_EXPORT int webrtc_Network_AddIP(struct webrtc_Network* self,
+                                       const char* ip,
+                                       size_t ip_len) {
+  assert(ip != nullptr);
+  auto network = reinterpret_cast<webrtc::Network*>(self);
+  webrtc::IPAddress address;
+  if (!webrtc::IPFromString(absl::string_view(ip, ip_len), &address)) {
+    return 0;
+  }
+  network->AddIP(address);
+  return 1;
+}
+
+// -------------------------
+// webrtc::NetworkManager
+// -------------------------
+
+WEBRTC_DEFINE_UNIQUE(webrtc_NetworkManager, webrtc::NetworkManager);
+
+WEBRTC_EXPORT struct webrtc_NetworkManager_unique* webrtc_NetworkManager_new(
+    const struct webrtc_NetworkManager_cbs* cbs,
+    void* user_data) {
+  auto manager = std::make_unique<NetworkManagerImpl>(cbs, user_data);
+  return reinterpret_cast<struct webrtc_NetworkManager_unique*>(
+      static_cast<webrtc::NetworkManager*>(manager.release()));
+}
+
+WEBRTC_EXPORT void webrtc_NetworkManager_UpdateNetworks(
+    struct webrtc_NetworkManager* self,
+    struct webrtc_Network_unique** networks,
+    size_t networks_len) {
+  assert(networks != nullptr || networks_len == 0);
+  auto manager = static_cast<NetworkManagerImpl*>(
+      reinterpret_cast<webrtc::NetworkManager*>(self));
+  std::vector<std::unique_ptr<webrtc::Network>> list;
+  for (size_t i = 0; i < networks_len; ++i) {
+    assert(networks[i] != nullptr);
+    list.emplace_back(reinterpret_cast<webrtc::Network*>(networks[i]));
+  }
+  manager->UpdateNetworks(std::move(list));
+}
+
+WEBRTC_EXPORT void webrtc_NetworkManager_set_mdns_responder(
+    struct webrtc_NetworkManager* self,
+    struct webrtc_MdnsResponderInterface_unique* mdns_responder) {
+  auto manager = static_cast<NetworkManagerImpl*>(
+      reinterpret_cast<webrtc::NetworkManager*>(self));
+  manager->set_mdns_responder(std::unique_ptr<webrtc::MdnsResponderInterface>(
+      reinterpret_cast<webrtc::MdnsResponderInterface*>(mdns_responder)));
+}
+
+// -------------------------
+// webrtc::BasicNetworkManager
+// -------------------------
+
+WEBRTC_EXPORT struct webrtc_NetworkManager_unique*
+webrtc_BasicNetworkManager_new(
+    struct webrtc_Environment* env,
+    struct webrtc_Thread* network_thread,
+    struct webrtc_MdnsResponderInterface_unique* mdns_responder) {
+  assert(env != nullptr);
+  assert(network_thread != nullptr);
+  auto manager = std::make_unique<BasicNetworkManagerImpl>(
+      *reinterpret_cast<webrtc::Environment*>(env),
+      reinterpret_cast<webrtc::Thread*>(network_thread)->socketserver(),
+      std::unique_ptr<webrtc::MdnsResponderInterface>(
+          reinterpret_cast<webrtc::MdnsResponderInterface*>(mdns_responder)));
+  return reinterpret_cast<struct webrtc_NetworkManager_unique*>(
+      static_cast<webrtc::NetworkManager*>(manager.release()));
+}
+}
@@ -0,0 +1,101 @@
+#pragma once
+
+#include <stddef.h>
+
+#include "../common.h"
+#include "mdns_responder_interface.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::AdapterType
+// -------------------------
+
+typedef int webrtc_AdapterType;
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kUnknown;
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kEthernet;
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kWifi;
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kCellular;
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kVpn;
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kLoopback;
+WEBRTC_EXPORT extern const int webrtc_AdapterType_kAny;
+
+// -------------------------
+// webrtc::Network
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_Network);
+
+// prefix が IP アドレスとして解釈できない場合は null を返す。
+WEBRTC_EXPORT struct webrtc_Network_unique* webrtc_Network_new(
+    const char* name,
+    size_t name_len,
+    const char* description,
+    size_t description_len,
+    const char* prefix,
+    size_t prefix_len,
+    int prefix_length,
+    webrtc_AdapterType type);
+// ip が IP アドレスとして解釈できない場合は 0 を返す。
+WEBRTC_EXPORT int webrtc_Network_AddIP(struct webrtc_Network* self,
+                                       const char* ip,
+                                       size_t ip_len);
+
+// -------------------------
+// webrtc::NetworkManager
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_NetworkManager);
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// コールバックは network thread から呼ばれる。
+// StartUpdating の中で webrtc_NetworkManager_UpdateNetworks を呼ぶと、
+// その一覧がネットワーク変更として通知される。
+struct webrtc_NetworkManager_cbs {
+  void (*StartUpdating)(struct webrtc_NetworkManager* self, void* user_data);
+  void (*StopUpdating)(void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_NetworkManager_unique* webrtc_NetworkManager_new(
+    const struct webrtc_NetworkManager_cbs* cbs,
+    void* user_data);
+
+// webrtc_NetworkManager_new で生成したものに対して network thread から
+// 呼び出すこと。networks の各要素の所有権は self へ移る。
+WEBRTC_EXPORT void webrtc_NetworkManager_UpdateNetworks(
+    struct webrtc_NetworkManager* self,
+    struct webrtc_Network_unique** networks,
+    size_t networks_len);
+
+// webrtc_NetworkManager_new で生成したものに対してのみ呼び出せる。
+// mdns_responder を設定するとホスト candidate の IP アドレスが mDNS 名で
+// 隠蔽される。null を渡すと隠蔽しない (既定)。
+// mdns_responder の所有権は self へ移る。
+WEBRTC_EXPORT void webrtc_NetworkManager_set_mdns_responder(
+    struct webrtc_NetworkManager* self,
+    struct webrtc_MdnsResponderInterface_unique* mdns_responder);
+
+// -------------------------
+// webrtc::BasicNetworkManager
+// -------------------------
+
+struct webrtc_Environment;
+struct webrtc_Thread;
+// ConnectionContext が既定で使うものと同じ webrtc::BasicNetworkManager を
+// 生成する。env はコピーされる。network_thread の SocketServer を使うため、
+// network_thread は生成したものより長く生存させること。
+// mdns_responder を渡すとホスト candidate の IP アドレスが mDNS 名で
+// 隠蔽される。null を渡すと隠蔽しない。mdns_responder の所有権は移る。
+WEBRTC_EXPORT struct webrtc_NetworkManager_unique*
+webrtc_BasicNetworkManager_new(
+    struct webrtc_Environment* env,
+    struct webrtc_Thread* network_thread,
+    struct webrtc_MdnsResponderInterface_unique* mdns_responder);
+
+#if defined(__cplusplus)
+}
+#endif
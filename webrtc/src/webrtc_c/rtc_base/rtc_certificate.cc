@@ -0,0 +1,113 @@
+#include "rtc_certificate.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <memory>
+#include <string>
+#include <vector>
+
+// WebRTC
+#include <api/scoped_refptr.h>
+#include <rtc_base/rtc_certificate.h>
+#include <rtc_base/ssl_identity.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+#include "ssl_certificate.h"
+#include "ssl_identity.h"
+
+// -------------------------
+// webrtc::RTCCertificate
+// -------------------------
+
+extern "C" {
+WEBRTC_DEFINE_REFCOUNTED(webrtc_RTCCertificate, webrtc::RTCCertificate);
+WEBRTC_DEFINE_REFCOUNTED_VECTOR(webrtc_RTCCertificate, webrtc::RTCCertificate);
+
+WEBRTC_EXPORT struct webrtc_RTCCertificate_refcounted*
+webrtc_RTCCertificate_Create(struct webrtc_SSLIdentity_unique* identity) {
+  auto ssl_identity = std::unique_ptr<webrtc::SSLIdentity>(
+      reinterpret_cast<webrtc::SSLIdentity*>(
+          webrtc_SSLIdentity_unique_get(identity)));
+  auto certificate = webrtc::RTCCertificate::Create(std::move(ssl_identity));
+  return reinterpret_cast<struct webrtc_RTCCertificate_refcounted*>(
+      certificate.release());
+}
+WEBRTC_EXPORT uint64_t
+webrtc_RTCCertificate_Expires(const struct webrtc_RTCCertificate* self) {
+  auto certificate = reinterpret_cast<const webrtc::RTCCertificate*>(self);
+  return certificate->Expires();
+}
+WEBRTC_EXPORT int webrtc_RTCCertificate_HasExpired(
+    const struct webrtc_RTCCertificate* self,
+    uint64_t now) {
+  auto certificate = reinterpret_cast<const webrtc::RTCCertificate*>(self);
+  return certificate->HasExpired(now) ? 1 : 0;
+}
+WEBRTC_EXPORT const struct webrtc_SSLCertificate*
+webrtc_RTCCertificate_GetSSLCertificate(
+    const struct webrtc_RTCCertificate* self) {
+  auto certificate = reinterpret_cast<const webrtc::RTCCertificate*>(self);
+  return reinterpret_cast<const struct webrtc_SSLCertificate*>(
+      &certificate->GetSSLCertificate());
+}
+WEBRTC_EXPORT struct webrtc_RTCCertificatePEM* webrtc_RTCCertificate_ToPEM(
+    const struct webrtc_RTCCertificate* self) {
+  auto certificate = reinterpret_cast<const webrtc::RTCCertificate*>(self);
+  auto pem = new webrtc::RTCCertificatePEM(certificate->ToPEM());
+  return reinterpret_cast<struct webrtc_RTCCertificatePEM*>(pem);
+}
+WEBRTC_EXPORT struct webrtc_RTCCertificate_refcounted*
+webrtc_RTCCertificate_FromPEM(const struct webrtc_RTCCertificatePEM* pem) {
+  auto cpp_pem = reinterpret_cast<const webrtc::RTCCertificatePEM*>(pem);
+  auto certificate = webrtc::RTCCertificate::FromPEM(*cpp_pem);
+  if (!certificate) {
+    return nullptr;
+  }
+  return reinterpret_cast<struct webrtc_RTCCertificate_refcounted*>(
+      certificate.release());
+}
+
+// -------------------------
+// webrtc::RTCCertificatePEM
+// -------------------------
+
+WEBRTC_EXPORT struct webrtc_RTCCertificatePEM* webrtc_RTCCertificatePEM_new(
+    const char* private_key,
+    size_t private_key_len,
+    const char* certificate,
+    size_t certificate_len) {
+  auto pem = new webrtc::RTCCertificatePEM(
+      std::string(private_key, private_key_len),
+      std::string(certificate, certificate_len));
+  return reinterpret_cast<struct webrtc_RTCCertificatePEM*>(pem);
+}
+WEBRTC_EXPORT void webrtc_RTCCertificatePEM_delete(
+    struct webrtc_RTCCertificatePEM* self) {
+  auto pem = reinterpret_cast<webrtc::RTCCertificatePEM*>(self);
+  delete pem;
+}
+WEBRTC_EXPORT void webrtc_RTCCertificatePEM_private_key(
+    const struct webrtc_RTCCertificatePEM* self,
+    const char** out_private_key,
+    size_t* out_private_key_len) {
+  assert(out_private_key != nullptr);
+  assert(out_private_key_len != nullptr);
+  auto pem = reinterpret_cast<const webrtc::RTCCertificatePEM*>(self);
+  const auto& private_key = pem->private_key();
+  *out_private_key = private_key.data();
+  *out_private_key_len = private_key.size();
+}
+WEBRTC_EXPORT void webrtc_RTCCertificatePEM_certificate(
+    const struct webrtc_RTCCertificatePEM* self,
+    const char** out_certificate,
+    size_t* out_certificate_len) {
+  assert(out_certificate != nullptr);
+  assert(out_certificate_len != nullptr);
+  auto pem = reinterpret_cast<const webrtc::RTCCertificatePEM*>(self);
+  const auto& certificate = pem->certificate();
+  *out_certificate = certificate.data();
+  *out_certificate_len = certificate.size();
+}
+}
@@ -0,0 +1,63 @@
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+#include "../common.h"
+#include "ssl_certificate.h"
+#include "ssl_identity.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::RTCCertificate
+// -------------------------
+
+WEBRTC_DECLARE_REFCOUNTED(webrtc_RTCCertificate);
+WEBRTC_DECLARE_REFCOUNTED_VECTOR(webrtc_RTCCertificate);
+
+struct webrtc_RTCCertificatePEM;
+
+// identity の所有権は RTCCertificate に移る
+WEBRTC_EXPORT struct webrtc_RTCCertificate_refcounted*
+webrtc_RTCCertificate_Create(struct webrtc_SSLIdentity_unique* identity);
+WEBRTC_EXPORT uint64_t
+webrtc_RTCCertificate_Expires(const struct webrtc_RTCCertificate* self);
+WEBRTC_EXPORT int webrtc_RTCCertificate_HasExpired(
+    const struct webrtc_RTCCertificate* self,
+    uint64_t now);
+WEBRTC_EXPORT const struct webrtc_SSLCertificate*
+webrtc_RTCCertificate_GetSSLCertificate(
+    const struct webrtc_RTCCertificate* self);
+// 戻り値は webrtc_RTCCertificatePEM_delete で解放すること
+WEBRTC_EXPORT struct webrtc_RTCCertificatePEM* webrtc_RTCCertificate_ToPEM(
+    const struct webrtc_RTCCertificate* self);
+// 不正な PEM の場合は null を返す
+WEBRTC_EXPORT struct webrtc_RTCCertificate_refcounted*
+webrtc_RTCCertificate_FromPEM(const struct webrtc_RTCCertificatePEM* pem);
+
+// -------------------------
+// webrtc::RTCCertificatePEM
+// -------------------------
+
+WEBRTC_EXPORT struct webrtc_RTCCertificatePEM* webrtc_RTCCertificatePEM_new(
+    const char* private_key,
+    size_t private_key_len,
+    const char* certificate,
+    size_t certificate_len);
+WEBRTC_EXPORT void webrtc_RTCCertificatePEM_delete(
+    struct webrtc_RTCCertificatePEM* self);
+WEBRTC_EXPORT void webrtc_RTCCertificatePEM_private_key(
+    const struct webrtc_RTCCertificatePEM* self,
+    const char** out_private_key,
+    size_t* out_private_key_len);
+WEBRTC_EXPORT void webrtc_RTCCertificatePEM_certificate(
+    const struct webrtc_RTCCertificatePEM* self,
+    const char** out_certificate,
+    size_t* out_certificate_len);
+
+#if defined(__cplusplus)
+}
+#endif
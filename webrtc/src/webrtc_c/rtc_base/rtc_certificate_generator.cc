@@ -0,0 +1,37 @@
+#include "rtc_certificate_generator.h"
+
+#include <stdint.h>
+#include <optional>
+
+// WebRTC
+#include <api/scoped_refptr.h>
+#include <rtc_base/rtc_certificate.h>
+#include <rtc_base/rtc_certificate_generator.h>
+#include <rtc_base/ssl_identity.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+#include "../std.impl.h"
+
+// -------------------------
+// webrtc::RTCCertificateGenerator
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT struct webrtc_RTCCertificate_refcounted*
+webrtc_RTCCertificateGenerator_GenerateCertificate(
+    const struct webrtc_KeyParams* key_params,
+    int has_expires_ms,
+    const uint64_t* expires_ms) {
+  auto params = reinterpret_cast<const webrtc::KeyParams*>(key_params);
+  std::optional<uint64_t> expires;
+  webrtc_c::OptionalSet(expires, has_expires_ms, expires_ms);
+  auto certificate =
+      webrtc::RTCCertificateGenerator::GenerateCertificate(*params, expires);
+  if (!certificate) {
+    return nullptr;
+  }
+  return reinterpret_cast<struct webrtc_RTCCertificate_refcounted*>(
+      certificate.release());
+}
+}
@@ -0,0 +1,26 @@
+#pragma once
+
+#include <stdint.h>
+
+#include "../common.h"
+#include "rtc_certificate.h"
+#include "ssl_identity.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::RTCCertificateGenerator
+// -------------------------
+
+// 生成に失敗した場合は null を返す
+WEBRTC_EXPORT struct webrtc_RTCCertificate_refcounted*
+webrtc_RTCCertificateGenerator_GenerateCertificate(
+    const struct webrtc_KeyParams* key_params,
+    int has_expires_ms,
+    const uint64_t* expires_ms);
+
+#if defined(__cplusplus)
+}
+#endif
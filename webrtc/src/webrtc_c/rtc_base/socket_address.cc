@@ -0,0 +1,91 @@
+#include "socket_address.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <memory>
+#include <string>
+
+// WebRTC
+#include <absl/strings/string_view.h>
+#include <rtc_base/ip_address.h>
+#include <rtc_base/socket_address.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+#include "../std.h"
+
+// -------------------------
+// webrtc::SocketAddress
+// -------------------------
+
+extern "C" {
+WEBRTC_EXPORT extern const int webrtc_AF_INET = AF_INET;
+WEBRTC_EXPORT extern const int webrtc_AF_INET6 = AF_INET6;
+
+WEBRTC_EXPORT struct webrtc_SocketAddress* webrtc_SocketAddress_new(
+    const char* hostname,
+    size_t hostname_len,
+    int port) {
+  auto address = new webrtc::SocketAddress(
+      absl::string_view(hostname, hostname_len), port);
+  return reinterpret_cast<struct webrtc_SocketAddress*>(address);
+}
+WEBRTC_EXPORT void webrtc_SocketAddress_delete(
+    struct webrtc_SocketAddress* self) {
+  auto address = reinterpret_cast<webrtc::SocketAddress*>(self);
+  delete address;
+}
+WEBRTC_EXPORT void webrtc_SocketAddress_hostname(
+    const struct webrtc_SocketAddress* self,
+    const char** out_hostname,
+    size_t* out_hostname_len) {
+  assert(out_hostname != nullptr);
+  assert(out_hostname_len != nullptr);
+  auto address = reinterpret_cast<const webrtc::SocketAddress*>(self);
+  const auto& hostname = address->hostname();
+  *out_hostname = hostname.data();
+  *out_hostname_len = hostname.size();
+}
+WEBRTC_EXPORT int webrtc_SocketAddress_port(
+    const struct webrtc_SocketAddress* self) {
+  auto address = reinterpret_cast<const webrtc::SocketAddress*>(self);
+  return address->port();
+}
+WEBRTC_EXPORT int webrtc_SocketAddress_family(
+    const struct webrtc_SocketAddress* self) {
+  auto address = reinterpret_cast<const webrtc::SocketAddress*>(self);
+  return address->family();
+}
+WEBRTC_EXPORT int webrtc_SocketAddress_IsAnyIP(
+    const struct webrtc_SocketAddress* self) {
+  auto address = reinterpret_cast<const webrtc::SocketAddress*>(self);
+  return address->IsAnyIP() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_SocketAddress_IsLoopbackIP(
+    const struct webrtc_SocketAddress* self) {
+  auto address = reinterpret_cast<const webrtc::SocketAddress*>(self);
+  return address->IsLoopbackIP() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_SocketAddress_IsPrivateIP(
+    const struct webrtc_SocketAddress* self) {
+  auto address = reinterpret_cast<const webrtc::SocketAddress*>(self);
+  return address->IsPrivateIP() ? 1 : 0;
+}
+WEBRTC_EXPORT int webrtc_SocketAddress_IsUnresolvedIP(
+    const struct webrtc_SocketAddress* self) {
+  auto address = reinterpret_cast<const webrtc::SocketAddress*>(self);
+  return address->IsUnresolvedIP() ? 1 : 0;
+}
+WEBRTC_EXPORT struct std_string_unique* webrtc_SocketAddress_HostAsURIString(
+    const struct webrtc_SocketAddress* self) {
+  auto address = reinterpret_cast<const webrtc::SocketAddress*>(self);
+  auto str = std::make_unique<std::string>(address->HostAsURIString());
+  return reinterpret_cast<struct std_string_unique*>(str.release());
+}
+WEBRTC_EXPORT struct std_string_unique* webrtc_SocketAddress_ToString(
+    const struct webrtc_SocketAddress* self) {
+  auto address = reinterpret_cast<const webrtc::SocketAddress*>(self);
+  auto str = std::make_unique<std::string>(address->ToString());
+  return reinterpret_cast<struct std_string_unique*>(str.release());
+}
+}
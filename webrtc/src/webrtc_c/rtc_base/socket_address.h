@@ -0,0 +1,50 @@
+#pragma once
+
+#include <stddef.h>
+
+#include "../common.h"
+#include "../std.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::SocketAddress
+// -------------------------
+
+// SocketAddress::family() と比較するためのアドレスファミリー
+WEBRTC_EXPORT extern const int webrtc_AF_INET;
+WEBRTC_EXPORT extern const int webrtc_AF_INET6;
+
+struct webrtc_SocketAddress;
+WEBRTC_EXPORT struct webrtc_SocketAddress* webrtc_SocketAddress_new(
+    const char* hostname,
+    size_t hostname_len,
+    int port);
+WEBRTC_EXPORT void webrtc_SocketAddress_delete(
+    struct webrtc_SocketAddress* self);
+WEBRTC_EXPORT void webrtc_SocketAddress_hostname(
+    const struct webrtc_SocketAddress* self,
+    const char** out_hostname,
+    size_t* out_hostname_len);
+WEBRTC_EXPORT int webrtc_SocketAddress_port(
+    const struct webrtc_SocketAddress* self);
+WEBRTC_EXPORT int webrtc_SocketAddress_family(
+    const struct webrtc_SocketAddress* self);
+WEBRTC_EXPORT int webrtc_SocketAddress_IsAnyIP(
+    const struct webrtc_SocketAddress* self);
+WEBRTC_EXPORT int webrtc_SocketAddress_IsLoopbackIP(
+    const struct webrtc_SocketAddress* self);
+WEBRTC_EXPORT int webrtc_SocketAddress_IsPrivateIP(
+    const struct webrtc_SocketAddress* self);
+WEBRTC_EXPORT int webrtc_SocketAddress_IsUnresolvedIP(
+    const struct webrtc_SocketAddress* self);
+WEBRTC_EXPORT struct std_string_unique* webrtc_SocketAddress_HostAsURIString(
+    const struct webrtc_SocketAddress* self);
+WEBRTC_EXPORT struct std_string_unique* webrtc_SocketAddress_ToString(
+    const struct webrtc_SocketAddress* self);
+
+#if defined(__cplusplus)
+}
+#endif
@@ -0,0 +1,61 @@
+#include "ssl_fingerprint.h"
+
+#include <assert.h>
+#include <stddef.h>
+#include <memory>
+#include <string>
+
+// WebRTC
+#include <absl/strings/string_view.h>
+#include <rtc_base/rtc_certificate.h>
+#include <rtc_base/ssl_certificate.h>
+#include <rtc_base/ssl_fingerprint.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+#include "../std.h"
+
+// -------------------------
+// webrtc::SSLFingerprint
+// -------------------------
+
+extern "C" {
+WEBRTC_DEFINE_UNIQUE(webrtc_SSLFingerprint, webrtc::SSLFingerprint);
+
+WEBRTC_EXPORT struct webrtc_SSLFingerprint_unique* webrtc_SSLFingerprint_Create(
+    const char* algorithm,
+    size_t algorithm_len,
+    const struct webrtc_SSLCertificate* cert) {
+  auto cpp_cert = reinterpret_cast<const webrtc::SSLCertificate*>(cert);
+  auto fingerprint = webrtc::SSLFingerprint::Create(
+      absl::string_view(algorithm, algorithm_len), *cpp_cert);
+  return reinterpret_cast<struct webrtc_SSLFingerprint_unique*>(
+      fingerprint.release());
+}
+WEBRTC_EXPORT struct webrtc_SSLFingerprint_unique*
+webrtc_SSLFingerprint_CreateFromCertificate(
+    const struct webrtc_RTCCertificate* cert) {
+  auto cpp_cert = reinterpret_cast<const webrtc::RTCCertificate*>(cert);
+  auto fingerprint = webrtc::SSLFingerprint::CreateFromCertificate(*cpp_cert);
+  return reinterpret_cast<struct webrtc_SSLFingerprint_unique*>(
+      fingerprint.release());
+}
+WEBRTC_EXPORT struct std_string_unique*
+webrtc_SSLFingerprint_GetRfc4572Fingerprint(
+    const struct webrtc_SSLFingerprint* self) {
+  auto fingerprint = reinterpret_cast<const webrtc::SSLFingerprint*>(self);
+  auto str =
+      std::make_unique<std::string>(fingerprint->GetRfc4572Fingerprint());
+  return reinterpret_cast<struct std_string_unique*>(str.release());
+}
+WEBRTC_EXPORT void webrtc_SSLFingerprint_get_algorithm(
+    const struct webrtc_SSLFingerprint* self,
+    const char** out_algorithm,
+    size_t* out_algorithm_len) {
+  assert(out_algorithm != nullptr);
+  assert(out_algorithm_len != nullptr);
+  auto fingerprint = reinterpret_cast<const webrtc::SSLFingerprint*>(self);
+  *out_algorithm = fingerprint->algorithm.data();
+  *out_algorithm_len = fingerprint->algorithm.size();
+}
+}
@@ -0,0 +1,39 @@
+#pragma once
+
+#include <stddef.h>
+
+#include "../common.h"
+#include "../std.h"
+#include "rtc_certificate.h"
+#include "ssl_certificate.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::SSLFingerprint
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_SSLFingerprint);
+
+// 未対応のアルゴリズムの場合は null を返す
+WEBRTC_EXPORT struct webrtc_SSLFingerprint_unique* webrtc_SSLFingerprint_Create(
+    const char* algorithm,
+    size_t algorithm_len,
+    const struct webrtc_SSLCertificate* cert);
+// 証明書の署名アルゴリズムに対応するダイジェストで fingerprint を生成する
+WEBRTC_EXPORT struct webrtc_SSLFingerprint_unique*
+webrtc_SSLFingerprint_CreateFromCertificate(
+    const struct webrtc_RTCCertificate* cert);
+WEBRTC_EXPORT struct std_string_unique*
+webrtc_SSLFingerprint_GetRfc4572Fingerprint(
+    const struct webrtc_SSLFingerprint* self);
+WEBRTC_EXPORT void webrtc_SSLFingerprint_get_algorithm(
+    const struct webrtc_SSLFingerprint* self,
+    const char** out_algorithm,
+    size_t* out_algorithm_len);
+
+#if defined(__cplusplus)
+}
+#endif
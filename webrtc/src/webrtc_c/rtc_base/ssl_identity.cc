@@ -11,6 +11,39 @@
 #include "../common.impl.h"
 
 extern "C" {
+// -------------------------
+// webrtc::KeyParams
+// -------------------------
+
+WEBRTC_EXPORT extern const int webrtc_ECCurve_EC_NIST_P256 =
+    static_cast<int>(webrtc::EC_NIST_P256);
+
+WEBRTC_EXPORT struct webrtc_KeyParams* webrtc_KeyParams_RSA(int mod_size,
+                                                            int pub_exp) {
+  auto params =
+      new webrtc::KeyParams(webrtc::KeyParams::RSA(mod_size, pub_exp));
+  return reinterpret_cast<struct webrtc_KeyParams*>(params);
+}
+WEBRTC_EXPORT struct webrtc_KeyParams* webrtc_KeyParams_ECDSA(
+    webrtc_ECCurve curve) {
+  auto params = new webrtc::KeyParams(
+      webrtc::KeyParams::ECDSA(static_cast<webrtc::ECCurve>(curve)));
+  return reinterpret_cast<struct webrtc_KeyParams*>(params);
+}
+WEBRTC_EXPORT void webrtc_KeyParams_delete(struct webrtc_KeyParams* self) {
+  auto params = reinterpret_cast<webrtc::KeyParams*>(self);
+  delete params;
+}
+WEBRTC_EXPORT int webrtc_KeyParams_IsValid(
+    const struct webrtc_KeyParams* self) {
+  auto params = reinterpret_cast<const webrtc::KeyParams*>(self);
+  return params->IsValid() ? 1 : 0;
+}
+
+// -------------------------
+// webrtc::SSLIdentity
+// -------------------------
+
 WEBRTC_DEFINE_UNIQUE(webrtc_SSLIdentity, webrtc::SSLIdentity);
 
 WEBRTC_EXPORT struct webrtc_SSLIdentity_unique*
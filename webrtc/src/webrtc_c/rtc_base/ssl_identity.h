@@ -8,6 +8,21 @@
 extern "C" {
 #endif
 
+// -------------------------
+// webrtc::KeyParams
+// -------------------------
+
+typedef int webrtc_ECCurve;
+WEBRTC_EXPORT extern const int webrtc_ECCurve_EC_NIST_P256;
+
+struct webrtc_KeyParams;
+WEBRTC_EXPORT struct webrtc_KeyParams* webrtc_KeyParams_RSA(int mod_size,
+                                                            int pub_exp);
+WEBRTC_EXPORT struct webrtc_KeyParams* webrtc_KeyParams_ECDSA(
+    webrtc_ECCurve curve);
+WEBRTC_EXPORT void webrtc_KeyParams_delete(struct webrtc_KeyParams* self);
+WEBRTC_EXPORT int webrtc_KeyParams_IsValid(const struct webrtc_KeyParams* self);
+
 // -------------------------
 // webrtc::SSLIdentity
 // -------------------------
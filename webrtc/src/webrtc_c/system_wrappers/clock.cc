@@ -0,0 +1,72 @@
+#include "clock.h"
+
+#include <assert.h>
+#include <stdint.h>
+#include <memory>
+
+// WebRTC
+#include <api/units/timestamp.h>
+#include <system_wrappers/include/clock.h>
+#include <system_wrappers/include/ntp_time.h>
+
+#include "../common.h"
+#include "../common.impl.h"
+
+// -------------------------
+// webrtc::Clock
+// -------------------------
+
+namespace {
+
+class ClockImpl : public webrtc::Clock {
+ public:
+  ClockImpl(const struct webrtc_Clock_cbs* cbs, void* user_data)
+      : user_data_(user_data) {
+    assert(cbs != nullptr);
+    assert(cbs->CurrentTimeUs != nullptr);
+    assert(cbs->OnDestroy != nullptr);
+    cbs_ = *cbs;
+  }
+
+  ~ClockImpl() override { cbs_.OnDestroy(user_data_); }
+
+  webrtc::Timestamp CurrentTime() override {
+    return webrtc::Timestamp::Micros(cbs_.CurrentTimeUs(user_data_));
+  }
+
+  // SimulatedClock と同様に、起点を 1970-01-01 とみなして NTP 時刻に変換する。
+  webrtc::NtpTime ConvertTimestampToNtpTime(
+      webrtc::Timestamp timestamp) override {
+    constexpr int64_t kNtpJan1970Seconds = 2'208'988'800;
+    const int64_t now_us = timestamp.us();
+    const uint32_t seconds =
+        static_cast<uint32_t>(now_us / 1'000'000 + kNtpJan1970Seconds);
+    const uint32_t fractions = static_cast<uint32_t>(
+        (now_us % 1'000'000) * webrtc::NtpTime::kFractionsPerSecond /
+        1'000'000);
+    return webrtc::NtpTime(seconds, fractions);
+  }
+
+ private:
+  webrtc_Clock_cbs cbs_{};
+  void* user_data_;
+};
+
+}  // namespace
+
+extern "C" {
+
+WEBRTC_DEFINE_UNIQUE(webrtc_Clock, webrtc::Clock);
+
+WEBRTC_EXPORT struct webrtc_Clock_unique* webrtc_Clock_new(
+    const struct webrtc_Clock_cbs* cbs,
+    void* user_data) {
+  auto clock = std::make_unique<ClockImpl>(cbs, user_data);
+  return reinterpret_cast<struct webrtc_Clock_unique*>(
+      static_cast<webrtc::Clock*>(clock.release()));
+}
+WEBRTC_EXPORT int64_t webrtc_Clock_CurrentTimeUs(struct webrtc_Clock* self) {
+  auto clock = reinterpret_cast<webrtc::Clock*>(self);
+  return clock->CurrentTime().us();
+}
+}
@@ -0,0 +1,33 @@
+#pragma once
+
+#include <stdint.h>
+
+#include "../common.h"
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// -------------------------
+// webrtc::Clock
+// -------------------------
+
+WEBRTC_DECLARE_UNIQUE(webrtc_Clock);
+
+// 全コールバックは必須（null 非許容）。
+// 呼び出し側は全関数ポインタを非 null で設定しなければならない。
+// CurrentTimeUs は任意のスレッドから同時に呼ばれうる。
+struct webrtc_Clock_cbs {
+  // 現在時刻をマイクロ秒で返す。起点は任意だが単調増加でなければならない。
+  int64_t (*CurrentTimeUs)(void* user_data);
+  void (*OnDestroy)(void* user_data);
+};
+
+WEBRTC_EXPORT struct webrtc_Clock_unique* webrtc_Clock_new(
+    const struct webrtc_Clock_cbs* cbs,
+    void* user_data);
+WEBRTC_EXPORT int64_t webrtc_Clock_CurrentTimeUs(struct webrtc_Clock* self);
+
+#if defined(__cplusplus)
+}
+#endif